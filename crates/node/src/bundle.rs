@@ -13,21 +13,21 @@ use std::{
     sync::Arc,
 };
 use swc::{
-    config::SourceMapsConfig,
     resolver::{environment_resolver, paths_resolver},
     Compiler, TransformOutput,
 };
 use swc_atoms::{js_word, JsWord};
-use swc_bundler::{BundleKind, Bundler, Load, ModuleRecord, Resolve};
+use swc_bundler::{BundleKind, Bundler, ModuleRecord, Resolve};
 use swc_common::{collections::AHashMap, Span};
 use swc_ecma_ast::{
     Bool, Expr, Ident, KeyValueProp, Lit, MemberExpr, MemberProp, MetaPropExpr, MetaPropKind,
     PropName, Str,
 };
 use swc_ecma_loader::{TargetEnv, NODE_BUILTINS};
+use swc_node_bundler::loaders::swc::SwcLoader;
 
 struct ConfigItem {
-    loader: Box<dyn Load>,
+    loader: SwcLoader,
     resolver: Box<dyn Resolve>,
     static_items: StaticConfigItem,
 }
@@ -89,6 +89,7 @@ impl Task for BundleTask {
                                 .cloned(),
                         )
                         .collect(),
+                    code_splitting: self.config.static_items.config.code_splitting,
                     ..Default::default()
                 },
                 Box::new(Hook),
@@ -98,17 +99,18 @@ impl Task for BundleTask {
                 .bundle(self.config.static_items.config.entry.clone().into())
                 .convert_err()?;
 
+            let input_source_maps = self.config.loader.input_source_maps();
+
             let result = result
                 .into_iter()
                 .map(|bundle| match bundle.kind {
                     BundleKind::Named { name } | BundleKind::Lib { name } => {
                         Ok((name, bundle.module))
                     }
-                    BundleKind::Dynamic => bail!("unimplemented: dynamic code splitting"),
+                    BundleKind::Dynamic => bail!("dynamic bundles should be named by the bundler"),
                 })
                 .map(|res| {
                     res.and_then(|(k, m)| {
-                        // TODO: Source map
                         let minify = self
                             .config
                             .static_items
@@ -118,18 +120,11 @@ impl Task for BundleTask {
                             .map(|v| v.config.minify)
                             .unwrap_or(false);
 
-                        let output = self.swc.print(
+                        let output = self.swc.print_bundle(
                             &m,
-                            None,
-                            None,
-                            true,
                             codegen_target,
-                            SourceMapsConfig::Bool(true),
-                            // TODO
-                            &Default::default(),
-                            None,
                             minify,
-                            None,
+                            &input_source_maps,
                         )?;
 
                         Ok((k, output))
@@ -188,7 +183,7 @@ pub(crate) fn bundle(
 
     let static_items: StaticConfigItem = get_deserialized(&conf_items)?;
 
-    let loader = Box::new(swc_node_bundler::loaders::swc::SwcLoader::new(
+    let loader = SwcLoader::new(
         c.clone(),
         static_items
             .config
@@ -198,7 +193,7 @@ pub(crate) fn bundle(
             .unwrap_or_else(|| {
                 serde_json::from_value(serde_json::Value::Object(Default::default())).unwrap()
             }),
-    ));
+    );
 
     let target_env = static_items.config.target;
 
@@ -220,6 +215,12 @@ pub(crate) fn bundle(
         .cloned()
         .unwrap_or_default();
 
+    let conditions = static_items
+        .config
+        .resolve
+        .as_ref()
+        .and_then(|resolve| resolve.condition_names.clone());
+
     let resolver: Box<dyn Resolve> = if let Some((base_url, paths)) = paths {
         Box::new(paths_resolver(
             target_env,
@@ -227,12 +228,14 @@ pub(crate) fn bundle(
             base_url,
             paths,
             static_items.config.preserve_symlinks,
+            conditions,
         ))
     } else {
         Box::new(environment_resolver(
             target_env,
             alias,
             static_items.config.preserve_symlinks,
+            conditions,
         ))
     };
 
@@ -45,6 +45,7 @@ impl Task for PrintTask {
                 None,
                 options.config.minify,
                 None,
+                None,
             )
             .convert_err()
     }
@@ -98,6 +99,7 @@ pub fn print_sync(program: String, options: Buffer) -> napi::Result<TransformOut
         None,
         options.config.minify,
         None,
+        None,
     )
     .convert_err()
 }
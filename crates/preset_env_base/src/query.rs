@@ -0,0 +1,172 @@
+use crate::{version::Version, BrowserData, Versions};
+use anyhow::{bail, Context, Error};
+use dashmap::DashMap;
+use from_variant::FromVariant;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use st_map::StaticMap;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Deserialize, FromVariant)]
+#[serde(untagged)]
+pub enum Targets {
+    Query(Query),
+    EsModules(EsModules),
+    Versions(Versions),
+    /// `browsers` is a query, and other keys are versions of browsers which
+    /// override the result of the query.
+    HashMap(HashMap<String, QueryOrVersion, ahash::RandomState>),
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct EsModules {
+    esmodules: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, FromVariant)]
+#[serde(untagged)]
+pub enum QueryOrVersion {
+    /// Tried first, so that `"79"` is not treated as a query.
+    Version(Version),
+    Query(Query),
+}
+
+#[derive(Debug, Clone, Deserialize, FromVariant, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[serde(untagged)]
+pub enum Query {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+type QueryResult = Result<Versions, Error>;
+
+impl Query {
+    pub fn exec(&self) -> QueryResult {
+        fn query<T>(s: &[T]) -> QueryResult
+        where
+            T: AsRef<str>,
+        {
+            let distribs = browserslist::resolve(
+                s,
+                browserslist::Opts::new()
+                    .mobile_to_desktop(true)
+                    .ignore_unknown_versions(true),
+            )
+            .with_context(|| {
+                format!(
+                    "failed to resolve browserslist query: {:?}",
+                    s.iter().map(|v| v.as_ref()).collect::<Vec<_>>()
+                )
+            })?;
+
+            BrowserData::parse_versions(distribs).context("failed to parse browser version")
+        }
+
+        static CACHE: Lazy<DashMap<Query, Versions, ahash::RandomState>> =
+            Lazy::new(Default::default);
+
+        if let Some(v) = CACHE.get(self) {
+            return Ok(*v);
+        }
+
+        let result = match *self {
+            Query::Single(ref s) => {
+                if s.is_empty() {
+                    query(&["defaults"])
+                } else {
+                    query(&[s])
+                }
+            }
+            Query::Multiple(ref s) => query(s),
+        }
+        .context("failed to execute query")?;
+
+        CACHE.insert(self.clone(), result);
+
+        Ok(result)
+    }
+}
+
+pub fn targets_to_versions(v: Option<Targets>) -> Result<Versions, Error> {
+    match v {
+        None => Ok(Default::default()),
+        Some(Targets::Versions(v)) => Ok(v),
+        Some(Targets::Query(q)) => q
+            .exec()
+            .context("failed to convert target query to version data"),
+        Some(Targets::HashMap(mut map)) => {
+            let mut versions = match map.remove("browsers") {
+                Some(QueryOrVersion::Query(q)) => q
+                    .exec()
+                    .context("failed to convert target query to version data")?,
+                Some(QueryOrVersion::Version(v)) => {
+                    bail!("`browsers` should be a query, but got a version: {:?}", v)
+                }
+                None => Default::default(),
+            };
+
+            for (browser, version) in map {
+                let version = match version {
+                    QueryOrVersion::Version(v) => v,
+                    QueryOrVersion::Query(q) => {
+                        bail!(
+                            "`{}` should be a version, but got a query: {:?}",
+                            browser,
+                            q
+                        )
+                    }
+                };
+
+                match versions.iter_mut().find(|(k, _)| *k == browser) {
+                    Some((_, v)) => *v = Some(version),
+                    None => bail!("unknown browser: {}", browser),
+                }
+            }
+
+            Ok(versions)
+        }
+        Some(Targets::EsModules(..)) => bail!("`esmodules` is not supported as targets"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        let res = Query::Single("".into()).exec().unwrap();
+        assert!(
+            !res.is_any_target(),
+            "empty query should return non-empty result"
+        );
+    }
+
+    #[test]
+    fn versions_in_map() {
+        let targets: Targets = serde_json::from_str(r#"{ "chrome": "79" }"#).unwrap();
+        let versions = targets_to_versions(Some(targets)).unwrap();
+        assert_eq!(versions.chrome, Some("79".parse().unwrap()));
+
+        let targets: Targets = serde_json::from_str(r#"{ "chrome": 79.1, "ie": "11" }"#).unwrap();
+        let versions = targets_to_versions(Some(targets)).unwrap();
+        assert_eq!(versions.chrome, Some("79.1".parse().unwrap()));
+        assert_eq!(versions.ie, Some("11".parse().unwrap()));
+    }
+
+    #[test]
+    fn versions_override_query() {
+        let targets: Targets =
+            serde_json::from_str(r#"{ "browsers": "chrome 80", "node": "12.0" }"#).unwrap();
+        let versions = targets_to_versions(Some(targets)).unwrap();
+        assert_eq!(versions.chrome, Some("80".parse().unwrap()));
+        assert_eq!(versions.node, Some("12".parse().unwrap()));
+    }
+
+    #[test]
+    fn unknown_browser() {
+        let targets: Targets =
+            serde_json::from_str(r#"{ "browsers": "chrome 80", "foo": "1" }"#).unwrap();
+        assert!(targets_to_versions(Some(targets)).is_err());
+    }
+}
@@ -106,6 +106,7 @@ fn bench_codegen(b: &mut Bencher, _target: EsVersion) {
                 None,
                 false,
                 None,
+                None,
             )
             .unwrap(),
         );
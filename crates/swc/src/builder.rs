@@ -2,6 +2,7 @@ use crate::{
     config::{util::BoolOrObject, CompiledPaths, GlobalPassOption, JsMinifyOptions, ModuleConfig},
     SwcComments,
 };
+use anyhow::Error;
 use compat::{es2015::regenerator, es2020::export_namespace_from};
 use either::Either;
 use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc, sync::Arc};
@@ -161,7 +162,7 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
         syntax: Syntax,
         module: Option<ModuleConfig>,
         comments: Option<&'cmt SwcComments>,
-    ) -> impl 'cmt + swc_ecma_visit::Fold
+    ) -> Result<impl 'cmt + swc_ecma_visit::Fold, Error>
     where
         P: 'cmt,
     {
@@ -169,7 +170,7 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
             Some(ModuleConfig::CommonJs(ref c)) => !c.no_interop,
             Some(ModuleConfig::Amd(ref c)) => !c.config.no_interop,
             Some(ModuleConfig::Umd(ref c)) => !c.config.no_interop,
-            Some(ModuleConfig::Es6) | None => false,
+            Some(ModuleConfig::SystemJs(..)) | Some(ModuleConfig::Es6) | None => false,
         };
 
         // compat
@@ -178,11 +179,20 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
                 self.top_level_mark,
                 comments,
                 env,
-            ))
+                self.assumptions,
+            )?)
         } else {
             Either::Right(chain!(
                 Optional::new(
-                    compat::es2022::es2022(compat::es2022::Config { loose: self.loose }),
+                    compat::es2022::es2022(compat::es2022::Config {
+                        class_properties: compat::es2022::class_properties::Config {
+                            set_public_class_fields: self.assumptions.set_public_class_fields,
+                            private_fields_as_properties: self
+                                .assumptions
+                                .private_fields_as_properties,
+                        },
+                        ..Default::default()
+                    }),
                     should_enable(self.target, EsVersion::Es2022)
                 ),
                 Optional::new(
@@ -208,8 +218,8 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
                 Optional::new(
                     compat::es2018(compat::es2018::Config {
                         object_rest_spread: compat::es2018::object_rest_spread::Config {
-                            no_symbol: self.loose,
-                            set_property: self.loose
+                            no_symbol: self.assumptions.object_rest_no_symbols,
+                            set_property: self.assumptions.set_spread_properties
                         }
                     }),
                     should_enable(self.target, EsVersion::Es2018)
@@ -227,20 +237,35 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
                         self.top_level_mark,
                         comments,
                         compat::es2015::Config {
+                            classes: compat::es2015::classes::Config {
+                                constant_super: self.assumptions.constant_super,
+                                no_class_calls: self.assumptions.no_class_calls,
+                                set_class_methods: self.assumptions.set_class_methods,
+                                super_is_callable_constructor: self
+                                    .assumptions
+                                    .super_is_callable_constructor,
+                            },
                             computed_props: compat::es2015::computed_props::Config {
-                                loose: self.loose
+                                loose: self.assumptions.set_computed_properties
                             },
                             for_of: compat::es2015::for_of::Config {
-                                assume_array: self.loose
+                                assume_array: self.assumptions.iterable_is_array,
+                                skip_iterator_closing: self
+                                    .assumptions
+                                    .skip_for_of_iterator_closing
+                            },
+                            spread: compat::es2015::spread::Config {
+                                loose: self.assumptions.iterable_is_array,
+                                allow_array_like: self.assumptions.array_like_is_iterable
                             },
-                            spread: compat::es2015::spread::Config { loose: self.loose },
                             destructuring: compat::es2015::destructuring::Config {
-                                loose: self.loose
+                                loose: self.assumptions.iterable_is_array,
+                                allow_array_like: self.assumptions.array_like_is_iterable
                             },
                             regenerator: self.regenerator,
                             template_literal: compat::es2015::template_literal::Config {
-                                ignore_to_primitive: self.loose,
-                                mutable_template: self.loose
+                                ignore_to_primitive: self.assumptions.ignore_to_primitive_hint,
+                                mutable_template: self.assumptions.mutable_template_object
                             },
                             parameters: compat::es2015::parameters::Config {
                                 ignore_function_length: self.loose,
@@ -264,7 +289,7 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
             .unwrap_or(false);
 
         let module_scope = Rc::new(RefCell::new(Scope::default()));
-        chain!(
+        Ok(chain!(
             self.pass,
             Optional::new(private_in_object(), syntax.private_in_object()),
             compat_pass,
@@ -283,7 +308,8 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
                 base,
                 self.top_level_mark,
                 module,
-                Rc::clone(&module_scope)
+                Rc::clone(&module_scope),
+                self.assumptions,
             ),
             as_folder(MinifierPass {
                 options: self.minify,
@@ -296,7 +322,7 @@ impl<'a, 'b, P: swc_ecma_visit::Fold> PassBuilder<'a, 'b, P> {
                 self.hygiene.is_some() && !is_mangler_enabled
             ),
             Optional::new(fixer(comments.map(|v| v as &dyn Comments)), self.fixer),
-        )
+        ))
     }
 }
 
@@ -319,6 +345,8 @@ impl VisitMut for MinifierPass {
                     .into_obj()
                     .map(|v| v.into_config(self.cm.clone())),
                 mangle: options.mangle.clone().into_obj(),
+                wrap: options.wrap.clone(),
+                enclose: options.enclose.clone().into(),
                 ..Default::default()
             };
 
@@ -1,5 +1,9 @@
 use self::util::BoolOrObject;
-use crate::{builder::PassBuilder, plugin::PluginConfig, SwcComments, SwcImportResolver};
+use crate::{
+    builder::PassBuilder,
+    plugin::{PluginConfig, PluginErrorSlot, PluginFailurePolicy},
+    SwcComments, SwcImportResolver,
+};
 use anyhow::{bail, Context, Error};
 use dashmap::DashMap;
 use either::Either;
@@ -28,29 +32,33 @@ use swc_common::{
     FileName, Mark, SourceMap, SyntaxContext,
 };
 use swc_ecma_ast::{EsVersion, Expr, Program};
+use swc_ecma_codegen::QuoteStyle;
 use swc_ecma_ext_transforms::jest;
 use swc_ecma_lints::{
     config::LintConfig,
     rules::{lint_to_fold, LintParams},
+    suppression::Suppressions,
 };
 use swc_ecma_loader::resolvers::{
     lru::CachingResolver, node::NodeModulesResolver, tsc::TsConfigResolver,
 };
 use swc_ecma_minifier::option::{
-    terser::{TerserCompressorOptions, TerserEcmaVersion, TerserTopLevelOptions},
+    terser::{
+        TerserCompressorOptions, TerserEcmaVersion, TerserEncloseOption, TerserTopLevelOptions,
+    },
     MangleOptions, ManglePropertiesOptions,
 };
 #[allow(deprecated)]
 pub use swc_ecma_parser::JscTarget;
 use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsConfig};
 use swc_ecma_transforms::{
-    hygiene, modules,
+    flow, hygiene, modules,
     modules::{
         hoist::import_hoister, path::NodeImportResolver, rewriter::import_rewriter, util::Scope,
     },
     optimization::{const_modules, json_parse, simplifier},
     pass::{noop, Optional},
-    proposals::{decorators, export_default_from, import_assertions},
+    proposals::{auto_accessors, decorators, export_default_from, import_assertions},
     react,
     resolver::ts_resolver,
     resolver_with_mark, typescript, Assumptions,
@@ -271,6 +279,10 @@ impl Options {
         let mut source_maps = self.source_maps.clone();
         source_maps.merge(&config.source_maps);
 
+        if let Some(env) = &config.env {
+            env.validate().context("invalid `env` config")?;
+        }
+
         let JscConfig {
             assumptions,
             transform,
@@ -289,7 +301,7 @@ impl Options {
 
         let assumptions = assumptions.unwrap_or_else(|| {
             if loose {
-                Assumptions::all()
+                loose_assumptions()
             } else {
                 Assumptions::default()
             }
@@ -309,7 +321,7 @@ impl Options {
         //
         // We do this before creating custom passses, so custom passses can use the
         // variable management system based on the syntax contexts.
-        if syntax.typescript() {
+        if syntax.types() {
             program.visit_mut_with(&mut ts_resolver(top_level_mark));
         } else {
             program.visit_mut_with(&mut resolver_with_mark(top_level_mark));
@@ -354,8 +366,9 @@ impl Options {
         let regenerator = transform.regenerator.clone();
 
         let preserve_comments = js_minify.as_ref().map(|v| v.format.comments.clone());
+        let format = js_minify.as_ref().map(|v| v.format.clone());
 
-        if syntax.typescript() {
+        if syntax.typescript() && transform.decorator_version.is_none() {
             transform.legacy_decorator = true;
         }
         let optimizer = transform.optimizer;
@@ -388,6 +401,17 @@ impl Options {
 
         let top_level_ctxt = SyntaxContext::empty().apply_mark(top_level_mark);
 
+        let lint_suppressions = comments.map(|comments| {
+            let comments = comments
+                .leading
+                .iter()
+                .chain(comments.trailing.iter())
+                .flat_map(|entry| entry.value().clone())
+                .collect::<Vec<_>>();
+
+            Arc::new(Suppressions::from_comments(cm, &comments))
+        });
+
         let pass = chain!(
             const_modules,
             optimization,
@@ -415,26 +439,38 @@ impl Options {
                 syntax,
                 config.module,
                 comments,
-            );
+            )?;
+
+        let decorator_version = transform.decorator_version.unwrap_or_default();
+        // The `2022-03` version of decorators handles auto accessors by itself.
+        let lower_auto_accessors = !syntax.decorators()
+            || transform.legacy_decorator
+            || decorator_version != decorators::DecoratorVersion::V202203;
+
+        let plugin_error = PluginErrorSlot::default();
 
         let pass = chain!(
+            Optional::new(auto_accessors(), lower_auto_accessors),
             // Decorators may use type information
             Optional::new(
                 decorators(decorators::Config {
                     legacy: transform.legacy_decorator,
                     emit_metadata: transform.decorator_metadata,
+                    version: decorator_version,
                 }),
                 syntax.decorators()
             ),
             // The transform strips import assertions, so it's only enabled if
             // keep_import_assertions is false.
             Optional::new(import_assertions(), !experimental.keep_import_assertions),
+            Optional::new(flow::strip(), syntax.flow()),
             Optional::new(
                 typescript::strip_with_jsx(
                     cm.clone(),
                     typescript::Config {
                         pragma: Some(transform.react.pragma.clone()),
                         pragma_frag: Some(transform.react.pragma_frag.clone()),
+                        inline_const_enums: transform.inline_const_enums,
                         ..Default::default()
                     },
                     comments,
@@ -447,8 +483,18 @@ impl Options {
                 lint_config: &lints,
                 top_level_ctxt,
                 es_version,
+                suppressions: lint_suppressions,
             })),
-            crate::plugin::plugins(experimental),
+            crate::plugin::plugins(
+                experimental,
+                comments.cloned(),
+                cm.clone(),
+                base,
+                &self.env_name,
+                &self.cwd,
+                top_level_mark,
+                plugin_error.clone()
+            ),
             custom_before_pass(&program),
             // handle jsx
             Optional::new(
@@ -470,9 +516,11 @@ impl Options {
             source_maps: source_maps.unwrap_or(SourceMapsConfig::Bool(false)),
             inline_sources_content: config.inline_sources_content,
             input_source_map: config.input_source_map.clone(),
+            plugin_error,
             output_path: output_path.map(|v| v.to_path_buf()),
             source_file_name,
             preserve_comments,
+            format,
         })
     }
 }
@@ -710,6 +758,33 @@ pub struct JsMinifyOptions {
 
     #[serde(default = "true_by_default")]
     pub inline_sources_content: bool,
+
+    #[serde(default)]
+    pub wrap: Option<JsWord>,
+
+    #[serde(default)]
+    pub enclose: TerserEncloseOption,
+}
+
+/// Assumptions enabled by `jsc.loose`.
+///
+/// Assumptions added after `loose` are not enabled, as it would change the
+/// output of existing configs. They can be enabled with `jsc.assumptions`.
+fn loose_assumptions() -> Assumptions {
+    let mut assumptions = Assumptions::all();
+
+    assumptions.constant_reexports = false;
+    assumptions.enumerable_module_meta = false;
+    assumptions.no_incomplete_ns_import_detection = false;
+
+    assumptions.constant_super = false;
+    assumptions.no_class_calls = false;
+    assumptions.set_class_methods = false;
+    assumptions.super_is_callable_constructor = false;
+    assumptions.set_public_class_fields = false;
+    assumptions.private_fields_as_properties = false;
+
+    assumptions
 }
 
 fn true_by_default() -> bool {
@@ -734,14 +809,13 @@ pub struct TerserSourceMapOption {
 }
 
 /// `jsc.minify.format`.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct JsMinifyFormatOptions {
-    /// Not implemented yet.
     #[serde(default, alias = "ascii_only")]
     pub ascii_only: bool,
 
-    /// Not implemented yet.
+    /// If true, output is not minified even if `minify` is enabled.
     #[serde(default)]
     pub beautify: bool,
 
@@ -756,7 +830,8 @@ pub struct JsMinifyFormatOptions {
     #[serde(default)]
     pub ecma: usize,
 
-    /// Not implemented yet.
+    /// Number of spaces used for a level of indentation. `0` means the
+    /// default, which is `4`.
     #[serde(default, alias = "indent_level")]
     pub indent_level: usize,
 
@@ -764,7 +839,6 @@ pub struct JsMinifyFormatOptions {
     #[serde(default, alias = "indent_start")]
     pub indent_start: bool,
 
-    /// Not implemented yet.
     #[serde(default, alias = "inline_script")]
     pub inline_script: bool,
 
@@ -776,11 +850,10 @@ pub struct JsMinifyFormatOptions {
     #[serde(default, alias = "keep_quoted_props")]
     pub keep_quoted_props: bool,
 
-    /// Not implemented yet.
     #[serde(default, alias = "max_line_len")]
     pub max_line_len: BoolOrObject<usize>,
 
-    /// Not implemented yet.
+    /// Prepended to the output as-is.
     #[serde(default)]
     pub preamble: String,
 
@@ -788,7 +861,11 @@ pub struct JsMinifyFormatOptions {
     #[serde(default, alias = "quote_keys")]
     pub quote_keys: bool,
 
-    /// Not implemented yet.
+    /// `1` uses single quotes, `2` uses double quotes and `3` preserves the
+    /// original quotes.
+    ///
+    /// `0` is "prefer fewer escapes" in terser, which is not supported yet. The
+    /// original quotes are preserved like `3` instead.
     #[serde(default, alias = "quote_style")]
     pub quote_style: usize,
 
@@ -800,8 +877,9 @@ pub struct JsMinifyFormatOptions {
     #[serde(default)]
     pub safari10: bool,
 
-    /// Not implemented yet.
-    #[serde(default)]
+    /// If false, statements are separated by newlines instead of semicolons
+    /// where possible. Only used if the output is minified.
+    #[serde(default = "true_by_default")]
     pub semicolons: bool,
 
     /// Not implemented yet.
@@ -812,15 +890,39 @@ pub struct JsMinifyFormatOptions {
     #[serde(default)]
     pub webkit: bool,
 
-    /// Not implemented yet.
-    #[serde(default, alias = "warp_iife")]
+    #[serde(default, alias = "wrap_iife")]
     pub wrap_iife: bool,
 
-    /// Not implemented yet.
     #[serde(default, alias = "wrap_func_args")]
     pub wrap_func_args: bool,
 }
 
+impl Default for JsMinifyFormatOptions {
+    fn default() -> Self {
+        // Some options are enabled by default.
+        serde_json::from_str("{}").unwrap()
+    }
+}
+
+impl JsMinifyFormatOptions {
+    /// Creates a codegen config from the options. `minify` is not set by this
+    /// method.
+    pub fn to_codegen_config(&self) -> swc_ecma_codegen::Config {
+        swc_ecma_codegen::Config {
+            ascii_only: self.ascii_only,
+            inline_script: self.inline_script,
+            quote_style: match self.quote_style {
+                1 => QuoteStyle::AlwaysSingle,
+                2 => QuoteStyle::AlwaysDouble,
+                _ => QuoteStyle::Preserve,
+            },
+            wrap_iife: self.wrap_iife,
+            wrap_func_args: self.wrap_func_args,
+            ..Default::default()
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum JsMinifyCommentOption {
     #[serde(rename = "some")]
@@ -942,7 +1044,13 @@ pub struct BuiltInput<P: swc_ecma_visit::Fold> {
 
     pub preserve_comments: Option<BoolOrObject<JsMinifyCommentOption>>,
 
+    /// `jsc.minify.format`.
+    pub format: Option<JsMinifyFormatOptions>,
+
     pub inline_sources_content: bool,
+
+    /// Error of a plugin which should abort the build.
+    pub plugin_error: PluginErrorSlot,
 }
 
 /// `jsc` in  `.swcrc`.
@@ -1003,6 +1111,15 @@ pub struct JscExperimental {
     /// and will not be considered as breaking changes.
     #[serde(default)]
     pub cache_root: Option<String>,
+    /// What to do when a plugin fails. Defaults to `failFile`.
+    #[serde(default)]
+    pub plugin_failure: Option<PluginFailurePolicy>,
+    /// Maximum size of the memory each plugin can use, in bytes.
+    #[serde(default)]
+    pub plugin_memory_limit: Option<u64>,
+    /// Maximum number of wasm instructions each plugin can execute for a file.
+    #[serde(default)]
+    pub plugin_fuel_limit: Option<u64>,
 }
 
 impl Merge for JscExperimental {
@@ -1013,6 +1130,15 @@ impl Merge for JscExperimental {
         if self.cache_root.is_none() {
             self.cache_root = from.cache_root.clone();
         }
+        if self.plugin_failure.is_none() {
+            self.plugin_failure = from.plugin_failure;
+        }
+        if self.plugin_memory_limit.is_none() {
+            self.plugin_memory_limit = from.plugin_memory_limit;
+        }
+        if self.plugin_fuel_limit.is_none() {
+            self.plugin_fuel_limit = from.plugin_fuel_limit;
+        }
 
         self.keep_import_assertions |= from.keep_import_assertions;
     }
@@ -1032,11 +1158,14 @@ pub enum ModuleConfig {
     Umd(modules::umd::Config),
     #[serde(rename = "amd")]
     Amd(modules::amd::Config),
+    #[serde(rename = "systemjs")]
+    SystemJs(modules::system_js::Config),
     #[serde(rename = "es6")]
     Es6,
 }
 
 impl ModuleConfig {
+    #[allow(clippy::too_many_arguments)]
     pub fn build(
         cm: Arc<SourceMap>,
         base_url: PathBuf,
@@ -1045,7 +1174,15 @@ impl ModuleConfig {
         root_mark: Mark,
         config: Option<ModuleConfig>,
         scope: RustRc<RefCell<Scope>>,
+        assumptions: Assumptions,
     ) -> Box<dyn swc_ecma_visit::Fold> {
+        fn apply_assumptions(config: &mut modules::util::Config, assumptions: Assumptions) {
+            config.constant_reexports |= assumptions.constant_reexports;
+            config.enumerable_module_meta |= assumptions.enumerable_module_meta;
+            config.no_incomplete_ns_import_detection |=
+                assumptions.no_incomplete_ns_import_detection;
+        }
+
         let base = match base {
             FileName::Real(v) if !paths.is_empty() => {
                 FileName::Real(v.canonicalize().unwrap_or_else(|_| v.to_path_buf()))
@@ -1064,7 +1201,9 @@ impl ModuleConfig {
                     Box::new(chain!(import_rewriter(base, resolver), base_pass))
                 }
             }
-            Some(ModuleConfig::CommonJs(config)) => {
+            Some(ModuleConfig::CommonJs(mut config)) => {
+                apply_assumptions(&mut config, assumptions);
+
                 if paths.is_empty() {
                     Box::new(modules::common_js::common_js(
                         root_mark,
@@ -1083,7 +1222,9 @@ impl ModuleConfig {
                     ))
                 }
             }
-            Some(ModuleConfig::Umd(config)) => {
+            Some(ModuleConfig::Umd(mut config)) => {
+                apply_assumptions(&mut config.config, assumptions);
+
                 if paths.is_empty() {
                     Box::new(modules::umd::umd(cm, root_mark, config))
                 } else {
@@ -1094,7 +1235,9 @@ impl ModuleConfig {
                     ))
                 }
             }
-            Some(ModuleConfig::Amd(config)) => {
+            Some(ModuleConfig::Amd(mut config)) => {
+                apply_assumptions(&mut config.config, assumptions);
+
                 if paths.is_empty() {
                     Box::new(modules::amd::amd(config))
                 } else {
@@ -1103,6 +1246,17 @@ impl ModuleConfig {
                     Box::new(modules::amd::amd_with_resolver(resolver, base, config))
                 }
             }
+            Some(ModuleConfig::SystemJs(config)) => {
+                if paths.is_empty() {
+                    Box::new(modules::system_js::system_js(config))
+                } else {
+                    let resolver = build_resolver(base_url, paths);
+
+                    Box::new(modules::system_js::system_js_with_resolver(
+                        resolver, base, config,
+                    ))
+                }
+            }
         }
     }
 }
@@ -1125,11 +1279,20 @@ pub struct TransformConfig {
     #[serde(default)]
     pub decorator_metadata: bool,
 
+    /// The version of the decorators proposal. Ignored if
+    /// `legacy_decorator` is true.
+    #[serde(default)]
+    pub decorator_version: Option<decorators::DecoratorVersion>,
+
     #[serde(default)]
     pub hidden: HiddenTransformConfig,
 
     #[serde(default)]
     pub regenerator: regenerator::Config,
+
+    /// Inline members of `const enum`s declared in typescript files.
+    #[serde(default)]
+    pub inline_const_enums: bool,
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -1420,6 +1583,12 @@ impl Merge for JsMinifyOptions {
         self.safari10 |= from.safari10;
         self.toplevel |= from.toplevel;
         self.inline_sources_content |= from.inline_sources_content;
+        if self.wrap.is_none() {
+            self.wrap = from.wrap.clone();
+        }
+        if let TerserEncloseOption::Bool(false) = self.enclose {
+            self.enclose = from.enclose.clone();
+        }
     }
 }
 
@@ -1560,6 +1729,7 @@ impl Merge for swc_ecma_parser::EsConfig {
         self.import_assertions |= from.import_assertions;
         self.static_blocks |= from.static_blocks;
         self.private_in_object |= from.private_in_object;
+        self.auto_accessors |= from.auto_accessors;
     }
 }
 
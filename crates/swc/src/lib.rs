@@ -121,7 +121,9 @@ use common::{
     collections::AHashMap,
     errors::{EmitterWriter, HANDLER},
 };
-use config::{util::BoolOrObject, IsModule, JsMinifyCommentOption, JsMinifyOptions};
+use config::{
+    util::BoolOrObject, IsModule, JsMinifyCommentOption, JsMinifyFormatOptions, JsMinifyOptions,
+};
 use once_cell::sync::Lazy;
 use serde::Serialize;
 use serde_json::error::Category;
@@ -140,10 +142,14 @@ use swc_common::{
     input::StringInput,
     source_map::SourceMapGenConfig,
     sync::Lrc,
-    BytePos, FileName, Globals, Mark, SourceFile, SourceMap, Spanned, GLOBALS,
+    BytePos, FileName, Globals, Mark, SourceFile, SourceMap, Spanned, DUMMY_SP, GLOBALS,
 };
 use swc_ecma_ast::{EsVersion, Ident, Program};
-use swc_ecma_codegen::{self, text_writer::WriteJs, Emitter, Node};
+use swc_ecma_codegen::{
+    self,
+    text_writer::{JsWriterConfig, WriteJs},
+    Emitter, Node,
+};
 use swc_ecma_loader::resolvers::{
     lru::CachingResolver, node::NodeModulesResolver, tsc::TsConfigResolver,
 };
@@ -180,9 +186,10 @@ pub mod resolver {
         base_url: PathBuf,
         paths: CompiledPaths,
         preserve_symlinks: bool,
+        conditions: Option<Vec<String>>,
     ) -> CachingResolver<TsConfigResolver<NodeModulesResolver>> {
         let r = TsConfigResolver::new(
-            NodeModulesResolver::new(target_env, alias, preserve_symlinks),
+            node_resolver(target_env, alias, preserve_symlinks, conditions),
             base_url,
             paths,
         );
@@ -193,12 +200,26 @@ pub mod resolver {
         target_env: TargetEnv,
         alias: AHashMap<String, String>,
         preserve_symlinks: bool,
+        conditions: Option<Vec<String>>,
     ) -> NodeResolver {
         CachingResolver::new(
             40,
-            NodeModulesResolver::new(target_env, alias, preserve_symlinks),
+            node_resolver(target_env, alias, preserve_symlinks, conditions),
         )
     }
+
+    fn node_resolver(
+        target_env: TargetEnv,
+        alias: AHashMap<String, String>,
+        preserve_symlinks: bool,
+        conditions: Option<Vec<String>>,
+    ) -> NodeModulesResolver {
+        let r = NodeModulesResolver::new(target_env, alias, preserve_symlinks);
+        match conditions {
+            Some(conditions) => r.with_conditions(conditions),
+            None => r,
+        }
+    }
 }
 
 #[derive(Clone, Default)]
@@ -309,7 +330,11 @@ impl Compiler {
         GLOBALS.set(&self.globals, op)
     }
 
-    fn get_orig_src_map(
+    /// Loads the input source map of `fm`.
+    ///
+    /// If `is_default` is true, failures to open the source map file are
+    /// ignored.
+    pub fn get_orig_src_map(
         &self,
         fm: &SourceFile,
         input_src_map: &InputSourceMap,
@@ -464,6 +489,10 @@ impl Compiler {
     ///
     /// This method receives target file path, but does not write file to the
     /// path. See: https://github.com/swc-project/swc/issues/1255
+    ///
+    /// `format` is `jsc.minify.format`, and [JsMinifyFormatOptions::beautify]
+    /// takes precedence over `minify`.
+    #[allow(clippy::too_many_arguments)]
     pub fn print<T>(
         &self,
         node: &T,
@@ -476,6 +505,73 @@ impl Compiler {
         orig: Option<&sourcemap::SourceMap>,
         minify: bool,
         preserve_comments: Option<BoolOrObject<JsMinifyCommentOption>>,
+        format: Option<&JsMinifyFormatOptions>,
+    ) -> Result<TransformOutput, Error>
+    where
+        T: Node + VisitWith<IdentCollector>,
+    {
+        self.print_inner(
+            node,
+            source_file_name,
+            output_path,
+            inline_sources_content,
+            target,
+            source_map,
+            source_map_names,
+            orig,
+            None,
+            minify,
+            preserve_comments,
+            format,
+        )
+    }
+
+    /// Converts a bundle, which contains code from multiple files, to source
+    /// string and sourcemap.
+    ///
+    /// `input_source_maps` are input source maps of bundled files, and
+    /// mappings from those files are remapped to the original sources.
+    pub fn print_bundle<T>(
+        &self,
+        node: &T,
+        target: EsVersion,
+        minify: bool,
+        input_source_maps: &AHashMap<FileName, sourcemap::SourceMap>,
+    ) -> Result<TransformOutput, Error>
+    where
+        T: Node + VisitWith<IdentCollector>,
+    {
+        self.print_inner(
+            node,
+            None,
+            None,
+            true,
+            target,
+            SourceMapsConfig::Bool(true),
+            &Default::default(),
+            None,
+            Some(input_source_maps),
+            minify,
+            None,
+            None,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn print_inner<T>(
+        &self,
+        node: &T,
+        source_file_name: Option<&str>,
+        output_path: Option<PathBuf>,
+        inline_sources_content: bool,
+        target: EsVersion,
+        source_map: SourceMapsConfig,
+        source_map_names: &AHashMap<BytePos, JsWord>,
+        orig: Option<&sourcemap::SourceMap>,
+        input_source_maps: Option<&AHashMap<FileName, sourcemap::SourceMap>>,
+        minify: bool,
+        preserve_comments: Option<BoolOrObject<JsMinifyCommentOption>>,
+        format: Option<&JsMinifyFormatOptions>,
     ) -> Result<TransformOutput, Error>
     where
         T: Node + VisitWith<IdentCollector>,
@@ -489,6 +585,12 @@ impl Compiler {
                 }
             });
 
+            let default_format = JsMinifyFormatOptions::default();
+            let format = format.unwrap_or(&default_format);
+
+            // Minification of codegen.
+            let minify = minify && !format.beautify;
+
             let span = node.span();
 
             match preserve_comments {
@@ -528,10 +630,16 @@ impl Compiler {
 
             let mut src_map_buf = vec![];
 
+            let indent = if format.indent_level == 0 {
+                None
+            } else {
+                Some(" ".repeat(format.indent_level))
+            };
+
             let src = {
                 let mut buf = vec![];
                 {
-                    let mut wr = Box::new(swc_ecma_codegen::text_writer::JsWriter::with_target(
+                    let mut wr = Box::new(swc_ecma_codegen::text_writer::JsWriter::with_config(
                         self.cm.clone(),
                         "\n",
                         &mut buf,
@@ -541,14 +649,34 @@ impl Compiler {
                             None
                         },
                         target,
+                        JsWriterConfig {
+                            indent: indent.as_deref().unwrap_or("    "),
+                            max_line_len: match format.max_line_len {
+                                BoolOrObject::Obj(max_line_len) => Some(max_line_len),
+                                BoolOrObject::Bool(..) => None,
+                            },
+                        },
                     )) as Box<dyn WriteJs>;
 
+                    if !format.preamble.is_empty() {
+                        wr.write_lit(DUMMY_SP, &format.preamble)
+                            .context("failed to write preamble")?;
+                        wr.write_line().context("failed to write preamble")?;
+                    }
+
                     if minify {
-                        wr = Box::new(swc_ecma_codegen::text_writer::omit_trailing_semi(wr));
+                        wr = if format.semicolons {
+                            Box::new(swc_ecma_codegen::text_writer::omit_trailing_semi(wr))
+                        } else {
+                            Box::new(swc_ecma_codegen::text_writer::newline_as_semi(wr))
+                        };
                     }
 
                     let mut emitter = Emitter {
-                        cfg: swc_ecma_codegen::Config { minify },
+                        cfg: swc_ecma_codegen::Config {
+                            minify,
+                            ..format.to_codegen_config()
+                        },
                         comments: if minify { None } else { Some(&self.comments) },
                         cm: self.cm.clone(),
                         wr,
@@ -574,6 +702,7 @@ impl Compiler {
                                     output_path: output_path.as_deref(),
                                     names: source_map_names,
                                     inline_sources_content,
+                                    input_source_maps,
                                 },
                             )
                             .to_writer(&mut buf)
@@ -598,6 +727,7 @@ impl Compiler {
                                 output_path: output_path.as_deref(),
                                 names: source_map_names,
                                 inline_sources_content,
+                                input_source_maps,
                             },
                         )
                         .to_writer(&mut buf)
@@ -627,6 +757,9 @@ struct SwcSourceMapConfig<'a> {
     names: &'a AHashMap<BytePos, JsWord>,
 
     inline_sources_content: bool,
+
+    /// Input source maps of each file, used while printing a bundle.
+    input_source_maps: Option<&'a AHashMap<FileName, sourcemap::SourceMap>>,
 }
 
 impl SourceMapGenConfig for SwcSourceMapConfig<'_> {
@@ -665,6 +798,10 @@ impl SourceMapGenConfig for SwcSourceMapConfig<'_> {
     fn inline_sources_content(&self, _: &FileName) -> bool {
         self.inline_sources_content
     }
+
+    fn orig_source_map(&self, f: &FileName) -> Option<&sourcemap::SourceMap> {
+        self.input_source_maps?.get(f)
+    }
 }
 
 /// High-level apis.
@@ -902,7 +1039,9 @@ impl Compiler {
                 output_path: config.output_path,
                 source_file_name: config.source_file_name,
                 preserve_comments: config.preserve_comments,
+                format: config.format,
                 inline_sources_content: config.inline_sources_content,
+                plugin_error: config.plugin_error,
             };
 
             let orig = if config.source_maps.enabled() {
@@ -957,6 +1096,8 @@ impl Compiler {
                     .into_obj()
                     .map(|v| v.into_config(self.cm.clone())),
                 mangle: opts.mangle.clone().into_obj(),
+                wrap: opts.wrap.clone(),
+                enclose: opts.enclose.clone().into(),
                 ..Default::default()
             };
 
@@ -1039,6 +1180,7 @@ impl Compiler {
                 orig.as_ref(),
                 true,
                 Some(opts.format.comments.clone()),
+                Some(&opts.format),
             )
         })
     }
@@ -1084,6 +1226,10 @@ impl Compiler {
                 })
             });
 
+            if let Some(err) = config.plugin_error.take() {
+                return Err(err);
+            }
+
             self.print(
                 &program,
                 config.source_file_name.as_deref(),
@@ -1095,6 +1241,7 @@ impl Compiler {
                 orig,
                 config.minify,
                 config.preserve_comments,
+                config.format.as_ref(),
             )
         })
     }
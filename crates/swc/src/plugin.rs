@@ -3,7 +3,15 @@
 
 #![cfg_attr(not(feature = "plugin"), allow(unused))]
 
+use std::{
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Error;
+
 use serde::{Deserialize, Serialize};
+use swc_common::{FileName, Mark, SourceMap};
 #[cfg(feature = "plugin")]
 use swc_ecma_ast::*;
 #[cfg(not(feature = "plugin"))]
@@ -14,7 +22,56 @@ use swc_ecma_visit::{noop_fold_type, Fold};
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct PluginConfig(String, serde_json::Value);
 
-pub fn plugins(config: crate::config::JscExperimental) -> impl Fold {
+/// What to do when a plugin fails to transform a file, e.g. if it traps or
+/// exceeds the limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PluginFailurePolicy {
+    /// Reports an error for the file being transformed. Other files are not
+    /// affected.
+    FailFile,
+    /// Aborts the whole process.
+    FailBuild,
+}
+
+impl Default for PluginFailurePolicy {
+    fn default() -> Self {
+        PluginFailurePolicy::FailFile
+    }
+}
+
+/// Holds the error of a plugin which should fail the build.
+///
+/// Plugins are applied as a [Fold], which cannot return an error, so the error
+/// is stored here and returned by the compiler once the transform is done.
+#[derive(Debug, Clone, Default)]
+pub struct PluginErrorSlot(Arc<Mutex<Option<Error>>>);
+
+impl PluginErrorSlot {
+    /// Takes the stored error, if any.
+    pub fn take(&self) -> Option<Error> {
+        self.0.lock().expect("plugin error slot is poisoned").take()
+    }
+
+    /// Stores `err` unless an error is already stored.
+    fn set(&self, err: Error) {
+        let mut slot = self.0.lock().expect("plugin error slot is poisoned");
+        if slot.is_none() {
+            *slot = Some(err);
+        }
+    }
+}
+
+pub fn plugins(
+    config: crate::config::JscExperimental,
+    comments: Option<swc_node_comments::SwcComments>,
+    source_map: Arc<SourceMap>,
+    filename: &FileName,
+    env_name: &str,
+    cwd: &Path,
+    top_level_mark: Mark,
+    error_slot: PluginErrorSlot,
+) -> impl Fold {
     #[cfg(feature = "plugin")]
     {
         let cache_root =
@@ -23,6 +80,21 @@ pub fn plugins(config: crate::config::JscExperimental) -> impl Fold {
         RustPlugins {
             plugins: config.plugins,
             plugin_cache: cache_root,
+            comments,
+            source_map,
+            filename: match filename {
+                FileName::Anon => None,
+                _ => Some(filename.to_string()),
+            },
+            env_name: env_name.to_string(),
+            cwd: cwd.to_string_lossy().into_owned(),
+            top_level_mark,
+            failure_policy: config.plugin_failure.unwrap_or_default(),
+            error_slot,
+            limits: swc_plugin_runner::PluginLimits {
+                memory: config.plugin_memory_limit,
+                fuel: config.plugin_fuel_limit,
+            },
         }
     }
 
@@ -38,15 +110,42 @@ struct RustPlugins {
     /// swc, as well as cache.
     #[cfg(feature = "plugin")]
     plugin_cache: Option<swc_plugin_runner::resolve::PluginCache>,
+    /// Comments of the file being transformed, shared with plugins.
+    #[cfg(feature = "plugin")]
+    comments: Option<swc_node_comments::SwcComments>,
+    #[cfg(feature = "plugin")]
+    source_map: Arc<SourceMap>,
+    /// Metadata of the file being transformed, passed to plugins.
+    #[cfg(feature = "plugin")]
+    filename: Option<String>,
+    #[cfg(feature = "plugin")]
+    env_name: String,
+    #[cfg(feature = "plugin")]
+    cwd: String,
+    #[cfg(feature = "plugin")]
+    top_level_mark: Mark,
+    failure_policy: PluginFailurePolicy,
+    #[cfg(feature = "plugin")]
+    error_slot: PluginErrorSlot,
+    #[cfg(feature = "plugin")]
+    limits: swc_plugin_runner::PluginLimits,
 }
 
 impl RustPlugins {
     #[cfg(feature = "plugin")]
-    fn apply(&mut self, n: Program) -> Result<Program, anyhow::Error> {
+    fn apply(&mut self, n: &Program) -> Result<Program, Error> {
         use anyhow::Context;
-        use swc_common::plugin::Serialized;
+        use swc_common::plugin::{PluginContext, Serialized};
+
+        let plugin_context = PluginContext {
+            filename: self.filename.clone(),
+            env_name: self.env_name.clone(),
+            cwd: self.cwd.clone(),
+            is_module: matches!(n, Program::Module(..)),
+            top_level_mark: self.top_level_mark,
+        };
 
-        let mut serialized = Serialized::serialize(&n)?;
+        let mut serialized = Serialized::serialize(n)?;
 
         // Run plugin transformation against current program.
         // We do not serialize / deserialize between each plugin execution but
@@ -60,7 +159,8 @@ impl RustPlugins {
                     .context("failed to serialize plugin config as json")?;
                 let config_json = Serialized::serialize(&config_json)?;
 
-                let path = swc_plugin_runner::resolve::resolve(&p.0)?;
+                let path = swc_plugin_runner::resolve::resolve(&p.0)
+                    .with_context(|| format!("failed to resolve plugin `{}`", p.0))?;
 
                 serialized = swc_plugin_runner::apply_js_plugin(
                     &p.0,
@@ -68,6 +168,10 @@ impl RustPlugins {
                     &mut self.plugin_cache,
                     config_json,
                     serialized,
+                    self.comments.clone(),
+                    self.source_map.clone(),
+                    plugin_context.clone(),
+                    self.limits,
                 )?;
             }
         }
@@ -76,6 +180,47 @@ impl RustPlugins {
         // into Program
         Serialized::deserialize(&serialized)
     }
+
+    /// Applies plugins, and handles the failure according to
+    /// [PluginFailurePolicy].
+    ///
+    /// Returns `Ok(None)` if the failure is reported to the handler, in which
+    /// case the input should be used as-is.
+    #[cfg(feature = "plugin")]
+    fn apply_or_report(&mut self, n: &Program) -> Result<Option<Program>, Error> {
+        use swc_common::errors::HANDLER;
+
+        match self.apply(n) {
+            Ok(program) => Ok(Some(program)),
+            Err(err) => {
+                let err = err.context(format!(
+                    "failed to invoke plugin on `{}`",
+                    self.filename.as_deref().unwrap_or("<anon>")
+                ));
+
+                match self.failure_policy {
+                    PluginFailurePolicy::FailFile if HANDLER.is_set() => {
+                        HANDLER.with(|handler| handler.err(&format!("{:?}", err)));
+                        Ok(None)
+                    }
+                    _ => Err(err),
+                }
+            }
+        }
+    }
+
+    /// Stores the error to [PluginErrorSlot] if the build should fail.
+    #[cfg(feature = "plugin")]
+    fn apply_to(&mut self, n: Program) -> Program {
+        match self.apply_or_report(&n) {
+            Ok(Some(program)) => program,
+            Ok(None) => n,
+            Err(err) => {
+                self.error_slot.set(err);
+                n
+            }
+        }
+    }
 }
 
 impl Fold for RustPlugins {
@@ -83,15 +228,11 @@ impl Fold for RustPlugins {
 
     #[cfg(feature = "plugin")]
     fn fold_module(&mut self, n: Module) -> Module {
-        self.apply(Program::Module(n))
-            .expect("failed to invoke plugin")
-            .expect_module()
+        self.apply_to(Program::Module(n)).expect_module()
     }
 
     #[cfg(feature = "plugin")]
     fn fold_script(&mut self, n: Script) -> Script {
-        self.apply(Program::Script(n))
-            .expect("failed to invoke plugin")
-            .expect_script()
+        self.apply_to(Program::Script(n)).expect_script()
     }
 }
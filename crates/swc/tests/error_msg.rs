@@ -44,6 +44,14 @@ fn issue_1532() {
     assert!(f.contains("unknown variant `esnext`"))
 }
 
+#[test]
+fn usage_pure_with_corejs_2() {
+    let f = file("tests/swcrc_errors/usage-pure-corejs-2/index.js");
+    println!("{}", f);
+
+    assert!(f.contains("requires core-js 3"))
+}
+
 #[testing::fixture("tests/errors/**/input.js")]
 fn fixture(input: PathBuf) {
     let _log = testing::init();
@@ -0,0 +1,6 @@
+/* eslint-disable no-alert */
+
+alert();
+window.alert();
+
+console.log("not disabled");
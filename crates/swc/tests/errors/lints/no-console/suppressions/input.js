@@ -0,0 +1,17 @@
+// swc-lint-disable-next-line no-console
+console.log("next line");
+
+console.log("same line"); // swc-lint-disable-line no-console
+
+// eslint-disable-next-line no-console -- logging is intended here
+console.log("eslint spelling");
+
+// swc-lint-disable-next-line no-alert
+console.log("other rule");
+
+/* swc-lint-disable noConsole */
+console.log("block");
+console.log("block");
+/* swc-lint-enable */
+
+console.log("after block");
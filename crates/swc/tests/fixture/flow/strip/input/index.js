@@ -0,0 +1,15 @@
+// @flow
+import type { Node } from "react";
+import React, { type ComponentType } from "react";
+
+type Props = {|
+  +title: string,
+  onClick?: (event: SyntheticEvent<>) => void,
+|};
+
+export default function Button({ title, onClick }: Props): Node {
+  const label = (title: any);
+  return <button onClick={onClick}>{label}</button>;
+}
+
+export const wrap = (C: ComponentType<Props>): ComponentType<Props> => C;
@@ -0,0 +1,9 @@
+import React from "react";
+export default function Button({ title , onClick  }) {
+    const label = title;
+    return(/*#__PURE__*/ React.createElement("button", {
+        onClick: onClick
+    }, label));
+};
+export const wrap = (C)=>C
+;
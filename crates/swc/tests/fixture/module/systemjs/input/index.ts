@@ -0,0 +1,12 @@
+import { render, type Props } from "./render";
+
+export let mounted = false;
+
+export async function mount(props: Props) {
+    await render(props, import.meta.url);
+    mounted = true;
+}
+
+export default {
+    mount,
+};
@@ -0,0 +1,24 @@
+System.register([
+    "./render"
+], function(_export, _context) {
+    "use strict";
+    var render, mounted;
+    async function mount(props) {
+        await render(props, _context.meta.url);
+        _export("mounted", mounted = true);
+    }
+    _export("mount", mount);
+    return {
+        setters: [
+            function(_render) {
+                render = _render.render;
+            }
+        ],
+        execute: function() {
+            _export("mounted", mounted = false);
+            _export("default", {
+                mount
+            });
+        }
+    };
+});
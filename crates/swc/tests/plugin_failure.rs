@@ -0,0 +1,93 @@
+#![cfg(feature = "plugin")]
+
+use std::{env, fs, path::PathBuf};
+
+use serde_json::json;
+use swc::{
+    config::{Config, IsModule, JscConfig, Options},
+    Compiler,
+};
+use swc_common::FileName;
+
+/// A plugin which always traps.
+const TRAPPING_PLUGIN: &str = r#"
+(module
+  (memory (export "memory") 1)
+  (func (export "__alloc") (param i32) (result i32) i32.const 0)
+  (func (export "__free") (param i32 i32) (result i32) i32.const 0)
+  (func (export "__plugin_process_impl") (param i32 i32 i32 i32) (result i32)
+    unreachable))
+"#;
+
+fn plugin_path(name: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("swc_plugin_failure_{}.wat", name));
+    fs::write(&path, TRAPPING_PLUGIN).unwrap();
+    path
+}
+
+fn options(name: &str, policy: &str) -> Options {
+    Options {
+        config: Config {
+            jsc: JscConfig {
+                experimental: serde_json::from_value(json!({
+                    "plugins": [[plugin_path(name), {}]],
+                    "pluginFailure": policy,
+                }))
+                .unwrap(),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        swcrc: false,
+        is_module: IsModule::Bool(true),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn fail_file_reports_diagnostic() {
+    let err = testing::run_test(false, |cm, handler| {
+        let c = Compiler::new(cm.clone());
+        let fm = cm.new_source_file(FileName::Real("input.js".into()), "foo();".into());
+
+        let output = c
+            .process_js_file(fm, handler, &options("fail_file", "failFile"))
+            .expect("failFile should not abort the build");
+        assert_eq!(output.code.trim(), "foo();");
+
+        assert!(handler.has_errors(), "failFile should report an error");
+        Err(())
+    })
+    .unwrap_err();
+
+    assert!(
+        err.contains("failed to invoke plugin on `input.js`"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn fail_build_returns_error() {
+    testing::run_test(false, |cm, handler| {
+        let c = Compiler::new(cm.clone());
+        let fm = cm.new_source_file(FileName::Real("input.js".into()), "foo();".into());
+
+        let err = c
+            .process_js_file(fm, handler, &options("fail_build", "failBuild"))
+            .expect_err("failBuild should abort the build");
+
+        assert!(
+            format!("{:?}", err).contains("failed to invoke plugin on `input.js`"),
+            "{:?}",
+            err
+        );
+        assert!(
+            !handler.has_errors(),
+            "failBuild should not report an error"
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
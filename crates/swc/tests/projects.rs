@@ -744,7 +744,9 @@ fn should_visit() {
                 output_path: config.output_path,
                 source_file_name: config.source_file_name,
                 preserve_comments: config.preserve_comments,
+                format: config.format,
                 inline_sources_content: config.inline_sources_content,
+                plugin_error: config.plugin_error,
             };
 
             if config.minify {
@@ -776,6 +778,7 @@ fn should_visit() {
                 // TODO: figure out sourcemaps
                 config.minify,
                 config.preserve_comments,
+                config.format.as_ref(),
             )
             .unwrap()
             .code)
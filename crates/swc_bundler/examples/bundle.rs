@@ -90,6 +90,7 @@ fn do_test(_entry: &Path, entries: HashMap<String, FileName>, inline: bool, mini
                 disable_hygiene: minify,
                 disable_dce: false,
                 module: Default::default(),
+                code_splitting: false,
             },
             Box::new(Hook),
         );
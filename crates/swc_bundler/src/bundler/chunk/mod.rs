@@ -1,6 +1,7 @@
 use super::{load::TransformedModule, Bundler};
 use crate::{
     bundler::chunk::merge::Ctx, load::Load, resolve::Resolve, util::IntoParallelIterator, Bundle,
+    BundleKind,
 };
 use anyhow::{Context, Error};
 #[cfg(feature = "rayon")]
@@ -24,14 +25,14 @@ where
     L: Load,
     R: Resolve,
 {
-    /// `entries` - Entry modules (provided by user) by it's basename.
+    /// `entries` - Entry modules with the kind of bundles.
     ///
     /// # How it works
     ///
     /// For first, we load all dependencies and determine all entries.
     pub(super) fn chunk(
         &self,
-        entries: AHashMap<String, TransformedModule>,
+        entries: Vec<(BundleKind, TransformedModule)>,
     ) -> Result<Vec<Bundle>, Error> {
         #[cfg(not(target_arch = "wasm32"))]
         let start = Instant::now();
@@ -175,8 +176,12 @@ mod tests {
                     .bundler
                     .load_transformed(&FileName::Real("main.js".into()))?
                     .unwrap();
-                let mut entries = AHashMap::default();
-                entries.insert("main.js".to_string(), module);
+                let entries = vec![(
+                    BundleKind::Named {
+                        name: "main.js".to_string(),
+                    },
+                    module,
+                )];
 
                 let chunked = t.bundler.chunk(entries)?;
                 assert_eq!(chunked.len(), 1);
@@ -45,13 +45,13 @@ where
 {
     pub(super) fn determine_entries(
         &self,
-        entries: AHashMap<String, TransformedModule>,
+        entries: Vec<(BundleKind, TransformedModule)>,
     ) -> Result<(Plan, ModuleGraph, Vec<Vec<ModuleId>>), Error> {
         let mut builder = PlanBuilder::default();
         let mut analyzer = GraphAnalyzer::new(&self.scope);
 
-        for (name, module) in entries {
-            if let Some(v) = builder.kinds.insert(module.id, BundleKind::Named { name }) {
+        for (kind, module) in entries {
+            if let Some(v) = builder.kinds.insert(module.id, kind) {
                 bail!("Multiple entries with same input path detected: {:?}", v)
             }
 
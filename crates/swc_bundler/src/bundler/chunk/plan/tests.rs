@@ -1,4 +1,4 @@
-use crate::bundler::tests::suite;
+use crate::{bundler::tests::suite, BundleKind};
 use std::collections::HashMap;
 use swc_common::collections::{AHashMap, AHashSet};
 
@@ -10,8 +10,12 @@ fn assert_cycles(map: AHashMap<String, String>, cycle_entries: Vec<&str>) {
     }
 
     tester.run(|tester| {
-        let mut entries = AHashMap::default();
-        entries.insert("main.js".to_string(), tester.module("main.js"));
+        let entries = vec![(
+            BundleKind::Named {
+                name: "main.js".to_string(),
+            },
+            tester.module("main.js"),
+        )];
         let (_plan, _graph, cycles) = tester.bundler.determine_entries(entries).unwrap();
 
         dbg!(&cycles);
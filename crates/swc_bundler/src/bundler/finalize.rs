@@ -1,10 +1,10 @@
 use crate::{hash::calc_hash, Bundle, BundleKind, Bundler, Load, ModuleType, Resolve};
 use ahash::AHashMap;
 use anyhow::Error;
-use relative_path::RelativePath;
+use relative_path::{RelativePath, RelativePathBuf};
 use std::path::{Path, PathBuf};
-use swc_atoms::js_word;
-use swc_common::{util::move_map::MoveMap, FileName, DUMMY_SP};
+use swc_atoms::{js_word, JsWord};
+use swc_common::{FileName, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_transforms_base::{
     fixer::fixer,
@@ -12,7 +12,9 @@ use swc_ecma_transforms_base::{
     hygiene::hygiene,
 };
 use swc_ecma_utils::{find_ids, private_ident, ExprFactory};
-use swc_ecma_visit::{noop_fold_type, noop_visit_type, Fold, FoldWith, Visit, VisitWith};
+use swc_ecma_visit::{
+    noop_visit_mut_type, noop_visit_type, FoldWith, Visit, VisitMut, VisitMutWith, VisitWith,
+};
 
 impl<L, R> Bundler<'_, L, R>
 where
@@ -27,7 +29,6 @@ where
     pub(super) fn finalize(&self, bundles: Vec<Bundle>) -> Result<Vec<Bundle>, Error> {
         self.run(|| {
             let mut new = Vec::with_capacity(bundles.len());
-            let mut renamed = AHashMap::default();
 
             for mut bundle in bundles {
                 bundle.module = self.optimize(bundle.module);
@@ -56,91 +57,113 @@ where
                         HELPERS.set(&swc_helpers, || module.fold_with(&mut inject_helpers()));
                 }
 
-                match bundle.kind {
-                    BundleKind::Named { .. } => {
-                        // Inject helpers
-                        let helpers = self
-                            .scope
-                            .get_module(bundle.id)
-                            .expect("module should exist at this point")
-                            .helpers;
+                {
+                    // Inject helpers
+                    let helpers = self
+                        .scope
+                        .get_module(bundle.id)
+                        .expect("module should exist at this point")
+                        .helpers;
 
-                        helpers.add_to(&mut bundle.module.body);
+                    helpers.add_to(&mut bundle.module.body);
+                }
 
-                        new.push(Bundle { ..bundle });
-                    }
-                    BundleKind::Lib { name } => {
-                        let hash = calc_hash(self.cm.clone(), &bundle.module)?;
-                        let mut new_name = PathBuf::from(name);
-                        let key = new_name.clone();
-                        let file_name = new_name
-                            .file_name()
-                            .map(|path| -> PathBuf {
-                                let path = Path::new(path);
-                                let ext = path.extension();
-                                if let Some(ext) = ext {
-                                    return format!(
-                                        "{}-{}.{}",
-                                        path.file_stem().unwrap().to_string_lossy(),
-                                        hash,
-                                        ext.to_string_lossy()
-                                    )
-                                    .into();
-                                }
-                                return format!(
-                                    "{}-{}",
-                                    path.file_stem().unwrap().to_string_lossy(),
-                                    hash,
-                                )
-                                .into();
-                            })
-                            .expect("javascript file should have name");
-                        new_name.pop();
-                        new_name = new_name.join(file_name.clone());
-
-                        renamed.insert(key, new_name.to_string_lossy().to_string());
-
-                        new.push(Bundle {
-                            kind: BundleKind::Named {
-                                name: file_name.display().to_string(),
-                            },
-                            ..bundle
-                        })
-                    }
-                    _ => new.push(bundle),
+                new.push(bundle);
+            }
+
+            // Chunks are emitted at the path of their input file relative to the
+            // directory which corresponds to the output directory of named bundles.
+            let root = self.output_root(&new);
+            let mut names = AHashMap::default();
+            for bundle in &new {
+                if let BundleKind::Named { .. } = bundle.kind {
+                    continue;
                 }
+
+                let path = self.file_name_of(bundle);
+                let name = chunk_name(&path, root.as_deref());
+                names.insert(JsWord::from(path.to_string()), name);
             }
 
-            if new.len() == 1 {
+            if names.is_empty() {
                 return Ok(new);
             }
 
-            new = new.move_map(|bundle| {
-                let path = match self.scope.get_module(bundle.id).unwrap().fm.name {
-                    FileName::Real(ref v) => v.clone(),
-                    _ => {
-                        tracing::error!("Cannot rename: not a real file");
-                        return bundle;
-                    }
-                };
+            let mut renamed = AHashMap::default();
+            for bundle in &new {
+                if let BundleKind::Named { .. } = bundle.kind {
+                    continue;
+                }
 
-                let module = {
-                    // Change imports
-                    let mut v = Renamer {
-                        resolver: &self.resolver,
-                        base: &path,
-                        renamed: &renamed,
-                    };
-                    bundle.module.fold_with(&mut v)
+                let path = JsWord::from(self.file_name_of(bundle).to_string());
+                let name = &names[&path];
+
+                // Imports of other chunks are renamed to names without hashes, so that
+                // the hash does not depend on the location of input files.
+                let mut module = bundle.module.clone();
+                module.visit_mut_with(&mut Renamer {
+                    base: name,
+                    renamed: &names,
+                });
+                let hash = calc_hash(self.cm.clone(), &module)?;
+
+                renamed.insert(path, hashed_name(name, &hash));
+            }
+
+            for bundle in &mut new {
+                let name = match &bundle.kind {
+                    BundleKind::Named { name } => name.clone(),
+                    BundleKind::Lib { .. } | BundleKind::Dynamic => {
+                        renamed[&JsWord::from(self.file_name_of(bundle).to_string())].clone()
+                    }
                 };
 
-                Bundle { module, ..bundle }
-            });
+                bundle.module.visit_mut_with(&mut Renamer {
+                    base: &name,
+                    renamed: &renamed,
+                });
+                bundle.kind = BundleKind::Named { name };
+            }
 
             Ok(new)
         })
     }
 
+    fn file_name_of(&self, bundle: &Bundle) -> FileName {
+        self.scope
+            .module_id_gen
+            .file_name(bundle.id)
+            .expect("module should exist at this point")
+    }
+
+    /// Returns the input directory which corresponds to the output directory,
+    /// calculated from paths and names of named bundles.
+    ///
+    /// For example, it's `src` if `src/pages/index.js` is bundled as
+    /// `pages/index.js`.
+    fn output_root(&self, bundles: &[Bundle]) -> Option<PathBuf> {
+        let mut root: Option<PathBuf> = None;
+
+        for bundle in bundles {
+            let name = match &bundle.kind {
+                BundleKind::Named { name } => name,
+                _ => continue,
+            };
+            let path = match self.file_name_of(bundle) {
+                FileName::Real(path) => path,
+                _ => continue,
+            };
+            let dir = path.ancestors().nth(Path::new(name).components().count())?;
+
+            root = Some(match root {
+                Some(root) => root.ancestors().find(|v| dir.starts_with(v))?.to_path_buf(),
+                None => dir.to_path_buf(),
+            });
+        }
+
+        root
+    }
+
     fn may_wrap_with_iife(&self, module: Module) -> Module {
         if self.config.module != ModuleType::Iife {
             return module;
@@ -378,62 +401,99 @@ impl Visit for TopLevelAwaitFinder {
     }
 }
 
-/// Import renamer. This pass changes import path.
-struct Renamer<'a, R>
-where
-    R: Resolve,
-{
-    resolver: R,
-    base: &'a PathBuf,
-    renamed: &'a AHashMap<PathBuf, String>,
+/// Name of a chunk without a hash.
+///
+/// The directory of a chunk is preserved if it's in `root`.
+fn chunk_name(path: &FileName, root: Option<&Path>) -> String {
+    let path = match path {
+        FileName::Real(path) => path.clone(),
+        _ => PathBuf::from(path.to_string()),
+    };
+    let name = match root.and_then(|root| path.strip_prefix(root).ok()) {
+        Some(name) => name,
+        None => Path::new(path.file_name().expect("javascript file should have name")),
+    };
+
+    RelativePathBuf::from_path(name)
+        .map(|name| name.to_string())
+        .unwrap_or_else(|_| name.to_string_lossy().to_string())
 }
 
-impl<R> Fold for Renamer<'_, R>
-where
-    R: Resolve,
-{
-    noop_fold_type!();
-
-    fn fold_import_decl(&mut self, import: ImportDecl) -> ImportDecl {
-        let resolved = match self
-            .resolver
-            .resolve(&FileName::Real(self.base.clone()), &import.src.value)
-        {
-            Ok(v) => match v {
-                FileName::Real(v) => v,
-                _ => panic!("rename_bundles called with non-path module"),
-            },
-            Err(_) => return import,
-        };
+/// `pages/index.js` => `pages/index-[hash].js`
+fn hashed_name(name: &str, hash: &str) -> String {
+    let name = RelativePath::new(name);
+    let file_name = match name.extension() {
+        Some(ext) => format!(
+            "{}-{}.{}",
+            name.file_stem().expect("javascript file should have name"),
+            hash,
+            ext
+        ),
+        None => format!(
+            "{}-{}",
+            name.file_stem().expect("javascript file should have name"),
+            hash
+        ),
+    };
+
+    name.with_file_name(file_name).to_string()
+}
+
+/// Import renamer. This pass changes sources of imports from other bundles to
+/// the names of emitted bundles, relative to the bundle being processed.
+struct Renamer<'a> {
+    /// Name of the bundle being processed.
+    base: &'a str,
+    /// File name of the entry of a bundle to the name of the bundle.
+    renamed: &'a AHashMap<JsWord, String>,
+}
 
-        if let Some(v) = self.renamed.get(&resolved) {
-            // We use parent because RelativePath uses ../common-[hash].js
-            // if we use `entry-a.js` as a base.
-            //
-            // entry-a.js
-            // common.js
-            let base = self
-                .base
+impl Renamer<'_> {
+    fn rename(&self, src: &mut Str) {
+        if let Some(name) = self.renamed.get(&src.value) {
+            let base = RelativePath::new(self.base)
                 .parent()
-                .unwrap_or(self.base)
-                .as_os_str()
-                .to_string_lossy();
-            let base = RelativePath::new(&*base);
-            let v = base.relative(&*v);
-            let value = v.as_str();
-            return ImportDecl {
-                src: Str {
-                    value: if value.starts_with('.') {
-                        value.into()
-                    } else {
-                        format!("./{}", value).into()
-                    },
-                    ..import.src
-                },
-                ..import
+                .unwrap_or_else(|| RelativePath::new(""));
+            let value = base.relative(name);
+            let value = value.as_str();
+
+            src.value = if value.starts_with('.') {
+                value.into()
+            } else {
+                format!("./{}", value).into()
             };
+            src.has_escape = false;
+            src.kind = Default::default();
         }
+    }
+}
+
+impl VisitMut for Renamer<'_> {
+    noop_visit_mut_type!();
 
-        import
+    fn visit_mut_call_expr(&mut self, e: &mut CallExpr) {
+        e.visit_mut_children_with(self);
+
+        if let Callee::Import(..) = e.callee {
+            if let Some(ExprOrSpread { spread: None, expr }) = e.args.first_mut() {
+                if let Expr::Lit(Lit::Str(src)) = &mut **expr {
+                    self.rename(src);
+                }
+            }
+        }
+    }
+
+    fn visit_mut_export_all(&mut self, export: &mut ExportAll) {
+        self.rename(&mut export.src);
+    }
+
+    fn visit_mut_import_decl(&mut self, import: &mut ImportDecl) {
+        self.rename(&mut import.src);
+    }
+
+    fn visit_mut_named_export(&mut self, export: &mut NamedExport) {
+        if let Some(src) = &mut export.src {
+            self.rename(src);
+        }
     }
 }
@@ -261,19 +261,19 @@ where
                         self.info.lazy_imports.push(decl);
                     }
 
-                    // TODO: Uncomment this after implementing an option to make swc_bundler
-                    // includes dynamic imports
-                    //
-                    //
-                    // ExprOrSuper::Expr(ref e) => match &**e {
-                    //     Expr::Ident(Ident {
-                    //         sym: js_word!("import"),
-                    //         ..
-                    //     }) => {
-                    //         self.info.dynamic_imports.push(src.clone());
-                    //     }
-                    //     _ => {}
-                    // },
+                    Callee::Import(..) if self.bundler.config.code_splitting => {
+                        if self.bundler.is_external(&src.value) {
+                            return;
+                        }
+
+                        // Dynamic imports which cannot be resolved are preserved as-is.
+                        if self.bundler.resolve(self.path, &src.value).is_err() {
+                            return;
+                        }
+
+                        self.info.dynamic_imports.push(src.clone());
+                    }
+
                     _ => {}
                 }
             }
@@ -628,7 +628,7 @@ where
                         _ => return,
                     };
                     // Ignore core modules.
-                    if self.bundler.config.external_modules.contains(&src.value) {
+                    if self.bundler.is_external(&src.value) {
                         return;
                     }
 
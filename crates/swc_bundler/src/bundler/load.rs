@@ -111,6 +111,30 @@ where
         })
     }
 
+    /// Analyzes a module stored in the scope again and replaces it.
+    ///
+    /// Used if the module depends on information which was not available when
+    /// it was loaded, like entries of chunks.
+    pub(super) fn reload_transformed(&self, file_name: &FileName) -> Result<(), Error> {
+        self.run(|| {
+            tracing::trace!("reload_transformed: ({})", file_name);
+
+            let (_, data) = self.load(file_name).context("Bundler.load() failed")?;
+            let (v, mut files) = self
+                .analyze(file_name, data)
+                .context("failed to analyze module")?;
+            files.dedup_by_key(|v| v.1.clone());
+
+            self.scope.store_module(v);
+
+            for (_src, path) in files {
+                self.load_transformed(&path)?;
+            }
+
+            Ok(())
+        })
+    }
+
     fn load(&self, file_name: &FileName) -> Result<(ModuleId, ModuleData), Error> {
         self.run(|| {
             let (module_id, _, _) = self.scope.module_id_gen.gen(file_name);
@@ -138,6 +162,10 @@ where
 
             let mut module = data.module.fold_with(&mut resolver_with_mark(local_mark));
 
+            if !self.chunk_entries.is_empty() {
+                self.preserve_imports_of_chunks(file_name, &mut module);
+            }
+
             // {
             //     let code = self
             //         .swc
@@ -342,6 +370,11 @@ where
                 };
                 files.push((src.clone(), file_name));
 
+                if is_dynamic {
+                    merged.dynamic_imports.push(src);
+                    continue;
+                }
+
                 // TODO: Handle rename
                 let mut specifiers = vec![];
                 for s in decl.specifiers {
@@ -384,6 +417,9 @@ where
 pub(crate) struct Imports {
     /// If imported ids are empty, it is a side-effect import.
     pub specifiers: Vec<(Source, Vec<Specifier>)>,
+
+    /// Targets of `import()`, which are emitted as separate bundles.
+    pub dynamic_imports: Vec<Source>,
 }
 
 /// Clone is relatively cheap
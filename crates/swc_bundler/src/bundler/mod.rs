@@ -1,10 +1,10 @@
-use self::scope::Scope;
+use self::{load::TransformedModule, scope::Scope};
 use crate::{Hook, Load, ModuleId, Resolve};
 use anyhow::{Context, Error};
 use std::collections::HashMap;
 use swc_atoms::JsWord;
 use swc_common::{
-    collections::AHashMap, sync::Lrc, FileName, Globals, Mark, SourceMap, SyntaxContext, GLOBALS,
+    collections::AHashSet, sync::Lrc, FileName, Globals, Mark, SourceMap, SyntaxContext, GLOBALS,
 };
 use swc_ecma_ast::Module;
 
@@ -17,6 +17,7 @@ mod keywords;
 mod load;
 mod optimize;
 mod scope;
+mod split;
 #[cfg(test)]
 pub(crate) mod tests;
 
@@ -44,6 +45,11 @@ pub struct Config {
 
     /// Type of emitted module
     pub module: ModuleType,
+
+    /// If it's true, targets of `import()` with a string literal are emitted
+    /// as separate bundles, and modules used by multiple bundles are hoisted
+    /// into a common bundle.
+    pub code_splitting: bool,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash)]
@@ -101,6 +107,11 @@ where
 
     scope: Scope,
 
+    /// File names of modules which are emitted as separate bundles by code
+    /// splitting. Imports of these modules are preserved, like external
+    /// modules.
+    chunk_entries: AHashSet<JsWord>,
+
     hook: Box<dyn 'a + Hook>,
 }
 
@@ -135,12 +146,13 @@ where
                 synthesized_ctxt,
                 injected_ctxt,
                 scope: Default::default(),
+                chunk_entries: Default::default(),
                 hook,
             }
         })
     }
     pub(crate) fn is_external(&self, src: &JsWord) -> bool {
-        return self.config.external_modules.iter().any(|v| v == src);
+        self.config.external_modules.iter().any(|v| v == src) || self.chunk_entries.contains(src)
     }
     ///
     ///
@@ -149,7 +161,7 @@ where
     /// circular manner. However, it applies only to the provided `entries`, and
     /// dependencies with circular reference is ok.
     pub fn bundle(&mut self, entries: HashMap<String, FileName>) -> Result<Vec<Bundle>, Error> {
-        let results = entries
+        let entries = entries
             .into_iter()
             .map(|(name, path)| -> Result<_, Error> {
                 let path = match path {
@@ -166,28 +178,35 @@ where
                     _ => path,
                 };
 
-                let res = self
-                    .load_transformed(&path)
-                    .context("load_transformed failed")?;
-                Ok((name, res))
+                Ok((name, path))
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
 
-        // We collect at here to handle dynamic imports
-        // TODO: Handle dynamic imports
+        let mut local = self.load_entries(&entries)?;
 
-        let local = {
-            let mut output = AHashMap::default();
+        if self.config.code_splitting {
+            let chunks = self.determine_chunk_entries(&local);
 
-            for res in results {
-                let (name, m) = res?;
-                let m = m.unwrap();
+            if !chunks.is_empty() {
+                self.chunk_entries = chunks
+                    .iter()
+                    .map(|(path, _)| path.to_string().into())
+                    .collect();
 
-                output.insert(name, m);
-            }
+                self.reanalyze_importers_of_chunks(&local, &chunks)?;
 
-            output
-        };
+                local = self.load_entries(&entries)?;
+
+                for (path, kind) in chunks {
+                    let module = self
+                        .load_transformed(&path)
+                        .context("load_transformed failed")?
+                        .unwrap();
+
+                    local.push((kind, module));
+                }
+            }
+        }
 
         let bundles = self.chunk(local)?;
 
@@ -202,6 +221,23 @@ where
         Ok(bundles)
     }
 
+    fn load_entries(
+        &self,
+        entries: &[(String, FileName)],
+    ) -> Result<Vec<(BundleKind, TransformedModule)>, Error> {
+        entries
+            .iter()
+            .map(|(name, path)| -> Result<_, Error> {
+                let module = self
+                    .load_transformed(path)
+                    .context("load_transformed failed")?
+                    .unwrap();
+
+                Ok((BundleKind::Named { name: name.clone() }, module))
+            })
+            .collect()
+    }
+
     /// Sets `swc_common::GLOBALS`
     #[inline]
     fn run<F, Ret>(&self, op: F) -> Ret
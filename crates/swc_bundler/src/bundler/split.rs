@@ -0,0 +1,283 @@
+use super::{load::TransformedModule, Bundler};
+use crate::{BundleKind, Load, ModuleId, Resolve};
+use anyhow::{Context, Error};
+use indexmap::IndexSet;
+use swc_atoms::JsWord;
+use swc_common::{
+    collections::{AHashMap, AHashSet},
+    FileName,
+};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
+use swc_graph_analyzer::DepGraph;
+
+impl<L, R> Bundler<'_, L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    /// Determines entries of bundles created by code splitting.
+    ///
+    /// Targets of dynamic imports become [BundleKind::Dynamic], and es modules
+    /// used by multiple bundles become [BundleKind::Lib] so that they are
+    /// evaluated only once.
+    pub(super) fn determine_chunk_entries(
+        &self,
+        entries: &[(BundleKind, TransformedModule)],
+    ) -> Vec<(FileName, BundleKind)> {
+        self.run(|| {
+            let mut roots: IndexSet<ModuleId, ahash::RandomState> =
+                entries.iter().map(|(_, module)| module.id).collect();
+            let mut chunks = vec![];
+
+            let mut queue = roots.iter().copied().collect::<Vec<_>>();
+            let mut visited = AHashSet::default();
+            while let Some(id) = queue.pop() {
+                if !visited.insert(id) {
+                    continue;
+                }
+                let module = match self.scope.get_module(id) {
+                    Some(v) => v,
+                    None => continue,
+                };
+
+                queue.extend(self.scope.deps_of(id));
+
+                for src in &module.imports.dynamic_imports {
+                    queue.push(src.module_id);
+
+                    if roots.insert(src.module_id) {
+                        chunks.push((src.module_id, BundleKind::Dynamic));
+                    }
+                }
+            }
+
+            // Static entries are bundled as-is if there's nothing to split.
+            if chunks.is_empty() {
+                return vec![];
+            }
+
+            loop {
+                let mut users: AHashMap<_, AHashSet<_>> = AHashMap::default();
+                for &root in &roots {
+                    for id in self.deps_in_same_bundle(root, &roots) {
+                        users.entry(id).or_default().insert(root);
+                    }
+                }
+
+                let shared = users
+                    .into_iter()
+                    .filter(|(id, users)| users.len() > 1 && self.can_be_shared(*id))
+                    .map(|(id, _)| id)
+                    .collect::<AHashSet<_>>();
+                if shared.is_empty() {
+                    break;
+                }
+
+                // Shared modules used by another shared module are hoisted into the bundle
+                // of the other one, unless they are in a cycle.
+                let mut hoisted = shared
+                    .iter()
+                    .copied()
+                    .filter(|&id| {
+                        !shared.iter().any(|&other| {
+                            other != id && self.deps_in_same_bundle(other, &roots).contains(&id)
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                if hoisted.is_empty() {
+                    hoisted = shared.into_iter().collect();
+                }
+
+                for id in hoisted {
+                    roots.insert(id);
+
+                    if let Some(file_name) = self.scope.module_id_gen.file_name(id) {
+                        chunks.push((
+                            id,
+                            BundleKind::Lib {
+                                name: file_name.to_string(),
+                            },
+                        ));
+                    }
+                }
+            }
+
+            chunks
+                .into_iter()
+                .filter_map(|(id, kind)| Some((self.scope.module_id_gen.file_name(id)?, kind)))
+                .collect()
+        })
+    }
+
+    /// Analyzes modules importing `chunks` again, because their imports of the
+    /// chunks should be preserved. Other modules are kept in the scope as-is.
+    pub(super) fn reanalyze_importers_of_chunks(
+        &self,
+        entries: &[(BundleKind, TransformedModule)],
+        chunks: &[(FileName, BundleKind)],
+    ) -> Result<(), Error> {
+        self.run(|| {
+            let chunks = chunks
+                .iter()
+                .map(|(path, _)| self.scope.module_id_gen.gen(path).0)
+                .collect::<AHashSet<_>>();
+
+            let mut importers = vec![];
+            let mut queue = entries
+                .iter()
+                .map(|(_, module)| module.id)
+                .collect::<Vec<_>>();
+            let mut visited = AHashSet::default();
+            while let Some(id) = queue.pop() {
+                if !visited.insert(id) {
+                    continue;
+                }
+                let module = match self.scope.get_module(id) {
+                    Some(v) => v,
+                    None => continue,
+                };
+
+                let deps = self
+                    .scope
+                    .deps_of(id)
+                    .into_iter()
+                    .chain(
+                        module
+                            .imports
+                            .dynamic_imports
+                            .iter()
+                            .map(|src| src.module_id),
+                    )
+                    .collect::<Vec<_>>();
+
+                if deps.iter().any(|dep| *dep != id && chunks.contains(dep)) {
+                    importers.push(id);
+                }
+                queue.extend(deps);
+            }
+
+            for id in importers {
+                if let Some(file_name) = self.scope.module_id_gen.file_name(id) {
+                    self.reload_transformed(&file_name)
+                        .context("reload_transformed failed")?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Returns static dependencies of `start` which are not in the bundle of
+    /// other `roots`.
+    fn deps_in_same_bundle(
+        &self,
+        start: ModuleId,
+        roots: &IndexSet<ModuleId, ahash::RandomState>,
+    ) -> AHashSet<ModuleId> {
+        let mut deps = AHashSet::default();
+        let mut queue = self.scope.deps_of(start);
+
+        while let Some(id) = queue.pop() {
+            if id == start || roots.contains(&id) || !deps.insert(id) {
+                continue;
+            }
+
+            queue.extend(self.scope.deps_of(id));
+        }
+
+        deps
+    }
+
+    /// Common js modules are not shared, because they are wrapped with a
+    /// function by the bundle importing them.
+    fn can_be_shared(&self, id: ModuleId) -> bool {
+        if self.scope.is_cjs(id) {
+            return false;
+        }
+
+        self.scope
+            .get_module(id)
+            .map(|module| module.is_es6)
+            .unwrap_or(false)
+    }
+
+    /// Changes sources of imports from modules in other bundles to the file
+    /// names of the modules, so that they are preserved like imports of
+    /// external modules.
+    ///
+    /// The file names are replaced with the names of emitted bundles while
+    /// finalizing.
+    pub(super) fn preserve_imports_of_chunks(&self, base: &FileName, module: &mut Module) {
+        module.visit_mut_with(&mut ChunkImportHandler {
+            bundler: self,
+            base,
+        });
+    }
+}
+
+struct ChunkImportHandler<'a, 'b, L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    bundler: &'a Bundler<'b, L, R>,
+    base: &'a FileName,
+}
+
+impl<L, R> ChunkImportHandler<'_, '_, L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    fn handle_src(&self, src: &mut Str) {
+        if self.bundler.is_external(&src.value) {
+            return;
+        }
+
+        let path: JsWord = match self.bundler.resolve(self.base, &src.value) {
+            Ok(v) => v.to_string().into(),
+            Err(_) => return,
+        };
+
+        if self.bundler.chunk_entries.contains(&path) {
+            src.value = path;
+            src.has_escape = false;
+            src.kind = Default::default();
+        }
+    }
+}
+
+impl<L, R> VisitMut for ChunkImportHandler<'_, '_, L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    noop_visit_mut_type!();
+
+    fn visit_mut_call_expr(&mut self, e: &mut CallExpr) {
+        e.visit_mut_children_with(self);
+
+        if let Callee::Import(..) = e.callee {
+            if let Some(ExprOrSpread { spread: None, expr }) = e.args.first_mut() {
+                if let Expr::Lit(Lit::Str(src)) = &mut **expr {
+                    self.handle_src(src);
+                }
+            }
+        }
+    }
+
+    fn visit_mut_export_all(&mut self, n: &mut ExportAll) {
+        self.handle_src(&mut n.src);
+    }
+
+    fn visit_mut_import_decl(&mut self, n: &mut ImportDecl) {
+        self.handle_src(&mut n.src);
+    }
+
+    fn visit_mut_named_export(&mut self, n: &mut NamedExport) {
+        if let Some(src) = &mut n.src {
+            self.handle_src(src);
+        }
+    }
+}
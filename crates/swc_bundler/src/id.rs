@@ -44,6 +44,15 @@ impl ModuleIdGenerator {
         w.insert(file_name.clone(), v);
         (id, local_mark, export_mark)
     }
+
+    /// Returns the file name of the module with `id`.
+    pub fn file_name(&self, id: ModuleId) -> Option<FileName> {
+        self.cache
+            .lock()
+            .iter()
+            .find(|(_, v)| v.0 == id)
+            .map(|(file_name, _)| file_name.clone())
+    }
 }
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
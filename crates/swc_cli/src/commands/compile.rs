@@ -0,0 +1,517 @@
+use std::{
+    collections::HashMap,
+    env, fs,
+    io::{self, Read, Write},
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use anyhow::{bail, Context, Error};
+use rayon::prelude::*;
+use structopt::StructOpt;
+use swc::{
+    config::{Config, ConfigFile, Options, SourceMapsConfig},
+    try_with_handler, Compiler, TransformOutput,
+};
+use swc_common::{FileName, FilePathMapping, SourceFile, SourceMap};
+use walkdir::WalkDir;
+
+/// Interval used to poll input files in `--watch` mode.
+const WATCH_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Transform, compile files.
+#[derive(Debug, StructOpt)]
+pub struct CompileCommand {
+    ///Filename to use when reading from stdin - this will be used in
+    /// source-maps, errors etc
+    #[structopt(short = "f", long = "filename")]
+    file_name: Option<String>,
+    /// Path to a .swcrc file to use
+    #[structopt(parse(from_os_str), long = "config-file")]
+    config_file: Option<PathBuf>,
+    /// The name of the 'env' to use when loading configs and plugins.
+    /// Defaults to the value of SWC_ENV, or else NODE_ENV, or else
+    /// 'development'.
+    ///
+    /// DEPRECATED: use --env flag instead.
+    #[structopt(long = "env-name")]
+    env_name: Option<String>,
+    /// Whether or not to look up .swcrc files
+    #[structopt(long = "no-swcrc")]
+    no_swcrc: bool,
+    /// Generate source maps. `true` writes a `.map` file next to each output
+    /// file and `inline` appends the map to the output.
+    #[structopt(
+        short = "s",
+        long = "source-maps",
+        possible_values = &["true", "false", "inline"]
+    )]
+    source_maps: Option<String>,
+    /// Compile a single input file into an output file
+    #[structopt(parse(from_os_str), short = "o", long = "out-file")]
+    out_file: Option<PathBuf>,
+    /// Compile input files and directories into an output directory
+    #[structopt(parse(from_os_str), short = "d", long = "out-dir")]
+    out_dir: Option<PathBuf>,
+    /// Extension of the files written to `--out-dir`
+    #[structopt(long = "out-file-extension", default_value = "js")]
+    out_file_extension: String,
+    /// Comma-separated list of extensions to compile when a directory is
+    /// given as an input
+    #[structopt(
+        long = "extensions",
+        use_delimiter = true,
+        default_value = "js,jsx,es6,es,mjs,cjs,ts,tsx,mts,cts"
+    )]
+    extensions: Vec<String>,
+    /// Copy files which are not compiled to `--out-dir`
+    #[structopt(short = "D", long = "copy-files")]
+    copy_files: bool,
+    /// Recompile input files when they change
+    #[structopt(short = "w", long = "watch")]
+    watch: bool,
+    /// Suppress the compilation summary
+    #[structopt(short = "q", long = "quiet")]
+    quiet: bool,
+    /// Files and directories to compile. Reads from stdin if empty.
+    #[structopt(parse(from_os_str))]
+    files: Vec<PathBuf>,
+}
+
+/// A file found in the inputs of [CompileCommand].
+#[derive(Debug)]
+struct InputFile {
+    path: PathBuf,
+    /// Path relative to the input directory, or to the working directory if
+    /// the file is given directly. Used to compute the path in `--out-dir`.
+    rel_path: PathBuf,
+    /// `true` if the file should be copied instead of compiled.
+    copy: bool,
+    /// `true` if the file was found by walking a directory, which means it
+    /// should be skipped if it's ignored by `.swcrc`.
+    discovered: bool,
+}
+
+/// Result of handling an [InputFile].
+enum Outcome {
+    Compiled,
+    Copied,
+    Ignored,
+}
+
+impl CompileCommand {
+    pub fn execute(self) -> Result<(), Error> {
+        if self.out_file.is_some() && self.out_dir.is_some() {
+            bail!("--out-file and --out-dir cannot be used together");
+        }
+
+        let compiler = Compiler::new(Arc::new(SourceMap::new(FilePathMapping::empty())));
+
+        if self.files.is_empty() {
+            if self.watch {
+                bail!("--watch requires input files");
+            }
+
+            return self.compile_stdin(&compiler);
+        }
+
+        let inputs = self.collect_inputs()?;
+        if self.out_file.is_some() && inputs.len() != 1 {
+            bail!("--out-file requires exactly one input file; use --out-dir instead");
+        }
+
+        let failed = self.run(&compiler, &inputs);
+
+        if self.watch {
+            return self.watch(&compiler, &inputs);
+        }
+
+        if failed != 0 {
+            bail!("failed to compile {} file(s)", failed)
+        }
+
+        Ok(())
+    }
+
+    /// Compiles `inputs` in parallel, prints errors and the summary, and
+    /// returns the number of failed files.
+    fn run(&self, compiler: &Compiler, inputs: &[InputFile]) -> usize {
+        let start = Instant::now();
+
+        let results = inputs
+            .par_iter()
+            .map(|input| {
+                self.handle_file(compiler, input)
+                    .with_context(|| format!("failed to compile file ({})", input.path.display()))
+            })
+            .collect::<Vec<_>>();
+
+        let mut compiled = 0;
+        let mut copied = 0;
+        let mut failed = 0;
+        for result in results {
+            match result {
+                Ok(Outcome::Compiled) => compiled += 1,
+                Ok(Outcome::Copied) => copied += 1,
+                Ok(Outcome::Ignored) => {}
+                Err(err) => {
+                    failed += 1;
+                    eprintln!("{:?}", err);
+                }
+            }
+        }
+
+        if !self.quiet && self.writes_files() {
+            eprintln!(
+                "Successfully compiled {} file(s) with swc ({:.2?}).",
+                compiled,
+                start.elapsed()
+            );
+            if copied != 0 {
+                eprintln!("Copied {} file(s).", copied);
+            }
+        }
+
+        failed
+    }
+
+    /// Polls the inputs and recompiles files which are added or modified.
+    ///
+    /// `.swcrc` files are polled too, and all inputs are recompiled if one of
+    /// them is added, modified or removed. Configs are read from the disk for
+    /// each compilation, so recompiled files use the new config.
+    ///
+    /// Errors while reading the inputs, like a removed input file, are printed
+    /// and the inputs are polled again, so this never returns.
+    fn watch(&self, compiler: &Compiler, inputs: &[InputFile]) -> ! {
+        let mut mtimes = inputs
+            .iter()
+            .filter_map(|input| Some((input.path.clone(), modified(&input.path)?)))
+            .collect::<HashMap<_, _>>();
+        let mut config_mtimes = self
+            .config_files(inputs)
+            .into_iter()
+            .map(|path| {
+                let time = modified(&path);
+                (path, time)
+            })
+            .collect::<HashMap<_, _>>();
+
+        if !self.quiet {
+            eprintln!("Watching for file changes.");
+        }
+
+        // Used to print the same error only once.
+        let mut last_err = None;
+
+        loop {
+            thread::sleep(WATCH_INTERVAL);
+
+            let inputs = match self.collect_inputs() {
+                Ok(v) => {
+                    last_err = None;
+                    v
+                }
+                Err(err) => {
+                    let msg = format!("{:?}", err);
+                    if last_err.as_ref() != Some(&msg) {
+                        eprintln!("{}", msg);
+                        last_err = Some(msg);
+                    }
+                    continue;
+                }
+            };
+
+            let mut config_changed = false;
+            for path in self.config_files(&inputs) {
+                let time = modified(&path);
+                config_changed |= config_mtimes.insert(path, time) != Some(time);
+            }
+
+            let changed = inputs
+                .into_iter()
+                .filter(|input| {
+                    let time = match modified(&input.path) {
+                        Some(v) => v,
+                        None => return false,
+                    };
+
+                    let is_modified = mtimes.insert(input.path.clone(), time) != Some(time);
+                    is_modified || config_changed
+                })
+                .collect::<Vec<_>>();
+
+            if !changed.is_empty() {
+                self.run(compiler, &changed);
+            }
+        }
+    }
+
+    fn compile_stdin(&self, compiler: &Compiler) -> Result<(), Error> {
+        let mut src = String::new();
+        io::stdin()
+            .read_to_string(&mut src)
+            .context("failed to read from stdin")?;
+
+        let path = self.file_name.as_deref().map(Path::new);
+        let name = match path {
+            Some(path) => FileName::Real(path.to_path_buf()),
+            None => FileName::Anon,
+        };
+        let fm = compiler.cm.new_source_file(name, src);
+
+        let opts = self.options(path, self.out_file.as_deref());
+        let output = transform(compiler, fm, &opts)?;
+
+        match &self.out_file {
+            Some(out_file) => write_output(out_file, output),
+            None => {
+                io::stdout()
+                    .write_all(output.code.as_bytes())
+                    .context("failed to write to stdout")?;
+                Ok(())
+            }
+        }
+    }
+
+    fn handle_file(&self, compiler: &Compiler, input: &InputFile) -> Result<Outcome, Error> {
+        let out_path = self.out_path(input);
+
+        if input.copy {
+            let out_path = out_path.expect("files are copied only if --out-dir is set");
+            create_parent_dir(&out_path)?;
+            fs::copy(&input.path, &out_path)
+                .with_context(|| format!("failed to copy file to {}", out_path.display()))?;
+            return Ok(Outcome::Copied);
+        }
+
+        let opts = self.options(Some(&input.path), out_path.as_deref());
+
+        if input.discovered
+            && compiler
+                .read_config(&opts, &FileName::Real(input.path.clone()))?
+                .is_none()
+        {
+            return Ok(Outcome::Ignored);
+        }
+
+        let fm = compiler
+            .cm
+            .load_file(&input.path)
+            .context("failed to load file")?;
+        let output = transform(compiler, fm, &opts)?;
+
+        match out_path {
+            Some(out_path) => write_output(&out_path, output)?,
+            None => {
+                let stdout = io::stdout();
+                let mut stdout = stdout.lock();
+                stdout
+                    .write_all(output.code.as_bytes())
+                    .and_then(|_| stdout.write_all(b"\n"))
+                    .context("failed to write to stdout")?;
+            }
+        }
+
+        Ok(Outcome::Compiled)
+    }
+
+    /// Builds [Options] for a file.
+    ///
+    /// `out_path` is [None] if the output is printed to stdout.
+    fn options(&self, file: Option<&Path>, out_path: Option<&Path>) -> Options {
+        let mut config = Config::default();
+        if let Some(file) = file {
+            config.adjust(file);
+        }
+
+        let source_maps = self.source_maps.as_deref().map(|v| match v {
+            // There's no file to write the source map to.
+            "true" if out_path.is_none() => SourceMapsConfig::Str("inline".into()),
+            "inline" => SourceMapsConfig::Str("inline".into()),
+            v => SourceMapsConfig::Bool(v == "true"),
+        });
+
+        Options {
+            config,
+            cwd: env::current_dir().unwrap_or_default(),
+            filename: file
+                .map(|file| file.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            config_file: self
+                .config_file
+                .as_ref()
+                .map(|path| ConfigFile::Str(path.to_string_lossy().into_owned())),
+            swcrc: !self.no_swcrc,
+            env_name: self.env_name.clone().unwrap_or_else(default_env_name),
+            source_maps,
+            output_path: out_path.and_then(Path::parent).map(Path::to_path_buf),
+            ..Default::default()
+        }
+    }
+
+    /// Returns [None] if the output should be printed to stdout.
+    fn out_path(&self, input: &InputFile) -> Option<PathBuf> {
+        if let Some(out_file) = &self.out_file {
+            return Some(out_file.clone());
+        }
+
+        let out_dir = self.out_dir.as_ref()?;
+        let path = out_dir.join(&input.rel_path);
+        if input.copy {
+            Some(path)
+        } else {
+            Some(path.with_extension(&self.out_file_extension))
+        }
+    }
+
+    fn writes_files(&self) -> bool {
+        self.out_file.is_some() || self.out_dir.is_some()
+    }
+
+    fn collect_inputs(&self) -> Result<Vec<InputFile>, Error> {
+        let mut inputs = vec![];
+
+        for path in &self.files {
+            if !path.is_dir() {
+                if !path.is_file() {
+                    bail!("input file does not exist: {}", path.display())
+                }
+
+                inputs.push(InputFile {
+                    path: path.clone(),
+                    rel_path: rel_path_of_file(path),
+                    copy: false,
+                    discovered: false,
+                });
+                continue;
+            }
+
+            for entry in WalkDir::new(path).sort_by(|a, b| a.file_name().cmp(b.file_name())) {
+                let entry = entry
+                    .with_context(|| format!("failed to read directory {}", path.display()))?;
+                if !entry.file_type().is_file() {
+                    continue;
+                }
+
+                let copy = !self.is_compilable(entry.path());
+                if copy && !(self.copy_files && self.out_dir.is_some()) {
+                    continue;
+                }
+
+                inputs.push(InputFile {
+                    path: entry.path().to_path_buf(),
+                    rel_path: entry
+                        .path()
+                        .strip_prefix(path)
+                        .expect("walkdir should yield paths inside the root")
+                        .to_path_buf(),
+                    copy,
+                    discovered: true,
+                });
+            }
+        }
+
+        Ok(inputs)
+    }
+
+    /// Returns the paths of config files which may be used to compile
+    /// `inputs`, including ones which don't exist yet.
+    fn config_files(&self, inputs: &[InputFile]) -> Vec<PathBuf> {
+        let mut files = vec![];
+
+        if let Some(config_file) = &self.config_file {
+            files.push(config_file.clone());
+        }
+
+        if !self.no_swcrc {
+            for input in inputs {
+                files.extend(input.path.ancestors().skip(1).map(|dir| dir.join(".swcrc")));
+            }
+        }
+
+        files.sort();
+        files.dedup();
+        files
+    }
+
+    fn is_compilable(&self, path: &Path) -> bool {
+        let ext = match path.extension() {
+            Some(v) => v,
+            None => return false,
+        };
+
+        self.extensions
+            .iter()
+            .any(|v| ext == v.trim_start_matches('.'))
+    }
+}
+
+/// Returns the path of a file given as an input relative to the working
+/// directory, or the file name if it's outside of the working directory.
+fn rel_path_of_file(path: &Path) -> PathBuf {
+    let cwd = env::current_dir().unwrap_or_default();
+    let rel = path.strip_prefix(&cwd).unwrap_or(path);
+
+    let mut rel_path = PathBuf::new();
+    for c in rel.components() {
+        match c {
+            Component::Normal(c) => rel_path.push(c),
+            Component::CurDir => {}
+            _ => return path.file_name().map(PathBuf::from).unwrap_or_default(),
+        }
+    }
+    rel_path
+}
+
+fn transform(
+    compiler: &Compiler,
+    fm: Arc<SourceFile>,
+    opts: &Options,
+) -> Result<TransformOutput, Error> {
+    try_with_handler(compiler.cm.clone(), false, |handler| {
+        compiler.process_js_file(fm, handler, opts)
+    })
+}
+
+/// Writes the code to `out_path` and the source map, if any, next to it.
+fn write_output(out_path: &Path, output: TransformOutput) -> Result<(), Error> {
+    create_parent_dir(out_path)?;
+
+    let mut code = output.code;
+
+    if let Some(map) = output.map {
+        let mut map_path = out_path.as_os_str().to_os_string();
+        map_path.push(".map");
+        let map_path = PathBuf::from(map_path);
+
+        fs::write(&map_path, map)
+            .with_context(|| format!("failed to write source map to {}", map_path.display()))?;
+
+        code.push_str("\n//# sourceMappingURL=");
+        code.push_str(&map_path.file_name().unwrap_or_default().to_string_lossy());
+    }
+
+    fs::write(out_path, code)
+        .with_context(|| format!("failed to write output to {}", out_path.display()))
+}
+
+fn create_parent_dir(path: &Path) -> Result<(), Error> {
+    match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create directory {}", dir.display())),
+        _ => Ok(()),
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn default_env_name() -> String {
+    env::var("SWC_ENV")
+        .or_else(|_| env::var("NODE_ENV"))
+        .unwrap_or_else(|_| "development".into())
+}
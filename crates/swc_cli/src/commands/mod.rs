@@ -0,0 +1,3 @@
+pub use self::compile::CompileCommand;
+
+mod compile;
@@ -1,7 +1,8 @@
 mod cli;
+mod commands;
 use cli::SwcCommand;
 
 #[paw::main]
-fn main(_args: SwcCommand) {
-    unimplemented!("Not yet implemented");
+fn main(args: SwcCommand) -> anyhow::Result<()> {
+    args.execute()
 }
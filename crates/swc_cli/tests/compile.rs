@@ -0,0 +1,258 @@
+use std::{
+    fs,
+    io::Write,
+    path::Path,
+    process::{Command, Output, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use tempfile::TempDir;
+
+const INPUT: &str = "const a = 1;\n";
+
+fn swc(dir: &Path) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_swc"));
+    cmd.current_dir(dir).arg("compile");
+    cmd
+}
+
+fn success(cmd: &mut Command) -> Output {
+    let output = cmd.output().expect("failed to run swc");
+    assert!(
+        output.status.success(),
+        "swc failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    output
+}
+
+fn write(path: &Path, content: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+#[test]
+fn file_to_stdout() {
+    let dir = TempDir::new().unwrap();
+    write(&dir.path().join("input.js"), INPUT);
+
+    let output = success(swc(dir.path()).args(&["--no-swcrc", "input.js"]));
+
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "var a = 1;");
+}
+
+#[test]
+fn stdin_to_stdout() {
+    let dir = TempDir::new().unwrap();
+
+    let mut child = swc(dir.path())
+        .arg("--no-swcrc")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to run swc");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(INPUT.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert!(
+        output.status.success(),
+        "swc failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "var a = 1;");
+}
+
+#[test]
+fn out_file() {
+    let dir = TempDir::new().unwrap();
+    write(&dir.path().join("input.js"), INPUT);
+
+    success(swc(dir.path()).args(&[
+        "--no-swcrc",
+        "--source-maps",
+        "true",
+        "--out-file",
+        "out/output.js",
+        "input.js",
+    ]));
+
+    let code = fs::read_to_string(dir.path().join("out/output.js")).unwrap();
+    assert!(code.starts_with("var a = 1;"), "{}", code);
+    assert!(
+        code.ends_with("//# sourceMappingURL=output.js.map"),
+        "{}",
+        code
+    );
+    assert!(dir.path().join("out/output.js.map").is_file());
+}
+
+#[test]
+fn out_file_requires_single_input() {
+    let dir = TempDir::new().unwrap();
+    write(&dir.path().join("a.js"), INPUT);
+    write(&dir.path().join("b.js"), INPUT);
+
+    let output = swc(dir.path())
+        .args(&["--no-swcrc", "--out-file", "out.js", "a.js", "b.js"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(!dir.path().join("out.js").exists());
+}
+
+#[test]
+fn directory_to_out_dir() {
+    let dir = TempDir::new().unwrap();
+    write(&dir.path().join("src/a.js"), INPUT);
+    write(&dir.path().join("src/nested/b.mjs"), INPUT);
+    write(&dir.path().join("src/README.md"), "# readme\n");
+
+    success(swc(dir.path()).args(&["--no-swcrc", "--out-dir", "lib", "src"]));
+
+    let out = dir.path().join("lib");
+    assert_eq!(
+        fs::read_to_string(out.join("a.js")).unwrap().trim(),
+        "var a = 1;"
+    );
+    assert_eq!(
+        fs::read_to_string(out.join("nested/b.js")).unwrap().trim(),
+        "var a = 1;"
+    );
+    assert!(!out.join("README.md").exists());
+}
+
+#[test]
+fn file_to_out_dir() {
+    let dir = TempDir::new().unwrap();
+    write(&dir.path().join("src/nested/a.js"), INPUT);
+
+    success(swc(dir.path()).args(&["--no-swcrc", "--out-dir", "lib", "src/nested/a.js"]));
+
+    assert_eq!(
+        fs::read_to_string(dir.path().join("lib/src/nested/a.js"))
+            .unwrap()
+            .trim(),
+        "var a = 1;"
+    );
+}
+
+#[test]
+fn directory_copy_files() {
+    let dir = TempDir::new().unwrap();
+    write(&dir.path().join("src/a.js"), INPUT);
+    write(&dir.path().join("src/README.md"), "# readme\n");
+
+    success(swc(dir.path()).args(&["--no-swcrc", "--copy-files", "--out-dir", "lib", "src"]));
+
+    assert_eq!(
+        fs::read_to_string(dir.path().join("lib/README.md")).unwrap(),
+        "# readme\n"
+    );
+}
+
+#[test]
+fn watch_reloads_swcrc() {
+    let dir = TempDir::new().unwrap();
+    write(&dir.path().join("src/a.js"), INPUT);
+    write(
+        &dir.path().join("src/.swcrc"),
+        r#"{ "jsc": { "target": "es5" } }"#,
+    );
+
+    let mut child = swc(dir.path())
+        .args(&["--watch", "--out-dir", "lib", "src"])
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to run swc");
+
+    let out = dir.path().join("lib/a.js");
+    let wait_for = |expected: &str| {
+        let start = Instant::now();
+        loop {
+            if let Ok(code) = fs::read_to_string(&out) {
+                if code.trim() == expected {
+                    return true;
+                }
+            }
+            if start.elapsed() > Duration::from_secs(30) {
+                return false;
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+    };
+
+    let compiled = wait_for("var a = 1;");
+    let recompiled = compiled && {
+        // Make sure the modification time changes.
+        thread::sleep(Duration::from_millis(1100));
+        write(
+            &dir.path().join("src/.swcrc"),
+            r#"{ "jsc": { "target": "es2015" } }"#,
+        );
+        wait_for("const a = 1;")
+    };
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    assert!(compiled, "swc did not compile the input");
+    assert!(
+        recompiled,
+        "swc did not recompile the input with new .swcrc"
+    );
+}
+
+#[test]
+fn watch_survives_removed_input() {
+    let dir = TempDir::new().unwrap();
+    let input = dir.path().join("a.js");
+    write(&input, INPUT);
+
+    let mut child = swc(dir.path())
+        .args(&["--no-swcrc", "--watch", "--out-dir", "lib", "a.js"])
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to run swc");
+
+    let out = dir.path().join("lib/a.js");
+    let wait_for = |expected: &str| {
+        let start = Instant::now();
+        loop {
+            if let Ok(code) = fs::read_to_string(&out) {
+                if code.trim() == expected {
+                    return true;
+                }
+            }
+            if start.elapsed() > Duration::from_secs(30) {
+                return false;
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+    };
+
+    let compiled = wait_for("var a = 1;");
+    let recompiled = compiled && {
+        fs::remove_file(&input).unwrap();
+        // Let swc poll the inputs while the file is missing.
+        thread::sleep(Duration::from_millis(1100));
+        write(&input, "const b = 2;\n");
+        wait_for("var b = 2;")
+    };
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    assert!(compiled, "swc did not compile the input");
+    assert!(
+        recompiled,
+        "swc did not recompile the input after it was removed and added again"
+    );
+}
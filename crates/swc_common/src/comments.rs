@@ -525,6 +525,10 @@ impl SingleThreadedComments {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Comment {
     pub kind: CommentKind,
     pub span: Span,
@@ -538,6 +542,10 @@ impl Spanned for Comment {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub enum CommentKind {
     Line,
     Block,
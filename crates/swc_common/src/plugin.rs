@@ -33,6 +33,49 @@ pub enum PluginError {
     Serialize(String),
 }
 
+/// Metadata of the file being transformed, which plugins can access via the
+/// host context.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "plugin-base",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct PluginContext {
+    /// Name of the file being transformed. `None` for anonymous inputs.
+    pub filename: Option<String>,
+    /// Value of `envName` of the options.
+    pub env_name: String,
+    /// Current working directory of the transform.
+    pub cwd: String,
+    /// `true` if the input is parsed as an es module.
+    pub is_module: bool,
+    /// Mark applied to the top-level bindings by the resolver of the host.
+    ///
+    /// References to the unresolved (global) identifiers have the empty
+    /// [SyntaxContext].
+    pub top_level_mark: Mark,
+}
+
+/// Location of a position in the source, resolved by host's
+/// [SourceMap](crate::SourceMap).
+///
+/// Unlike [Loc](crate::Loc), this contains only the name of the source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "plugin-base",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct PluginLoc {
+    /// Name of the source file.
+    pub filename: String,
+    /// The (1-based) line number
+    pub line: usize,
+    /// The (0-based) column offset
+    pub col: usize,
+    /// The (0-based) column offset when displayed
+    pub col_display: usize,
+}
+
 /// Wraps internal representation of serialized data. Consumers should not
 /// rely on specific details of byte format struct contains: it is
 /// strictly implementation detail which can change anytime.
@@ -1122,6 +1122,8 @@ impl SourceMap {
         // mappings.sort_by_key(|v| v.0);
 
         let mut cur_file: Option<Lrc<SourceFile>> = None;
+        // Input source map of `cur_file`.
+        let mut cur_orig = orig;
 
         let mut ch_start = 0;
         let mut line_ch_start = 0;
@@ -1141,6 +1143,7 @@ impl SourceMap {
                 _ => {
                     f = self.lookup_source_file(pos);
                     src_id = builder.add_source(&config.file_name_to_source(&f.name));
+                    cur_orig = config.orig_source_map(&f.name).or(orig);
 
                     if config.inline_sources_content(&f.name) {
                         builder.set_source_contents(src_id, Some(&f.src));
@@ -1179,12 +1182,20 @@ impl SourceMap {
 
             let mut col = max(chpos, linechpos) - min(chpos, linechpos);
 
-            if let Some(orig) = &orig {
-                if let Some(token) = orig.lookup_token(line, col) {
+            if let Some(cur_orig) = cur_orig {
+                if let Some(token) = cur_orig.lookup_token(line - 1, col) {
                     line = token.get_src_line() + 1;
                     col = token.get_src_col();
                     if let Some(src) = token.get_source() {
                         src_id = builder.add_source(src);
+
+                        // Contents of `orig` are added above.
+                        if builder.get_source_contents(src_id).is_none() {
+                            if let Some(contents) = cur_orig.get_source_contents(token.get_src_id())
+                            {
+                                builder.set_source_contents(src_id, Some(contents));
+                            }
+                        }
                     }
                 }
             }
@@ -1271,6 +1282,17 @@ pub trait SourceMapGenConfig {
             FileName::Real(..) | FileName::Custom(..) | FileName::Url(..)
         )
     }
+
+    /// Input source map of `f`, which is used instead of the `orig` passed to
+    /// [SourceMap::build_source_map_with_config] for mappings from `f`.
+    ///
+    /// This is useful if the output contains code from multiple files, like a
+    /// bundle.
+    #[cfg(feature = "sourcemap")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sourcemap")))]
+    fn orig_source_map(&self, _f: &FileName) -> Option<&sourcemap::SourceMap> {
+        None
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -1285,6 +1307,19 @@ macro_rules! impl_ref {
             fn file_name_to_source(&self, f: &FileName) -> String {
                 (**self).file_name_to_source(f)
             }
+
+            fn name_for_bytepos(&self, bpos: BytePos) -> Option<&str> {
+                (**self).name_for_bytepos(bpos)
+            }
+
+            fn inline_sources_content(&self, f: &FileName) -> bool {
+                (**self).inline_sources_content(f)
+            }
+
+            #[cfg(feature = "sourcemap")]
+            fn orig_source_map(&self, f: &FileName) -> Option<&sourcemap::SourceMap> {
+                (**self).orig_source_map(f)
+            }
         }
     };
 }
@@ -1556,4 +1591,33 @@ mod tests {
             }
         }
     }
+    /// Line numbers of an input source map start at 0.
+    #[test]
+    #[cfg(feature = "sourcemap")]
+    fn build_source_map_from_orig_line() {
+        let sm = SourceMap::new(FilePathMapping::empty());
+        let fm = sm.new_source_file(PathBuf::from("a.js").into(), "a;\nb;".to_string());
+
+        let mut orig = sourcemap::SourceMapBuilder::new(None);
+        let src_id = orig.add_source("a.ts");
+        orig.add_raw(0, 0, 10, 0, Some(src_id), None);
+        orig.add_raw(1, 0, 20, 0, Some(src_id), None);
+        let orig = orig.into_sourcemap();
+
+        let map = sm.build_source_map_from(
+            &mut vec![
+                (fm.start_pos, LineCol { line: 0, col: 0 }),
+                (fm.start_pos + BytePos(3), LineCol { line: 1, col: 0 }),
+            ],
+            Some(&orig),
+        );
+
+        let a = map.lookup_token(0, 0).unwrap();
+        assert_eq!(a.get_source(), Some("a.ts"));
+        assert_eq!(a.get_src_line(), 10);
+
+        let b = map.lookup_token(1, 0).unwrap();
+        assert_eq!(b.get_source(), Some("a.ts"));
+        assert_eq!(b.get_src_line(), 20);
+    }
 }
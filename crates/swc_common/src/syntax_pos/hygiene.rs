@@ -53,6 +53,10 @@ struct SyntaxContextData {
 
 /// A mark is a unique id associated with a macro expansion.
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Mark(u32);
 
 #[derive(Clone, Debug)]
@@ -61,7 +65,32 @@ struct MarkData {
     is_builtin: bool,
 }
 
+// Operations on the hygiene data are proxied to the host while running as a
+// plugin, as the guest's `GLOBALS` are not shared with the host.
+#[cfg(all(feature = "plugin-mode", target_arch = "wasm32"))]
+extern "C" {
+    fn __mark_fresh_proxy(parent: u32) -> u32;
+    fn __mark_parent_proxy(self_mark: u32) -> u32;
+    fn __mark_is_descendant_of_proxy(self_mark: u32, ancestor: u32) -> u32;
+    fn __mark_least_ancestor_proxy(a: u32, b: u32) -> u32;
+    fn __syntax_context_apply_mark_proxy(self_syntax_context: u32, mark: u32) -> u32;
+    fn __syntax_context_remove_mark_proxy(self_syntax_context: u32, allocated_ret_ptr: i32);
+    fn __syntax_context_outer_proxy(self_syntax_context: u32) -> u32;
+}
+
+/// A struct host writes the result of `SyntaxContext::remove_mark` into.
+#[cfg(all(feature = "plugin-mode", target_arch = "wasm32"))]
+#[repr(C)]
+#[derive(Default)]
+struct MutableMarkContext(u32, u32);
+
 impl Mark {
+    #[cfg(all(feature = "plugin-mode", target_arch = "wasm32"))]
+    pub fn fresh(parent: Mark) -> Self {
+        Mark(unsafe { __mark_fresh_proxy(parent.0) })
+    }
+
+    #[cfg(not(all(feature = "plugin-mode", target_arch = "wasm32")))]
     pub fn fresh(parent: Mark) -> Self {
         HygieneData::with(|data| {
             data.marks.push(MarkData {
@@ -89,6 +118,13 @@ impl Mark {
         Mark(raw)
     }
 
+    #[cfg(all(feature = "plugin-mode", target_arch = "wasm32"))]
+    #[inline]
+    pub fn parent(self) -> Mark {
+        Mark(unsafe { __mark_parent_proxy(self.0) })
+    }
+
+    #[cfg(not(all(feature = "plugin-mode", target_arch = "wasm32")))]
     #[inline]
     pub fn parent(self) -> Mark {
         HygieneData::with(|data| data.marks[self.0 as usize].parent)
@@ -108,6 +144,12 @@ impl Mark {
         HygieneData::with(|data| data.marks[self.0 as usize].is_builtin = is_builtin)
     }
 
+    #[cfg(all(feature = "plugin-mode", target_arch = "wasm32"))]
+    pub fn is_descendant_of(self, ancestor: Mark) -> bool {
+        unsafe { __mark_is_descendant_of_proxy(self.0, ancestor.0) != 0 }
+    }
+
+    #[cfg(not(all(feature = "plugin-mode", target_arch = "wasm32")))]
     pub fn is_descendant_of(mut self, ancestor: Mark) -> bool {
         HygieneData::with(|data| {
             while self != ancestor {
@@ -120,6 +162,11 @@ impl Mark {
         })
     }
 
+    #[cfg(all(feature = "plugin-mode", target_arch = "wasm32"))]
+    pub fn least_ancestor(a: Mark, b: Mark) -> Mark {
+        Mark(unsafe { __mark_least_ancestor_proxy(a.0, b.0) })
+    }
+
     /// Computes a mark such that both input marks are descendants of (or equal
     /// to) the returned mark. That is, the following holds:
     ///
@@ -128,6 +175,7 @@ impl Mark {
     /// assert!(a.is_descendant_of(la))
     /// assert!(b.is_descendant_of(la))
     /// ```
+    #[cfg(not(all(feature = "plugin-mode", target_arch = "wasm32")))]
     #[allow(unused_mut)]
     pub fn least_ancestor(mut a: Mark, mut b: Mark) -> Mark {
         HygieneData::with(|data| {
@@ -200,13 +248,15 @@ impl SyntaxContext {
         SyntaxContext(0)
     }
 
-    // pub(crate) fn as_u32(self) -> u32 {
-    //     self.0
-    // }
-    //
-    // pub(crate) fn from_u32(raw: u32) -> SyntaxContext {
-    //     SyntaxContext(raw)
-    // }
+    #[inline]
+    pub fn as_u32(self) -> u32 {
+        self.0
+    }
+
+    #[inline]
+    pub fn from_u32(raw: u32) -> SyntaxContext {
+        SyntaxContext(raw)
+    }
 
     /// Extend a syntax context with a given mark and default transparency for
     /// that mark.
@@ -215,6 +265,12 @@ impl SyntaxContext {
         self.apply_mark_internal(mark)
     }
 
+    #[cfg(all(feature = "plugin-mode", target_arch = "wasm32"))]
+    fn apply_mark_internal(self, mark: Mark) -> SyntaxContext {
+        SyntaxContext(unsafe { __syntax_context_apply_mark_proxy(self.0, mark.0) })
+    }
+
+    #[cfg(not(all(feature = "plugin-mode", target_arch = "wasm32")))]
     fn apply_mark_internal(self, mark: Mark) -> SyntaxContext {
         HygieneData::with(|data| {
             let syntax_contexts = &mut data.syntax_contexts;
@@ -266,6 +322,25 @@ impl SyntaxContext {
     /// the SyntaxContext for the invocation of f that created g1.
     /// Returns the mark that was removed.
     pub fn remove_mark(&mut self) -> Mark {
+        self.remove_mark_internal()
+    }
+
+    #[cfg(all(feature = "plugin-mode", target_arch = "wasm32"))]
+    fn remove_mark_internal(&mut self) -> Mark {
+        let mut context = MutableMarkContext::default();
+        unsafe {
+            __syntax_context_remove_mark_proxy(
+                self.0,
+                &mut context as *mut MutableMarkContext as _,
+            );
+        }
+
+        *self = SyntaxContext(context.0);
+        Mark(context.1)
+    }
+
+    #[cfg(not(all(feature = "plugin-mode", target_arch = "wasm32")))]
+    fn remove_mark_internal(&mut self) -> Mark {
         HygieneData::with(|data| {
             let outer_mark = data.syntax_contexts[self.0 as usize].outer_mark;
             *self = data.syntax_contexts[self.0 as usize].prev_ctxt;
@@ -381,6 +456,13 @@ impl SyntaxContext {
         Some(scope)
     }
 
+    #[cfg(all(feature = "plugin-mode", target_arch = "wasm32"))]
+    #[inline]
+    pub fn outer(self) -> Mark {
+        Mark(unsafe { __syntax_context_outer_proxy(self.0) })
+    }
+
+    #[cfg(not(all(feature = "plugin-mode", target_arch = "wasm32")))]
     #[inline]
     pub fn outer(self) -> Mark {
         HygieneData::with(|data| data.syntax_contexts[self.0 as usize].outer_mark)
@@ -99,7 +99,7 @@ where
 
     #[emitter]
     fn emit_charset_rule(&mut self, n: &CharsetRule) -> Result {
-        punct!(self, "@");
+        punct!(self, lo_span_offset!(n.span, 1), "@");
         keyword!(self, "charset");
         // https://drafts.csswg.org/css2/#charset%E2%91%A0
         // @charset must be written literally, i.e., the 10 characters '@charset "'
@@ -112,7 +112,7 @@ where
 
     #[emitter]
     fn emit_import_rule(&mut self, n: &ImportRule) -> Result {
-        punct!(self, "@");
+        punct!(self, lo_span_offset!(n.span, 1), "@");
         keyword!(self, "import");
 
         match n.href {
@@ -171,7 +171,7 @@ where
 
     #[emitter]
     fn emit_font_face_rule(&mut self, n: &FontFaceRule) -> Result {
-        punct!(self, "@");
+        punct!(self, lo_span_offset!(n.span, 1), "@");
         keyword!(self, "font-face");
         formatting_space!(self);
         emit!(self, n.block);
@@ -179,7 +179,7 @@ where
 
     #[emitter]
     fn emit_keyframes_rule(&mut self, n: &KeyframesRule) -> Result {
-        punct!(self, "@");
+        punct!(self, lo_span_offset!(n.span, 1), "@");
         keyword!(self, "keyframes");
 
         match n.name {
@@ -254,7 +254,7 @@ where
 
     #[emitter]
     fn emit_layer_rule(&mut self, n: &LayerRule) -> Result {
-        punct!(self, "@");
+        punct!(self, lo_span_offset!(n.span, 1), "@");
         keyword!(self, "layer");
 
         if n.prelude.is_some() {
@@ -282,7 +282,7 @@ where
 
     #[emitter]
     fn emit_media_rule(&mut self, n: &MediaRule) -> Result {
-        punct!(self, "@");
+        punct!(self, lo_span_offset!(n.span, 1), "@");
         keyword!(self, "media");
 
         if n.media.is_some() {
@@ -487,7 +487,7 @@ where
 
     #[emitter]
     fn emit_supports_rule(&mut self, n: &SupportsRule) -> Result {
-        punct!(self, "@");
+        punct!(self, lo_span_offset!(n.span, 1), "@");
         keyword!(self, "supports");
 
         match n.condition.conditions.get(0) {
@@ -570,7 +570,7 @@ where
 
     #[emitter]
     fn emit_page_rule(&mut self, n: &PageRule) -> Result {
-        punct!(self, "@");
+        punct!(self, lo_span_offset!(n.span, 1), "@");
         keyword!(self, "page");
         space!(self);
 
@@ -597,7 +597,7 @@ where
 
     #[emitter]
     fn emit_namespace_rule(&mut self, n: &NamespaceRule) -> Result {
-        punct!(self, "@");
+        punct!(self, lo_span_offset!(n.span, 1), "@");
         keyword!(self, "namespace");
 
         let has_prefix = n.prefix.is_some();
@@ -628,7 +628,7 @@ where
 
     #[emitter]
     fn emit_viewport_rule(&mut self, n: &ViewportRule) -> Result {
-        punct!(self, "@");
+        punct!(self, lo_span_offset!(n.span, 1), "@");
         keyword!(self, "viewport");
         formatting_space!(self);
 
@@ -637,7 +637,7 @@ where
 
     #[emitter]
     fn emit_document_rule(&mut self, n: &DocumentRule) -> Result {
-        punct!(self, "@");
+        punct!(self, lo_span_offset!(n.span, 1), "@");
         keyword!(self, "document");
         space!(self);
 
@@ -737,7 +737,7 @@ where
 
     #[emitter]
     fn emit_color_profile_rule(&mut self, n: &ColorProfileRule) -> Result {
-        punct!(self, "@");
+        punct!(self, lo_span_offset!(n.span, 1), "@");
         keyword!(self, "color-profile");
         space!(self);
         emit!(self, n.name);
@@ -749,7 +749,7 @@ where
 
     #[emitter]
     fn emit_unknown_at_rule(&mut self, n: &UnknownAtRule) -> Result {
-        punct!(self, "@");
+        punct!(self, lo_span_offset!(n.span, 1), "@");
         emit!(self, n.name);
 
         self.emit_list(&n.prelude, ListFormat::NotDelimited)?;
@@ -795,7 +795,7 @@ where
 
     #[emitter]
     fn emit_block(&mut self, n: &Block) -> Result {
-        punct!(self, "{");
+        punct!(self, lo_span_offset!(n.span, 1), "{");
 
         self.emit_list(
             &n.value,
@@ -806,7 +806,7 @@ where
             },
         )?;
 
-        punct!(self, "}");
+        punct!(self, hi_span_offset!(n.span, 1), "}");
     }
 
     #[emitter]
@@ -1263,7 +1263,7 @@ where
 
     #[emitter]
     fn emit_combinator(&mut self, n: &Combinator) -> Result {
-        self.wr.write_punct(None, n.value.as_str())?;
+        self.wr.write_punct(Some(n.span), n.value.as_str())?;
     }
 
     #[emitter]
@@ -1322,19 +1322,19 @@ where
 
     #[emitter]
     fn emit_id_selector(&mut self, n: &IdSelector) -> Result {
-        punct!(self, "#");
+        punct!(self, lo_span_offset!(n.span, 1), "#");
         emit!(self, n.text);
     }
 
     #[emitter]
     fn emit_class_selector(&mut self, n: &ClassSelector) -> Result {
-        punct!(self, ".");
+        punct!(self, lo_span_offset!(n.span, 1), ".");
         emit!(self, n.text);
     }
 
     #[emitter]
     fn emit_attribute_selector(&mut self, n: &AttributeSelector) -> Result {
-        punct!(self, "[");
+        punct!(self, lo_span_offset!(n.span, 1), "[");
         emit!(self, n.name);
 
         if n.matcher.is_some() {
@@ -1416,7 +1416,7 @@ where
 
     #[emitter]
     fn emit_pseudo_class_selector(&mut self, n: &PseudoClassSelector) -> Result {
-        punct!(self, ":");
+        punct!(self, lo_span_offset!(n.span, 1), ":");
 
         emit!(self, n.name);
 
@@ -1429,8 +1429,7 @@ where
 
     #[emitter]
     fn emit_pseudo_element_selector(&mut self, n: &PseudoElementSelector) -> Result {
-        punct!(self, ":");
-        punct!(self, ":");
+        punct!(self, lo_span_offset!(n.span, 2), "::");
 
         emit!(self, n.name);
 
@@ -1443,7 +1442,7 @@ where
 
     #[emitter]
     fn emit_at_selector(&mut self, n: &AtSelector) -> Result {
-        punct!(self, "@");
+        punct!(self, lo_span_offset!(n.span, 1), "@");
         emit!(self, n.text);
     }
 
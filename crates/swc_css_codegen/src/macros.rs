@@ -53,3 +53,31 @@ macro_rules! semi {
         punct!($g, ";");
     }};
 }
+
+/// Span of the first `$len` bytes of `$span`, used to map punctuations like
+/// `@` of at-rules.
+macro_rules! lo_span_offset {
+    ($span:expr,$len:expr) => {{
+        let span: swc_common::Span = $span;
+
+        if span.is_dummy() {
+            span
+        } else {
+            span.with_hi(span.lo + swc_common::BytePos($len))
+        }
+    }};
+}
+
+/// Span of the last `$len` bytes of `$span`, used to map punctuations like
+/// `}` of blocks.
+macro_rules! hi_span_offset {
+    ($span:expr,$len:expr) => {{
+        let span: swc_common::Span = $span;
+
+        if span.is_dummy() {
+            span
+        } else {
+            span.with_lo(span.hi - swc_common::BytePos($len))
+        }
+    }};
+}
@@ -3,7 +3,7 @@ use std::{
     fmt::{Result, Write},
     str::from_utf8,
 };
-use swc_common::Span;
+use swc_common::{BytePos, LineCol, Span};
 
 pub struct BasicCssWriterConfig<'a> {
     pub indent: &'a str,
@@ -18,6 +18,8 @@ where
 
     indent_level: usize,
 
+    srcmap: Option<&'a mut Vec<(BytePos, LineCol)>>,
+
     config: BasicCssWriterConfig<'a>,
     w: W,
 }
@@ -26,16 +28,62 @@ impl<'a, W> BasicCssWriter<'a, W>
 where
     W: Write,
 {
-    pub fn new(writer: W, config: BasicCssWriterConfig<'a>) -> Self {
+    /// Mappings are pushed to `srcmap` if it's [Some], and it can be passed to
+    /// [swc_common::SourceMap::build_source_map].
+    pub fn new(
+        writer: W,
+        srcmap: Option<&'a mut Vec<(BytePos, LineCol)>>,
+        config: BasicCssWriterConfig<'a>,
+    ) -> Self {
         BasicCssWriter {
             config,
             w: writer,
             line: 0,
             col: 0,
             indent_level: 0,
+            srcmap,
+        }
+    }
+
+    fn srcmap(&mut self, byte_pos: BytePos) {
+        if let Some(ref mut srcmap) = self.srcmap {
+            srcmap.push((
+                byte_pos,
+                LineCol {
+                    line: self.line as _,
+                    col: self.col as _,
+                },
+            ))
         }
     }
 
+    fn srcmap_lo(&mut self, span: Option<Span>) {
+        if let Some(span) = span {
+            if !span.is_dummy() {
+                self.srcmap(span.lo);
+            }
+        }
+    }
+
+    fn srcmap_hi(&mut self, span: Option<Span>) {
+        if let Some(span) = span {
+            if !span.is_dummy() {
+                self.srcmap(span.hi);
+            }
+        }
+    }
+
+    fn write_char(&mut self, c: char) -> Result {
+        if c == '\n' {
+            self.line += 1;
+            self.col = 0;
+        } else {
+            self.col += c.len_utf8();
+        }
+
+        self.w.write_char(c)
+    }
+
     /// Applies indents if we are at the start of a line.
     fn apply_indent(&mut self) -> Result {
         if self.col == 0 {
@@ -53,21 +101,23 @@ impl<W> CssWriter for BasicCssWriter<'_, W>
 where
     W: Write,
 {
-    fn write_punct(&mut self, _span: Option<Span>, punct: &str) -> Result {
+    fn write_punct(&mut self, span: Option<Span>, punct: &str) -> Result {
         debug_assert!(
             !punct.contains('\n'),
             "punct should not contain newline characters"
         );
 
         self.apply_indent()?;
+        self.srcmap_lo(span);
         self.col += punct.len();
         self.w.write_str(punct)?;
+        self.srcmap_hi(span);
 
         Ok(())
     }
 
     fn write_space(&mut self) -> Result {
-        self.w.write_char(' ')
+        self.write_char(' ')
     }
 
     fn write_str(&mut self, span: Option<Span>, text: &str) -> Result {
@@ -129,30 +179,31 @@ where
             };
         }
 
-        if dq > sq {
-            self.write_raw_char(span, '\'')?;
-            self.write_raw(span, &new_string.replace('\'', "\\'"))?;
-            self.write_raw_char(span, '\'')?;
+        let quoted = if dq > sq {
+            format!("'{}'", new_string.replace('\'', "\\'"))
         } else {
-            self.write_raw_char(span, '"')?;
-            self.write_raw(span, &new_string.replace('"', "\\\""))?;
-            self.write_raw_char(span, '"')?;
-        }
+            format!("\"{}\"", new_string.replace('"', "\\\""))
+        };
 
-        Ok(())
+        self.write_raw(span, &quoted)
     }
 
     fn write_raw(&mut self, span: Option<Span>, text: &str) -> Result {
+        self.srcmap_lo(span);
+
         for char in text.chars() {
-            self.write_raw_char(span, char)?;
+            self.write_char(char)?;
         }
 
+        self.srcmap_hi(span);
+
         Ok(())
     }
 
-    fn write_raw_char(&mut self, _span: Option<Span>, c: char) -> Result {
-        self.col += c.len_utf8();
-        self.w.write_char(c)?;
+    fn write_raw_char(&mut self, span: Option<Span>, c: char) -> Result {
+        self.srcmap_lo(span);
+        self.write_char(c)?;
+        self.srcmap_hi(span);
 
         Ok(())
     }
@@ -51,6 +51,7 @@ fn run(input: &Path, minify: bool) {
         {
             let wr = BasicCssWriter::new(
                 &mut css_str,
+                None,
                 BasicCssWriterConfig {
                     indent: if minify { "" } else { "\t" },
                 },
@@ -170,7 +171,7 @@ fn parse_again(input: PathBuf) {
 
         let mut css_str = String::new();
         {
-            let wr = BasicCssWriter::new(&mut css_str, BasicCssWriterConfig { indent: "\t" });
+            let wr = BasicCssWriter::new(&mut css_str, None, BasicCssWriterConfig { indent: "\t" });
             let mut gen = CodeGenerator::new(wr, CodegenConfig { minify: false });
 
             gen.emit(&stylesheet).unwrap();
@@ -0,0 +1,84 @@
+use swc_common::{BytePos, FileName, LineCol};
+use swc_css_ast::Stylesheet;
+use swc_css_codegen::{
+    writer::basic::{BasicCssWriter, BasicCssWriterConfig},
+    CodeGenerator, CodegenConfig, Emit,
+};
+use swc_css_parser::parse_file;
+
+const INPUT: &str = "a {
+    color: red;
+}
+
+@media print {
+    .b > c {
+        margin: 0;
+    }
+}
+";
+
+/// Prints `INPUT` and returns the original `(line, col)` of each `needle`
+/// found in the output.
+fn original_positions(minify: bool, needles: &[&str]) -> Vec<(u32, u32)> {
+    testing::run_test2(false, |cm, _handler| {
+        let fm = cm.new_source_file(FileName::Anon, INPUT.into());
+
+        let mut errors = vec![];
+        let stylesheet: Stylesheet = parse_file(&fm, Default::default(), &mut errors).unwrap();
+        assert!(errors.is_empty());
+
+        let mut css_str = String::new();
+        let mut src_map_buf: Vec<(BytePos, LineCol)> = vec![];
+        {
+            let wr = BasicCssWriter::new(
+                &mut css_str,
+                Some(&mut src_map_buf),
+                BasicCssWriterConfig { indent: "\t" },
+            );
+            let mut gen = CodeGenerator::new(wr, CodegenConfig { minify });
+
+            gen.emit(&stylesheet).unwrap();
+        }
+
+        let map = cm.build_source_map(&mut src_map_buf);
+
+        Ok(needles
+            .iter()
+            .map(|needle| {
+                let (line, line_str) = css_str
+                    .lines()
+                    .enumerate()
+                    .find(|(_, line)| line.contains(needle))
+                    .unwrap_or_else(|| panic!("`{}` is not found in\n{}", needle, css_str));
+                let col = line_str.find(needle).unwrap();
+
+                let token = map
+                    .lookup_token(line as _, col as _)
+                    .unwrap_or_else(|| panic!("`{}` is not mapped", needle));
+                assert_eq!(
+                    (token.get_dst_line(), token.get_dst_col()),
+                    (line as _, col as _),
+                    "`{}` should be mapped exactly",
+                    needle
+                );
+
+                (token.get_src_line(), token.get_src_col())
+            })
+            .collect())
+    })
+    .unwrap()
+}
+
+#[test]
+fn selectors_declarations_and_at_rules() {
+    let expected = vec![(0, 0), (1, 4), (4, 0), (5, 4), (5, 7), (6, 8)];
+
+    assert_eq!(
+        original_positions(false, &["a", "color", "@media", ".b", ">", "margin"]),
+        expected
+    );
+    assert_eq!(
+        original_positions(true, &["a{", "color", "@media", ".b", ">", "margin"]),
+        expected
+    );
+}
@@ -0,0 +1,247 @@
+use super::Compressor;
+use swc_atoms::JsWord;
+use swc_css_ast::*;
+
+impl Compressor {
+    /// Shortens hex colors and named colors.
+    ///
+    /// `#FFFFFF` => `#fff`, `#ff0000` => `red`, `white` => `#fff`
+    pub(super) fn compress_color(&mut self, n: &mut Value) {
+        match n {
+            Value::Color(Color::HexColor(color)) => {
+                let hex = minify_hex(&color.value);
+
+                // Named colors are shorter than some hex colors.
+                if let Some(name) = shorter_named_color(&hex) {
+                    *n = Value::Ident(Ident {
+                        span: color.span,
+                        value: name.into(),
+                        raw: name.into(),
+                    });
+                    return;
+                }
+
+                let hex: JsWord = hex.into();
+                color.value = hex.clone();
+                color.raw = hex;
+            }
+
+            Value::Ident(ident) if !self.ctx.preserve_idents => {
+                let hex = match NAMED_COLORS
+                    .binary_search_by(|(name, _)| (*name).cmp(&*ident.value.to_ascii_lowercase()))
+                {
+                    Ok(idx) => minify_hex(NAMED_COLORS[idx].1),
+                    Err(..) => return,
+                };
+
+                // `#` + hex
+                if hex.len() + 1 < ident.value.len() {
+                    let hex: JsWord = hex.into();
+
+                    *n = Value::Color(Color::HexColor(HexColor {
+                        span: ident.span,
+                        value: hex.clone(),
+                        raw: hex,
+                    }));
+                }
+            }
+
+            _ => {}
+        }
+    }
+}
+
+/// Returns true if `name` is a named color, except ones added by CSS Color
+/// Module Level 4.
+pub(super) fn is_named_color(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+
+    name != "rebeccapurple"
+        && NAMED_COLORS
+            .binary_search_by(|(color, _)| (*color).cmp(&*name))
+            .is_ok()
+}
+
+/// Lowercases the hex color and uses the short notation if possible.
+///
+/// Note: The value does not include `#`.
+fn minify_hex(value: &str) -> String {
+    let value = value.to_ascii_lowercase();
+    let chars = value.as_bytes();
+
+    match chars.len() {
+        // `#rrggbbaa` with opaque alpha
+        8 if chars[6] == b'f' && chars[7] == b'f' => minify_hex(&value[..6]),
+        // `#rgba` with opaque alpha
+        4 if chars[3] == b'f' => value[..3].to_string(),
+        6 | 8
+            if chars
+                .chunks(2)
+                .all(|pair| pair[0] == pair[1]) =>
+        {
+            chars.iter().step_by(2).map(|&c| c as char).collect()
+        }
+        _ => value,
+    }
+}
+
+/// Returns the name of the color if it's shorter than `#` + `hex`.
+fn shorter_named_color(hex: &str) -> Option<&'static str> {
+    NAMED_COLORS
+        .iter()
+        .filter(|(name, value)| name.len() < hex.len() + 1 && minify_hex(value) == hex)
+        .map(|(name, _)| *name)
+        .min_by_key(|name| name.len())
+}
+
+/// Named colors, sorted by name.
+static NAMED_COLORS: &[(&str, &str)] = &[
+    ("aliceblue", "f0f8ff"),
+    ("antiquewhite", "faebd7"),
+    ("aqua", "00ffff"),
+    ("aquamarine", "7fffd4"),
+    ("azure", "f0ffff"),
+    ("beige", "f5f5dc"),
+    ("bisque", "ffe4c4"),
+    ("black", "000000"),
+    ("blanchedalmond", "ffebcd"),
+    ("blue", "0000ff"),
+    ("blueviolet", "8a2be2"),
+    ("brown", "a52a2a"),
+    ("burlywood", "deb887"),
+    ("cadetblue", "5f9ea0"),
+    ("chartreuse", "7fff00"),
+    ("chocolate", "d2691e"),
+    ("coral", "ff7f50"),
+    ("cornflowerblue", "6495ed"),
+    ("cornsilk", "fff8dc"),
+    ("crimson", "dc143c"),
+    ("cyan", "00ffff"),
+    ("darkblue", "00008b"),
+    ("darkcyan", "008b8b"),
+    ("darkgoldenrod", "b8860b"),
+    ("darkgray", "a9a9a9"),
+    ("darkgreen", "006400"),
+    ("darkgrey", "a9a9a9"),
+    ("darkkhaki", "bdb76b"),
+    ("darkmagenta", "8b008b"),
+    ("darkolivegreen", "556b2f"),
+    ("darkorange", "ff8c00"),
+    ("darkorchid", "9932cc"),
+    ("darkred", "8b0000"),
+    ("darksalmon", "e9967a"),
+    ("darkseagreen", "8fbc8f"),
+    ("darkslateblue", "483d8b"),
+    ("darkslategray", "2f4f4f"),
+    ("darkslategrey", "2f4f4f"),
+    ("darkturquoise", "00ced1"),
+    ("darkviolet", "9400d3"),
+    ("deeppink", "ff1493"),
+    ("deepskyblue", "00bfff"),
+    ("dimgray", "696969"),
+    ("dimgrey", "696969"),
+    ("dodgerblue", "1e90ff"),
+    ("firebrick", "b22222"),
+    ("floralwhite", "fffaf0"),
+    ("forestgreen", "228b22"),
+    ("fuchsia", "ff00ff"),
+    ("gainsboro", "dcdcdc"),
+    ("ghostwhite", "f8f8ff"),
+    ("gold", "ffd700"),
+    ("goldenrod", "daa520"),
+    ("gray", "808080"),
+    ("green", "008000"),
+    ("greenyellow", "adff2f"),
+    ("grey", "808080"),
+    ("honeydew", "f0fff0"),
+    ("hotpink", "ff69b4"),
+    ("indianred", "cd5c5c"),
+    ("indigo", "4b0082"),
+    ("ivory", "fffff0"),
+    ("khaki", "f0e68c"),
+    ("lavender", "e6e6fa"),
+    ("lavenderblush", "fff0f5"),
+    ("lawngreen", "7cfc00"),
+    ("lemonchiffon", "fffacd"),
+    ("lightblue", "add8e6"),
+    ("lightcoral", "f08080"),
+    ("lightcyan", "e0ffff"),
+    ("lightgoldenrodyellow", "fafad2"),
+    ("lightgray", "d3d3d3"),
+    ("lightgreen", "90ee90"),
+    ("lightgrey", "d3d3d3"),
+    ("lightpink", "ffb6c1"),
+    ("lightsalmon", "ffa07a"),
+    ("lightseagreen", "20b2aa"),
+    ("lightskyblue", "87cefa"),
+    ("lightslategray", "778899"),
+    ("lightslategrey", "778899"),
+    ("lightsteelblue", "b0c4de"),
+    ("lightyellow", "ffffe0"),
+    ("lime", "00ff00"),
+    ("limegreen", "32cd32"),
+    ("linen", "faf0e6"),
+    ("magenta", "ff00ff"),
+    ("maroon", "800000"),
+    ("mediumaquamarine", "66cdaa"),
+    ("mediumblue", "0000cd"),
+    ("mediumorchid", "ba55d3"),
+    ("mediumpurple", "9370db"),
+    ("mediumseagreen", "3cb371"),
+    ("mediumslateblue", "7b68ee"),
+    ("mediumspringgreen", "00fa9a"),
+    ("mediumturquoise", "48d1cc"),
+    ("mediumvioletred", "c71585"),
+    ("midnightblue", "191970"),
+    ("mintcream", "f5fffa"),
+    ("mistyrose", "ffe4e1"),
+    ("moccasin", "ffe4b5"),
+    ("navajowhite", "ffdead"),
+    ("navy", "000080"),
+    ("oldlace", "fdf5e6"),
+    ("olive", "808000"),
+    ("olivedrab", "6b8e23"),
+    ("orange", "ffa500"),
+    ("orangered", "ff4500"),
+    ("orchid", "da70d6"),
+    ("palegoldenrod", "eee8aa"),
+    ("palegreen", "98fb98"),
+    ("paleturquoise", "afeeee"),
+    ("palevioletred", "db7093"),
+    ("papayawhip", "ffefd5"),
+    ("peachpuff", "ffdab9"),
+    ("peru", "cd853f"),
+    ("pink", "ffc0cb"),
+    ("plum", "dda0dd"),
+    ("powderblue", "b0e0e6"),
+    ("purple", "800080"),
+    ("rebeccapurple", "663399"),
+    ("red", "ff0000"),
+    ("rosybrown", "bc8f8f"),
+    ("royalblue", "4169e1"),
+    ("saddlebrown", "8b4513"),
+    ("salmon", "fa8072"),
+    ("sandybrown", "f4a460"),
+    ("seagreen", "2e8b57"),
+    ("seashell", "fff5ee"),
+    ("sienna", "a0522d"),
+    ("silver", "c0c0c0"),
+    ("skyblue", "87ceeb"),
+    ("slateblue", "6a5acd"),
+    ("slategray", "708090"),
+    ("slategrey", "708090"),
+    ("snow", "fffafa"),
+    ("springgreen", "00ff7f"),
+    ("steelblue", "4682b4"),
+    ("tan", "d2b48c"),
+    ("teal", "008080"),
+    ("thistle", "d8bfd8"),
+    ("tomato", "ff6347"),
+    ("turquoise", "40e0d0"),
+    ("violet", "ee82ee"),
+    ("wheat", "f5deb3"),
+    ("white", "ffffff"),
+    ("whitesmoke", "f5f5f5"),
+    ("yellow", "ffff00"),
+    ("yellowgreen", "9acd32"),
+];
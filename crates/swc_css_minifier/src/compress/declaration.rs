@@ -0,0 +1,98 @@
+use super::{color::is_named_color, Compressor};
+use crate::util::eq_ignore_span;
+use swc_css_ast::*;
+
+impl Compressor {
+    /// Removes declarations overridden by another declaration of the same
+    /// property, which is a later one or an earlier one with `!important`.
+    ///
+    /// As earlier declarations are often used as fallbacks for old browsers,
+    /// e.g. `display: -webkit-box; display: flex`, a declaration is removed
+    /// only if the later one is identical or the later value is supported by
+    /// all browsers.
+    pub(super) fn remove_overridden_declarations(
+        &mut self,
+        items: &mut Vec<DeclarationBlockItem>,
+    ) {
+        let overridden = items
+            .iter()
+            .enumerate()
+            .map(|(idx, item)| match item {
+                DeclarationBlockItem::Declaration(decl) => {
+                    items[idx + 1..].iter().any(|later| match later {
+                        DeclarationBlockItem::Declaration(later) => is_overridden_by(decl, later),
+                        _ => false,
+                    }) || (decl.important.is_none()
+                        && items[..idx].iter().any(|earlier| match earlier {
+                            DeclarationBlockItem::Declaration(earlier) => {
+                                earlier.important.is_some() && is_same_property(decl, earlier)
+                            }
+                            _ => false,
+                        }))
+                }
+                _ => false,
+            })
+            .collect::<Vec<_>>();
+
+        let mut idx = 0;
+        items.retain(|_| {
+            idx += 1;
+            !overridden[idx - 1]
+        });
+    }
+}
+
+fn is_same_property(a: &Declaration, b: &Declaration) -> bool {
+    match (&a.name, &b.name) {
+        (DeclarationName::Ident(a), DeclarationName::Ident(b)) => {
+            a.value.eq_ignore_ascii_case(&b.value)
+        }
+        (DeclarationName::DashedIdent(a), DeclarationName::DashedIdent(b)) => a.value == b.value,
+        _ => false,
+    }
+}
+
+fn is_overridden_by(decl: &Declaration, later: &Declaration) -> bool {
+    if !is_same_property(decl, later) {
+        return false;
+    }
+
+    // `!important` wins over later declarations.
+    if decl.important.is_some() && later.important.is_none() {
+        return false;
+    }
+
+    if decl.important.is_some() == later.important.is_some()
+        && decl.value.len() == later.value.len()
+        && decl
+            .value
+            .iter()
+            .zip(later.value.iter())
+            .all(|(a, b)| eq_ignore_span(a, b))
+    {
+        return true;
+    }
+
+    later.value.iter().all(is_universally_supported)
+}
+
+/// Returns true if `v` is supported by all browsers, so the declaration
+/// cannot be a fallback.
+fn is_universally_supported(v: &Value) -> bool {
+    match v {
+        Value::Number(..) | Value::Percent(..) | Value::Str(..) | Value::Delimiter(..) => true,
+        Value::Color(Color::HexColor(c)) => c.value.len() == 3 || c.value.len() == 6,
+        Value::Dimension(d) => matches!(
+            &*d.unit.value.to_ascii_lowercase(),
+            "px" | "em" | "ex" | "cm" | "mm" | "in" | "pt" | "pc" | "ms" | "s" | "deg"
+        ),
+        Value::Ident(i) => {
+            is_named_color(&i.value)
+                || matches!(
+                    &*i.value.to_ascii_lowercase(),
+                    "none" | "auto" | "inherit" | "normal" | "bold" | "block" | "inline" | "hidden"
+                )
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,52 @@
+use super::Compressor;
+use crate::util::eq_ignore_span;
+use swc_css_ast::*;
+
+impl Compressor {
+    /// `from` => `0%`, `100%` => `to`
+    ///
+    /// Duplicate selectors are removed.
+    pub(super) fn compress_keyframe_selectors(&mut self, selectors: &mut Vec<KeyframeSelector>) {
+        for selector in selectors.iter_mut() {
+            match selector {
+                KeyframeSelector::Ident(i) if &*i.value.to_ascii_lowercase() == "from" => {
+                    *selector = KeyframeSelector::Percent(Percent {
+                        span: i.span,
+                        value: Number {
+                            span: i.span,
+                            value: 0.0,
+                            raw: "0".into(),
+                        },
+                    });
+                }
+
+                KeyframeSelector::Ident(i) if &*i.value.to_ascii_lowercase() == "to" => {
+                    i.value = "to".into();
+                    i.raw = "to".into();
+                }
+
+                KeyframeSelector::Percent(p) if p.value.value == 100.0 => {
+                    *selector = KeyframeSelector::Ident(Ident {
+                        span: p.span,
+                        value: "to".into(),
+                        raw: "to".into(),
+                    });
+                }
+
+                _ => {}
+            }
+        }
+
+        let mut i = 0;
+        while i < selectors.len() {
+            if selectors[..i]
+                .iter()
+                .any(|prev| eq_ignore_span(prev, &selectors[i]))
+            {
+                selectors.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use super::Compressor;
+use swc_css_ast::*;
+
+impl Compressor {
+    /// `0px` => `0`
+    pub(super) fn compress_length(&mut self, n: &mut Value) {
+        if self.ctx.preserve_units || self.ctx.in_math_function {
+            return;
+        }
+
+        if let Value::Dimension(Dimension { span, value, unit }) = n {
+            if value.value != 0.0 || !is_length_unit(&unit.value.to_ascii_lowercase()) {
+                return;
+            }
+
+            *n = Value::Number(Number {
+                span: *span,
+                value: 0.0,
+                raw: "0".into(),
+            });
+        }
+    }
+}
+
+/// Units of `<length>`.
+fn is_length_unit(unit: &str) -> bool {
+    matches!(
+        unit,
+        "px" | "em"
+            | "rem"
+            | "ex"
+            | "ch"
+            | "vw"
+            | "vh"
+            | "vmin"
+            | "vmax"
+            | "cm"
+            | "mm"
+            | "q"
+            | "in"
+            | "pt"
+            | "pc"
+    )
+}
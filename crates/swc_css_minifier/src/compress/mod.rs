@@ -0,0 +1,154 @@
+use swc_css_ast::*;
+use swc_css_visit::{VisitMut, VisitMutWith};
+
+mod color;
+mod declaration;
+mod keyframes;
+mod length;
+mod rules;
+
+pub(crate) fn compressor() -> impl VisitMut {
+    Compressor::default()
+}
+
+#[derive(Default)]
+struct Compressor {
+    ctx: Ctx,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Ctx {
+    /// `true` while visiting the value of a declaration.
+    in_declaration: bool,
+
+    /// `true` if identifiers in the value of the current declaration cannot
+    /// be colors, like `font-family: Red`.
+    preserve_idents: bool,
+
+    /// `true` if units of zero lengths should be preserved.
+    preserve_units: bool,
+
+    /// `true` while visiting arguments of `calc()` and similar functions.
+    in_math_function: bool,
+}
+
+impl Compressor {
+    /// Runs `op` with `ctx`, and restores the original context after it.
+    fn with_ctx<F>(&mut self, ctx: Ctx, op: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let orig = self.ctx;
+        self.ctx = ctx;
+        op(self);
+        self.ctx = orig;
+    }
+}
+
+impl VisitMut for Compressor {
+    fn visit_mut_block(&mut self, n: &mut Block) {
+        n.visit_mut_children_with(self);
+
+        self.remove_overridden_declarations(&mut n.value);
+    }
+
+    fn visit_mut_declaration(&mut self, n: &mut Declaration) {
+        let ctx = match &n.name {
+            DeclarationName::Ident(name) => {
+                let name = name.value.to_ascii_lowercase();
+
+                Ctx {
+                    in_declaration: true,
+                    preserve_idents: !can_have_named_color(&name),
+                    preserve_units: !can_drop_units_of_zero(&name),
+                    ..self.ctx
+                }
+            }
+            // Custom properties may be used in any context.
+            DeclarationName::DashedIdent(..) => Ctx {
+                in_declaration: true,
+                preserve_idents: true,
+                preserve_units: true,
+                ..self.ctx
+            },
+        };
+
+        self.with_ctx(ctx, |v| n.value.visit_mut_with(v));
+    }
+
+    fn visit_mut_function(&mut self, n: &mut Function) {
+        let is_math_function = matches!(
+            &*n.name.value.to_ascii_lowercase(),
+            "calc" | "min" | "max" | "clamp"
+        );
+
+        let ctx = Ctx {
+            in_math_function: self.ctx.in_math_function || is_math_function,
+            ..self.ctx
+        };
+
+        self.with_ctx(ctx, |v| n.visit_mut_children_with(v));
+    }
+
+    fn visit_mut_keyframe_block(&mut self, n: &mut KeyframeBlock) {
+        n.visit_mut_children_with(self);
+
+        self.compress_keyframe_selectors(&mut n.prelude);
+    }
+
+    fn visit_mut_keyframes_rule(&mut self, n: &mut KeyframesRule) {
+        n.visit_mut_children_with(self);
+
+        n.blocks.retain(|block| !block.block.value.is_empty());
+    }
+
+    fn visit_mut_rules(&mut self, n: &mut Vec<Rule>) {
+        n.visit_mut_children_with(self);
+
+        self.compress_rules(n);
+    }
+
+    fn visit_mut_selector_list(&mut self, n: &mut SelectorList) {
+        // Values in selectors are not declaration values.
+        self.with_ctx(Ctx::default(), |v| n.visit_mut_children_with(v));
+    }
+
+    fn visit_mut_tokens(&mut self, tokens: &mut Tokens) {
+        for tok in tokens.tokens.iter_mut() {
+            if let Token::WhiteSpace { value, .. } = &mut tok.token {
+                if value.len() >= 2 {
+                    *value = " ".into()
+                }
+            }
+        }
+    }
+
+    fn visit_mut_value(&mut self, n: &mut Value) {
+        n.visit_mut_children_with(self);
+
+        if !self.ctx.in_declaration {
+            return;
+        }
+
+        self.compress_color(n);
+        self.compress_length(n);
+    }
+}
+
+/// Returns false if identifiers in the value of the property are not colors
+/// even if they look like named colors.
+fn can_have_named_color(name: &str) -> bool {
+    !(name.contains("font")
+        || name.contains("animation")
+        || name.contains("grid")
+        || name.contains("counter")
+        || name.starts_with("transition")
+        || matches!(name, "src" | "content" | "quotes" | "will-change" | "list-style-type"))
+}
+
+/// Returns false if `0` is not equivalent to zero length for the property.
+fn can_drop_units_of_zero(name: &str) -> bool {
+    // A unitless zero is a flex factor in `flex` and some browsers do not
+    // accept it for `flex-basis`.
+    !matches!(name, "flex" | "flex-basis" | "-webkit-flex" | "-ms-flex")
+}
@@ -0,0 +1,115 @@
+use super::Compressor;
+use crate::util::{eq_ignore_span, is_vendor_prefixed};
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitWith};
+
+impl Compressor {
+    /// Removes empty rules, and merges rules with the same selectors or the
+    /// same declarations.
+    pub(super) fn compress_rules(&mut self, rules: &mut Vec<Rule>) {
+        rules.retain(|rule| !is_empty_rule(rule));
+
+        self.remove_duplicate_rules(rules);
+        self.merge_adjacent_rules(rules);
+    }
+
+    /// Removes a qualified rule if an identical rule comes later, because the
+    /// later one wins anyway.
+    fn remove_duplicate_rules(&mut self, rules: &mut Vec<Rule>) {
+        let duplicate = rules
+            .iter()
+            .enumerate()
+            .map(|(idx, rule)| match rule {
+                Rule::QualifiedRule(rule) => rules[idx + 1..].iter().any(|later| match later {
+                    Rule::QualifiedRule(later) => eq_ignore_span(rule, later),
+                    _ => false,
+                }),
+                _ => false,
+            })
+            .collect::<Vec<_>>();
+
+        let mut idx = 0;
+        rules.retain(|_| {
+            idx += 1;
+            !duplicate[idx - 1]
+        });
+    }
+
+    /// `a { color: red } a { margin: 0 }` => `a { color: red; margin: 0 }`
+    ///
+    /// `a { color: red } b { color: red }` => `a, b { color: red }`
+    fn merge_adjacent_rules(&mut self, rules: &mut Vec<Rule>) {
+        let mut merged: Vec<Rule> = Vec::with_capacity(rules.len());
+
+        for rule in rules.drain(..) {
+            if let (Some(Rule::QualifiedRule(prev)), Rule::QualifiedRule(cur)) =
+                (merged.last_mut(), &rule)
+            {
+                if eq_ignore_span(&prev.prelude, &cur.prelude) {
+                    prev.block.value.extend(cur.block.value.iter().cloned());
+                    self.remove_overridden_declarations(&mut prev.block.value);
+                    continue;
+                }
+
+                if eq_ignore_span(&prev.block, &cur.block)
+                    && can_merge_selectors(&prev.prelude)
+                    && can_merge_selectors(&cur.prelude)
+                {
+                    for selector in &cur.prelude.children {
+                        if !prev
+                            .prelude
+                            .children
+                            .iter()
+                            .any(|prev| eq_ignore_span(prev, selector))
+                        {
+                            prev.prelude.children.push(selector.clone());
+                        }
+                    }
+                    continue;
+                }
+            }
+
+            merged.push(rule);
+        }
+
+        *rules = merged;
+    }
+}
+
+fn is_empty_rule(rule: &Rule) -> bool {
+    match rule {
+        Rule::QualifiedRule(rule) => rule.block.value.is_empty(),
+        Rule::AtRule(AtRule::Media(MediaRule { rules, .. }))
+        | Rule::AtRule(AtRule::Supports(SupportsRule { rules, .. }))
+        | Rule::AtRule(AtRule::Document(DocumentRule { block: rules, .. })) => rules.is_empty(),
+        _ => false,
+    }
+}
+
+/// Browsers drop a whole selector list if any selector in it is not
+/// supported, so selectors with vendor-prefixed pseudo classes or elements
+/// should not be merged into a list.
+fn can_merge_selectors(selectors: &SelectorList) -> bool {
+    let mut v = VendorPrefixFinder { found: false };
+    selectors.visit_with(&mut v);
+
+    !v.found
+}
+
+struct VendorPrefixFinder {
+    found: bool,
+}
+
+impl Visit for VendorPrefixFinder {
+    fn visit_pseudo_class_selector(&mut self, n: &PseudoClassSelector) {
+        self.found |= is_vendor_prefixed(&n.name.value);
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_pseudo_element_selector(&mut self, n: &PseudoElementSelector) {
+        self.found |= is_vendor_prefixed(&n.name.value);
+
+        n.visit_children_with(self);
+    }
+}
@@ -1,24 +1,16 @@
-use swc_css_ast::*;
-use swc_css_visit::{VisitMut, VisitMutWith};
-
-pub fn minify(ss: &mut Stylesheet) {
-    ss.visit_mut_with(&mut minifier());
-}
+#![deny(clippy::all)]
 
-fn minifier() -> impl VisitMut {
-    Minifier {}
-}
+use self::compress::compressor;
+use swc_css_ast::*;
+use swc_css_visit::VisitMutWith;
 
-struct Minifier {}
+mod compress;
+mod util;
 
-impl VisitMut for Minifier {
-    fn visit_mut_tokens(&mut self, tokens: &mut Tokens) {
-        for tok in tokens.tokens.iter_mut() {
-            if let Token::WhiteSpace { value, .. } = &mut tok.token {
-                if value.len() >= 2 {
-                    *value = " ".into()
-                }
-            }
-        }
-    }
+/// Minifies a stylesheet.
+///
+/// Colors, lengths and `@keyframes` selectors are shortened, and rules and
+/// declarations which do not affect the result are removed or merged.
+pub fn minify(ss: &mut Stylesheet) {
+    ss.visit_mut_with(&mut compressor());
 }
@@ -0,0 +1,31 @@
+use swc_common::{Span, DUMMY_SP};
+use swc_css_visit::{VisitMut, VisitMutWith};
+
+/// Returns true if `a` and `b` are equal, ignoring spans.
+pub(crate) fn eq_ignore_span<N>(a: &N, b: &N) -> bool
+where
+    N: Clone + PartialEq + VisitMutWith<DropSpan>,
+{
+    drop_span(a.clone()) == drop_span(b.clone())
+}
+
+fn drop_span<N>(mut n: N) -> N
+where
+    N: VisitMutWith<DropSpan>,
+{
+    n.visit_mut_with(&mut DropSpan);
+    n
+}
+
+pub(crate) struct DropSpan;
+
+impl VisitMut for DropSpan {
+    fn visit_mut_span(&mut self, span: &mut Span) {
+        *span = DUMMY_SP;
+    }
+}
+
+/// Returns true if `name` is a vendor-prefixed name like `-webkit-box`.
+pub(crate) fn is_vendor_prefixed(name: &str) -> bool {
+    name.starts_with('-') && !name.starts_with("--") && name[1..].contains('-')
+}
@@ -8,6 +8,39 @@ use swc_css_minifier::minify;
 use swc_css_parser::parse_file;
 use testing::NormalizedOutput;
 
+#[testing::fixture("tests/fixture/**/input.css")]
+fn minify_fixtures(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.min.css");
+
+    testing::run_test(false, |cm, _handler| {
+        let fm = cm.load_file(&input).unwrap();
+
+        let mut errors = vec![];
+        let res: Result<Stylesheet, _> = parse_file(&fm, Default::default(), &mut errors);
+        assert!(errors.is_empty(), "failed to parse {}", input.display());
+
+        let mut ss = res.unwrap();
+
+        minify(&mut ss);
+
+        let mut css_str = String::new();
+        {
+            let wr = BasicCssWriter::new(&mut css_str, None, BasicCssWriterConfig { indent: "\t" });
+            let mut gen = CodeGenerator::new(wr, CodegenConfig { minify: true });
+
+            gen.emit(&ss).unwrap();
+        }
+
+        NormalizedOutput::from(css_str)
+            .compare_to_file(&output)
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
+
 #[testing::fixture("../swc_css_parser/tests/fixture/**/input.css")]
 fn parser_fixture(input: PathBuf) {
     // TODO: Remove this.
@@ -50,7 +83,7 @@ fn parser_fixture(input: PathBuf) {
         let mut css_str = String::new();
         {
             // TODO: Create minifying css writer
-            let wr = BasicCssWriter::new(&mut css_str, BasicCssWriterConfig { indent: "\t" });
+            let wr = BasicCssWriter::new(&mut css_str, None, BasicCssWriterConfig { indent: "\t" });
             let mut gen = CodeGenerator::new(wr, CodegenConfig { minify: true });
 
             gen.emit(&ss).unwrap();
@@ -82,6 +82,10 @@ pub enum ClassMember {
     // Stage 3
     #[tag("StaticBlock")]
     StaticBlock(StaticBlock),
+
+    /// Stage 3
+    #[tag("AutoAccessor")]
+    AutoAccessor(AutoAccessor),
 }
 
 impl Take for ClassMember {
@@ -279,3 +283,65 @@ impl Take for StaticBlock {
         }
     }
 }
+
+/// Either a private name or a public name.
+#[ast_node]
+#[derive(Is, Eq, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Key {
+    #[tag("PrivateName")]
+    Private(PrivateName),
+    #[tag("Identifier")]
+    #[tag("StringLiteral")]
+    #[tag("NumericLiteral")]
+    #[tag("Computed")]
+    #[tag("BigInt")]
+    Public(PropName),
+}
+
+impl Take for Key {
+    fn dummy() -> Self {
+        Key::Public(Take::dummy())
+    }
+}
+
+/// `accessor foo = bar;`
+#[ast_node("AutoAccessor")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct AutoAccessor {
+    #[serde(default)]
+    pub span: Span,
+
+    pub key: Key,
+
+    #[serde(default)]
+    pub value: Option<Box<Expr>>,
+
+    #[serde(default, rename = "typeAnnotation")]
+    pub type_ann: Option<TsTypeAnn>,
+
+    #[serde(default)]
+    pub is_static: bool,
+
+    #[serde(default)]
+    pub decorators: Vec<Decorator>,
+
+    /// Typescript extension.
+    #[serde(default)]
+    pub accessibility: Option<Accessibility>,
+}
+
+impl Take for AutoAccessor {
+    fn dummy() -> Self {
+        AutoAccessor {
+            span: DUMMY_SP,
+            key: Take::dummy(),
+            value: None,
+            type_ann: None,
+            is_static: false,
+            decorators: Vec::new(),
+            accessibility: None,
+        }
+    }
+}
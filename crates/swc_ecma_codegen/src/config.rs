@@ -1,4 +1,39 @@
 #[derive(Debug, Default, Clone, Copy)]
 pub struct Config {
     pub minify: bool,
+
+    /// If true, non-ascii characters in strings, templates, regular
+    /// expressions and identifiers are escaped.
+    pub ascii_only: bool,
+
+    /// If true, `</script`, `<!--` and `-->` in strings, templates and regular
+    /// expressions are escaped, so the output can be inlined in a `<script>`
+    /// tag.
+    pub inline_script: bool,
+
+    /// Quotes used for string literals.
+    pub quote_style: QuoteStyle,
+
+    /// If true, immediately invoked function expressions are wrapped in
+    /// parens, like `!(function(){})()`.
+    pub wrap_iife: bool,
+
+    /// If true, function expressions and arrow functions passed as arguments
+    /// are wrapped in parens, like `foo((function(){}))`.
+    pub wrap_func_args: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// Use the quote of the original source. Strings created by transforms
+    /// use double quotes.
+    Preserve,
+    AlwaysSingle,
+    AlwaysDouble,
+}
+
+impl Default for QuoteStyle {
+    fn default() -> Self {
+        QuoteStyle::Preserve
+    }
 }
@@ -1,7 +1,7 @@
 #![recursion_limit = "1024"]
 #![allow(unused_variables)]
 
-pub use self::config::Config;
+pub use self::config::{Config, QuoteStyle};
 use self::{
     list::ListFormat,
     text_writer::WriteJs,
@@ -465,7 +465,7 @@ where
             Lit::Num(ref n) => emit!(n),
             Lit::Regex(ref n) => {
                 punct!("/");
-                self.wr.write_str(&n.exp)?;
+                self.wr.write_str(&escape_for_output(&self.cfg, &n.exp))?;
                 punct!("/");
                 self.wr.write_str(&n.flags)?;
             }
@@ -483,6 +483,12 @@ where
     fn emit_str_lit(&mut self, node: &Str) -> Result {
         self.emit_leading_comments_of_span(node.span(), false)?;
 
+        let forced_single_quote = match self.cfg.quote_style {
+            QuoteStyle::Preserve => None,
+            QuoteStyle::AlwaysSingle => Some(true),
+            QuoteStyle::AlwaysDouble => Some(false),
+        };
+
         let (single_quote, value) = match node.kind {
             StrKind::Normal { contains_quote } => {
                 let single_quote = if contains_quote {
@@ -491,23 +497,33 @@ where
                     None
                 };
 
-                let value = escape_with_source(
-                    &self.cm,
-                    self.wr.target(),
-                    node.span,
-                    &node.value,
-                    single_quote,
-                );
+                match forced_single_quote {
+                    Some(forced) if single_quote.unwrap_or(false) != forced => {
+                        let value = escape_without_source(&node.value, self.wr.target(), forced);
 
-                (single_quote.unwrap_or(false), value)
+                        (forced, value)
+                    }
+                    _ => {
+                        let value = escape_with_source(
+                            &self.cm,
+                            self.wr.target(),
+                            node.span,
+                            &node.value,
+                            single_quote,
+                        );
+
+                        (single_quote.unwrap_or(false), value)
+                    }
+                }
             }
             StrKind::Synthesized => {
-                let single_quote = false;
+                let single_quote = forced_single_quote.unwrap_or(false);
                 let value = escape_without_source(&node.value, self.wr.target(), single_quote);
 
                 (single_quote, value)
             }
         };
+        let value = escape_for_output(&self.cfg, &value);
 
         if single_quote {
             punct!(node.span, "'");
@@ -576,6 +592,11 @@ where
     #[emitter]
     fn emit_callee(&mut self, node: &Callee) -> Result {
         match *node {
+            Callee::Expr(ref e) if self.cfg.wrap_iife && matches!(**e, Expr::Fn(..)) => {
+                punct!("(");
+                emit!(e);
+                punct!(")");
+            }
             Callee::Expr(ref e) => emit!(e),
             Callee::Super(ref n) => emit!(n),
             Callee::Import(ref n) => emit!(n),
@@ -680,7 +701,15 @@ where
         emit!(node.callee);
 
         punct!("(");
-        self.emit_expr_or_spreads(node.span(), &node.args, ListFormat::CallExpressionArguments)?;
+        if self.cfg.wrap_func_args {
+            self.emit_wrapped_func_args(&node.args)?;
+        } else {
+            self.emit_expr_or_spreads(
+                node.span(),
+                &node.args,
+                ListFormat::CallExpressionArguments,
+            )?;
+        }
         punct!(")");
     }
 
@@ -1063,6 +1092,7 @@ where
             ClassMember::TsIndexSignature(ref n) => emit!(n),
             ClassMember::Empty(ref n) => emit!(n),
             ClassMember::StaticBlock(ref n) => emit!(n),
+            ClassMember::AutoAccessor(ref n) => emit!(n),
         }
     }
 
@@ -1328,6 +1358,55 @@ where
         emit!(n.body);
     }
 
+    #[emitter]
+    fn emit_auto_accessor(&mut self, n: &AutoAccessor) -> Result {
+        self.emit_leading_comments_of_span(n.span(), false)?;
+
+        self.emit_list(n.span, Some(&n.decorators), ListFormat::Decorators)?;
+
+        self.emit_accesibility(n.accessibility)?;
+
+        if n.is_static {
+            keyword!("static");
+            space!();
+        }
+
+        keyword!("accessor");
+        space!();
+
+        emit!(n.key);
+
+        if let Some(ty) = &n.type_ann {
+            punct!(":");
+            space!();
+            emit!(ty);
+        }
+
+        if let Some(v) = &n.value {
+            formatting_space!();
+            punct!("=");
+            formatting_space!();
+
+            if v.is_seq() {
+                punct!("(");
+                emit!(v);
+                punct!(")");
+            } else {
+                emit!(v);
+            }
+        }
+
+        semi!();
+    }
+
+    #[emitter]
+    fn emit_key(&mut self, n: &Key) -> Result {
+        match n {
+            Key::Private(n) => emit!(n),
+            Key::Public(n) => emit!(n),
+        }
+    }
+
     #[emitter]
     fn emit_prop_name(&mut self, node: &PropName) -> Result {
         match *node {
@@ -1474,10 +1553,10 @@ where
             _ => false,
         };
 
-        self.wr.write_str_lit(
-            node.span,
-            &unescape_tpl_lit(&node.raw.value, is_synthesized),
-        )?;
+        let raw = unescape_tpl_lit(&node.raw.value, is_synthesized);
+
+        self.wr
+            .write_str_lit(node.span, &escape_for_output(&self.cfg, &raw))?;
         return Ok(());
     }
 
@@ -1557,6 +1636,32 @@ where
         self.emit_list(parent_node, Some(nodes), format)
     }
 
+    /// Emits arguments of a call expression while wrapping function
+    /// expressions in parens. See [Config::wrap_func_args].
+    fn emit_wrapped_func_args(&mut self, args: &[ExprOrSpread]) -> Result {
+        for (i, arg) in args.iter().enumerate() {
+            if i != 0 {
+                punct!(self, ",");
+                formatting_space!(self);
+            }
+
+            match *arg.expr {
+                Expr::Fn(..) | Expr::Arrow(..) if arg.spread.is_none() => {
+                    if self.comments.is_some() {
+                        self.emit_leading_comments_of_span(arg.span(), false)?;
+                    }
+
+                    punct!(self, "(");
+                    emit!(self, arg.expr);
+                    punct!(self, ")");
+                }
+                _ => emit!(self, arg),
+            }
+        }
+
+        Ok(())
+    }
+
     #[emitter]
     fn emit_expr_or_spread(&mut self, node: &ExprOrSpread) -> Result {
         if self.comments.is_some() {
@@ -1804,8 +1909,13 @@ where
         self.emit_leading_comments_of_span(ident.span, false)?;
 
         // TODO: span
-        self.wr
-            .write_symbol(ident.span, &handle_invalid_unicodes(&ident.sym))?;
+        let sym = handle_invalid_unicodes(&ident.sym);
+        if self.cfg.ascii_only {
+            self.wr
+                .write_symbol(ident.span, &get_ascii_only_ident(&sym))?;
+        } else {
+            self.wr.write_symbol(ident.span, &sym)?;
+        }
         if ident.optional {
             punct!("?");
         }
@@ -3311,6 +3421,87 @@ fn handle_invalid_unicodes(s: &str) -> Cow<str> {
     Cow::Owned(s.replace("\\\0", "\\"))
 }
 
+/// Applies [Config::ascii_only] and [Config::inline_script] to the escaped
+/// content of a string, template or regular expression literal.
+fn escape_for_output<'a>(cfg: &Config, s: &'a str) -> Cow<'a, str> {
+    if (!cfg.ascii_only || s.is_ascii()) && (!cfg.inline_script || !s.contains(&['<', '>'][..])) {
+        return Cow::Borrowed(s);
+    }
+
+    let mut buf = String::with_capacity(s.len());
+
+    for (i, c) in s.char_indices() {
+        if cfg.inline_script {
+            let rest = s[i..].as_bytes();
+
+            if c == '<' && rest.len() >= 8 && rest[..8].eq_ignore_ascii_case(b"</script") {
+                buf.push_str("<\\");
+                continue;
+            }
+
+            if c == '<' && rest.starts_with(b"<!--") {
+                remove_identity_escape(&mut buf);
+                buf.push_str("\\x3c");
+                continue;
+            }
+
+            if c == '>' && s[..i].ends_with("--") {
+                buf.push_str("\\x3e");
+                continue;
+            }
+        }
+
+        if cfg.ascii_only && !c.is_ascii() {
+            remove_identity_escape(&mut buf);
+
+            let code = c as u32;
+            if code <= 0xff {
+                let _ = write!(buf, "\\x{:02x}", code);
+            } else if code <= 0xffff {
+                let _ = write!(buf, "\\u{:04x}", code);
+            } else {
+                // Surrogate pairs work for regular expressions without the `u` flag.
+                let mut units = [0; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    let _ = write!(buf, "\\u{:04x}", unit);
+                }
+            }
+            continue;
+        }
+
+        buf.push(c);
+    }
+
+    Cow::Owned(buf)
+}
+
+/// `\é` is same as `é`, but `\\u00e9` is not same as `\u00e9`.
+fn remove_identity_escape(buf: &mut String) {
+    let backslashes = buf.bytes().rev().take_while(|&b| b == b'\\').count();
+    if backslashes % 2 == 1 {
+        buf.pop();
+    }
+}
+
+fn get_ascii_only_ident(sym: &str) -> Cow<str> {
+    if sym.is_ascii() {
+        return Cow::Borrowed(sym);
+    }
+
+    let mut buf = String::with_capacity(sym.len());
+    for c in sym.chars() {
+        if c.is_ascii() {
+            buf.push(c);
+        } else if c as u32 <= 0xffff {
+            let _ = write!(buf, "\\u{:04x}", c as u32);
+        } else {
+            let _ = write!(buf, "\\u{{{:x}}}", c as u32);
+        }
+    }
+
+    Cow::Owned(buf)
+}
+
 fn is_space_require_before_rhs(rhs: &Expr) -> bool {
     match rhs {
         Expr::Lit(Lit::Num(v)) if v.value.is_sign_negative() => true,
@@ -1,6 +1,9 @@
 use self::swc_ecma_parser::{EsConfig, Parser, StringInput, Syntax};
 use super::*;
-use crate::{config::Config, text_writer::omit_trailing_semi};
+use crate::{
+    config::Config,
+    text_writer::{newline_as_semi, omit_trailing_semi, JsWriterConfig},
+};
 use std::{
     fmt::Debug,
     io::Write,
@@ -12,6 +15,7 @@ use testing::DebugUsingDisplay;
 
 struct Builder {
     cfg: Config,
+    writer_cfg: JsWriterConfig<'static>,
     cm: Lrc<SourceMap>,
     comments: SingleThreadedComments,
     target: EsVersion,
@@ -23,8 +27,14 @@ impl Builder {
         F: for<'aa> FnOnce(&mut Emitter<'aa, Box<(dyn WriteJs + 'aa)>>) -> Ret,
         Ret: 'static,
     {
-        let writer =
-            text_writer::JsWriter::with_target(self.cm.clone(), "\n", s, None, self.target);
+        let writer = text_writer::JsWriter::with_config(
+            self.cm.clone(),
+            "\n",
+            s,
+            None,
+            self.target,
+            self.writer_cfg,
+        );
         let writer: Box<dyn WriteJs> = if self.cfg.minify {
             Box::new(omit_trailing_semi(writer))
         } else {
@@ -56,6 +66,16 @@ impl Builder {
 }
 
 fn parse_then_emit(from: &str, cfg: Config, syntax: Syntax, target: EsVersion) -> String {
+    parse_then_emit_with_writer_config(from, cfg, Default::default(), syntax, target)
+}
+
+fn parse_then_emit_with_writer_config(
+    from: &str,
+    cfg: Config,
+    writer_cfg: JsWriterConfig<'static>,
+    syntax: Syntax,
+    target: EsVersion,
+) -> String {
     ::testing::run_test(false, |cm, handler| {
         let src = cm.new_source_file(FileName::Real("custom.js".into()), from.to_string());
         println!(
@@ -79,6 +99,7 @@ fn parse_then_emit(from: &str, cfg: Config, syntax: Syntax, target: EsVersion) -
 
         let out = Builder {
             cfg,
+            writer_cfg,
             cm,
             comments,
             target,
@@ -93,7 +114,10 @@ fn parse_then_emit(from: &str, cfg: Config, syntax: Syntax, target: EsVersion) -
 pub(crate) fn assert_min(from: &str, to: &str) {
     let out = parse_then_emit(
         from,
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Syntax::Es(EsConfig {
             static_blocks: true,
             ..Default::default()
@@ -105,7 +129,15 @@ pub(crate) fn assert_min(from: &str, to: &str) {
 }
 
 pub(crate) fn assert_min_target(from: &str, to: &str, target: EsVersion) {
-    let out = parse_then_emit(from, Config { minify: true }, Syntax::default(), target);
+    let out = parse_then_emit(
+        from,
+        Config {
+            minify: true,
+            ..Default::default()
+        },
+        Syntax::default(),
+        target,
+    );
 
     assert_eq!(DebugUsingDisplay(out.trim()), DebugUsingDisplay(to),);
 }
@@ -114,7 +146,10 @@ pub(crate) fn assert_min_target(from: &str, to: &str, target: EsVersion) {
 pub(crate) fn assert_min_typescript(from: &str, to: &str) {
     let out = parse_then_emit(
         from,
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Syntax::Typescript(Default::default()),
         EsVersion::latest(),
     );
@@ -125,7 +160,10 @@ pub(crate) fn assert_min_typescript(from: &str, to: &str) {
 pub(crate) fn assert_pretty(from: &str, to: &str) {
     let out = parse_then_emit(
         from,
-        Config { minify: false },
+        Config {
+            minify: false,
+            ..Default::default()
+        },
         Syntax::default(),
         EsVersion::latest(),
     );
@@ -236,7 +274,10 @@ fn empty_named_export_min() {
     test_from_to_custom_config(
         "export { }",
         "export{}",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Default::default(),
     );
 }
@@ -251,7 +292,10 @@ fn empty_named_export_from_min() {
     test_from_to_custom_config(
         "export { } from 'foo';",
         "export{}from'foo'",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Default::default(),
     );
 }
@@ -266,7 +310,10 @@ fn named_export_from_min() {
     test_from_to_custom_config(
         "export { bar } from 'foo';",
         "export{bar}from'foo'",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Default::default(),
     );
 }
@@ -288,7 +335,10 @@ fn export_namespace_from_min() {
     test_from_to_custom_config(
         "export * as Foo from 'foo';",
         "export*as Foo from'foo'",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Syntax::Es(EsConfig {
             ..EsConfig::default()
         }),
@@ -312,7 +362,10 @@ fn named_and_namespace_export_from_min() {
     test_from_to_custom_config(
         "export * as Foo, { bar } from 'foo';",
         "export*as Foo,{bar}from'foo'",
-        Config { minify: true },
+        Config {
+            minify: true,
+            ..Default::default()
+        },
         Syntax::Es(EsConfig {
             ..EsConfig::default()
         }),
@@ -618,7 +671,10 @@ fn issue_1619_3() {
 fn check_latest(src: &str, expected: &str) {
     let actual = parse_then_emit(
         src,
-        Config { minify: false },
+        Config {
+            minify: false,
+            ..Default::default()
+        },
         Default::default(),
         EsVersion::latest(),
     );
@@ -655,3 +711,253 @@ impl Write for Buf {
 fn issue_2213() {
     assert_min("a - -b * c", "a- -b*c")
 }
+
+fn check_min_with_config(src: &str, expected: &str, cfg: Config) {
+    let actual = parse_then_emit(
+        src,
+        Config {
+            minify: true,
+            ..cfg
+        },
+        Default::default(),
+        EsVersion::latest(),
+    );
+    assert_eq!(
+        DebugUsingDisplay(expected),
+        DebugUsingDisplay(actual.trim())
+    );
+}
+
+#[test]
+fn ascii_only_str_lit() {
+    let cfg = Config {
+        ascii_only: true,
+        ..Default::default()
+    };
+
+    check_min_with_config("'é'", "'\\xe9'", cfg);
+    check_min_with_config("'\\é'", "'\\xe9'", cfg);
+    check_min_with_config("'\u{1000}'", "'\\u1000'", cfg);
+    check_min_with_config("'😀'", "'\\ud83d\\ude00'", cfg);
+}
+
+#[test]
+fn ascii_only_tpl_and_regex() {
+    let cfg = Config {
+        ascii_only: true,
+        ..Default::default()
+    };
+
+    check_min_with_config("`é${a}ü`", "`\\xe9${a}\\xfc`", cfg);
+    check_min_with_config("/é/g", "/\\xe9/g", cfg);
+}
+
+#[test]
+fn ascii_only_ident() {
+    let cfg = Config {
+        ascii_only: true,
+        ..Default::default()
+    };
+
+    check_min_with_config("var é", "var \\u00e9", cfg);
+    check_min_with_config("var 𐊧", "var \\u{102a7}", cfg);
+}
+
+#[test]
+fn inline_script() {
+    let cfg = Config {
+        inline_script: true,
+        ..Default::default()
+    };
+
+    check_min_with_config("'</script>'", "'<\\/script>'", cfg);
+    check_min_with_config("'</SCRIPT>'", "'<\\/SCRIPT>'", cfg);
+    check_min_with_config("`<!--${a}-->`", "`\\x3c!--${a}--\\x3e`", cfg);
+    check_min_with_config("/<\\/script>/", "/<\\/script>/", cfg);
+}
+
+#[test]
+fn quote_style() {
+    check_min_with_config(
+        "'a'; \"b\"; 'c\"'",
+        "\"a\";\"b\";\"c\\\"\"",
+        Config {
+            quote_style: QuoteStyle::AlwaysDouble,
+            ..Default::default()
+        },
+    );
+    check_min_with_config(
+        "'a'; \"b\"; \"c'\"",
+        "'a';'b';'c\\''",
+        Config {
+            quote_style: QuoteStyle::AlwaysSingle,
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn wrap_iife() {
+    let cfg = Config {
+        wrap_iife: true,
+        ..Default::default()
+    };
+
+    check_min_with_config("!function(){}()", "!(function(){})()", cfg);
+    check_min_with_config("(function(){})()", "(function(){})()", cfg);
+    check_min_with_config("foo(function(){})", "foo(function(){})", cfg);
+}
+
+#[test]
+fn wrap_func_args() {
+    let cfg = Config {
+        wrap_func_args: true,
+        ..Default::default()
+    };
+
+    check_min_with_config(
+        "foo(a, function(){}, () => b, ...c)",
+        "foo(a,(function(){}),(()=>b),...c)",
+        cfg,
+    );
+    check_min_with_config("!function(){}()", "!function(){}()", cfg);
+}
+
+#[test]
+fn max_line_len() {
+    let out = parse_then_emit_with_writer_config(
+        "var a = 1, b = 2; foo('a long string', bar); if (a) { b() }",
+        Config {
+            minify: true,
+            ..Default::default()
+        },
+        JsWriterConfig {
+            max_line_len: Some(12),
+            ..Default::default()
+        },
+        Default::default(),
+        EsVersion::latest(),
+    );
+
+    assert_eq!(
+        DebugUsingDisplay(out.trim()),
+        DebugUsingDisplay("var a=1,b=2;\nfoo('a long string',\nbar);if(a){b()}")
+    );
+}
+
+#[test]
+fn max_line_len_source_map() {
+    let src = "var a = 1, b = 2; foo('a long string', bar); if (a) { b() }";
+
+    ::testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(FileName::Real("custom.js".into()), src.into());
+        let module = Parser::new(Default::default(), StringInput::from(&*fm), None)
+            .parse_module()
+            .unwrap();
+
+        let mut buf = vec![];
+        let mut srcmap = vec![];
+        {
+            let writer = text_writer::JsWriter::with_config(
+                cm.clone(),
+                "\n",
+                &mut buf,
+                Some(&mut srcmap),
+                EsVersion::latest(),
+                JsWriterConfig {
+                    max_line_len: Some(12),
+                    ..Default::default()
+                },
+            );
+            let mut emitter = Emitter {
+                cfg: Config {
+                    minify: true,
+                    ..Default::default()
+                },
+                cm: cm.clone(),
+                comments: None,
+                wr: omit_trailing_semi(writer),
+            };
+            emitter.emit_module(&module).unwrap();
+        }
+
+        assert_eq!(
+            DebugUsingDisplay(&*String::from_utf8(buf).unwrap()),
+            DebugUsingDisplay("var a=1,b=2;\nfoo('a long string',\nbar);if(a){b()}")
+        );
+
+        let map = cm.build_source_map(&mut srcmap);
+        let original_pos = |line, col| {
+            let token = map
+                .lookup_token(line, col)
+                .unwrap_or_else(|| panic!("no mapping for {}:{}", line, col));
+            (token.get_src_line(), token.get_src_col() as usize)
+        };
+
+        assert_eq!(original_pos(1, 0), (0, src.find("foo").unwrap()));
+        // Tokens moved to the next line by the line break.
+        assert_eq!(original_pos(2, 0), (0, src.find("bar").unwrap()));
+        assert_eq!(
+            original_pos(2, "bar);if(a){".len() as u32),
+            (0, src.rfind("b()").unwrap())
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn newline_as_semicolon() {
+    let src = "a(); b(); c = 1; [d] = e; -f; if (g) { h() } i()";
+
+    let out = ::testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(FileName::Real("custom.js".into()), src.into());
+        let module = Parser::new(Default::default(), StringInput::from(&*fm), None)
+            .parse_module()
+            .unwrap();
+
+        let mut buf = vec![];
+        {
+            let writer = text_writer::JsWriter::new(cm.clone(), "\n", &mut buf, None);
+            let mut emitter = Emitter {
+                cfg: Config {
+                    minify: true,
+                    ..Default::default()
+                },
+                cm: cm.clone(),
+                comments: None,
+                wr: newline_as_semi(writer),
+            };
+            emitter.emit_module(&module).unwrap();
+        }
+
+        Ok(String::from_utf8(buf).unwrap())
+    })
+    .unwrap();
+
+    // Semicolons are kept if the next line may continue the statement.
+    assert_eq!(
+        DebugUsingDisplay(&*out),
+        DebugUsingDisplay("a()\nb()\nc=1;[d]=e;-f\nif(g){h()}i()")
+    );
+}
+
+#[test]
+fn indent() {
+    let out = parse_then_emit_with_writer_config(
+        "if (a) { if (b) { c() } }",
+        Default::default(),
+        JsWriterConfig {
+            indent: "  ",
+            ..Default::default()
+        },
+        Default::default(),
+        EsVersion::latest(),
+    );
+
+    assert_eq!(
+        DebugUsingDisplay(out.trim()),
+        DebugUsingDisplay("if (a) {\n  if (b) {\n    c();\n  }\n}")
+    );
+}
@@ -1,4 +1,7 @@
-pub use self::{basic_impl::JsWriter, semicolon::omit_trailing_semi};
+pub use self::{
+    basic_impl::{JsWriter, JsWriterConfig},
+    semicolon::{newline_as_semi, omit_trailing_semi},
+};
 use super::*;
 use swc_common::Span;
 
@@ -3,6 +3,25 @@ use std::io::{self, Write};
 use swc_common::{sync::Lrc, BytePos, LineCol, SourceMap, Span};
 use swc_ecma_ast::EsVersion;
 
+#[derive(Debug, Clone, Copy)]
+pub struct JsWriterConfig<'a> {
+    /// String used for a level of indentation.
+    pub indent: &'a str,
+
+    /// If set, a line is broken after `;`, `,` or `{` if the next token
+    /// exceeds this length.
+    pub max_line_len: Option<usize>,
+}
+
+impl Default for JsWriterConfig<'_> {
+    fn default() -> Self {
+        JsWriterConfig {
+            indent: "    ",
+            max_line_len: None,
+        }
+    }
+}
+
 ///
 /// -----
 ///
@@ -14,10 +33,13 @@ pub struct JsWriter<'a, W: Write> {
     line_start: bool,
     line_count: usize,
     line_pos: usize,
+    /// True if a line break can be inserted before the next token.
+    can_break_line: bool,
     new_line: &'a str,
     srcmap: Option<&'a mut Vec<(BytePos, LineCol)>>,
     wr: W,
     target: EsVersion,
+    config: JsWriterConfig<'a>,
 }
 
 impl<'a, W: Write> JsWriter<'a, W> {
@@ -31,35 +53,69 @@ impl<'a, W: Write> JsWriter<'a, W> {
     }
 
     pub fn with_target(
+        cm: Lrc<SourceMap>,
+        new_line: &'a str,
+        wr: W,
+        srcmap: Option<&'a mut Vec<(BytePos, LineCol)>>,
+        target: EsVersion,
+    ) -> Self {
+        Self::with_config(cm, new_line, wr, srcmap, target, Default::default())
+    }
+
+    pub fn with_config(
         _: Lrc<SourceMap>,
         new_line: &'a str,
         wr: W,
         srcmap: Option<&'a mut Vec<(BytePos, LineCol)>>,
         target: EsVersion,
+        config: JsWriterConfig<'a>,
     ) -> Self {
         JsWriter {
             indent: Default::default(),
             line_start: true,
             line_count: 0,
             line_pos: Default::default(),
+            can_break_line: false,
             new_line,
             srcmap,
             wr,
             target,
+            config,
         }
     }
 
     fn write_indent_string(&mut self) -> io::Result<usize> {
-        const INDENT: &[u8] = b"    ";
+        let indent = self.config.indent.as_bytes();
 
         let mut cnt = 0;
         for _ in 0..self.indent {
-            cnt += self.raw_write(INDENT)?;
+            cnt += self.raw_write(indent)?;
         }
 
         Ok(cnt)
     }
 
+    /// Breaks the line if a token with length `len` does not fit in
+    /// [JsWriterConfig::max_line_len], and writes indentation at the start of
+    /// a line.
+    ///
+    /// This should be called before adding a mapping for the token.
+    fn start_token(&mut self, len: usize) -> io::Result<usize> {
+        if let Some(max_line_len) = self.config.max_line_len {
+            if self.can_break_line && !self.line_start && self.line_pos + len > max_line_len {
+                self.write_line()?;
+            }
+        }
+        self.can_break_line = false;
+
+        if self.line_start {
+            self.line_start = false;
+            return self.write_indent_string();
+        }
+
+        Ok(0)
+    }
+
     fn raw_write(&mut self, data: &[u8]) -> io::Result<usize> {
         let written = self.wr.write(data)?;
         self.line_pos += written;
@@ -70,10 +126,7 @@ impl<'a, W: Write> JsWriter<'a, W> {
         let mut cnt = 0;
 
         if !data.is_empty() {
-            if self.line_start {
-                cnt += self.write_indent_string()?;
-                self.line_start = false;
-            }
+            cnt += self.start_token(data.len())?;
 
             if let Some(span) = span {
                 if !span.is_dummy() {
@@ -121,6 +174,7 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
     }
     fn write_semi(&mut self, span: Option<Span>) -> Result {
         self.write(span, ";")?;
+        self.can_break_line = true;
         Ok(())
     }
 
@@ -155,6 +209,7 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
             self.line_count += 1;
             self.line_pos = 0;
             self.line_start = true;
+            self.can_break_line = false;
         }
 
         Ok(())
@@ -162,6 +217,8 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
 
     fn write_lit(&mut self, span: Span, s: &str) -> Result {
         if !s.is_empty() {
+            self.start_token(s.lines().next().map_or(0, str::len))?;
+
             if !span.is_dummy() {
                 self.srcmap(span.lo())
             }
@@ -196,6 +253,8 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
 
     fn write_str_lit(&mut self, span: Span, s: &str) -> Result {
         if !s.is_empty() {
+            self.start_token(s.lines().next().map_or(0, str::len))?;
+
             if !span.is_dummy() {
                 self.srcmap(span.lo())
             }
@@ -228,6 +287,7 @@ impl<'a, W: Write> WriteJs for JsWriter<'a, W> {
 
     fn write_punct(&mut self, span: Option<Span>, s: &'static str) -> Result {
         self.write(span, s)?;
+        self.can_break_line = matches!(s, ";" | "," | "{");
         Ok(())
     }
 
@@ -5,13 +5,30 @@ pub fn omit_trailing_semi<W: WriteJs>(w: W) -> impl WriteJs {
     OmitTrailingSemi {
         inner: w,
         pending_semi: false,
+        newline: false,
     }
 }
 
+/// Same as [omit_trailing_semi], but semicolons between statements are
+/// replaced with newlines unless the next line may continue the statement.
+pub fn newline_as_semi<W: WriteJs>(w: W) -> impl WriteJs {
+    OmitTrailingSemi {
+        inner: w,
+        pending_semi: false,
+        newline: true,
+    }
+}
+
+/// Tokens which may continue the previous statement if a newline is used
+/// instead of a semicolon.
+const CONTINUATIONS: &[char] = &['(', '[', '+', '-', '*', '/', ',', '.', '`', '<'];
+
 #[derive(Debug, Clone)]
 struct OmitTrailingSemi<W: WriteJs> {
     inner: W,
     pending_semi: bool,
+    /// Use a newline for a pending semicolon if possible.
+    newline: bool,
 }
 
 macro_rules! with_semi {
@@ -45,7 +62,13 @@ impl<W: WriteJs> WriteJs for OmitTrailingSemi<W> {
     with_semi!(write_space());
     with_semi!(write_comment(span: Span, s: &str));
     with_semi!(write_keyword(span: Option<Span>, s: &'static str));
-    with_semi!(write_operator(span: Option<Span>, s: &str));
+
+    fn write_operator(&mut self, span: Option<Span>, s: &str) -> Result {
+        self.commit_pending_semi_before(s)?;
+
+        self.inner.write_operator(span, s)
+    }
+
     with_semi!(write_param(s: &str));
     with_semi!(write_property(s: &str));
     with_semi!(write_line());
@@ -57,7 +80,11 @@ impl<W: WriteJs> WriteJs for OmitTrailingSemi<W> {
     fn write_punct(&mut self, span: Option<Span>, s: &'static str) -> Result {
         match s {
             "\"" | "'" | "[" | "!" | "/" | "{" | "(" | "~" | "-" | "+" | "#" => {
-                self.commit_pending_semi()?;
+                self.commit_pending_semi_before(s)?;
+            }
+
+            "`" | "*" | "<" if self.newline => {
+                self.commit_pending_semi_before(s)?;
             }
 
             _ => {
@@ -80,8 +107,17 @@ impl<W: WriteJs> WriteJs for OmitTrailingSemi<W> {
 
 impl<W: WriteJs> OmitTrailingSemi<W> {
     fn commit_pending_semi(&mut self) -> Result {
+        self.commit_pending_semi_before("")
+    }
+
+    /// `next` is the token written after the pending semicolon.
+    fn commit_pending_semi_before(&mut self, next: &str) -> Result {
         if self.pending_semi {
-            self.inner.write_punct(None, ";")?;
+            if self.newline && !next.starts_with(CONTINUATIONS) {
+                self.inner.write_line()?;
+            } else {
+                self.inner.write_punct(None, ";")?;
+            }
             self.pending_semi = false;
         }
         Ok(())
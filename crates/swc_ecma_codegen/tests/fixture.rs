@@ -47,7 +47,10 @@ fn run(input: &Path, minify: bool) {
             }
 
             let mut emitter = Emitter {
-                cfg: swc_ecma_codegen::Config { minify },
+                cfg: swc_ecma_codegen::Config {
+                    minify,
+                    ..Default::default()
+                },
                 cm,
                 comments: None,
                 wr,
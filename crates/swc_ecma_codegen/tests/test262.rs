@@ -136,7 +136,10 @@ fn do_test(entry: &Path, minify: bool) {
             }
 
             let mut emitter = Emitter {
-                cfg: swc_ecma_codegen::Config { minify },
+                cfg: swc_ecma_codegen::Config {
+                    minify,
+                    ..Default::default()
+                },
                 cm,
                 wr,
                 comments: if minify { None } else { Some(&comments) },
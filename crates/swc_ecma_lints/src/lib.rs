@@ -1,3 +1,4 @@
 pub mod config;
 pub mod rule;
 pub mod rules;
+pub mod suppression;
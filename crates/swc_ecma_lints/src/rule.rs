@@ -27,19 +27,7 @@ macro_rules! for_vec {
         } else {
             let errors = $s
                 .par_iter_mut()
-                .flat_map(|rule| {
-                    let emitter = Capturing::default();
-                    {
-                        let handler = Handler::with_emitter(true, false, Box::new(emitter.clone()));
-                        HANDLER.set(&handler, || {
-                            rule.$name(program);
-                        });
-                    }
-
-                    let errors = Arc::try_unwrap(emitter.errors).unwrap().into_inner();
-
-                    errors
-                })
+                .flat_map(|rule| capture_errors(|| rule.$name(program)))
                 .collect::<Vec<_>>();
 
             HANDLER.with(|handler| {
@@ -76,6 +64,21 @@ impl Emitter for Capturing {
     }
 }
 
+/// Runs `op` and returns diagnostics reported to [HANDLER] instead of emitting
+/// them.
+pub(crate) fn capture_errors<F>(op: F) -> Vec<Diagnostic>
+where
+    F: FnOnce(),
+{
+    let emitter = Capturing::default();
+    {
+        let handler = Handler::with_emitter(true, false, Box::new(emitter.clone()));
+        HANDLER.set(&handler, op);
+    }
+
+    Arc::try_unwrap(emitter.errors).unwrap().into_inner()
+}
+
 pub(crate) fn visitor_rule<V>(v: V) -> Box<dyn Rule>
 where
     V: 'static + Send + Sync + Visit + Default + Debug,
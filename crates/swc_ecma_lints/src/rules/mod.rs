@@ -1,4 +1,5 @@
-use crate::{config::LintConfig, rule::Rule};
+use crate::{config::LintConfig, rule::Rule, suppression::Suppressions};
+use std::sync::Arc;
 use swc_common::SyntaxContext;
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_fold_type, Fold};
@@ -24,6 +25,9 @@ pub struct LintParams<'a> {
     pub lint_config: &'a LintConfig,
     pub top_level_ctxt: SyntaxContext,
     pub es_version: EsVersion,
+    /// Suppressions from comments of the file. Only non-critical lints can be
+    /// suppressed.
+    pub suppressions: Option<Arc<Suppressions>>,
 }
 
 pub fn all(lint_params: LintParams) -> Vec<Box<dyn Rule>> {
@@ -35,33 +39,43 @@ pub fn all(lint_params: LintParams) -> Vec<Box<dyn Rule>> {
 
     #[cfg(feature = "non_critical_lints")]
     {
+        use crate::suppression::suppressible;
+
         let LintParams {
             program,
             lint_config,
             top_level_ctxt,
             es_version,
+            suppressions,
         } = lint_params;
 
-        rules.extend(no_console::no_console(
-            &lint_config.no_console,
-            top_level_ctxt,
-        ));
-
-        rules.extend(no_alert::no_alert(
-            program,
-            &lint_config.no_alert,
-            top_level_ctxt,
-            es_version,
-        ));
-
-        rules.extend(no_debugger::no_debugger(&lint_config.no_debugger));
-
-        rules.extend(prefer_regex_literals::prefer_regex_literals(
-            program,
-            &lint_config.prefer_regex_literals,
-            top_level_ctxt,
-            es_version,
-        ));
+        let non_critical_rules = [
+            (
+                "no-console",
+                no_console::no_console(&lint_config.no_console, top_level_ctxt),
+            ),
+            (
+                "no-alert",
+                no_alert::no_alert(program, &lint_config.no_alert, top_level_ctxt, es_version),
+            ),
+            (
+                "no-debugger",
+                no_debugger::no_debugger(&lint_config.no_debugger),
+            ),
+            (
+                "prefer-regex-literals",
+                prefer_regex_literals::prefer_regex_literals(
+                    program,
+                    &lint_config.prefer_regex_literals,
+                    top_level_ctxt,
+                    es_version,
+                ),
+            ),
+        ];
+
+        rules.extend(non_critical_rules.into_iter().filter_map(|(name, rule)| {
+            rule.map(|rule| suppressible(name, rule, suppressions.clone()))
+        }));
     }
 
     rules
@@ -0,0 +1,295 @@
+//! Inline suppression of lint diagnostics using comments.
+//!
+//! Both `swc-lint-*` and `eslint-*` spellings are accepted.
+//!
+//! ```js
+//! // swc-lint-disable-next-line no-console
+//! console.log("foo");
+//!
+//! alert("foo"); // eslint-disable-line no-alert
+//!
+//! /* swc-lint-disable no-console, no-alert */
+//! console.log(alert("foo"));
+//! /* swc-lint-enable */
+//! ```
+//!
+//! A `disable` directive without a matching `enable` directive suppresses
+//! diagnostics until the end of the file, so placing it at the top of a file
+//! disables rules for the whole file. If no rule is listed, all suppressible
+//! rules are affected. Text after `--` is treated as a description.
+//!
+//! Note that enabling a specific rule does not end a `disable` directive
+//! without rule names.
+
+#![cfg_attr(not(feature = "non_critical_lints"), allow(unused))]
+
+use std::sync::Arc;
+
+use swc_common::{
+    comments::{Comment, SingleThreadedComments},
+    errors::{DiagnosticBuilder, HANDLER},
+    BytePos, SourceMap,
+};
+use swc_ecma_ast::{Module, Script};
+
+use crate::rule::{capture_errors, Rule};
+
+/// Locations where diagnostics of rules are suppressed.
+#[derive(Debug, Default)]
+pub struct Suppressions {
+    ranges: Vec<SuppressedRange>,
+}
+
+#[derive(Debug)]
+struct SuppressedRange {
+    lo: BytePos,
+    hi: BytePos,
+    /// [None] means all rules.
+    rules: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DirectiveKind {
+    Disable,
+    Enable,
+    DisableLine,
+    DisableNextLine,
+}
+
+impl Suppressions {
+    pub fn from_single_threaded_comments(
+        cm: &SourceMap,
+        comments: &SingleThreadedComments,
+    ) -> Self {
+        let (leading, trailing) = comments.borrow_all();
+
+        Self::from_comments(cm, leading.values().chain(trailing.values()).flatten())
+    }
+
+    /// Collects suppressions from comments of a file, which can be given in any
+    /// order.
+    pub fn from_comments<'a, I>(cm: &SourceMap, comments: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Comment>,
+    {
+        let mut directives = comments
+            .into_iter()
+            .filter_map(|cmt| {
+                parse_directive(&cmt.text)
+                    .map(|(kind, rules)| (cmt.span.lo, cmt.span.hi, kind, rules))
+            })
+            .collect::<Vec<_>>();
+        directives.sort_by_key(|(lo, ..)| *lo);
+
+        let mut ranges = vec![];
+        // Start of `disable` directives without matching `enable` directives.
+        let mut disabled_all: Option<BytePos> = None;
+        let mut disabled: Vec<(String, BytePos)> = vec![];
+
+        for (lo, hi, kind, rules) in directives {
+            match kind {
+                DirectiveKind::Disable => {
+                    if rules.is_empty() {
+                        disabled_all.get_or_insert(hi);
+                    } else {
+                        for rule in rules {
+                            if disabled.iter().all(|(disabled, _)| *disabled != rule) {
+                                disabled.push((rule, hi));
+                            }
+                        }
+                    }
+                }
+                DirectiveKind::Enable => {
+                    if rules.is_empty() {
+                        if let Some(start) = disabled_all.take() {
+                            ranges.push(SuppressedRange {
+                                lo: start,
+                                hi: lo,
+                                rules: None,
+                            });
+                        }
+                    }
+
+                    disabled.retain(|(rule, start)| {
+                        if rules.is_empty() || rules.contains(rule) {
+                            ranges.push(SuppressedRange {
+                                lo: *start,
+                                hi: lo,
+                                rules: Some(vec![rule.clone()]),
+                            });
+                            return false;
+                        }
+                        true
+                    });
+                }
+                DirectiveKind::DisableLine | DirectiveKind::DisableNextLine => {
+                    let pos = if kind == DirectiveKind::DisableLine {
+                        lo
+                    } else {
+                        hi
+                    };
+                    let line = match cm.lookup_line(pos) {
+                        Ok(line) => line,
+                        Err(..) => continue,
+                    };
+                    let line_index = if kind == DirectiveKind::DisableLine {
+                        line.line
+                    } else {
+                        line.line + 1
+                    };
+                    if line_index >= line.sf.count_lines() {
+                        continue;
+                    }
+
+                    let (line_lo, line_hi) = line.sf.line_bounds(line_index);
+                    ranges.push(SuppressedRange {
+                        lo: line_lo,
+                        hi: line_hi,
+                        rules: if rules.is_empty() { None } else { Some(rules) },
+                    });
+                }
+            }
+        }
+
+        // Unterminated `disable` directives affect the rest of the file.
+        let end_of_file = |pos: BytePos| cm.lookup_byte_offset(pos).sf.end_pos;
+        if let Some(start) = disabled_all {
+            ranges.push(SuppressedRange {
+                lo: start,
+                hi: end_of_file(start),
+                rules: None,
+            });
+        }
+        for (rule, start) in disabled {
+            ranges.push(SuppressedRange {
+                lo: start,
+                hi: end_of_file(start),
+                rules: Some(vec![rule]),
+            });
+        }
+
+        Suppressions { ranges }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns true if diagnostics of `rule` at `pos` should not be reported.
+    pub fn is_suppressed(&self, rule: &str, pos: BytePos) -> bool {
+        self.ranges.iter().any(|range| {
+            range.lo <= pos
+                && pos < range.hi
+                && match &range.rules {
+                    Some(rules) => rules.iter().any(|r| r == rule),
+                    None => true,
+                }
+        })
+    }
+}
+
+/// Parses a directive like `swc-lint-disable-next-line no-console, no-alert`.
+fn parse_directive(text: &str) -> Option<(DirectiveKind, Vec<String>)> {
+    let text = text.trim();
+    let text = text
+        .strip_prefix("swc-lint-")
+        .or_else(|| text.strip_prefix("eslint-"))?;
+
+    // Longer names should come first.
+    let (kind, rest) = [
+        ("disable-next-line", DirectiveKind::DisableNextLine),
+        ("disable-line", DirectiveKind::DisableLine),
+        ("disable", DirectiveKind::Disable),
+        ("enable", DirectiveKind::Enable),
+    ]
+    .into_iter()
+    .find_map(|(name, kind)| text.strip_prefix(name).map(|rest| (kind, rest)))?;
+
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    // Text after `--` is a description.
+    let rest = rest.split("--").next().unwrap_or_default();
+    let rules = rest
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|rule| !rule.is_empty())
+        .map(normalize_rule_name)
+        .collect();
+
+    Some((kind, rules))
+}
+
+/// Converts `noConsole` and `no_console` into `no-console`.
+fn normalize_rule_name(name: &str) -> String {
+    let mut normalized = String::with_capacity(name.len());
+
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            normalized.push('-');
+            normalized.push(c.to_ascii_lowercase());
+        } else if c == '_' {
+            normalized.push('-');
+        } else {
+            normalized.push(c);
+        }
+    }
+
+    normalized
+}
+
+/// Makes diagnostics of `rule` suppressible with comments.
+pub(crate) fn suppressible(
+    name: &'static str,
+    rule: Box<dyn Rule>,
+    suppressions: Option<Arc<Suppressions>>,
+) -> Box<dyn Rule> {
+    match suppressions {
+        Some(suppressions) if !suppressions.is_empty() => Box::new(SuppressibleRule {
+            name,
+            rule,
+            suppressions,
+        }),
+        _ => rule,
+    }
+}
+
+#[derive(Debug)]
+struct SuppressibleRule {
+    name: &'static str,
+    rule: Box<dyn Rule>,
+    suppressions: Arc<Suppressions>,
+}
+
+impl SuppressibleRule {
+    /// Emits captured diagnostics which are not suppressed.
+    fn emit<F>(&mut self, op: F)
+    where
+        F: FnOnce(&mut Box<dyn Rule>),
+    {
+        let rule = &mut self.rule;
+        let errors = capture_errors(|| op(rule));
+
+        HANDLER.with(|handler| {
+            for error in errors {
+                let suppressed = error.span.primary_span().map_or(false, |span| {
+                    self.suppressions.is_suppressed(self.name, span.lo)
+                });
+
+                if !suppressed {
+                    DiagnosticBuilder::new_diagnostic(handler, error).emit();
+                }
+            }
+        });
+    }
+}
+
+impl Rule for SuppressibleRule {
+    fn lint_module(&mut self, program: &Module) {
+        self.emit(|rule| rule.lint_module(program));
+    }
+
+    fn lint_script(&mut self, program: &Script) {
+        self.emit(|rule| rule.lint_script(program));
+    }
+}
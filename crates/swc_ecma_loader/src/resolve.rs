@@ -5,8 +5,39 @@ use swc_common::{
     FileName,
 };
 
+/// How a module is imported.
+///
+/// Resolvers may resolve a module differently depending on this, like the
+/// `import` and `require` conditions of `exports` in package.json.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImportKind {
+    /// `import` declarations, re-exports and `import()`.
+    Import,
+    /// `require()` calls.
+    Require,
+}
+
+impl Default for ImportKind {
+    fn default() -> Self {
+        ImportKind::Import
+    }
+}
+
 pub trait Resolve: Send + Sync {
     fn resolve(&self, base: &FileName, module_specifier: &str) -> Result<FileName, Error>;
+
+    /// Resolves `module_specifier` imported as `kind`.
+    ///
+    /// [Resolve::resolve] should resolve modules imported with
+    /// [ImportKind::Import]. The default implementation ignores `kind`.
+    fn resolve_with_kind(
+        &self,
+        base: &FileName,
+        module_specifier: &str,
+        _kind: ImportKind,
+    ) -> Result<FileName, Error> {
+        self.resolve(base, module_specifier)
+    }
 }
 
 macro_rules! impl_ref {
@@ -18,6 +49,15 @@ macro_rules! impl_ref {
             fn resolve(&self, base: &FileName, src: &str) -> Result<FileName, Error> {
                 (**self).resolve(base, src)
             }
+
+            fn resolve_with_kind(
+                &self,
+                base: &FileName,
+                src: &str,
+                kind: ImportKind,
+            ) -> Result<FileName, Error> {
+                (**self).resolve_with_kind(base, src, kind)
+            }
         }
     };
 }
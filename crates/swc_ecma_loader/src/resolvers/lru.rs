@@ -1,4 +1,4 @@
-use crate::resolve::Resolve;
+use crate::resolve::{ImportKind, Resolve};
 use anyhow::Error;
 use lru::LruCache;
 use std::sync::Mutex;
@@ -9,7 +9,7 @@ pub struct CachingResolver<R>
 where
     R: Resolve,
 {
-    cache: Mutex<LruCache<(FileName, String), FileName>>,
+    cache: Mutex<LruCache<(FileName, String, ImportKind), FileName>>,
     inner: R,
 }
 
@@ -39,21 +39,30 @@ where
     R: Resolve,
 {
     fn resolve(&self, base: &FileName, src: &str) -> Result<FileName, Error> {
+        self.resolve_with_kind(base, src, ImportKind::Import)
+    }
+
+    fn resolve_with_kind(
+        &self,
+        base: &FileName,
+        src: &str,
+        kind: ImportKind,
+    ) -> Result<FileName, Error> {
         {
             let lock = self.cache.lock();
             if let Ok(mut lock) = lock {
                 //
-                if let Some(v) = lock.get(&(base.clone(), src.to_string())) {
+                if let Some(v) = lock.get(&(base.clone(), src.to_string(), kind)) {
                     return Ok(v.clone());
                 }
             }
         }
 
-        let resolved = self.inner.resolve(base, src)?;
+        let resolved = self.inner.resolve_with_kind(base, src, kind)?;
         {
             let lock = self.cache.lock();
             if let Ok(mut lock) = lock {
-                lock.put((base.clone(), src.to_string()), resolved.clone());
+                lock.put((base.clone(), src.to_string(), kind), resolved.clone());
             }
         }
 
@@ -2,15 +2,23 @@
 //!
 //! See: https://github.com/goto-bus-stop/node-resolve
 
-use crate::{resolve::Resolve, TargetEnv, NODE_BUILTINS};
+use crate::{
+    resolve::{ImportKind, Resolve},
+    TargetEnv, NODE_BUILTINS,
+};
 use anyhow::{bail, Context, Error};
 use dashmap::{DashMap, DashSet};
 #[cfg(windows)]
 use normpath::BasePath;
 use once_cell::sync::Lazy;
 use path_clean::PathClean;
-use serde::Deserialize;
+use serde::{
+    de::{MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
 use std::{
+    cmp::Ordering,
+    fmt,
     fs::File,
     io::BufReader,
     path::{Component, Path, PathBuf},
@@ -55,14 +63,131 @@ pub(crate) fn is_core_module(s: &str) -> bool {
     NODE_BUILTINS.contains(&s)
 }
 
+/// Helper to find the nearest directory containing a `package.json` file,
+/// starting from `dir` itself.
+fn find_package_dir(dir: &Path) -> Option<&Path> {
+    dir.ancestors().find(|p| p.join(PACKAGE).is_file())
+}
+
+/// Splits a bare specifier into the package name and the subpath, like
+/// `@scope/pkg/sub` => (`@scope/pkg`, `./sub`).
+fn parse_package_specifier(target: &str) -> Option<(&str, String)> {
+    let name_len = if target.starts_with('@') {
+        let slash = target.find('/')?;
+        target[slash + 1..]
+            .find('/')
+            .map_or(target.len(), |idx| slash + 1 + idx)
+    } else {
+        target.find('/').unwrap_or(target.len())
+    };
+    let name = &target[..name_len];
+
+    if name.is_empty() || name.ends_with('/') || name.starts_with('.') || name.contains('\\') {
+        return None;
+    }
+
+    Some((name, format!(".{}", &target[name_len..])))
+}
+
+fn read_package_json(pkg_path: &Path) -> Result<PackageJson, Error> {
+    let file = File::open(pkg_path)?;
+    let reader = BufReader::new(file);
+    serde_json::from_reader(reader).context(format!("failed to deserialize {}", pkg_path.display()))
+}
+
 #[derive(Deserialize)]
 struct PackageJson {
+    #[serde(default)]
+    name: Option<String>,
     #[serde(default)]
     main: Option<String>,
     #[serde(default)]
     browser: Option<Browser>,
     #[serde(default)]
     module: Option<String>,
+    #[serde(default)]
+    exports: Option<ExportsValue>,
+    #[serde(default)]
+    imports: Option<ExportsValue>,
+}
+
+/// A value of the `exports` or the `imports` field.
+///
+/// Entries of objects are stored in order, because the first matching
+/// condition wins.
+#[derive(Debug)]
+enum ExportsValue {
+    Null,
+    Str(String),
+    Array(Vec<ExportsValue>),
+    Map(Vec<(String, ExportsValue)>),
+}
+
+impl<'de> Deserialize<'de> for ExportsValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ExportsValueVisitor;
+
+        impl<'de> Visitor<'de> for ExportsValueVisitor {
+            type Value = ExportsValue;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string, an array, an object or null")
+            }
+
+            fn visit_unit<E>(self) -> Result<Self::Value, E> {
+                Ok(ExportsValue::Null)
+            }
+
+            fn visit_none<E>(self) -> Result<Self::Value, E> {
+                Ok(ExportsValue::Null)
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(ExportsValue::Str(v.to_string()))
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+                Ok(ExportsValue::Str(v))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut items = vec![];
+                while let Some(item) = seq.next_element()? {
+                    items.push(item);
+                }
+                Ok(ExportsValue::Array(items))
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut entries = vec![];
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(ExportsValue::Map(entries))
+            }
+        }
+
+        deserializer.deserialize_any(ExportsValueVisitor)
+    }
+}
+
+/// The result of resolving a target of `exports` or `imports`.
+#[derive(Debug)]
+enum PackageTarget {
+    /// No condition matched.
+    Unmatched,
+    /// The target is `null`, which excludes the subpath.
+    Null,
+    Path(PathBuf),
 }
 
 #[derive(Deserialize)]
@@ -79,12 +204,20 @@ enum StringOrBool {
     Bool(bool),
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct NodeModulesResolver {
     target_env: TargetEnv,
     alias: AHashMap<String, String>,
     // if true do not resolve symlink
     preserve_symlinks: bool,
+    /// Condition names used for `exports` and `imports` of package.json.
+    conditions: Vec<String>,
+}
+
+impl Default for NodeModulesResolver {
+    fn default() -> Self {
+        Self::new(Default::default(), Default::default(), false)
+    }
 }
 
 static EXTENSIONS: &[&str] = &["ts", "tsx", "js", "jsx", "json", "node"];
@@ -100,9 +233,21 @@ impl NodeModulesResolver {
             target_env,
             alias,
             preserve_symlinks,
+            conditions: default_conditions(target_env),
         }
     }
 
+    /// Overrides the condition names used to resolve `exports` and `imports`
+    /// of package.json, e.g. to add `development`.
+    ///
+    /// `default` always matches, and `import` or `require` matches depending
+    /// on how the module is imported. See [default_conditions] for the default
+    /// value.
+    pub fn with_conditions(mut self, conditions: Vec<String>) -> Self {
+        self.conditions = conditions;
+        self
+    }
+
     fn wrap(&self, path: Option<PathBuf>) -> Result<FileName, Error> {
         if let Some(path) = path {
             if self.preserve_symlinks {
@@ -177,10 +322,14 @@ impl NodeModulesResolver {
 
     /// Resolve a path as a directory, using the "main" key from a package.json
     /// file if it exists, or resolving to the index.EXT file if it exists.
-    fn resolve_as_directory(&self, path: &Path) -> Result<Option<PathBuf>, Error> {
+    fn resolve_as_directory(
+        &self,
+        path: &Path,
+        kind: ImportKind,
+    ) -> Result<Option<PathBuf>, Error> {
         let pkg_path = path.join(PACKAGE);
         if pkg_path.is_file() {
-            if let Some(main) = self.resolve_package_entry(path, &pkg_path)? {
+            if let Some(main) = self.resolve_package_entry(path, &pkg_path, kind)? {
                 return Ok(Some(main));
             }
         }
@@ -200,11 +349,15 @@ impl NodeModulesResolver {
         &self,
         pkg_dir: &Path,
         pkg_path: &Path,
+        kind: ImportKind,
     ) -> Result<Option<PathBuf>, Error> {
-        let file = File::open(pkg_path)?;
-        let reader = BufReader::new(file);
-        let pkg: PackageJson = serde_json::from_reader(reader)
-            .context(format!("failed to deserialize {}", pkg_path.display()))?;
+        let pkg = read_package_json(pkg_path)?;
+
+        if let Some(exports) = &pkg.exports {
+            return self
+                .resolve_package_exports(pkg_dir, ".", exports, kind)
+                .map(Some);
+        }
 
         let main_fields = match self.target_env {
             TargetEnv::Node => {
@@ -228,7 +381,7 @@ impl NodeModulesResolver {
                                     let path = pkg_dir.join(k);
                                     if let Ok(file) = self
                                         .resolve_as_file(&path)
-                                        .or_else(|_| self.resolve_as_directory(&path))
+                                        .or_else(|_| self.resolve_as_directory(&path, kind))
                                     {
                                         file
                                     } else {
@@ -243,7 +396,7 @@ impl NodeModulesResolver {
                                         let path = pkg_dir.join(dest);
                                         let file = self
                                             .resolve_as_file(&path)
-                                            .or_else(|_| self.resolve_as_directory(&path))?;
+                                            .or_else(|_| self.resolve_as_directory(&path, kind))?;
                                         if let Some(file) = file {
                                             let target = file.clean();
 
@@ -280,26 +433,62 @@ impl NodeModulesResolver {
             let path = pkg_dir.join(target);
             return self
                 .resolve_as_file(&path)
-                .or_else(|_| self.resolve_as_directory(&path));
+                .or_else(|_| self.resolve_as_directory(&path, kind));
         }
 
         Ok(None)
     }
 
     /// Resolve by walking up node_modules folders.
+    ///
+    /// If the package has the `exports` field, the subpath is resolved only
+    /// using it.
     fn resolve_node_modules(
         &self,
         base_dir: &Path,
         target: &str,
+        kind: ImportKind,
     ) -> Result<Option<PathBuf>, Error> {
+        let specifier = parse_package_specifier(target);
+
+        // A package can import itself by its name.
+        if let Some((name, subpath)) = &specifier {
+            if let Some(pkg_dir) = find_package_dir(base_dir) {
+                if let Ok(PackageJson {
+                    name: Some(pkg_name),
+                    exports: Some(exports),
+                    ..
+                }) = read_package_json(&pkg_dir.join(PACKAGE))
+                {
+                    if pkg_name == *name {
+                        return self
+                            .resolve_package_exports(pkg_dir, subpath, &exports, kind)
+                            .map(Some);
+                    }
+                }
+            }
+        }
+
         let mut path = Some(base_dir);
         while let Some(dir) = path {
             let node_modules = dir.join("node_modules");
             if node_modules.is_dir() {
+                if let Some((name, subpath)) = &specifier {
+                    let pkg_dir = node_modules.join(name);
+                    let pkg_path = pkg_dir.join(PACKAGE);
+                    if pkg_path.is_file() {
+                        if let Some(exports) = &read_package_json(&pkg_path)?.exports {
+                            return self
+                                .resolve_package_exports(&pkg_dir, subpath, exports, kind)
+                                .map(Some);
+                        }
+                    }
+                }
+
                 let path = node_modules.join(target);
                 if let Some(result) = self
                     .resolve_as_file(&path)
-                    .or_else(|_| self.resolve_as_directory(&path))?
+                    .or_else(|_| self.resolve_as_directory(&path, kind))?
                 {
                     return Ok(Some(result));
                 }
@@ -309,10 +498,298 @@ impl NodeModulesResolver {
 
         Ok(None)
     }
+
+    /// Resolve `subpath` (`.` or `./*`) of a package using the `exports`
+    /// field.
+    fn resolve_package_exports(
+        &self,
+        pkg_dir: &Path,
+        subpath: &str,
+        exports: &ExportsValue,
+        kind: ImportKind,
+    ) -> Result<PathBuf, Error> {
+        let is_subpath_map = match exports {
+            ExportsValue::Map(entries) => {
+                let dot_keys = entries.iter().filter(|(k, _)| k.starts_with('.')).count();
+                if dot_keys != 0 && dot_keys != entries.len() {
+                    bail!(
+                        "\"exports\" of {} cannot contain both subpaths and conditions",
+                        pkg_dir.display()
+                    )
+                }
+                dot_keys != 0
+            }
+            _ => false,
+        };
+
+        let resolved = if is_subpath_map {
+            match exports {
+                ExportsValue::Map(entries) => {
+                    self.resolve_imports_exports(pkg_dir, subpath, entries, false, kind)?
+                }
+                _ => unreachable!(),
+            }
+        } else if subpath == "." {
+            self.resolve_package_target(pkg_dir, exports, None, false, kind)?
+        } else {
+            PackageTarget::Unmatched
+        };
+
+        match resolved {
+            PackageTarget::Path(path) => check_target_exists(pkg_dir, path),
+            PackageTarget::Unmatched | PackageTarget::Null => bail!(
+                "package subpath `{}` is not defined by \"exports\" in {}",
+                subpath,
+                pkg_dir.join(PACKAGE).display()
+            ),
+        }
+    }
+
+    /// Resolve a `#`-prefixed specifier using the `imports` field of the
+    /// nearest package.json.
+    fn resolve_package_imports(
+        &self,
+        base_dir: &Path,
+        target: &str,
+        kind: ImportKind,
+    ) -> Result<PathBuf, Error> {
+        if target == "#" || target.starts_with("#/") {
+            bail!("invalid import specifier `{}`", target)
+        }
+
+        if let Some(pkg_dir) = find_package_dir(base_dir) {
+            let pkg = read_package_json(&pkg_dir.join(PACKAGE))?;
+            if let Some(ExportsValue::Map(entries)) = &pkg.imports {
+                if let PackageTarget::Path(path) =
+                    self.resolve_imports_exports(pkg_dir, target, entries, true, kind)?
+                {
+                    return check_target_exists(pkg_dir, path);
+                }
+            }
+
+            bail!(
+                "import specifier `{}` is not defined in {}",
+                target,
+                pkg_dir.join(PACKAGE).display()
+            )
+        }
+
+        bail!("import specifier `{}` is used outside of a package", target)
+    }
+
+    /// Finds the entry matching `key`, which may be a subpath pattern
+    /// containing `*`.
+    fn resolve_imports_exports(
+        &self,
+        pkg_dir: &Path,
+        key: &str,
+        entries: &[(String, ExportsValue)],
+        is_imports: bool,
+        kind: ImportKind,
+    ) -> Result<PackageTarget, Error> {
+        if !key.contains('*') {
+            if let Some((_, target)) = entries.iter().find(|(k, _)| k == key) {
+                return self.resolve_package_target(pkg_dir, target, None, is_imports, kind);
+            }
+        }
+
+        let mut patterns = entries
+            .iter()
+            .filter(|(k, _)| k.matches('*').count() == 1)
+            .collect::<Vec<_>>();
+        patterns.sort_by(|(a, _), (b, _)| compare_pattern_keys(a, b));
+
+        for (pattern, target) in patterns {
+            let star = pattern.find('*').unwrap();
+            let (base, trailer) = (&pattern[..star], &pattern[star + 1..]);
+
+            if key.starts_with(base)
+                && key != base
+                && key.ends_with(trailer)
+                && key.len() >= pattern.len()
+            {
+                let pattern_match = &key[base.len()..key.len() - trailer.len()];
+                return self.resolve_package_target(
+                    pkg_dir,
+                    target,
+                    Some(pattern_match),
+                    is_imports,
+                    kind,
+                );
+            }
+        }
+
+        Ok(PackageTarget::Unmatched)
+    }
+
+    /// Resolve a target of `exports` or `imports`, which is a path, an array
+    /// of fallbacks or a map of conditions.
+    ///
+    /// Paths are used as-is, without trying extensions, and the caller should
+    /// check that the file exists.
+    fn resolve_package_target(
+        &self,
+        pkg_dir: &Path,
+        target: &ExportsValue,
+        pattern_match: Option<&str>,
+        is_imports: bool,
+        kind: ImportKind,
+    ) -> Result<PackageTarget, Error> {
+        match target {
+            ExportsValue::Null => Ok(PackageTarget::Null),
+
+            ExportsValue::Str(target) => {
+                if let Some(pattern_match) = pattern_match {
+                    if has_invalid_segment(pattern_match.split(&['/', '\\'][..])) {
+                        bail!("invalid module specifier `{}`", pattern_match)
+                    }
+                }
+
+                let target = match pattern_match {
+                    Some(pattern_match) => target.replace('*', pattern_match),
+                    None => target.clone(),
+                };
+
+                if !target.starts_with("./") {
+                    // `imports` can map to other packages.
+                    if is_imports
+                        && !target.starts_with("../")
+                        && !target.starts_with('/')
+                        && !target.contains(':')
+                    {
+                        return match self.resolve_node_modules(pkg_dir, &target, kind)? {
+                            Some(path) => Ok(PackageTarget::Path(path)),
+                            None => bail!(
+                                "failed to resolve `{}` defined in {}",
+                                target,
+                                pkg_dir.join(PACKAGE).display()
+                            ),
+                        };
+                    }
+
+                    bail!(
+                        "invalid target `{}` defined in {}",
+                        target,
+                        pkg_dir.join(PACKAGE).display()
+                    )
+                }
+
+                if has_invalid_segment(target.split(&['/', '\\'][..]).skip(1)) {
+                    bail!(
+                        "invalid target `{}` defined in {}",
+                        target,
+                        pkg_dir.join(PACKAGE).display()
+                    )
+                }
+
+                Ok(PackageTarget::Path(pkg_dir.join(&target).clean()))
+            }
+
+            ExportsValue::Array(targets) => {
+                // Invalid targets are skipped, and the last `null` or error is
+                // returned if there's no valid target.
+                let mut last = Ok(PackageTarget::Unmatched);
+                for target in targets {
+                    match self.resolve_package_target(
+                        pkg_dir,
+                        target,
+                        pattern_match,
+                        is_imports,
+                        kind,
+                    ) {
+                        Ok(PackageTarget::Unmatched) => {}
+                        Ok(PackageTarget::Null) => last = Ok(PackageTarget::Null),
+                        Ok(v) => return Ok(v),
+                        Err(err) => last = Err(err),
+                    }
+                }
+
+                last
+            }
+
+            ExportsValue::Map(conditions) => {
+                let kind_condition = match kind {
+                    ImportKind::Import => "import",
+                    ImportKind::Require => "require",
+                };
+
+                for (condition, target) in conditions {
+                    if condition == "default"
+                        || condition == kind_condition
+                        || self.conditions.contains(condition)
+                    {
+                        // The first matching condition wins, even if the target is `null` or
+                        // does not exist.
+                        match self.resolve_package_target(
+                            pkg_dir,
+                            target,
+                            pattern_match,
+                            is_imports,
+                            kind,
+                        )? {
+                            PackageTarget::Unmatched => {}
+                            v => return Ok(v),
+                        }
+                    }
+                }
+
+                Ok(PackageTarget::Unmatched)
+            }
+        }
+    }
+}
+
+/// The default condition names for `target_env`.
+///
+/// `import` or `require` is not included, because it's added depending on
+/// [ImportKind].
+pub fn default_conditions(target_env: TargetEnv) -> Vec<String> {
+    let env = match target_env {
+        TargetEnv::Browser => "browser",
+        TargetEnv::Node => "node",
+    };
+
+    vec![env.into()]
+}
+
+/// Targets of `exports` and `imports` should be resolved exactly.
+fn check_target_exists(pkg_dir: &Path, path: PathBuf) -> Result<PathBuf, Error> {
+    if !path.is_file() {
+        bail!(
+            "`{}` defined in {} does not exist",
+            path.display(),
+            pkg_dir.join(PACKAGE).display()
+        )
+    }
+
+    Ok(path)
+}
+
+/// Orders pattern keys from the most specific one.
+fn compare_pattern_keys(a: &str, b: &str) -> Ordering {
+    let base_len = |k: &str| k.find('*').map_or(k.len(), |idx| idx + 1);
+
+    base_len(b)
+        .cmp(&base_len(a))
+        .then_with(|| b.len().cmp(&a.len()))
+}
+
+fn has_invalid_segment<'a>(mut segments: impl Iterator<Item = &'a str>) -> bool {
+    segments
+        .any(|s| s.is_empty() || s == "." || s == ".." || s.eq_ignore_ascii_case("node_modules"))
 }
 
 impl Resolve for NodeModulesResolver {
     fn resolve(&self, base: &FileName, target: &str) -> Result<FileName, Error> {
+        self.resolve_with_kind(base, target, ImportKind::Import)
+    }
+
+    fn resolve_with_kind(
+        &self,
+        base: &FileName,
+        target: &str,
+        kind: ImportKind,
+    ) -> Result<FileName, Error> {
         debug!(
             "Resolve {} from {:#?} for {:#?}",
             target, base, self.target_env
@@ -353,6 +830,12 @@ impl Resolve for NodeModulesResolver {
             }
         }
 
+        if target.starts_with('#') {
+            return self
+                .resolve_package_imports(base_dir, target, kind)
+                .and_then(|p| self.wrap(Some(p)));
+        }
+
         // Aliases allow browser shims to be renamed so we can
         // map `stream` to `stream-browserify` for example
         let target = if let Some(alias) = self.alias.get(target) {
@@ -367,7 +850,7 @@ impl Resolve for NodeModulesResolver {
             if target_path.is_absolute() {
                 let path = PathBuf::from(target_path);
                 self.resolve_as_file(&path)
-                    .or_else(|_| self.resolve_as_directory(&path))
+                    .or_else(|_| self.resolve_as_directory(&path, kind))
                     .and_then(|p| self.wrap(p))
             } else {
                 let mut components = target_path.components();
@@ -385,10 +868,10 @@ impl Resolve for NodeModulesResolver {
                     #[cfg(not(windows))]
                     let path = base_dir.join(target);
                     self.resolve_as_file(&path)
-                        .or_else(|_| self.resolve_as_directory(&path))
+                        .or_else(|_| self.resolve_as_directory(&path, kind))
                         .and_then(|p| self.wrap(p))
                 } else {
-                    self.resolve_node_modules(base_dir, target)
+                    self.resolve_node_modules(base_dir, target, kind)
                         .and_then(|p| self.wrap(p))
                 }
             }
@@ -1,4 +1,4 @@
-use crate::resolve::Resolve;
+use crate::resolve::{ImportKind, Resolve};
 use anyhow::{bail, Context, Error};
 use dashmap::DashMap;
 use once_cell::sync::Lazy;
@@ -94,11 +94,20 @@ where
     R: Resolve,
 {
     fn resolve(&self, base: &FileName, src: &str) -> Result<FileName, Error> {
+        self.resolve_with_kind(base, src, ImportKind::Import)
+    }
+
+    fn resolve_with_kind(
+        &self,
+        base: &FileName,
+        src: &str,
+        kind: ImportKind,
+    ) -> Result<FileName, Error> {
         if src.starts_with('.') && (src == ".." || src.starts_with("./") || src.starts_with("../"))
         {
             return self
                 .inner
-                .resolve(base, src)
+                .resolve_with_kind(base, src, kind)
                 .context("not processed by tsc resolver because it's relative import");
         }
 
@@ -107,7 +116,7 @@ where
                 Component::Normal(v) => v == "node_modules",
                 _ => false,
             }) {
-                return self.inner.resolve(base, src).context(
+                return self.inner.resolve_with_kind(base, src, kind).context(
                     "not processed by tsc resolver because base module is in node_modules",
                 );
             }
@@ -134,12 +143,15 @@ where
                         let mut replaced = target.replace('*', capture.as_str());
                         let rel = format!("./{}", replaced);
 
-                        let res = self.inner.resolve(base, &rel).with_context(|| {
-                            format!(
-                                "failed to resolve `{}`, which is expanded from `{}`",
-                                replaced, src
-                            )
-                        });
+                        let res =
+                            self.inner
+                                .resolve_with_kind(base, &rel, kind)
+                                .with_context(|| {
+                                    format!(
+                                        "failed to resolve `{}`, which is expanded from `{}`",
+                                        replaced, src
+                                    )
+                                });
 
                         errors.push(match res {
                             Ok(v) => return Ok(v),
@@ -175,7 +187,7 @@ where
 
                         return self
                             .inner
-                            .resolve(base, &format!("./{}", &to[0]))
+                            .resolve_with_kind(base, &format!("./{}", &to[0]), kind)
                             .with_context(|| {
                                 format!(
                                     "tried to resolve `{}` because `{}` was exactly matched",
@@ -187,7 +199,7 @@ where
             }
         }
 
-        self.inner.resolve(base, src)
+        self.inner.resolve_with_kind(base, src, kind)
     }
 }
 
@@ -0,0 +1,196 @@
+#![cfg(feature = "node")]
+
+use std::path::{Path, PathBuf};
+use swc_common::FileName;
+use swc_ecma_loader::{
+    resolve::{ImportKind, Resolve},
+    resolvers::node::{default_conditions, NodeModulesResolver},
+    TargetEnv,
+};
+
+fn fixture_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("node_resolver")
+        .join("exports")
+        .canonicalize()
+        .unwrap()
+}
+
+fn resolve(resolver: &NodeModulesResolver, target: &str) -> Option<String> {
+    resolve_with_kind(resolver, target, ImportKind::Import)
+}
+
+fn resolve_with_kind(
+    resolver: &NodeModulesResolver,
+    target: &str,
+    kind: ImportKind,
+) -> Option<String> {
+    let dir = fixture_dir();
+    let base = FileName::Real(dir.join("src").join("index.js"));
+
+    match resolver.resolve_with_kind(&base, target, kind).ok()? {
+        FileName::Real(path) => Some(
+            path.strip_prefix(&dir)
+                .unwrap()
+                .to_string_lossy()
+                .replace('\\', "/"),
+        ),
+        name => panic!("unexpected file name: {}", name),
+    }
+}
+
+fn node() -> NodeModulesResolver {
+    NodeModulesResolver::new(TargetEnv::Node, Default::default(), false)
+}
+
+fn browser() -> NodeModulesResolver {
+    NodeModulesResolver::new(TargetEnv::Browser, Default::default(), false)
+}
+
+#[test]
+fn exports_sugar() {
+    assert_eq!(
+        resolve(&node(), "sugar").as_deref(),
+        Some("node_modules/sugar/sugar.js")
+    );
+    assert_eq!(resolve(&node(), "sugar/other.js"), None);
+}
+
+#[test]
+fn exports_conditions() {
+    assert_eq!(
+        resolve(&node(), "cond").as_deref(),
+        Some("node_modules/cond/dist/node.js")
+    );
+    assert_eq!(
+        resolve(&browser(), "cond").as_deref(),
+        Some("node_modules/cond/dist/browser.js")
+    );
+
+    let mut conditions = default_conditions(TargetEnv::Browser);
+    conditions.push("development".into());
+    assert_eq!(
+        resolve(&browser().with_conditions(conditions), "cond").as_deref(),
+        Some("node_modules/cond/dist/browser.dev.js")
+    );
+
+    assert_eq!(
+        resolve(&browser().with_conditions(vec![]), "cond").as_deref(),
+        Some("node_modules/cond/dist/index.mjs")
+    );
+    assert_eq!(
+        resolve_with_kind(
+            &browser().with_conditions(vec![]),
+            "cond",
+            ImportKind::Require
+        )
+        .as_deref(),
+        Some("node_modules/cond/dist/index.js")
+    );
+}
+
+#[test]
+fn exports_exact_targets() {
+    // Extensions are not tried for targets.
+    assert_eq!(resolve(&node(), "cond/exact"), None);
+
+    // A matched condition does not fall through to the next one, even if the
+    // target does not exist.
+    assert_eq!(resolve(&node(), "cond/missing"), None);
+    assert_eq!(
+        resolve(&browser(), "cond/missing").as_deref(),
+        Some("node_modules/cond/dist/index.js")
+    );
+
+    assert_eq!(resolve(&node(), "cond/excluded"), None);
+    assert_eq!(
+        resolve(&browser(), "cond/excluded").as_deref(),
+        Some("node_modules/cond/dist/index.js")
+    );
+}
+
+#[test]
+fn exports_subpath_patterns() {
+    assert_eq!(
+        resolve(&node(), "cond/features/a").as_deref(),
+        Some("node_modules/cond/lib/features/a.js")
+    );
+    assert_eq!(
+        resolve(&node(), "cond/features/a.js").as_deref(),
+        Some("node_modules/cond/lib/features/a.js")
+    );
+    assert_eq!(
+        resolve(&node(), "cond/package.json").as_deref(),
+        Some("node_modules/cond/package.json")
+    );
+
+    // Excluded by `null`
+    assert_eq!(resolve(&node(), "cond/features/private/secret"), None);
+    // Not exported
+    assert_eq!(resolve(&node(), "cond/main.js"), None);
+}
+
+#[test]
+fn exports_scoped_package() {
+    assert_eq!(
+        resolve(&node(), "@scope/pkg").as_deref(),
+        Some("node_modules/@scope/pkg/esm/index.js")
+    );
+    assert_eq!(resolve(&node(), "@scope/pkg/sub"), None);
+    assert_eq!(
+        resolve_with_kind(&node(), "@scope/pkg/sub", ImportKind::Require).as_deref(),
+        Some("node_modules/@scope/pkg/esm/sub.js")
+    );
+    assert_eq!(
+        resolve_with_kind(
+            &node().with_conditions(vec![]),
+            "@scope/pkg/sub",
+            ImportKind::Require
+        )
+        .as_deref(),
+        Some("node_modules/@scope/pkg/esm/sub.js")
+    );
+}
+
+#[test]
+fn exports_self_reference() {
+    assert_eq!(resolve(&node(), "app").as_deref(), Some("src/index.js"));
+    assert_eq!(
+        resolve(&node(), "app/utils").as_deref(),
+        Some("src/utils.js")
+    );
+}
+
+#[test]
+fn legacy_main() {
+    assert_eq!(
+        resolve(&node(), "legacy").as_deref(),
+        Some("node_modules/legacy/main.js")
+    );
+    assert_eq!(
+        resolve(&node(), "legacy/other").as_deref(),
+        Some("node_modules/legacy/other.js")
+    );
+}
+
+#[test]
+fn imports() {
+    assert_eq!(resolve(&node(), "#utils").as_deref(), Some("src/utils.js"));
+    assert_eq!(
+        resolve(&node(), "#internal/browser").as_deref(),
+        Some("src/browser.js")
+    );
+    assert_eq!(
+        resolve(&node(), "#dep").as_deref(),
+        Some("node_modules/dep/index.js")
+    );
+    assert_eq!(resolve(&node(), "#env").as_deref(), Some("src/utils.js"));
+    assert_eq!(
+        resolve(&browser(), "#env").as_deref(),
+        Some("src/browser.js")
+    );
+
+    assert_eq!(resolve(&node(), "#unknown"), None);
+    assert_eq!(resolve(&node(), "#"), None);
+}
@@ -0,0 +1 @@
+export const browser = 1;
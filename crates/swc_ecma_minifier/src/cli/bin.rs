@@ -30,7 +30,10 @@ fn print_js(cm: Lrc<SourceMap>, module: &Module) {
     let stdout = io::stdout();
 
     let mut emitter = swc_ecma_codegen::Emitter {
-        cfg: swc_ecma_codegen::Config { minify: true },
+        cfg: swc_ecma_codegen::Config {
+            minify: true,
+            ..Default::default()
+        },
         cm: cm.clone(),
         comments: None,
         wr: Box::new(JsWriter::new(cm.clone(), "\n", &stdout, None)),
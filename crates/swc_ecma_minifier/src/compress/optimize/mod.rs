@@ -849,6 +849,47 @@ where
                 ..
             }) => return Some(e.take()),
 
+            // Calls to `pure_funcs` can be removed, but arguments may have side effects.
+            Expr::Call(CallExpr {
+                span,
+                callee: Callee::Expr(callee),
+                args,
+                ..
+            })
+            | Expr::New(NewExpr {
+                span,
+                callee,
+                args: Some(args),
+                ..
+            }) if self.options.is_pure_func(callee)
+                && args.iter().all(|arg| arg.spread.is_none()) =>
+            {
+                tracing::debug!("ignore_return_value: Dropping a call to a pure function");
+                self.changed = true;
+
+                let span = *span;
+                let mut exprs = args
+                    .take()
+                    .into_iter()
+                    .filter_map(|mut arg| self.ignore_return_value(&mut arg.expr))
+                    .map(Box::new)
+                    .collect::<Vec<_>>();
+
+                return match exprs.len() {
+                    0 => None,
+                    1 => exprs.pop().map(|e| *e),
+                    _ => Some(Expr::Seq(SeqExpr { span, exprs })),
+                };
+            }
+
+            Expr::New(NewExpr {
+                callee, args: None, ..
+            }) if self.options.is_pure_func(callee) => {
+                tracing::debug!("ignore_return_value: Dropping a call to a pure function");
+                self.changed = true;
+                return None;
+            }
+
             // Pure calls can be removed
             Expr::Call(CallExpr {
                 callee: Callee::Expr(callee),
@@ -1684,7 +1725,15 @@ where
                     },
                 ..self.ctx
             };
-            e.callee.visit_mut_with(&mut *self.with_ctx(ctx));
+            // Callees listed in `pure_funcs` should not be inlined, as it makes the call
+            // impossible to drop.
+            let is_pure_func = match &e.callee {
+                Callee::Expr(callee) => self.options.is_pure_func(callee),
+                _ => false,
+            };
+            if !is_pure_func {
+                e.callee.visit_mut_with(&mut *self.with_ctx(ctx));
+            }
         }
 
         if is_this_undefined {
@@ -231,6 +231,53 @@ where
                     }
                 }
 
+                Expr::Call(CallExpr {
+                    span,
+                    callee: Callee::Expr(callee),
+                    args,
+                    ..
+                })
+                | Expr::New(NewExpr {
+                    span,
+                    callee,
+                    args: Some(args),
+                    ..
+                }) if self.options.is_pure_func(callee)
+                    && args.iter().all(|arg| arg.spread.is_none()) =>
+                {
+                    tracing::debug!("Dropping a call to a pure function");
+                    self.changed = true;
+
+                    let span = *span;
+                    let mut exprs = args.take().into_iter().map(|arg| arg.expr).collect::<Vec<_>>();
+                    for arg in &mut exprs {
+                        self.ignore_return_value(
+                            arg,
+                            DropOpts {
+                                drop_zero: true,
+                                drop_str_lit: true,
+                            },
+                        );
+                    }
+                    exprs.retain(|arg| !arg.is_invalid());
+
+                    *e = match exprs.len() {
+                        0 => Expr::Invalid(Invalid { span: DUMMY_SP }),
+                        1 => *exprs.pop().unwrap(),
+                        _ => Expr::Seq(SeqExpr { span, exprs }),
+                    };
+                    return;
+                }
+
+                Expr::New(NewExpr {
+                    callee, args: None, ..
+                }) if self.options.is_pure_func(callee) => {
+                    tracing::debug!("Dropping a call to a pure function");
+                    self.changed = true;
+                    *e = Expr::Invalid(Invalid { span: DUMMY_SP });
+                    return;
+                }
+
                 _ => {}
             }
         }
@@ -27,6 +27,7 @@ use crate::{
         compute_char_freq::compute_char_freq, expand_names::name_expander, global_defs,
         mangle_names::name_mangler, mangle_props::mangle_properties,
         precompress::precompress_optimizer,
+        wrap::{enclose, wrap_commonjs},
     },
 };
 use mode::Minification;
@@ -55,7 +56,7 @@ const MAX_PAR_DEPTH: u8 = 3;
 #[inline]
 pub fn optimize(
     mut m: Module,
-    _cm: Lrc<SourceMap>,
+    cm: Lrc<SourceMap>,
     comments: Option<&dyn Comments>,
     mut timings: Option<&mut Timings>,
     options: &MinifyOptions,
@@ -88,14 +89,18 @@ pub fn optimize(
     m.visit_mut_with(&mut info_marker(comments, marks, extra.top_level_mark));
     m.visit_mut_with(&mut unique_scope());
 
-    if options.wrap {
-        // TODO: wrap_common_js
-        // toplevel = toplevel.wrap_commonjs(options.wrap);
+    // As the former top level becomes a function scope, the compressor and the
+    // mangler treat top-level bindings as local variables after this.
+    if let Some(name) = &options.wrap {
+        let _timer = timer!("wrap commonjs");
+
+        m.visit_mut_with(&mut wrap_commonjs(name.clone(), extra.top_level_mark));
     }
 
-    if options.enclose {
-        // TODO: enclose
-        // toplevel = toplevel.wrap_enclose(options.enclose);
+    if let Some(args_values) = &options.enclose {
+        let _timer = timer!("enclose");
+
+        m.visit_mut_with(&mut enclose(&cm, args_values, extra.top_level_mark));
     }
 
     // We don't need validation.
@@ -1,7 +1,7 @@
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use swc_atoms::JsWord;
-use swc_common::{collections::AHashMap, Mark};
+use swc_common::{collections::AHashMap, EqIgnoreSpan, Mark};
 use swc_ecma_ast::{EsVersion, Expr};
 
 pub mod terser;
@@ -23,10 +23,15 @@ pub struct MinifyOptions {
     pub compress: Option<CompressOptions>,
     #[serde(default)]
     pub mangle: Option<MangleOptions>,
+    /// Wraps the module with a CommonJS-like wrapper, and exposes `exports`
+    /// of it as a global variable with this name.
     #[serde(default)]
-    pub wrap: bool,
+    pub wrap: Option<JsWord>,
+    /// Wraps the module with an IIFE. The value is `params:args` like
+    /// `window,undefined:window`, and an empty string means an IIFE without
+    /// any parameter.
     #[serde(default)]
-    pub enclose: bool,
+    pub enclose: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -235,7 +240,13 @@ pub struct CompressOptions {
     #[serde(alias = "properties")]
     pub pure_getters: PureGetterOption,
 
-    // pure_funcs    : null,
+    /// Functions which can be dropped if the return value is not used.
+    ///
+    /// All expressions should have dummy span. Use [swc_ecma_utils::drop_span]
+    /// to remove spans.
+    #[serde(skip)]
+    pub pure_funcs: Vec<Box<Expr>>,
+
     #[serde(default)]
     #[serde(alias = "reduce_funcs")]
     pub reduce_fns: bool,
@@ -317,6 +328,11 @@ impl CompressOptions {
 
         self.top_level.map(|v| v.functions).unwrap_or(false)
     }
+
+    /// Returns `true` if `callee` is listed in `pure_funcs`.
+    pub(crate) fn is_pure_func(&self, callee: &Expr) -> bool {
+        self.pure_funcs.iter().any(|f| (**f).eq_ignore_span(callee))
+    }
 }
 
 const fn true_by_default() -> bool {
@@ -9,7 +9,7 @@ use swc_common::{
     collections::AHashMap, input::SourceFileInput, sync::Lrc, FileName, SourceMap, DUMMY_SP,
 };
 use swc_ecma_ast::*;
-use swc_ecma_parser::{lexer::Lexer, Parser};
+use swc_ecma_parser::{lexer::Lexer, PResult, Parser};
 use swc_ecma_utils::drop_span;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -74,6 +74,21 @@ pub enum TerserTopRetainOption {
     Seq(Vec<JsWord>),
 }
 
+/// `enclose` of terser.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(untagged)]
+pub enum TerserEncloseOption {
+    Bool(bool),
+    Str(String),
+}
+
+impl Default for TerserEncloseOption {
+    fn default() -> Self {
+        Self::Bool(false)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct TerserCompressorOptions {
@@ -330,17 +345,7 @@ impl TerserCompressorOptions {
                 .into_iter()
                 .map(|(k, v)| {
                     let parse = |input: String| {
-                        let fm = cm.new_source_file(FileName::Anon, input);
-
-                        let lexer = Lexer::new(
-                            Default::default(),
-                            Default::default(),
-                            SourceFileInput::from(&*fm),
-                            None,
-                        );
-                        let mut parser = Parser::new_from(lexer);
-
-                        parser.parse_expr().map(drop_span).unwrap_or_else(|err| {
+                        parse_expr(&cm, input).unwrap_or_else(|err| {
                             panic!(
                                 "failed to parse `global_defs.{}` of minifier options: {:?}",
                                 k, err
@@ -407,6 +412,18 @@ impl TerserCompressorOptions {
                     PureGetterOption::Str(v.split(',').map(From::from).collect())
                 }
             },
+            pure_funcs: self
+                .pure_funcs
+                .into_iter()
+                .map(|f| {
+                    parse_expr(&cm, f.clone()).unwrap_or_else(|err| {
+                        panic!(
+                            "failed to parse `pure_funcs` of minifier options: `{}`: {:?}",
+                            f, err
+                        )
+                    })
+                })
+                .collect(),
             reduce_fns: self.reduce_funcs,
             reduce_vars: self.reduce_vars,
             sequences: self
@@ -491,6 +508,32 @@ impl From<TerserTopRetainOption> for Vec<JsWord> {
     }
 }
 
+impl From<TerserEncloseOption> for Option<String> {
+    fn from(v: TerserEncloseOption) -> Self {
+        match v {
+            TerserEncloseOption::Bool(false) => None,
+            TerserEncloseOption::Bool(true) => Some(Default::default()),
+            TerserEncloseOption::Str(s) => Some(s),
+        }
+    }
+}
+
+/// Parses an expression from the config. The returned expression does not have
+/// span.
+fn parse_expr(cm: &SourceMap, input: String) -> PResult<Box<Expr>> {
+    let fm = cm.new_source_file(FileName::Anon, input);
+
+    let lexer = Lexer::new(
+        Default::default(),
+        Default::default(),
+        SourceFileInput::from(&*fm),
+        None,
+    );
+    let mut parser = Parser::new_from(lexer);
+
+    parser.parse_expr().map(drop_span)
+}
+
 fn value_to_expr(v: Value) -> Box<Expr> {
     match v {
         Value::Null => Box::new(Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))),
@@ -0,0 +1,539 @@
+use swc_atoms::{js_word, JsWord};
+use swc_common::{
+    input::SourceFileInput, util::take::Take, FileName, Mark, SourceMap, Span, Spanned,
+    SyntaxContext, DUMMY_SP,
+};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{lexer::Lexer, Parser};
+use swc_ecma_utils::{drop_span, find_ids, ExprFactory};
+use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
+
+/// Port of `wrap_commonjs` of terser.
+///
+/// Wraps the module with
+///
+/// ```js
+/// (function(exports){
+///     // original code
+/// })(typeof NAME == "undefined" ? (NAME = {}) : NAME);
+/// ```
+///
+/// so the code can be loaded as a global script. `export`s of the module are
+/// converted to assignments to `exports`, and `import`s and re-exports are
+/// converted to calls to `require` in the wrapper.
+///
+/// As there's no CommonJS equivalent of it, a module with `export * from` is
+/// not modified.
+pub fn wrap_commonjs(name: JsWord, top_level_mark: Mark) -> impl VisitMut {
+    WrapCommonJs {
+        name,
+        top_level_ctxt: SyntaxContext::empty().apply_mark(top_level_mark),
+    }
+}
+
+/// Port of `wrap_enclose` of terser.
+///
+/// Wraps the module with `(function(params){ /* original code */ })(args)`,
+/// where `args_values` is `params:args` like `window,undefined:window`.
+/// Identifiers in `args` are treated as references to global variables.
+///
+/// As bindings declared in the wrapper function cannot be exported, a module
+/// with local `export`s is not modified.
+pub fn enclose(cm: &SourceMap, args_values: &str, top_level_mark: Mark) -> impl VisitMut {
+    let (params, args) = args_values.split_once(':').unwrap_or((args_values, ""));
+
+    let fm = cm.new_source_file(
+        FileName::Anon,
+        format!("(function({}){{}})({})", params, args),
+    );
+    let lexer = Lexer::new(
+        Default::default(),
+        Default::default(),
+        SourceFileInput::from(&*fm),
+        None,
+    );
+    let mut parser = Parser::new_from(lexer);
+    let parsed = parser.parse_expr().map(drop_span).unwrap_or_else(|err| {
+        panic!(
+            "failed to parse `enclose` of minifier options: `{}`: {:?}",
+            args_values, err
+        )
+    });
+
+    let (params, args) = match *parsed {
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        }) => match *callee {
+            Expr::Paren(ParenExpr { expr, .. }) => match *expr {
+                Expr::Fn(FnExpr { function, .. }) => {
+                    (function.params.into_iter().map(|p| p.pat).collect(), args)
+                }
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+
+    Enclose {
+        params,
+        args,
+        top_level_ctxt: SyntaxContext::empty().apply_mark(top_level_mark),
+    }
+}
+
+struct WrapCommonJs {
+    name: JsWord,
+    top_level_ctxt: SyntaxContext,
+}
+
+impl VisitMut for WrapCommonJs {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        let has_export_all = m
+            .body
+            .iter()
+            .any(|item| matches!(item, ModuleItem::ModuleDecl(ModuleDecl::ExportAll(..))));
+        if has_export_all {
+            tracing::warn!("wrap: The module has `export * from`, so it cannot be wrapped");
+            return;
+        }
+
+        let exports = Ident::new("exports".into(), DUMMY_SP.with_ctxt(self.top_level_ctxt));
+        let require = Ident::new("require".into(), DUMMY_SP.with_ctxt(self.top_level_ctxt));
+        let name = Ident::new(self.name.clone(), DUMMY_SP.with_ctxt(self.top_level_ctxt));
+
+        // Imports and re-exports are evaluated before the body of the module.
+        let mut imports = vec![];
+        let mut stmts = vec![];
+        // Exports of bindings are assigned at the end of the wrapper, so that
+        // bindings declared after the `export` statement are exported properly.
+        let mut exported_bindings = vec![];
+
+        for item in m.body.take() {
+            match item {
+                ModuleItem::Stmt(s) => stmts.push(s),
+                ModuleItem::ModuleDecl(decl) => match decl {
+                    ModuleDecl::Import(import) => {
+                        imports.extend(import_to_require(&require, import));
+                    }
+
+                    ModuleDecl::ExportNamed(NamedExport {
+                        specifiers,
+                        src: Some(src),
+                        type_only: false,
+                        ..
+                    }) => {
+                        let module = require_call(&require, *src);
+
+                        for s in specifiers {
+                            let (exported, expr) = match s {
+                                ExportSpecifier::Namespace(ExportNamespaceSpecifier {
+                                    name,
+                                    ..
+                                }) => (module_export_name(name), module.clone()),
+                                ExportSpecifier::Default(ExportDefaultSpecifier { exported }) => {
+                                    (exported.sym, member(module.clone(), js_word!("default")))
+                                }
+                                ExportSpecifier::Named(ExportNamedSpecifier {
+                                    orig,
+                                    exported,
+                                    is_type_only: false,
+                                    ..
+                                }) => {
+                                    let orig = module_export_name(orig);
+                                    (
+                                        exported
+                                            .map(module_export_name)
+                                            .unwrap_or_else(|| orig.clone()),
+                                        member(module.clone(), orig),
+                                    )
+                                }
+                                _ => continue,
+                            };
+
+                            imports.push(export_to(&exports, exported, expr));
+                        }
+                    }
+
+                    ModuleDecl::ExportDecl(ExportDecl { decl, .. }) => {
+                        match &decl {
+                            Decl::Class(ClassDecl { ident, .. })
+                            | Decl::Fn(FnDecl { ident, .. }) => {
+                                exported_bindings.push((ident.sym.clone(), ident.clone()));
+                            }
+                            Decl::Var(v) => {
+                                let ids: Vec<Ident> = find_ids(&v.decls);
+                                exported_bindings
+                                    .extend(ids.into_iter().map(|id| (id.sym.clone(), id)));
+                            }
+                            _ => {}
+                        }
+
+                        stmts.push(Stmt::Decl(decl));
+                    }
+
+                    ModuleDecl::ExportNamed(NamedExport {
+                        specifiers,
+                        src: None,
+                        type_only: false,
+                        ..
+                    }) => {
+                        for s in specifiers {
+                            if let ExportSpecifier::Named(ExportNamedSpecifier {
+                                orig: ModuleExportName::Ident(orig),
+                                exported,
+                                is_type_only: false,
+                                ..
+                            }) = s
+                            {
+                                let exported = exported
+                                    .map(module_export_name)
+                                    .unwrap_or_else(|| orig.sym.clone());
+                                exported_bindings.push((exported, orig));
+                            }
+                        }
+                    }
+
+                    ModuleDecl::ExportDefaultDecl(ExportDefaultDecl { decl, .. }) => {
+                        let (ident, expr) = match decl {
+                            DefaultDecl::Class(ClassExpr {
+                                ident: Some(ident),
+                                class,
+                            }) => {
+                                stmts.push(Stmt::Decl(Decl::Class(ClassDecl {
+                                    ident: ident.clone(),
+                                    declare: false,
+                                    class,
+                                })));
+                                (Some(ident), None)
+                            }
+                            DefaultDecl::Fn(FnExpr {
+                                ident: Some(ident),
+                                function,
+                            }) => {
+                                stmts.push(Stmt::Decl(Decl::Fn(FnDecl {
+                                    ident: ident.clone(),
+                                    declare: false,
+                                    function,
+                                })));
+                                (Some(ident), None)
+                            }
+                            DefaultDecl::Class(c) => (None, Some(Expr::Class(c))),
+                            DefaultDecl::Fn(f) => (None, Some(Expr::Fn(f))),
+                            DefaultDecl::TsInterfaceDecl(..) => (None, None),
+                        };
+
+                        if let Some(ident) = ident {
+                            exported_bindings.push((js_word!("default"), ident));
+                        }
+                        if let Some(expr) = expr {
+                            stmts.push(export_to(&exports, js_word!("default"), expr));
+                        }
+                    }
+
+                    ModuleDecl::ExportDefaultExpr(ExportDefaultExpr { expr, .. }) => {
+                        stmts.push(export_to(&exports, js_word!("default"), *expr));
+                    }
+
+                    // Type-only imports and exports.
+                    _ => {}
+                },
+            }
+        }
+
+        imports.append(&mut stmts);
+        let mut stmts = imports;
+
+        stmts.extend(
+            exported_bindings
+                .into_iter()
+                .map(|(exported, orig)| export_to(&exports, exported, Expr::Ident(orig))),
+        );
+
+        // typeof NAME == "undefined" ? (NAME = {}) : NAME
+        let arg = Expr::Cond(CondExpr {
+            span: DUMMY_SP,
+            test: Box::new(
+                Expr::Unary(UnaryExpr {
+                    span: DUMMY_SP,
+                    op: op!("typeof"),
+                    arg: Box::new(Expr::Ident(name.clone())),
+                })
+                .make_bin(
+                    op!("=="),
+                    Expr::Lit(Lit::Str(Str {
+                        span: DUMMY_SP,
+                        value: js_word!("undefined"),
+                        has_escape: false,
+                        kind: Default::default(),
+                    })),
+                ),
+            ),
+            cons: Box::new(Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: op!("="),
+                left: PatOrExpr::Pat(Box::new(Pat::Ident(name.clone().into()))),
+                right: Box::new(Expr::Object(ObjectLit {
+                    span: DUMMY_SP,
+                    props: Default::default(),
+                })),
+            })),
+            alt: Box::new(Expr::Ident(name)),
+        });
+
+        m.body = vec![ModuleItem::Stmt(iife(
+            vec![Pat::Ident(exports.into())],
+            stmts,
+            vec![arg.as_arg()],
+        ))];
+    }
+}
+
+struct Enclose {
+    params: Vec<Pat>,
+    args: Vec<ExprOrSpread>,
+    top_level_ctxt: SyntaxContext,
+}
+
+impl VisitMut for Enclose {
+    noop_visit_mut_type!();
+
+    fn visit_mut_ident(&mut self, i: &mut Ident) {
+        i.span.ctxt = self.top_level_ctxt;
+    }
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        let has_local_export = m.body.iter().any(|item| {
+            matches!(
+                item,
+                ModuleItem::ModuleDecl(
+                    ModuleDecl::ExportDecl(..)
+                        | ModuleDecl::ExportDefaultDecl(..)
+                        | ModuleDecl::ExportDefaultExpr(..)
+                        | ModuleDecl::ExportNamed(NamedExport { src: None, .. })
+                )
+            )
+        });
+        if has_local_export {
+            tracing::warn!("enclose: The module has `export`s, so it cannot be enclosed");
+            return;
+        }
+
+        let mut params = self.params.take();
+        let mut args = self.args.take();
+        params.visit_mut_with(self);
+        args.visit_mut_with(self);
+
+        let mut outer = vec![];
+        let mut stmts = vec![];
+
+        for item in m.body.take() {
+            match item {
+                ModuleItem::Stmt(s) => stmts.push(s),
+                // Imports and re-exports should stay in the top level.
+                ModuleItem::ModuleDecl(decl) => outer.push(ModuleItem::ModuleDecl(decl)),
+            }
+        }
+
+        outer.push(ModuleItem::Stmt(iife(params, stmts, args)));
+
+        m.body = outer;
+    }
+}
+
+/// Converts an import to
+///
+/// ```js
+/// var ns = require("src"), { imported: local, default: def } = ns;
+/// ```
+///
+/// or `require("src");` if nothing is imported.
+fn import_to_require(require: &Ident, import: ImportDecl) -> Option<Stmt> {
+    if import.type_only {
+        return None;
+    }
+
+    let span = import.span;
+    let mut module = require_call(require, import.src);
+
+    if import.specifiers.is_empty() {
+        return Some(Stmt::Expr(ExprStmt {
+            span,
+            expr: Box::new(module),
+        }));
+    }
+
+    let mut decls = vec![];
+    let mut props = vec![];
+
+    for s in import.specifiers {
+        let (imported, local) = match s {
+            ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                decls.push(VarDeclarator {
+                    span: DUMMY_SP,
+                    name: Pat::Ident(local.clone().into()),
+                    init: Some(Box::new(module)),
+                    definite: false,
+                });
+                module = Expr::Ident(local);
+                continue;
+            }
+            ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) => {
+                (js_word!("default"), local)
+            }
+            ImportSpecifier::Named(ImportNamedSpecifier {
+                local,
+                imported,
+                is_type_only: false,
+                ..
+            }) => (
+                imported
+                    .map(module_export_name)
+                    .unwrap_or_else(|| local.sym.clone()),
+                local,
+            ),
+            _ => continue,
+        };
+
+        props.push(ObjectPatProp::KeyValue(KeyValuePatProp {
+            key: if is_valid_prop_ident(&imported) {
+                PropName::Ident(Ident::new(imported, DUMMY_SP))
+            } else {
+                PropName::Str(str_lit(imported))
+            },
+            value: Box::new(Pat::Ident(local.into())),
+        }));
+    }
+
+    if !props.is_empty() {
+        decls.push(VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Object(ObjectPat {
+                span: DUMMY_SP,
+                props,
+                optional: false,
+                type_ann: None,
+            }),
+            init: Some(Box::new(module)),
+            definite: false,
+        });
+    }
+
+    if decls.is_empty() {
+        return None;
+    }
+
+    Some(Stmt::Decl(Decl::Var(VarDecl {
+        span,
+        kind: VarDeclKind::Var,
+        declare: false,
+        decls,
+    })))
+}
+
+/// `require("src")`
+fn require_call(require: &Ident, src: Str) -> Expr {
+    Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: require.clone().as_callee(),
+        args: vec![Expr::Lit(Lit::Str(src)).as_arg()],
+        type_args: Default::default(),
+    })
+}
+
+/// `exports.exported = expr;`
+fn export_to(exports: &Ident, exported: JsWord, expr: Expr) -> Stmt {
+    let span = expr.span();
+    let left = member(Expr::Ident(exports.clone()), exported);
+
+    Stmt::Expr(ExprStmt {
+        span,
+        expr: Box::new(Expr::Assign(AssignExpr {
+            span: DUMMY_SP,
+            op: op!("="),
+            left: PatOrExpr::Expr(Box::new(left)),
+            right: Box::new(expr),
+        })),
+    })
+}
+
+/// `obj.prop` or `obj["prop"]`
+fn member(obj: Expr, prop: JsWord) -> Expr {
+    if is_valid_prop_ident(&prop) {
+        obj.make_member(Ident::new(prop, DUMMY_SP))
+    } else {
+        obj.computed_member(Expr::Lit(Lit::Str(str_lit(prop))))
+    }
+}
+
+fn is_valid_prop_ident(s: &str) -> bool {
+    let mut chars = s.chars();
+
+    chars.next().map_or(false, Ident::is_valid_start) && chars.all(Ident::is_valid_continue)
+}
+
+fn module_export_name(name: ModuleExportName) -> JsWord {
+    match name {
+        ModuleExportName::Ident(i) => i.sym,
+        ModuleExportName::Str(s) => s.value,
+    }
+}
+
+fn str_lit(value: JsWord) -> Str {
+    Str {
+        span: DUMMY_SP,
+        value,
+        has_escape: false,
+        kind: Default::default(),
+    }
+}
+
+/// `(function(params){ stmts })(args);`
+///
+/// The function and its body are marked unique, just like [super::unique_scope]
+/// does.
+fn iife(params: Vec<Pat>, stmts: Vec<Stmt>, args: Vec<ExprOrSpread>) -> Stmt {
+    fn unique_span() -> Span {
+        DUMMY_SP.apply_mark(Mark::fresh(Mark::root()))
+    }
+
+    let function = Function {
+        params: params
+            .into_iter()
+            .map(|pat| Param {
+                span: DUMMY_SP,
+                decorators: Default::default(),
+                pat,
+            })
+            .collect(),
+        decorators: Default::default(),
+        span: unique_span(),
+        body: Some(BlockStmt {
+            span: unique_span(),
+            stmts,
+        }),
+        is_generator: false,
+        is_async: false,
+        type_params: Default::default(),
+        return_type: Default::default(),
+    };
+
+    Stmt::Expr(ExprStmt {
+        span: DUMMY_SP,
+        expr: Box::new(Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: FnExpr {
+                ident: None,
+                function,
+            }
+            .wrap_with_paren()
+            .as_callee(),
+            args,
+            type_args: Default::default(),
+        })),
+    })
+}
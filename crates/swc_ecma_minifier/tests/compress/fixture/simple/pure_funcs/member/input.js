@@ -0,0 +1,9 @@
+export function foo(a, b) {
+    invariant(a, "a is required");
+    assert.ok(b());
+    console.log("foo", a);
+    console.error("foo", a);
+    const unused = invariant(b);
+    new invariant(a);
+    return invariant(a, b);
+}
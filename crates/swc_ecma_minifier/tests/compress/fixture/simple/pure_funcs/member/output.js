@@ -0,0 +1,3 @@
+export function foo(a, b) {
+    return b(), console.error("foo", a), invariant(a, b);
+}
@@ -77,7 +77,10 @@ fn print<N: swc_ecma_codegen::Node>(
         }
 
         let mut emitter = Emitter {
-            cfg: swc_ecma_codegen::Config { minify },
+            cfg: swc_ecma_codegen::Config {
+                minify,
+                ..Default::default()
+            },
             cm,
             comments: None,
             wr,
@@ -158,11 +161,25 @@ fn run(
 }
 
 fn run_exec_test(input_src: &str, config: &str, skip_mangle: bool) {
+    let expected_output = stdout_of(input_src).unwrap();
+
+    run_exec_test_with_output(input_src, config, &expected_output, skip_mangle)
+}
+
+/// Same as [run_exec_test], but compares the output of the compressed code
+/// with `expected_output` instead of the output of `input_src`.
+///
+/// This is required for options like `pure_funcs`, which are allowed to change
+/// the behavior of the code.
+fn run_exec_test_with_output(
+    input_src: &str,
+    config: &str,
+    expected_output: &str,
+    skip_mangle: bool,
+) {
     eprintln!("---- {} -----\n{}", Color::Green.paint("Config"), config);
 
     testing::run_test2(false, |cm, handler| {
-        let expected_output = stdout_of(input_src).unwrap();
-
         eprintln!(
             "---- {} -----\n{}",
             Color::Green.paint("Expected"),
@@ -184,7 +201,7 @@ fn run_exec_test(input_src: &str, config: &str, skip_mangle: bool) {
 
         assert_eq!(
             DebugUsingDisplay(&actual_output),
-            DebugUsingDisplay(&*expected_output)
+            DebugUsingDisplay(expected_output)
         );
 
         Ok(())
@@ -9205,7 +9222,9 @@ debug(
     "unused": true
 }"###;
 
-    run_exec_test(src, config, false);
+    // `debug` is listed in `pure_funcs`, so the call is dropped while the side
+    // effects of the argument are preserved.
+    run_exec_test_with_output(src, config, "PASS\n", false);
 }
 
 #[test]
@@ -9227,7 +9246,9 @@ debug(
     "unused": true
 }"###;
 
-    run_exec_test(src, config, false);
+    // `debug` is listed in `pure_funcs`, so the call is dropped while the side
+    // effects of the argument are preserved.
+    run_exec_test_with_output(src, config, "PASS\n", false);
 }
 
 #[test]
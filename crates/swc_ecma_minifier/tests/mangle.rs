@@ -1,15 +1,23 @@
 #![deny(warnings)]
 
-use std::path::{Path, PathBuf};
+use serde::Deserialize;
+use std::{
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
 use swc_common::{input::SourceFileInput, sync::Lrc, FileName, Mark, SourceFile, SourceMap};
 use swc_ecma_ast::*;
 use swc_ecma_codegen::{
     text_writer::{omit_trailing_semi, JsWriter, WriteJs},
     Emitter,
 };
+use swc_atoms::JsWord;
 use swc_ecma_minifier::{
     optimize,
-    option::{ExtraOptions, MangleOptions, ManglePropertiesOptions, MinifyOptions},
+    option::{
+        terser::TerserCompressorOptions, ExtraOptions, MangleOptions, ManglePropertiesOptions,
+        MinifyOptions,
+    },
 };
 use swc_ecma_parser::{lexer::Lexer, Parser};
 use swc_ecma_transforms::resolver_with_mark;
@@ -27,7 +35,10 @@ fn print(cm: Lrc<SourceMap>, m: &Module, minify: bool) -> String {
         }
 
         let mut emitter = Emitter {
-            cfg: swc_ecma_codegen::Config { minify },
+            cfg: swc_ecma_codegen::Config {
+                minify,
+                ..Default::default()
+            },
             cm,
             comments: None,
             wr,
@@ -134,3 +145,52 @@ fn fixture(input: PathBuf) {
     })
     .unwrap();
 }
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WrapOptions {
+    #[serde(default)]
+    wrap: Option<JsWord>,
+    #[serde(default)]
+    enclose: Option<String>,
+}
+
+#[testing::fixture("tests/wrap/**/input.js")]
+fn wrap(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let options = read_to_string(dir.join("options.json")).expect("failed to read options.json");
+    let options: WrapOptions =
+        serde_json::from_str(&options).expect("failed to deserialize options.json");
+
+    testing::run_test2(false, |cm, _handler| {
+        let mut m = parse(cm.clone(), &input);
+
+        let top_level_mark = Mark::fresh(Mark::root());
+
+        m.visit_mut_with(&mut resolver_with_mark(top_level_mark));
+
+        let m = optimize(
+            m,
+            cm.clone(),
+            None,
+            None,
+            &MinifyOptions {
+                compress: Some(TerserCompressorOptions::default().into_config(cm.clone())),
+                mangle: Some(Default::default()),
+                wrap: options.wrap,
+                enclose: options.enclose,
+                ..Default::default()
+            },
+            &ExtraOptions { top_level_mark },
+        );
+
+        let mangled = print(cm, &m, false);
+
+        NormalizedOutput::from(mangled)
+            .compare_to_file(dir.join("output.js"))
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
@@ -0,0 +1,23 @@
+import { join } from "path";
+
+const separator = "/";
+
+function resolve(base, path) {
+    return join(base, separator, path);
+}
+
+export class Resolver {
+    constructor(base) {
+        this.base = base;
+    }
+
+    resolve(path) {
+        return resolve(this.base, path);
+    }
+}
+
+export { resolve as resolvePath };
+
+export default function createResolver(base) {
+    return new Resolver(base);
+}
@@ -0,0 +1,17 @@
+!function(a) {
+    var { join: b  } = require("path");
+    function c(a, c) {
+        return b(a, "/", c);
+    }
+    class d {
+        constructor(e){
+            this.base = e;
+        }
+        resolve(f) {
+            return c(this.base, f);
+        }
+    }
+    a.Resolver = d, a.resolvePath = c, a.default = function(a) {
+        return new d(a);
+    };
+}("undefined" == typeof MyLib ? MyLib = {} : MyLib);
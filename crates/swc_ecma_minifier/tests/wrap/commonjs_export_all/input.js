@@ -0,0 +1,5 @@
+export * from "path";
+
+import separator from "./separator";
+
+console.log(separator);
@@ -0,0 +1,3 @@
+export * from "path";
+import a from "./separator";
+console.log(a);
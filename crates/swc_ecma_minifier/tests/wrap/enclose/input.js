@@ -0,0 +1,8 @@
+var counter = 0;
+
+function increment(step) {
+    counter += step;
+    return counter;
+}
+
+console.log(increment(1), increment(2));
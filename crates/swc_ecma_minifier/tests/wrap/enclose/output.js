@@ -0,0 +1,7 @@
+!function() {
+    var a = 0;
+    function b(b) {
+        return a += b;
+    }
+    console.log(b(1), b(2));
+}();
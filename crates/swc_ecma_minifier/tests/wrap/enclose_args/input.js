@@ -0,0 +1,11 @@
+var elements = document.querySelectorAll(".item");
+
+function show(element) {
+    element.style.display = "block";
+}
+
+for (var i = 0; i < elements.length; i++) {
+    show(elements[i]);
+}
+
+undefined;
@@ -0,0 +1,7 @@
+!function(a, b, c) {
+    var d = document.querySelectorAll(".item");
+    function e(a) {
+        a.style.display = "block";
+    }
+    for(var f = 0; f < d.length; f++)e(d[f]);
+}(window, document);
@@ -280,7 +280,7 @@ impl<'a, I: Input> Iterator for Lexer<'a, I> {
                 return self.read_tmpl_token(start_pos_of_tpl).map(Some);
             }
 
-            if self.syntax.typescript() && self.ctx.in_type {
+            if self.syntax.types() && self.ctx.in_type {
                 if c == '<' {
                     self.input.bump();
                     return Ok(Some(tok!('<')));
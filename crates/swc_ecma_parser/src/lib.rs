@@ -145,6 +145,15 @@ pub enum Syntax {
     #[cfg_attr(docsrs, doc(cfg(feature = "typescript")))]
     #[serde(rename = "typescript")]
     Typescript(TsConfig),
+    /// Flow is parsed using the parser for typescript, so this variant also
+    /// requires the cargo feature `typescript`.
+    ///
+    /// Type annotations are represented using the typescript nodes of the
+    /// ast.
+    #[cfg(feature = "typescript")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "typescript")))]
+    #[serde(rename = "flow")]
+    Flow(FlowConfig),
 }
 
 impl Default for Syntax {
@@ -159,7 +168,7 @@ impl Syntax {
             Syntax::Es(EsConfig {
                 import_assertions, ..
             }) => import_assertions,
-            Syntax::Typescript(_) => true,
+            Syntax::Typescript(_) | Syntax::Flow(_) => true,
         }
     }
 
@@ -170,6 +179,17 @@ impl Syntax {
                 static_blocks: true,
                 ..
             }) | Syntax::Typescript(..)
+                | Syntax::Flow(..)
+        )
+    }
+
+    pub fn auto_accessors(self) -> bool {
+        matches!(
+            self,
+            Syntax::Es(EsConfig {
+                auto_accessors: true,
+                ..
+            }) | Syntax::Typescript(..)
         )
     }
 
@@ -177,7 +197,9 @@ impl Syntax {
     pub fn jsx(self) -> bool {
         matches!(
             self,
-            Syntax::Es(EsConfig { jsx: true, .. }) | Syntax::Typescript(TsConfig { tsx: true, .. })
+            Syntax::Es(EsConfig { jsx: true, .. })
+                | Syntax::Typescript(TsConfig { tsx: true, .. })
+                | Syntax::Flow(FlowConfig { jsx: true, .. })
         )
     }
 
@@ -202,6 +224,9 @@ impl Syntax {
             }) | Syntax::Typescript(TsConfig {
                 decorators: true,
                 ..
+            }) | Syntax::Flow(FlowConfig {
+                decorators: true,
+                ..
             })
         )
     }
@@ -213,6 +238,7 @@ impl Syntax {
                 decorators_before_export: true,
                 ..
             }) | Syntax::Typescript(..)
+                | Syntax::Flow(..)
         )
     }
 
@@ -228,6 +254,34 @@ impl Syntax {
         matches!(self, Syntax::Typescript(..))
     }
 
+    /// Should we parse type annotations?
+    #[cfg(not(feature = "typescript"))]
+    pub const fn types(self) -> bool {
+        false
+    }
+
+    /// Should we parse type annotations?
+    ///
+    /// This returns `true` for both of typescript and flow, because flow is
+    /// parsed using the parser for typescript. Syntax only valid in
+    /// typescript, like `enum` or `x as T`, is gated by [Syntax::typescript].
+    #[cfg(feature = "typescript")]
+    pub const fn types(self) -> bool {
+        matches!(self, Syntax::Typescript(..) | Syntax::Flow(..))
+    }
+
+    /// Should we parse flow?
+    #[cfg(not(feature = "typescript"))]
+    pub const fn flow(self) -> bool {
+        false
+    }
+
+    /// Should we parse flow?
+    #[cfg(feature = "typescript")]
+    pub const fn flow(self) -> bool {
+        matches!(self, Syntax::Flow(..))
+    }
+
     pub fn export_default_from(self) -> bool {
         matches!(
             self,
@@ -250,7 +304,7 @@ impl Syntax {
             Syntax::Es(EsConfig {
                 private_in_object, ..
             }) => private_in_object,
-            Syntax::Typescript(_) => true,
+            Syntax::Typescript(_) | Syntax::Flow(_) => true,
         }
     }
     pub fn allow_super_outside_method(self) -> bool {
@@ -260,13 +314,14 @@ impl Syntax {
                 ..
             }) => allow_super_outside_method,
             Syntax::Typescript(_) => true,
+            Syntax::Flow(_) => false,
         }
     }
 
     pub(crate) fn early_errors(self) -> bool {
         match self {
             Syntax::Typescript(t) => !t.no_early_errors,
-            Syntax::Es(..) => true,
+            Syntax::Es(..) | Syntax::Flow(..) => true,
         }
     }
 }
@@ -288,6 +343,16 @@ pub struct TsConfig {
     pub no_early_errors: bool,
 }
 
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowConfig {
+    #[serde(default)]
+    pub jsx: bool,
+
+    #[serde(default)]
+    pub decorators: bool,
+}
+
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EsConfig {
@@ -325,6 +390,10 @@ pub struct EsConfig {
 
     #[serde(default, rename = "allowSuperOutsideMethod")]
     pub allow_super_outside_method: bool,
+
+    /// Stage 3.
+    #[serde(default, rename = "autoAccessors")]
+    pub auto_accessors: bool,
 }
 
 /// Syntactic context.
@@ -369,6 +438,10 @@ pub struct Context {
     /// If true, `:` should not be treated as a type annotation.
     dont_parse_colon_as_type_ann: bool,
 
+    /// Flow extension. If true, `T => U` is not parsed as a function type,
+    /// as in the return type of arrow functions.
+    flow_no_anon_fn_type: bool,
+
     // If true, allow super.x and super[x]
     allow_direct_super: bool,
 }
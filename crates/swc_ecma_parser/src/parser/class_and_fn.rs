@@ -85,13 +85,13 @@ impl<'a, I: Tokens> Parser<I> {
             expect!(p, "class");
 
             let ident = p.parse_maybe_opt_binding_ident()?;
-            if p.input.syntax().typescript() {
+            if p.input.syntax().types() {
                 if let Some(span) = ident.invalid_class_name() {
                     p.emit_err(span, SyntaxError::TS2414);
                 }
             }
 
-            let type_params = if p.input.syntax().typescript() {
+            let type_params = if p.input.syntax().types() {
                 p.try_parse_ts_type_params()?
             } else {
                 None
@@ -99,13 +99,13 @@ impl<'a, I: Tokens> Parser<I> {
 
             let (mut super_class, mut super_type_params) = if eat!(p, "extends") {
                 let super_class = p.parse_lhs_expr().map(Some)?;
-                let super_type_params = if p.input.syntax().typescript() && is!(p, '<') {
+                let super_type_params = if p.input.syntax().types() && is!(p, '<') {
                     Some(p.parse_ts_type_args()?)
                 } else {
                     None
                 };
 
-                if p.syntax().typescript() && eat!(p, ',') {
+                if p.syntax().types() && eat!(p, ',') {
                     let exprs = p.parse_ts_heritage_clause()?;
 
                     for e in &exprs {
@@ -123,12 +123,12 @@ impl<'a, I: Tokens> Parser<I> {
                 p.emit_err(p.input.prev_span(), SyntaxError::TS1172);
 
                 p.parse_lhs_expr()?;
-                if p.input.syntax().typescript() && is!(p, '<') {
+                if p.input.syntax().types() && is!(p, '<') {
                     p.parse_ts_type_args()?;
                 }
             };
 
-            let implements = if p.input.syntax().typescript() && eat!(p, "implements") {
+            let implements = if p.input.syntax().types() && eat!(p, "implements") {
                 p.parse_ts_heritage_clause()?
             } else {
                 vec![]
@@ -136,7 +136,7 @@ impl<'a, I: Tokens> Parser<I> {
 
             {
                 // Handle TS1175
-                if p.input.syntax().typescript() && eat!(p, "implements") {
+                if p.input.syntax().types() && eat!(p, "implements") {
                     p.emit_err(p.input.prev_span(), SyntaxError::TS1175);
 
                     p.parse_ts_heritage_clause()?;
@@ -144,11 +144,11 @@ impl<'a, I: Tokens> Parser<I> {
             }
 
             // Handle TS1173
-            if p.input.syntax().typescript() && eat!(p, "extends") {
+            if p.input.syntax().types() && eat!(p, "extends") {
                 p.emit_err(p.input.prev_span(), SyntaxError::TS1173);
 
                 let sc = p.parse_lhs_expr()?;
-                let type_params = if p.input.syntax().typescript() && is!(p, '<') {
+                let type_params = if p.input.syntax().types() && is!(p, '<') {
                     p.parse_ts_type_args().map(Some)?
                 } else {
                     None
@@ -248,7 +248,7 @@ impl<'a, I: Tokens> Parser<I> {
     }
 
     fn parse_maybe_decorator_args(&mut self, expr: Box<Expr>) -> PResult<Box<Expr>> {
-        let type_args = if self.input.syntax().typescript() && is!(self, '<') {
+        let type_args = if self.input.syntax().types() && is!(self, '<') {
             Some(self.parse_ts_type_args()?)
         } else {
             None
@@ -302,14 +302,14 @@ impl<'a, I: Tokens> Parser<I> {
 
         let start = cur_pos!(self);
         let decorators = self.parse_decorators(false)?;
-        let declare = self.syntax().typescript() && eat!(self, "declare");
+        let declare = self.syntax().types() && eat!(self, "declare");
         let accessibility = if self.input.syntax().typescript() {
             self.parse_access_modifier()?
         } else {
             None
         };
         // Allow `private declare`.
-        let declare = declare || self.syntax().typescript() && eat!(self, "declare");
+        let declare = declare || self.syntax().types() && eat!(self, "declare");
 
         let declare_token = if declare {
             // Handle declare(){}
@@ -318,7 +318,7 @@ impl<'a, I: Tokens> Parser<I> {
                     js_word!("declare"),
                     span!(self, start),
                 )));
-                let is_optional = self.input.syntax().typescript() && eat!(self, '?');
+                let is_optional = self.input.syntax().types() && eat!(self, '?');
                 return self.make_method(
                     |p| p.parse_unique_formal_params(),
                     MakeMethodArgs {
@@ -336,7 +336,7 @@ impl<'a, I: Tokens> Parser<I> {
                     },
                 );
             } else if self.is_class_property(/* asi */ true)
-                || (self.syntax().typescript() && is!(self, '?'))
+                || (self.syntax().types() && is!(self, '?'))
             {
                 // Property named `declare`
 
@@ -344,7 +344,7 @@ impl<'a, I: Tokens> Parser<I> {
                     js_word!("declare"),
                     span!(self, start),
                 )));
-                let is_optional = self.input.syntax().typescript() && eat!(self, '?');
+                let is_optional = self.input.syntax().types() && eat!(self, '?');
                 return self.make_property(
                     start,
                     decorators,
@@ -380,7 +380,7 @@ impl<'a, I: Tokens> Parser<I> {
                     js_word!("static"),
                     static_token,
                 )));
-                let is_optional = self.input.syntax().typescript() && eat!(self, '?');
+                let is_optional = self.input.syntax().types() && eat!(self, '?');
                 return self.make_method(
                     |p| p.parse_unique_formal_params(),
                     MakeMethodArgs {
@@ -398,7 +398,7 @@ impl<'a, I: Tokens> Parser<I> {
                     },
                 );
             } else if self.is_class_property(/* asi */ true)
-                || (self.syntax().typescript() && is!(self, '?'))
+                || (self.syntax().types() && is!(self, '?'))
             {
                 // Property named `static`
 
@@ -412,7 +412,7 @@ impl<'a, I: Tokens> Parser<I> {
                         js_word!("static"),
                         static_token,
                     )));
-                    let is_optional = self.input.syntax().typescript() && eat!(self, '?');
+                    let is_optional = self.input.syntax().types() && eat!(self, '?');
                     return self.make_property(
                         start,
                         decorators,
@@ -549,11 +549,7 @@ impl<'a, I: Tokens> Parser<I> {
             }
         }
 
-        if self.input.syntax().typescript()
-            && !is_abstract
-            && !is_override
-            && accessibility.is_none()
-        {
+        if self.input.syntax().types() && !is_abstract && !is_override && accessibility.is_none() {
             let idx = self.try_parse_ts_index_signature(start, readonly.is_some(), is_static)?;
             if let Some(idx) = idx {
                 return Ok(idx.into());
@@ -597,7 +593,7 @@ impl<'a, I: Tokens> Parser<I> {
         } else {
             self.parse_class_prop_name()?
         };
-        let is_optional = self.input.syntax().typescript() && eat!(self, '?');
+        let is_optional = self.input.syntax().types() && eat!(self, '?');
 
         if let Either::Right(PropName::Ident(i)) = &mut key {
             i.optional = is_optional;
@@ -625,7 +621,7 @@ impl<'a, I: Tokens> Parser<I> {
                     );
                 }
 
-                if self.syntax().typescript() && is!(self, '<') {
+                if self.syntax().types() && is!(self, '<') {
                     let start = cur_pos!(self);
                     if peeked_is!(self, '>') {
                         assert_and_bump!(self, '<');
@@ -649,7 +645,7 @@ impl<'a, I: Tokens> Parser<I> {
                 let params = self.parse_constructor_params()?;
                 expect!(self, ')');
 
-                if self.syntax().typescript() && is!(self, ':') {
+                if self.syntax().types() && is!(self, ':') {
                     let start = cur_pos!(self);
                     let type_ann = self.parse_ts_type_ann(true, start)?;
 
@@ -659,7 +655,7 @@ impl<'a, I: Tokens> Parser<I> {
                 let body: Option<_> =
                     self.parse_fn_body(false, false, params.is_simple_parameter_list())?;
 
-                if self.syntax().typescript() && body.is_none() {
+                if self.syntax().types() && body.is_none() {
                     // Declare constructors cannot have assignment pattern in parameters
                     for p in &params {
                         // TODO: Search deeply for assignment pattern using a Visitor
@@ -751,6 +747,19 @@ impl<'a, I: Tokens> Parser<I> {
             );
         }
 
+        if self.input.syntax().auto_accessors()
+            && match key {
+                Either::Right(PropName::Ident(ref i)) => i.sym == js_word!("accessor"),
+                _ => false,
+            }
+            && !self.input.had_line_break_before_cur()
+        {
+            // handle accessor foo = bar;
+            let key = self.parse_class_prop_name()?;
+
+            return self.make_auto_accessor(start, decorators, accessibility, key, is_static);
+        }
+
         if match key {
             Either::Right(PropName::Ident(ref i)) => i.sym == js_word!("async"),
             _ => false,
@@ -776,7 +785,7 @@ impl<'a, I: Tokens> Parser<I> {
             }
 
             // handle async foo(){}
-            let is_optional = is_optional || self.input.syntax().typescript() && eat!(self, '?');
+            let is_optional = is_optional || self.input.syntax().types() && eat!(self, '?');
             return self.make_method(
                 |p| p.parse_unique_formal_params(),
                 MakeMethodArgs {
@@ -943,14 +952,60 @@ impl<'a, I: Tokens> Parser<I> {
         })
     }
 
+    fn make_auto_accessor(
+        &mut self,
+        start: BytePos,
+        decorators: Vec<Decorator>,
+        accessibility: Option<Accessibility>,
+        key: Either<PrivateName, PropName>,
+        is_static: bool,
+    ) -> PResult<ClassMember> {
+        if is_constructor(&key) {
+            syntax_error!(self, key.span(), SyntaxError::PropertyNamedConstructor);
+        }
+
+        let type_ann = self.try_parse_ts_type_ann()?;
+
+        let ctx = Context {
+            include_in_expr: true,
+            ..self.ctx()
+        };
+        self.with_ctx(ctx).parse_with(|p| {
+            let value = if is!(p, '=') {
+                assert_and_bump!(p, '=');
+                Some(p.parse_assignment_expr()?)
+            } else {
+                None
+            };
+
+            if !eat!(p, ';') {
+                p.emit_err(p.input.cur_span(), SyntaxError::TS1005);
+            }
+
+            Ok(AutoAccessor {
+                span: span!(p, start),
+                key: match key {
+                    Either::Left(key) => Key::Private(key),
+                    Either::Right(key) => Key::Public(key),
+                },
+                value,
+                type_ann,
+                is_static,
+                decorators,
+                accessibility,
+            }
+            .into())
+        })
+    }
+
     fn is_class_method(&mut self) -> bool {
         is!(self, '(')
-            || (self.input.syntax().typescript() && is!(self, '<'))
-            || (self.input.syntax().typescript() && is!(self, JSXTagStart))
+            || (self.input.syntax().types() && is!(self, '<'))
+            || (self.input.syntax().types() && is!(self, JSXTagStart))
     }
 
     fn is_class_property(&mut self, asi: bool) -> bool {
-        (self.input.syntax().typescript() && is_one_of!(self, '!', ':'))
+        (self.input.syntax().types() && is_one_of!(self, '!', ':'))
             || is_one_of!(self, '=', '}')
             || if asi {
                 is!(self, ';')
@@ -1054,7 +1109,7 @@ impl<'a, I: Tokens> Parser<I> {
         };
 
         self.with_ctx(ctx).parse_with(|p| {
-            let type_params = if p.syntax().typescript() {
+            let type_params = if p.syntax().types() {
                 p.in_type().parse_with(|p| {
                     trace_cur!(p, parse_fn_args_body__type_params);
 
@@ -1093,7 +1148,7 @@ impl<'a, I: Tokens> Parser<I> {
             expect!(p, ')');
 
             // typescript extension
-            let return_type = if p.syntax().typescript() && is!(p, ':') {
+            let return_type = if p.syntax().types() && is!(p, ':') {
                 p.parse_ts_type_or_type_predicate_ann(&tok!(':'))
                     .map(Some)?
             } else {
@@ -1103,7 +1158,7 @@ impl<'a, I: Tokens> Parser<I> {
             let body: Option<_> =
                 p.parse_fn_body(is_async, is_generator, params.is_simple_parameter_list())?;
 
-            if p.syntax().typescript() && body.is_none() {
+            if p.syntax().types() && body.is_none() {
                 // Declare functions cannot have assignment pattern in parameters
                 for param in &params {
                     // TODO: Search deeply for assignment pattern using a Visitor
@@ -1133,6 +1188,9 @@ impl<'a, I: Tokens> Parser<I> {
     }
 
     fn parse_class_prop_name(&mut self) -> PResult<Either<PrivateName, PropName>> {
+        // Variance of flow, as in `+foo: T`
+        self.eat_flow_variance();
+
         if is!(self, '#') {
             self.parse_private_name().map(Either::Left)
         } else {
@@ -1149,7 +1207,7 @@ impl<'a, I: Tokens> Parser<I> {
     where
         Self: FnBodyParser<T>,
     {
-        if self.ctx().in_declare && self.syntax().typescript() && is!(self, '{') {
+        if self.ctx().in_declare && self.syntax().types() && is!(self, '{') {
             //            self.emit_err(
             //                self.ctx().span_of_fn_name.expect("we are not in function"),
             //                SyntaxError::TS1183,
@@ -37,7 +37,7 @@ impl<'a, I: Tokens> Parser<I> {
     pub(super) fn parse_assignment_expr(&mut self) -> PResult<Box<Expr>> {
         trace_cur!(self, parse_assignment_expr);
 
-        if self.input.syntax().typescript() && self.input.syntax().jsx() {
+        if self.input.syntax().types() && self.input.syntax().jsx() {
             // Note: When the JSX plugin is on, type assertions (`<T> x`) aren't valid
             // syntax.
 
@@ -78,7 +78,7 @@ impl<'a, I: Tokens> Parser<I> {
     fn parse_assignment_expr_base(&mut self) -> PResult<Box<Expr>> {
         trace_cur!(self, parse_assignment_expr_base);
 
-        if self.input.syntax().typescript()
+        if self.input.syntax().types()
             && (is_one_of!(self, '<', JSXTagStart))
             && (peeked_is!(self, IdentName) || peeked_is!(self, JSXName))
         {
@@ -273,7 +273,7 @@ impl<'a, I: Tokens> Parser<I> {
                         return self.parse_async_fn_expr();
                     }
 
-                    if can_be_arrow && self.input.syntax().typescript() && peeked_is!(self, '<') {
+                    if can_be_arrow && self.input.syntax().types() && peeked_is!(self, '<') {
                         // try parsing `async<T>() => {}`
                         if let Some(res) = self.try_parse_ts(|p| {
                             let start = cur_pos!(p);
@@ -351,7 +351,7 @@ impl<'a, I: Tokens> Parser<I> {
         }
 
         if is!(self, "let")
-            || (self.input.syntax().typescript() && is!(self, IdentName))
+            || (self.input.syntax().types() && is!(self, IdentName))
             || is!(self, IdentRef)
         {
             // TODO: Handle [Yield, Await]
@@ -516,7 +516,7 @@ impl<'a, I: Tokens> Parser<I> {
             let callee = self.parse_member_expr_or_new_expr(is_new_expr)?;
             return_if_arrow!(self, callee);
 
-            let type_args = if self.input.syntax().typescript() && is!(self, '<') {
+            let type_args = if self.input.syntax().types() && is!(self, '<') {
                 self.try_parse_ts(|p| {
                     let args = p.parse_ts_type_args()?;
                     if !is!(p, '(') {
@@ -672,11 +672,11 @@ impl<'a, I: Tokens> Parser<I> {
         let is_direct_child_of_cond = self.ctx().is_direct_child_of_cond;
 
         // This is slow path. We handle arrow in conditional expression.
-        if self.syntax().typescript() && self.ctx().in_cond_expr && is!(self, ':') {
+        if self.syntax().types() && self.ctx().in_cond_expr && is!(self, ':') {
             // TODO: Remove clone
             let items_ref = &paren_items;
             if let Some(expr) = self.try_parse_ts(|p| {
-                let return_type = p.parse_ts_type_or_type_predicate_ann(&tok!(':'))?;
+                let return_type = p.parse_flow_arrow_return_type()?;
 
                 expect!(p, "=>");
 
@@ -711,11 +711,22 @@ impl<'a, I: Tokens> Parser<I> {
         }
 
         let return_type = if !(self.ctx().in_cond_expr && self.ctx().is_direct_child_of_cond)
-            && self.input.syntax().typescript()
+            && self.input.syntax().types()
             && is!(self, ':')
             && !self.ctx().dont_parse_colon_as_type_ann
         {
-            Some(self.parse_ts_type_or_type_predicate_ann(&tok!(':'))?)
+            if self.input.syntax().flow() {
+                // `(a): T` without `=>` is a type cast of flow.
+                self.try_parse_ts(|p| {
+                    let return_type = p.parse_flow_arrow_return_type()?;
+                    if !is!(p, "=>") {
+                        unexpected!(p, "=>")
+                    }
+                    Ok(Some(return_type))
+                })
+            } else {
+                Some(self.parse_flow_arrow_return_type()?)
+            }
         } else {
             None
         };
@@ -998,8 +1009,11 @@ impl<'a, I: Tokens> Parser<I> {
     ) -> PResult<(Box<Expr>, bool)> {
         let _ = cur!(self, false);
 
-        if self.input.syntax().typescript() {
-            if !self.input.had_line_break_before_cur() && is!(self, '!') {
+        if self.input.syntax().types() {
+            if self.input.syntax().typescript()
+                && !self.input.had_line_break_before_cur()
+                && is!(self, '!')
+            {
                 self.input.set_expr_allowed(false);
                 assert_and_bump!(self, '!');
 
@@ -1357,7 +1371,7 @@ impl<'a, I: Tokens> Parser<I> {
         let callee = self.parse_new_expr()?;
         return_if_arrow!(self, callee);
 
-        let type_args = if self.input.syntax().typescript() && is!(self, '<') {
+        let type_args = if self.input.syntax().types() && is!(self, '<') {
             self.try_parse_ts(|p| {
                 let type_args = p.parse_ts_type_args()?;
                 if is!(p, '(') {
@@ -1450,7 +1464,7 @@ impl<'a, I: Tokens> Parser<I> {
             let pat_start = cur_pos!(self);
 
             let mut arg = {
-                if self.input.syntax().typescript()
+                if self.input.syntax().types()
                     && (is!(self, IdentRef) || (is!(self, "...") && peeked_is!(self, IdentRef)))
                 {
                     let spread = if eat!(self, "...") {
@@ -1479,7 +1493,7 @@ impl<'a, I: Tokens> Parser<I> {
                 }
             };
 
-            let optional = if self.input.syntax().typescript() {
+            let optional = if self.input.syntax().types() {
                 if is!(self, '?') {
                     if peeked_is!(self, ',')
                         || peeked_is!(self, ':')
@@ -1542,10 +1556,36 @@ impl<'a, I: Tokens> Parser<I> {
                 false
             };
 
-            if optional || (self.input.syntax().typescript() && is!(self, ':')) {
+            if self.input.syntax().flow() && !optional && arg.spread.is_none() && is!(self, ':') {
+                // Type cast of flow, as in `(a: T)`. This is converted to a pattern if it turns
+                // out to be a parameter of an arrow function.
+                let type_ann_start = cur_pos!(self);
+                let type_ann = self.parse_ts_type_ann(/* eat_colon */ true, type_ann_start)?;
+                let expr = TsAsExpr {
+                    span: span!(self, pat_start),
+                    expr: arg.expr,
+                    type_ann: type_ann.type_ann,
+                };
+
+                if eat!(self, '=') {
+                    let left = self.reparse_flow_type_cast_as_pat(PatType::BindingPat, expr)?;
+                    let right = self.parse_assignment_expr()?;
+                    items.push(PatOrExprOrSpread::Pat(Pat::Assign(AssignPat {
+                        span: span!(self, pat_start),
+                        left: Box::new(left),
+                        right,
+                        type_ann: None,
+                    })));
+                } else {
+                    items.push(PatOrExprOrSpread::ExprOrSpread(ExprOrSpread {
+                        spread: None,
+                        expr: Box::new(Expr::TsAs(expr)),
+                    }));
+                }
+            } else if optional || (self.input.syntax().types() && is!(self, ':')) {
                 // TODO: `async(...args?: any[]) : any => {}`
                 //
-                // if self.input.syntax().typescript() && optional && arg.spread.is_some() {
+                // if self.input.syntax().types() && optional && arg.spread.is_some() {
                 //     self.emit_err(self.input.prev_span(), SyntaxError::TS1047)
                 // }
 
@@ -0,0 +1,447 @@
+//! Parser for flow extensions.
+//!
+//! Flow is parsed by the parser for typescript, and the methods in this file
+//! handle the syntax which differs from typescript. Flow types are converted
+//! into the equivalent typescript nodes, e.g. `?T` is represented as
+//! `T | null | undefined`.
+use super::{pat::PatType, util::WithCtx, *};
+use crate::token::BinOpToken;
+use swc_common::Spanned;
+
+const LOGICAL_OR: Token = Token::BinOp(BinOpToken::LogicalOr);
+
+impl<I: Tokens> Parser<I> {
+    /// Returns a parser which accepts `T => U` in types again.
+    pub(super) fn flow_allow_anon_fn_type(&mut self) -> WithCtx<I> {
+        let ctx = Context {
+            flow_no_anon_fn_type: false,
+            ..self.ctx()
+        };
+        self.with_ctx(ctx)
+    }
+
+    /// Parses the return type of an arrow function, where `T => U` is not
+    /// allowed.
+    pub(super) fn parse_flow_arrow_return_type(&mut self) -> PResult<TsTypeAnn> {
+        let ctx = Context {
+            flow_no_anon_fn_type: self.input.syntax().flow(),
+            ..self.ctx()
+        };
+        self.with_ctx(ctx)
+            .parse_ts_type_or_type_predicate_ann(&tok!(':'))
+    }
+
+    /// `flowParsePrefixType`: Parses `?T`.
+    pub(super) fn parse_flow_maybe_type(&mut self) -> PResult<Box<TsType>> {
+        debug_assert!(self.input.syntax().flow());
+
+        let start = cur_pos!(self);
+        assert_and_bump!(self, '?');
+        let question_span = span!(self, start);
+
+        let ty = self.parse_ts_type_operator_or_higher()?;
+
+        let keyword = |kind| {
+            Box::new(TsType::TsKeywordType(TsKeywordType {
+                span: question_span,
+                kind,
+            }))
+        };
+
+        Ok(Box::new(TsType::TsUnionOrIntersectionType(
+            TsUnionOrIntersectionType::TsUnionType(TsUnionType {
+                span: span!(self, start),
+                types: vec![
+                    ty,
+                    keyword(TsKeywordTypeKind::TsNullKeyword),
+                    keyword(TsKeywordTypeKind::TsUndefinedKeyword),
+                ],
+            }),
+        )))
+    }
+
+    /// `flowParseAnonFunctionWithoutParens`: Parses `T => U`.
+    pub(super) fn parse_flow_anon_fn_type_or_higher(&mut self) -> PResult<Box<TsType>> {
+        debug_assert!(self.input.syntax().flow());
+
+        let start = cur_pos!(self);
+        let param = self.parse_ts_type_operator_or_higher()?;
+
+        if self.ctx().flow_no_anon_fn_type || !is!(self, "=>") {
+            return Ok(param);
+        }
+
+        let param = unnamed_fn_param(0, param);
+        let type_ann = self.parse_ts_type_or_type_predicate_ann(&tok!("=>"))?;
+
+        Ok(Box::new(TsType::TsFnOrConstructorType(
+            TsFnOrConstructorType::TsFnType(TsFnType {
+                span: span!(self, start),
+                params: vec![param],
+                type_params: None,
+                type_ann,
+            }),
+        )))
+    }
+
+    /// Returns true if the current `(` starts a function type like
+    /// `(string, number) => void`.
+    pub(super) fn is_flow_start_of_fn_type(&mut self) -> PResult<bool> {
+        debug_assert!(self.input.syntax().flow());
+
+        if self.ctx().flow_no_anon_fn_type {
+            return self.ts_look_ahead(|p| p.is_ts_unambiguously_start_of_fn_type());
+        }
+
+        self.ts_look_ahead(|p| {
+            assert_and_bump!(p, '(');
+
+            let mut depth = 1;
+            while depth > 0 {
+                match cur!(p, false) {
+                    Ok(&tok!('(')) => depth += 1,
+                    Ok(&tok!(')')) => depth -= 1,
+                    Ok(..) => {}
+                    Err(..) => return Ok(false),
+                }
+                bump!(p);
+            }
+
+            Ok(is!(p, "=>"))
+        })
+    }
+
+    /// Parses parameters of a function type, where the names of parameters
+    /// are optional. `(` should be already consumed.
+    ///
+    /// `flowParseFunctionTypeParams`
+    pub(super) fn parse_flow_fn_type_params(&mut self) -> PResult<Vec<TsFnParam>> {
+        debug_assert!(self.input.syntax().flow());
+
+        self.flow_allow_anon_fn_type().parse_with(|p| {
+            let mut params = vec![];
+
+            while !eof!(p) && !is!(p, ')') {
+                let start = cur_pos!(p);
+                let dot3_token = if is!(p, "...") {
+                    let dot3_start = cur_pos!(p);
+                    bump!(p);
+                    Some(span!(p, dot3_start))
+                } else {
+                    None
+                };
+
+                let is_named = is!(p, IdentName) && (peeked_is!(p, ':') || peeked_is!(p, '?'));
+
+                let param = if is_named {
+                    let mut id = p.parse_ident_name()?;
+                    id.optional = eat!(p, '?');
+                    let type_ann_start = cur_pos!(p);
+                    expect!(p, ':');
+                    let type_ann =
+                        p.parse_ts_type_ann(/* eat_colon */ false, type_ann_start)?;
+
+                    BindingIdent {
+                        id,
+                        type_ann: Some(type_ann),
+                    }
+                } else {
+                    let ty = p.parse_ts_type()?;
+                    match unnamed_fn_param(params.len(), ty) {
+                        TsFnParam::Ident(i) => i,
+                        _ => unreachable!(),
+                    }
+                };
+
+                params.push(match dot3_token {
+                    Some(dot3_token) => {
+                        let BindingIdent { id, type_ann } = param;
+                        TsFnParam::Rest(RestPat {
+                            span: span!(p, start),
+                            dot3_token,
+                            arg: Box::new(Pat::Ident(id.into())),
+                            type_ann,
+                        })
+                    }
+                    None => TsFnParam::Ident(param),
+                });
+
+                if !is!(p, ')') {
+                    expect!(p, ',');
+                }
+            }
+
+            expect!(p, ')');
+            Ok(params)
+        })
+    }
+
+    /// Returns true at `|}`, which closes an exact object type.
+    pub(super) fn is_flow_exact_object_end(&mut self) -> PResult<bool> {
+        Ok(self.input.syntax().flow() && is!(self, '|') && peeked_is!(self, '}'))
+    }
+
+    /// `flowParseObjectType`: Parses `{ a: T }`, `{| a: T |}` and
+    /// `{ ...T, a: U }`.
+    ///
+    /// Spreads are represented as an intersection type.
+    pub(super) fn parse_flow_object_type(&mut self) -> PResult<Box<TsType>> {
+        debug_assert!(self.input.syntax().flow());
+
+        let start = cur_pos!(self);
+        expect!(self, '{');
+
+        // `{||}` is lexed as `{` `||` `}`.
+        let exact = is!(self, '|') || self.input.is(&LOGICAL_OR);
+        let (members, spreads) = if self.input.eat(&LOGICAL_OR) {
+            (vec![], vec![])
+        } else {
+            if exact {
+                bump!(self);
+            }
+            let members = self.parse_flow_object_type_members(true)?;
+            if exact {
+                expect!(self, '|');
+            }
+            members
+        };
+        expect!(self, '}');
+
+        let lit = Box::new(TsType::TsTypeLit(TsTypeLit {
+            span: span!(self, start),
+            members,
+        }));
+
+        if spreads.is_empty() {
+            return Ok(lit);
+        }
+
+        let mut types = spreads;
+        types.push(lit);
+
+        Ok(Box::new(TsType::TsUnionOrIntersectionType(
+            TsUnionOrIntersectionType::TsIntersectionType(TsIntersectionType {
+                span: span!(self, start),
+                types,
+            }),
+        )))
+    }
+
+    /// Parses members of an object type until `}` or `|}`, and returns them
+    /// along with the types of spreads.
+    pub(super) fn parse_flow_object_type_members(
+        &mut self,
+        allow_spread: bool,
+    ) -> PResult<(Vec<TsTypeElement>, Vec<Box<TsType>>)> {
+        debug_assert!(self.input.syntax().flow());
+
+        self.flow_allow_anon_fn_type().parse_with(|p| {
+            let mut members = vec![];
+            let mut spreads = vec![];
+
+            while !eof!(p) && !is!(p, '}') && !p.is_flow_exact_object_end()? {
+                if eat!(p, "...") {
+                    // `...` without a type makes the object type inexact.
+                    if !is_one_of!(p, '}', ',', ';') && !p.is_flow_exact_object_end()? {
+                        if !allow_spread {
+                            unexpected!(p, "a property")
+                        }
+                        spreads.push(p.parse_ts_type()?);
+                    }
+                    p.parse_ts_type_member_semicolon()?;
+                    continue;
+                }
+
+                members.push(p.parse_flow_object_type_member()?);
+            }
+
+            Ok((members, spreads))
+        })
+    }
+
+    /// `flowParseObjectTypeProperty`, `flowParseObjectTypeIndexer`, ...
+    fn parse_flow_object_type_member(&mut self) -> PResult<TsTypeElement> {
+        let start = cur_pos!(self);
+
+        // Variance. As typescript does not have write-only properties, `-` is
+        // dropped.
+        let readonly = if is_one_of!(self, '+', '-') {
+            let readonly = is!(self, '+');
+            bump!(self);
+            readonly
+        } else {
+            false
+        };
+
+        if is!(self, '[') {
+            if let Some(idx) = self.try_parse_ts_index_signature(start, readonly, false)? {
+                return Ok(idx.into());
+            }
+
+            // `[K]: V`
+            let is_indexer = self.ts_look_ahead(|p| {
+                bump!(p);
+                p.parse_ts_type()?;
+                expect!(p, ']');
+                Ok(is!(p, ':'))
+            });
+            if let Ok(true) = is_indexer {
+                return self
+                    .parse_flow_unnamed_indexer(start, readonly)
+                    .map(From::from);
+            }
+        }
+
+        if readonly {
+            let (computed, key) = self.parse_ts_property_name()?;
+            let optional = eat!(self, '?');
+            let type_ann = self.try_parse_ts_type_ann()?;
+            self.parse_ts_type_member_semicolon()?;
+
+            return Ok(TsTypeElement::TsPropertySignature(TsPropertySignature {
+                span: span!(self, start),
+                readonly,
+                key,
+                computed,
+                optional,
+                init: None,
+                params: vec![],
+                type_ann,
+                type_params: None,
+            }));
+        }
+
+        self.parse_ts_type_member()
+    }
+
+    /// Parses `[K]: V`, and uses `key` as the name of the parameter.
+    fn parse_flow_unnamed_indexer(
+        &mut self,
+        start: BytePos,
+        readonly: bool,
+    ) -> PResult<TsIndexSignature> {
+        expect!(self, '[');
+        let key = self.parse_ts_type()?;
+        expect!(self, ']');
+
+        let key_span = key.span();
+        let param = TsFnParam::Ident(BindingIdent {
+            id: Ident::new(
+                "key".into(),
+                Span::new(key_span.lo, key_span.lo, Default::default()),
+            ),
+            type_ann: Some(TsTypeAnn {
+                span: key_span,
+                type_ann: key,
+            }),
+        });
+
+        let type_ann = self.try_parse_ts_type_ann()?;
+        self.parse_ts_type_member_semicolon()?;
+
+        Ok(TsIndexSignature {
+            span: span!(self, start),
+            readonly,
+            is_static: false,
+            params: vec![param],
+            type_ann,
+        })
+    }
+
+    /// Eats variance of a type parameter or a class property, i.e. `+` or
+    /// `-`.
+    pub(super) fn eat_flow_variance(&mut self) -> bool {
+        if self.input.syntax().flow() && is_one_of!(self, '+', '-') {
+            bump!(self);
+            return true;
+        }
+
+        false
+    }
+
+    /// Parses `opaque type T: Super = U;`. `opaque` should be already
+    /// consumed.
+    ///
+    /// The declaration is represented as a type alias, which aliases the
+    /// underlying type if present, and the supertype otherwise.
+    pub(super) fn parse_flow_opaque_type(&mut self, start: BytePos) -> PResult<TsTypeAliasDecl> {
+        debug_assert!(self.input.syntax().flow());
+
+        expect!(self, "type");
+        let id = self.parse_ident_name()?;
+        let type_params = self.try_parse_ts_type_params()?;
+        let supertype = self.eat_then_parse_ts_type(&tok!(':'))?;
+        let underlying = self.eat_then_parse_ts_type(&tok!('='))?;
+        expect!(self, ';');
+
+        let type_ann = match underlying.or(supertype) {
+            Some(ty) => ty,
+            None => Box::new(TsType::TsKeywordType(TsKeywordType {
+                span: id.span,
+                kind: TsKeywordTypeKind::TsUnknownKeyword,
+            })),
+        };
+
+        Ok(TsTypeAliasDecl {
+            span: span!(self, start),
+            declare: false,
+            id,
+            type_params,
+            type_ann,
+        })
+    }
+
+    /// `typeCastToParameter`: Converts `(a: T)` into a pattern of an arrow
+    /// function.
+    pub(super) fn reparse_flow_type_cast_as_pat(
+        &mut self,
+        pat_ty: PatType,
+        expr: TsAsExpr,
+    ) -> PResult<Pat> {
+        let span = expr.span;
+        let mut pat = self.reparse_expr_as_pat(pat_ty, expr.expr)?;
+        let type_ann = Some(TsTypeAnn {
+            span: expr.type_ann.span(),
+            type_ann: expr.type_ann,
+        });
+
+        match pat {
+            Pat::Ident(BindingIdent {
+                id: Ident {
+                    span: ref mut s, ..
+                },
+                type_ann: ref mut t,
+            })
+            | Pat::Array(ArrayPat {
+                span: ref mut s,
+                type_ann: ref mut t,
+                ..
+            })
+            | Pat::Object(ObjectPat {
+                span: ref mut s,
+                type_ann: ref mut t,
+                ..
+            }) => {
+                *s = span;
+                *t = type_ann;
+            }
+            _ => syntax_error!(self, span, SyntaxError::InvalidPat),
+        }
+
+        Ok(pat)
+    }
+}
+
+/// Creates a parameter for a function type without the name, like `string`
+/// of `(string) => void`.
+fn unnamed_fn_param(index: usize, ty: Box<TsType>) -> TsFnParam {
+    let span = ty.span();
+
+    TsFnParam::Ident(BindingIdent {
+        id: Ident::new(
+            format!("arg{}", index).into(),
+            Span::new(span.lo, span.lo, Default::default()),
+        ),
+        type_ann: Some(TsTypeAnn { span, type_ann: ty }),
+    })
+}
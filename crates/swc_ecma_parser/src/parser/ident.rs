@@ -133,18 +133,14 @@ impl<'a, I: Tokens> Parser<I> {
             // value as the StringValue of any ReservedWord except for yield or await.
 
             match w {
-                Word::Keyword(Keyword::Await) if p.input.syntax().typescript() => {
-                    Ok(js_word!("await"))
-                }
+                Word::Keyword(Keyword::Await) if p.input.syntax().types() => Ok(js_word!("await")),
 
                 // It is a Syntax Error if the goal symbol of the syntactic grammar is Module
                 // and the StringValue of IdentifierName is "await".
                 Word::Keyword(Keyword::Await) if p.ctx().module => {
                     syntax_error!(p, p.input.prev_span(), SyntaxError::ExpectedIdent)
                 }
-                Word::Keyword(Keyword::This) if p.input.syntax().typescript() => {
-                    Ok(js_word!("this"))
-                }
+                Word::Keyword(Keyword::This) if p.input.syntax().types() => Ok(js_word!("this")),
                 Word::Keyword(Keyword::Let) => Ok(js_word!("let")),
                 Word::Ident(ident) => Ok(ident),
                 Word::Keyword(Keyword::Yield) if incl_yield => Ok(js_word!("yield")),
@@ -202,7 +202,7 @@ impl<'a, I: Tokens> Parser<I> {
     ) -> PResult<JSXOpeningElement> {
         debug_assert!(self.input.syntax().jsx());
 
-        let type_args = if self.input.syntax().typescript() && is!(self, '<') {
+        let type_args = if self.input.syntax().types() && is!(self, '<') {
             self.try_parse_ts(|p| p.parse_ts_type_args().map(Some))
         } else {
             None
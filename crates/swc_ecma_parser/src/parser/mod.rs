@@ -21,6 +21,7 @@ use test::Bencher;
 mod macros;
 mod class_and_fn;
 mod expr;
+mod flow;
 mod ident;
 pub mod input;
 mod jsx;
@@ -96,7 +97,7 @@ impl<I: Tokens> Parser<I> {
     pub fn parse_typescript_module(&mut self) -> PResult<Module> {
         trace_cur!(self, parse_typescript_module);
 
-        debug_assert!(self.syntax().typescript());
+        debug_assert!(self.syntax().types());
 
         //TODO: parse() -> PResult<Program>
         let ctx = Context {
@@ -93,7 +93,7 @@ impl<'a, I: Tokens> Parser<I> {
 
                     let mut expr = p.include_in_expr(true).parse_assignment_expr()?;
 
-                    if p.syntax().typescript() && is!(p, ',') {
+                    if p.syntax().types() && is!(p, ',') {
                         let mut exprs = vec![expr];
 
                         while eat!(p, ',') {
@@ -180,9 +180,9 @@ impl<I: Tokens> ParseObject<Box<Expr>> for Parser<I> {
 
         let key = self.parse_prop_name()?;
 
-        if self.input.syntax().typescript()
+        if self.input.syntax().types()
             && !is_one_of!(self, '(', '[', ':', ',', '?', '=', '*', IdentName, Str, Num)
-            && !(self.input.syntax().typescript() && is!(self, '<'))
+            && !(self.input.syntax().types() && is!(self, '<'))
             && !(is!(self, '}') && matches!(key, PropName::Ident(..)))
         {
             trace_cur!(self, parse_object_prop_error);
@@ -209,7 +209,7 @@ impl<I: Tokens> ParseObject<Box<Expr>> for Parser<I> {
         }
 
         // Handle `a(){}` (and async(){} / get(){} / set(){})
-        if (self.input.syntax().typescript() && is!(self, '<')) || is!(self, '(') {
+        if (self.input.syntax().types() && is!(self, '<')) || is!(self, '(') {
             return self
                 .with_ctx(Context {
                     allow_direct_super: true,
@@ -395,7 +395,7 @@ impl<I: Tokens> ParseObject<Box<Expr>> for Parser<I> {
                 })
             }
             _ => {
-                if self.input.syntax().typescript() {
+                if self.input.syntax().types() {
                     unexpected!(
                         self,
                         "... , *,  (, [, :, , ?, =, an identifier, public, protected, private, \
@@ -12,7 +12,7 @@ impl<'a, I: Tokens> Parser<I> {
     pub(super) fn parse_opt_binding_ident(&mut self) -> PResult<Option<BindingIdent>> {
         trace_cur!(self, parse_opt_binding_ident);
 
-        if is!(self, BindingIdent) || (self.input.syntax().typescript() && is!(self, "this")) {
+        if is!(self, BindingIdent) || (self.input.syntax().types() && is!(self, "this")) {
             self.parse_binding_ident().map(Some)
         } else {
             Ok(None)
@@ -165,7 +165,7 @@ impl<'a, I: Tokens> Parser<I> {
         let mut pat = self.parse_binding_element()?;
         let mut opt = false;
 
-        if self.input.syntax().typescript() {
+        if self.input.syntax().types() {
             if eat!(self, '?') {
                 match pat {
                     Pat::Ident(BindingIdent {
@@ -285,7 +285,7 @@ impl<'a, I: Tokens> Parser<I> {
                 let dot3_token = span!(self, pat_start);
 
                 let pat = self.parse_binding_pat_or_ident()?;
-                let type_ann = if self.input.syntax().typescript() && is!(self, ':') {
+                let type_ann = if self.input.syntax().types() && is!(self, ':') {
                     let cur_pos = cur_pos!(self);
                     Some(self.parse_ts_type_ann(/* eat_colon */ true, cur_pos)?)
                 } else {
@@ -422,7 +422,7 @@ impl<'a, I: Tokens> Parser<I> {
                     .into();
                 }
 
-                let type_ann = if self.input.syntax().typescript() && is!(self, ':') {
+                let type_ann = if self.input.syntax().types() && is!(self, ':') {
                     let cur_pos = cur_pos!(self);
                     let ty = self.parse_ts_type_ann(/* eat_colon */ true, cur_pos)?;
                     Some(ty)
@@ -437,7 +437,7 @@ impl<'a, I: Tokens> Parser<I> {
                     type_ann,
                 });
 
-                if self.syntax().typescript() && eat!(self, '?') {
+                if self.syntax().types() && eat!(self, '?') {
                     self.emit_err(self.input.prev_span(), SyntaxError::TS1047);
                     //
                 }
@@ -585,6 +585,10 @@ impl<'a, I: Tokens> Parser<I> {
                 self.emit_err(span, SyntaxError::InvalidPat);
                 Ok(Pat::Invalid(Invalid { span }))
             }
+            // Parameter with a type annotation of flow, which is parsed as a type cast.
+            Expr::TsAs(expr) if self.input.syntax().flow() => {
+                self.reparse_flow_type_cast_as_pat(pat_ty, expr)
+            }
             Expr::Assign(
                 assign_expr @ AssignExpr {
                     op: AssignOpToken::Assign,
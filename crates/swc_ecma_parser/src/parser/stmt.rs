@@ -321,7 +321,7 @@ impl<'a, I: Tokens> Parser<I> {
                 }));
             }
 
-            if self.input.syntax().typescript() {
+            if self.input.syntax().types() {
                 if let Some(decl) = self.parse_ts_expr_stmt(decorators, ident.clone())? {
                     return Ok(Stmt::Decl(decl));
                 }
@@ -590,7 +590,7 @@ impl<'a, I: Tokens> Parser<I> {
 
             let type_ann_start = cur_pos!(self);
 
-            if self.syntax().typescript() && eat!(self, ':') {
+            if self.syntax().types() && eat!(self, ':') {
                 let ctx = Context {
                     in_type: true,
                     ..self.ctx()
@@ -632,7 +632,7 @@ impl<'a, I: Tokens> Parser<I> {
         let var_span = span!(self, start);
         let should_include_in = kind != VarDeclKind::Var || !for_loop;
 
-        if self.syntax().typescript() && for_loop {
+        if self.syntax().types() && for_loop {
             let res = if is_one_of!(self, "in", "of") {
                 self.ts_look_ahead(|p| {
                     //
@@ -734,7 +734,7 @@ impl<'a, I: Tokens> Parser<I> {
         };
 
         // Typescript extension
-        if self.input.syntax().typescript() && is!(self, ':') {
+        if self.input.syntax().types() && is!(self, ':') {
             let type_annotation = self.try_parse_ts_type_ann()?;
             match name {
                 Pat::Array(ArrayPat {
@@ -1003,7 +1003,7 @@ impl<'a, I: Tokens> Parser<I> {
                         );
                     }
 
-                    if self.syntax().typescript() {
+                    if self.syntax().types() {
                         let type_ann = match decl.decls[0].name {
                             Pat::Ident(ref v) => Some(&v.type_ann),
                             Pat::Array(ref v) => Some(&v.type_ann),
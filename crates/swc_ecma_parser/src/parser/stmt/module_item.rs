@@ -88,12 +88,13 @@ impl<'a, I: Tokens> Parser<I> {
             .map(ModuleItem::from);
         }
 
-        let type_only = self.input.syntax().typescript()
-            && is!(self, "type")
+        // `import typeof` of flow is also treated as a type-only import.
+        let type_only = self.input.syntax().types()
+            && (is!(self, "type") || (self.input.syntax().flow() && is!(self, "typeof")))
             && (peeked_is!(self, '{') || !peeked_is!(self, "from") && !peeked_is!(self, ','));
 
         if type_only {
-            assert_and_bump!(self, "type");
+            bump!(self);
 
             if is!(self, IdentRef) && peeked_is!(self, '=') {
                 return self
@@ -199,8 +200,9 @@ impl<'a, I: Tokens> Parser<I> {
                 // `import { type as } from 'mod'`
                 // `import { type as as } from 'mod'`
                 // `import { type as as as } from 'mod'`
-                if self.syntax().typescript()
-                    && orig_name.sym == js_word!("type")
+                if self.syntax().types()
+                    && (orig_name.sym == js_word!("type")
+                        || (self.syntax().flow() && orig_name.sym == js_word!("typeof")))
                     && is!(self, IdentName)
                 {
                     let possibly_orig_name = self.parse_ident_name()?;
@@ -350,7 +352,7 @@ impl<'a, I: Tokens> Parser<I> {
         let after_export_start = cur_pos!(self);
 
         // "export declare" is equivalent to just "export".
-        let declare = self.input.syntax().typescript() && eat!(self, "declare");
+        let declare = self.input.syntax().types() && eat!(self, "declare");
 
         if declare {
             // TODO: Remove
@@ -362,7 +364,7 @@ impl<'a, I: Tokens> Parser<I> {
             }
         }
 
-        if self.input.syntax().typescript() && is!(self, IdentName) {
+        if self.input.syntax().types() && is!(self, IdentName) {
             let sym = match *cur!(self, true)? {
                 Token::Word(ref w) => w.clone().into(),
                 _ => unreachable!(),
@@ -415,11 +417,11 @@ impl<'a, I: Tokens> Parser<I> {
         let mut export_ns = None;
         let ns_export_specifier_start = cur_pos!(self);
 
-        let type_only = self.input.syntax().typescript() && eat!(self, "type");
+        let type_only = self.input.syntax().types() && eat!(self, "type");
 
         if eat!(self, '*') {
             has_star = true;
-            if self.input.syntax().typescript() && type_only {
+            if self.input.syntax().types() && type_only {
                 // export type * from "mod";
                 // or
                 // export type * as foo from "mod";
@@ -448,8 +450,9 @@ impl<'a, I: Tokens> Parser<I> {
         let mut export_default = None;
 
         if !type_only && export_ns.is_none() && eat!(self, "default") {
-            if self.input.syntax().typescript() {
-                if is!(self, "abstract")
+            if self.input.syntax().types() {
+                if self.input.syntax().typescript()
+                    && is!(self, "abstract")
                     && peeked_is!(self, "class")
                     && !self.input.has_linebreak_between_cur_and_peeked()
                 {
@@ -687,7 +690,7 @@ impl<'a, I: Tokens> Parser<I> {
                 // `export { type as }`
                 // `export { type as as }`
                 // `export { type as as as }`
-                if self.syntax().typescript()
+                if self.syntax().types()
                     && orig_ident.sym == js_word!("type")
                     && is!(self, IdentName)
                 {
@@ -7,7 +7,7 @@ use swc_common::{Spanned, SyntaxContext};
 impl<I: Tokens> Parser<I> {
     /// `tsNextTokenCanFollowModifier`
     fn ts_next_token_can_follow_modifier(&mut self) -> PResult<bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         // Note: TypeScript's implementation is much more complicated because
         // more things are considered modifiers there.
@@ -61,7 +61,7 @@ impl<I: Tokens> Parser<I> {
     /// `tsIsListTerminator`
 
     fn is_ts_list_terminator(&mut self, kind: ParsingContext) -> PResult<bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         Ok(match kind {
             ParsingContext::EnumMembers | ParsingContext::TypeMembers => is!(self, '}'),
@@ -78,7 +78,7 @@ impl<I: Tokens> Parser<I> {
     where
         F: FnMut(&mut Self) -> PResult<T>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let mut buf = vec![];
         while !self.is_ts_list_terminator(kind)? {
@@ -114,7 +114,7 @@ impl<I: Tokens> Parser<I> {
     where
         F: FnMut(&mut Self) -> PResult<(BytePos, T)>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let mut buf = vec![];
 
@@ -158,7 +158,7 @@ impl<I: Tokens> Parser<I> {
     where
         F: FnMut(&mut Self) -> PResult<T>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         if !skip_first_token {
             if bracket {
@@ -181,7 +181,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseEntityName`
     fn parse_ts_entity_name(&mut self, allow_reserved_words: bool) -> PResult<TsEntityName> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let init = self.parse_ident_name()?;
         if let Ident {
@@ -219,7 +219,7 @@ impl<I: Tokens> Parser<I> {
     /// `tsParseTypeReference`
     fn parse_ts_type_ref(&mut self) -> PResult<TsTypeRef> {
         trace_cur!(self, parse_ts_type_ref);
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
 
@@ -251,7 +251,7 @@ impl<I: Tokens> Parser<I> {
         has_asserts_keyword: bool,
         lhs: TsThisType,
     ) -> PResult<TsTypePredicate> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let param_name = TsThisTypeOrIdent::TsThisType(lhs);
         let type_ann = if eat!(self, "is") {
@@ -274,7 +274,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseThisTypeNode`
     fn parse_ts_this_type_node(&mut self) -> PResult<TsThisType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         expect!(self, "this");
 
@@ -342,7 +342,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseTypeQuery`
     fn parse_ts_type_query(&mut self) -> PResult<TsTypeQuery> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
         expect!(self, "typeof");
@@ -364,12 +364,18 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseTypeParameter`
     fn parse_ts_type_param(&mut self) -> PResult<TsTypeParam> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
 
+        self.eat_flow_variance();
         let name = self.in_type().parse_ident_name()?;
-        let constraint = self.eat_then_parse_ts_type(&tok!("extends"))?;
+        let constraint = if self.input.syntax().flow() && is!(self, ':') {
+            // Bound of flow, as in `T: Foo`
+            self.eat_then_parse_ts_type(&tok!(':'))?
+        } else {
+            self.eat_then_parse_ts_type(&tok!("extends"))?
+        };
         let default = self.eat_then_parse_ts_type(&tok!('='))?;
 
         Ok(TsTypeParam {
@@ -412,7 +418,7 @@ impl<I: Tokens> Parser<I> {
         &mut self,
         return_token: &'static Token,
     ) -> PResult<TsTypeAnn> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         self.in_type().parse_with(|p| {
             let return_token_start = cur_pos!(p);
@@ -472,7 +478,7 @@ impl<I: Tokens> Parser<I> {
     where
         F: FnOnce(&mut Self) -> PResult<Option<bool>>,
     {
-        if !self.input.syntax().typescript() {
+        if !self.input.syntax().types() {
             return Ok(false);
         }
         let prev_ignore_error = self.input.get_ctx().ignore_error;
@@ -503,7 +509,7 @@ impl<I: Tokens> Parser<I> {
     where
         F: FnOnce(&mut Self) -> PResult<Option<T>>,
     {
-        if !self.input.syntax().typescript() {
+        if !self.input.syntax().types() {
             return None;
         }
         #[cfg(feature = "debug")]
@@ -554,7 +560,7 @@ impl<I: Tokens> Parser<I> {
     ) -> PResult<TsTypeAnn> {
         trace_cur!(self, parse_ts_type_ann);
 
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         self.in_type().parse_with(|p| {
             if eat_colon {
@@ -573,7 +579,7 @@ impl<I: Tokens> Parser<I> {
     }
 
     /// `tsEatThenParseType`
-    fn eat_then_parse_ts_type(
+    pub(super) fn eat_then_parse_ts_type(
         &mut self,
         token_to_eat: &'static Token,
     ) -> PResult<Option<Box<TsType>>> {
@@ -596,7 +602,7 @@ impl<I: Tokens> Parser<I> {
         token: &'static Token,
         token_str: &'static str,
     ) -> PResult<Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         self.in_type().parse_with(|p| {
             if !p.input.eat(token) {
@@ -617,7 +623,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsNextThenParseType`
     pub(super) fn next_then_parse_ts_type(&mut self) -> PResult<Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         self.in_type().parse_with(|p| {
             bump!(p);
@@ -628,7 +634,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseEnumMember`
     fn parse_ts_enum_member(&mut self) -> PResult<TsEnumMember> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
         // Computed property names are grammar errors in an enum, so accept just string
@@ -695,7 +701,7 @@ impl<I: Tokens> Parser<I> {
         start: BytePos,
         is_const: bool,
     ) -> PResult<TsEnumDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let id = self.parse_ident_name()?;
         expect!(self, '{');
@@ -716,7 +722,7 @@ impl<I: Tokens> Parser<I> {
     fn parse_ts_module_block(&mut self) -> PResult<TsModuleBlock> {
         trace_cur!(self, parse_ts_module_block);
 
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
         expect!(self, '{');
@@ -736,7 +742,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseModuleOrNamespaceDeclaration`
     fn parse_ts_module_or_ns_decl(&mut self, start: BytePos) -> PResult<TsModuleDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let id = self.parse_ident_name()?;
         let body: TsNamespaceBody = if eat!(self, '.') {
@@ -768,7 +774,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseAmbientExternalModuleDeclaration`
     fn parse_ts_ambient_external_module_decl(&mut self, start: BytePos) -> PResult<TsModuleDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let (global, id) = if is!(self, "global") {
             let id = self.parse_ident_name()?;
@@ -800,7 +806,7 @@ impl<I: Tokens> Parser<I> {
     }
 
     pub fn parse_type(&mut self) -> PResult<Box<TsType>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         self.in_type().parse_ts_type()
     }
@@ -811,7 +817,7 @@ impl<I: Tokens> Parser<I> {
     pub(super) fn parse_ts_type(&mut self) -> PResult<Box<TsType>> {
         trace_cur!(self, parse_ts_type);
 
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         // Need to set `state.inType` so that we don't parse JSX in a type context.
         debug_assert!(self.ctx().in_type);
@@ -847,7 +853,7 @@ impl<I: Tokens> Parser<I> {
     fn parse_ts_non_conditional_type(&mut self) -> PResult<Box<TsType>> {
         trace_cur!(self, parse_ts_non_conditional_type);
 
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         if self.is_ts_start_of_fn_type()? {
             return self
@@ -867,18 +873,22 @@ impl<I: Tokens> Parser<I> {
     }
 
     fn is_ts_start_of_fn_type(&mut self) -> PResult<bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         if is!(self, '<') {
             return Ok(true);
         }
 
+        if self.input.syntax().flow() {
+            return Ok(is!(self, '(') && self.is_flow_start_of_fn_type()?);
+        }
+
         Ok(is!(self, '(') && self.ts_look_ahead(|p| p.is_ts_unambiguously_start_of_fn_type())?)
     }
 
     /// `tsParseTypeAssertion`
     pub(super) fn parse_ts_type_assertion(&mut self, start: BytePos) -> PResult<TsTypeAssertion> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         // Not actually necessary to set state.inType because we never reach here if JSX
         // plugin is enabled, but need `tsInType` to satisfy the assertion in
@@ -895,7 +905,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseHeritageClause`
     pub(super) fn parse_ts_heritage_clause(&mut self) -> PResult<Vec<TsExprWithTypeArgs>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         self.parse_ts_delimited_list(ParsingContext::HeritageClauseElement, |p| {
             p.parse_expr_with_type_args()
@@ -904,7 +914,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseExpressionWithTypeArguments`
     fn parse_expr_with_type_args(&mut self) -> PResult<TsExprWithTypeArgs> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
         // Note: TS uses parseLeftHandSideExpressionOrHigher,
@@ -925,7 +935,7 @@ impl<I: Tokens> Parser<I> {
     }
     /// `tsParseInterfaceDeclaration`
     pub(super) fn parse_ts_interface_decl(&mut self, start: BytePos) -> PResult<TsInterfaceDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let id = self.parse_ident_name()?;
         match id.sym {
@@ -985,7 +995,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseTypeAliasDeclaration`
     fn parse_ts_type_alias_decl(&mut self, start: BytePos) -> PResult<TsTypeAliasDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let id = self.parse_ident_name()?;
         let type_params = self.try_parse_ts_type_params()?;
@@ -1007,7 +1017,7 @@ impl<I: Tokens> Parser<I> {
         is_export: bool,
         is_type_only: bool,
     ) -> PResult<TsImportEqualsDecl> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let id = self.parse_ident_name()?;
         expect!(self, '=');
@@ -1026,14 +1036,14 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsIsExternalModuleReference`
     fn is_ts_external_module_ref(&mut self) -> PResult<bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         Ok(is!(self, "require") && peeked_is!(self, '('))
     }
 
     /// `tsParseModuleReference`
     fn parse_ts_module_ref(&mut self) -> PResult<TsModuleRef> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         if self.is_ts_external_module_ref()? {
             self.parse_ts_external_module_ref().map(From::from)
@@ -1045,7 +1055,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseExternalModuleReference`
     fn parse_ts_external_module_ref(&mut self) -> PResult<TsExternalModuleRef> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
         expect!(self, "require");
@@ -1069,7 +1079,7 @@ impl<I: Tokens> Parser<I> {
     where
         F: FnOnce(&mut Self) -> PResult<T>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let mut cloned = self.clone();
         let ctx = Context {
@@ -1081,8 +1091,8 @@ impl<I: Tokens> Parser<I> {
     }
 
     /// `tsIsUnambiguouslyStartOfFunctionType`
-    fn is_ts_unambiguously_start_of_fn_type(&mut self) -> PResult<bool> {
-        debug_assert!(self.input.syntax().typescript());
+    pub(super) fn is_ts_unambiguously_start_of_fn_type(&mut self) -> PResult<bool> {
+        debug_assert!(self.input.syntax().types());
 
         assert_and_bump!(self, '(');
         if is_one_of!(self, ')', "...") {
@@ -1108,7 +1118,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsSkipParameterStart`
     fn skip_ts_parameter_start(&mut self) -> PResult<bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let _ = self.eat_any_ts_modifier()?;
 
@@ -1125,8 +1135,13 @@ impl<I: Tokens> Parser<I> {
     }
 
     /// `tsParseTypeMemberSemicolon`
-    fn parse_ts_type_member_semicolon(&mut self) -> PResult<()> {
-        debug_assert!(self.input.syntax().typescript());
+    pub(super) fn parse_ts_type_member_semicolon(&mut self) -> PResult<()> {
+        debug_assert!(self.input.syntax().types());
+
+        // `{| a: T |}` of flow
+        if self.is_flow_exact_object_end()? {
+            return Ok(());
+        }
 
         if !eat!(self, ',') {
             expect!(self, ';');
@@ -1140,7 +1155,7 @@ impl<I: Tokens> Parser<I> {
         &mut self,
         kind: SignatureParsingMode,
     ) -> PResult<Either<TsCallSignatureDecl, TsConstructSignatureDecl>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
 
@@ -1183,7 +1198,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsIsUnambiguouslyIndexSignature`
     fn is_ts_unambiguously_index_signature(&mut self) -> PResult<bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         // Note: babel's comment is wrong
         assert_and_bump!(self, '['); // Skip '['
@@ -1242,7 +1257,7 @@ impl<I: Tokens> Parser<I> {
     /// `parsePropertyName` in babel.
     ///
     /// Returns `(computed, key)`.
-    fn parse_ts_property_name(&mut self) -> PResult<(bool, Box<Expr>)> {
+    pub(super) fn parse_ts_property_name(&mut self) -> PResult<(bool, Box<Expr>)> {
         let (computed, key) = if eat!(self, '[') {
             let key = self.parse_assignment_expr()?;
             expect!(self, ']');
@@ -1279,7 +1294,7 @@ impl<I: Tokens> Parser<I> {
         start: BytePos,
         readonly: bool,
     ) -> PResult<Either<TsPropertySignature, TsMethodSignature>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let (computed, key) = self.parse_ts_property_name()?;
 
@@ -1327,8 +1342,8 @@ impl<I: Tokens> Parser<I> {
     }
 
     /// `tsParseTypeMember`
-    fn parse_ts_type_member(&mut self) -> PResult<TsTypeElement> {
-        debug_assert!(self.input.syntax().typescript());
+    pub(super) fn parse_ts_type_member(&mut self) -> PResult<TsTypeElement> {
+        debug_assert!(self.input.syntax().types());
 
         fn into_type_elem(
             e: Either<TsCallSignatureDecl, TsConstructSignatureDecl>,
@@ -1420,7 +1435,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsIsStartOfConstructSignature`
     fn is_ts_start_of_construct_signature(&mut self) -> PResult<bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         bump!(self);
 
@@ -1429,7 +1444,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseTypeLiteral`
     fn parse_ts_type_lit(&mut self) -> PResult<TsTypeLit> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
         let members = self.parse_ts_object_type_members()?;
@@ -1441,18 +1456,22 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseObjectTypeMembers`
     fn parse_ts_object_type_members(&mut self) -> PResult<Vec<TsTypeElement>> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         expect!(self, '{');
-        let members =
-            self.parse_ts_list(ParsingContext::TypeMembers, |p| p.parse_ts_type_member())?;
+        let members = if self.input.syntax().flow() {
+            self.parse_flow_object_type_members(/* allow_spread */ false)?
+                .0
+        } else {
+            self.parse_ts_list(ParsingContext::TypeMembers, |p| p.parse_ts_type_member())?
+        };
         expect!(self, '}');
         Ok(members)
     }
 
     /// `tsIsStartOfMappedType`
     fn is_ts_start_of_mapped_type(&mut self) -> PResult<bool> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         bump!(self);
         if eat!(self, '+') || eat!(self, '-') {
@@ -1475,7 +1494,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseMappedTypeParameter`
     fn parse_ts_mapped_type_param(&mut self) -> PResult<TsTypeParam> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
         let name = self.parse_ident_name()?;
@@ -1491,7 +1510,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseMappedType`
     fn parse_ts_mapped_type(&mut self) -> PResult<TsMappedType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
         expect!(self, '{');
@@ -1546,15 +1565,17 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseTupleType`
     fn parse_ts_tuple_type(&mut self) -> PResult<TsTupleType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
-        let elems = self.parse_ts_bracketed_list(
-            ParsingContext::TupleElementTypes,
-            |p| p.parse_ts_tuple_element_type(),
-            /* bracket */ true,
-            /* skipFirstToken */ false,
-        )?;
+        let elems = self.flow_allow_anon_fn_type().parse_with(|p| {
+            p.parse_ts_bracketed_list(
+                ParsingContext::TupleElementTypes,
+                |p| p.parse_ts_tuple_element_type(),
+                /* bracket */ true,
+                /* skipFirstToken */ false,
+            )
+        })?;
 
         // Validate the elementTypes to ensure:
         //   No mandatory elements may follow optional elements
@@ -1621,7 +1642,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseTupleElementType`
     fn parse_ts_tuple_element_type(&mut self) -> PResult<TsTupleElement> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         // parses `...TsType[]`
         let start = cur_pos!(self);
@@ -1663,11 +1684,13 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseParenthesizedType`
     fn parse_ts_parenthesized_type(&mut self) -> PResult<TsParenthesizedType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
         expect!(self, '(');
-        let type_ann = self.parse_ts_type()?;
+        let type_ann = self
+            .flow_allow_anon_fn_type()
+            .parse_with(|p| p.parse_ts_type())?;
         expect!(self, ')');
         Ok(TsParenthesizedType {
             span: span!(self, start),
@@ -1682,7 +1705,7 @@ impl<I: Tokens> Parser<I> {
     ) -> PResult<TsFnOrConstructorType> {
         trace_cur!(self, parse_ts_fn_or_constructor_type);
 
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
         let is_abstract = if !is_fn_type {
@@ -1721,7 +1744,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseLiteralTypeNode`
     fn parse_ts_lit_type_node(&mut self) -> PResult<TsLitType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
 
@@ -1747,7 +1770,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseTemplateLiteralType`
     fn parse_ts_tpl_lit_type(&mut self) -> PResult<TsTplLitType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
 
@@ -1797,7 +1820,11 @@ impl<I: Tokens> Parser<I> {
             return Ok(Default::default());
         }
 
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
+
+        if self.input.syntax().flow() {
+            return self.parse_flow_fn_type_params();
+        }
 
         let params = self.parse_formal_params()?;
         let mut list = vec![];
@@ -1877,10 +1904,25 @@ impl<I: Tokens> Parser<I> {
             unreachable!()
         }
         trace_cur!(self, parse_ts_non_array_type);
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
 
+        if self.input.syntax().flow() {
+            // Existential type of flow
+            if is!(self, '*') {
+                bump!(self);
+                return Ok(Box::new(TsType::TsKeywordType(TsKeywordType {
+                    span: span!(self, start),
+                    kind: TsKeywordTypeKind::TsAnyKeyword,
+                })));
+            }
+
+            if is!(self, '{') && !self.ts_look_ahead(|p| p.is_ts_start_of_mapped_type())? {
+                return self.parse_flow_object_type();
+            }
+        }
+
         match *cur!(self, true)? {
             Token::Word(Word::Ident(..))
             | tok!("void")
@@ -2025,7 +2067,7 @@ impl<I: Tokens> Parser<I> {
     /// `tsParseArrayTypeOrHigher`
     fn parse_ts_array_type_or_higher(&mut self, readonly: bool) -> PResult<Box<TsType>> {
         trace_cur!(self, parse_ts_array_type_or_higher);
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let mut ty = self.parse_ts_non_array_type()?;
 
@@ -2052,7 +2094,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseTypeOperator`
     fn parse_ts_type_operator(&mut self, op: TsTypeOperatorOp) -> PResult<TsTypeOperator> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
         match op {
@@ -2071,7 +2113,7 @@ impl<I: Tokens> Parser<I> {
 
     /// `tsParseInferType`
     fn parse_ts_infer_type(&mut self) -> PResult<TsInferType> {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
         expect!(self, "infer");
@@ -2089,9 +2131,9 @@ impl<I: Tokens> Parser<I> {
     }
 
     /// `tsParseTypeOperatorOrHigher`
-    fn parse_ts_type_operator_or_higher(&mut self) -> PResult<Box<TsType>> {
+    pub(super) fn parse_ts_type_operator_or_higher(&mut self) -> PResult<Box<TsType>> {
         trace_cur!(self, parse_ts_type_operator_or_higher);
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let operator = if is!(self, "keyof") {
             Some(TsTypeOperatorOp::KeyOf)
@@ -2113,6 +2155,8 @@ impl<I: Tokens> Parser<I> {
 
                 if is!(self, "infer") {
                     self.parse_ts_infer_type().map(TsType::from).map(Box::new)
+                } else if self.input.syntax().flow() && is!(self, '?') {
+                    self.parse_flow_maybe_type()
                 } else {
                     let readonly = self.parse_ts_modifier(&["readonly"], false)?.is_some();
                     self.parse_ts_array_type_or_higher(readonly)
@@ -2170,7 +2214,7 @@ impl<I: Tokens> Parser<I> {
                 // declaration.
                 // Would like to use tsParseAmbientExternalModuleDeclaration here, but already
                 // ran past "global".
-                if is!(self, '{') {
+                if self.input.syntax().typescript() && is!(self, '{') {
                     let global = true;
                     let id = TsModuleName::Ident(expr);
                     let body = self
@@ -2201,11 +2245,12 @@ impl<I: Tokens> Parser<I> {
         start: BytePos,
         decorators: Vec<Decorator>,
     ) -> PResult<Option<Decl>> {
-        if !self.syntax().typescript() {
+        if !self.syntax().types() {
             return Ok(None);
         }
 
-        if self.ctx().in_declare {
+        // Flow allows `declare` in `declare module`.
+        if self.ctx().in_declare && !self.input.syntax().flow() {
             let span_of_declare = span!(self, start);
             self.emit_err(span_of_declare, SyntaxError::TS1038);
         }
@@ -2257,7 +2302,7 @@ impl<I: Tokens> Parser<I> {
                     .map(Some);
             }
 
-            if is!(p, "const") && peeked_is!(p, "enum") {
+            if p.input.syntax().typescript() && is!(p, "const") && peeked_is!(p, "enum") {
                 assert_and_bump!(p, "const");
                 let _ = cur!(p, true);
                 assert_and_bump!(p, "enum");
@@ -2290,7 +2335,7 @@ impl<I: Tokens> Parser<I> {
                     .map(Some);
             }
 
-            if is!(p, "global") {
+            if p.input.syntax().typescript() && is!(p, "global") {
                 return p
                     .parse_ts_ambient_external_module_decl(start)
                     .map(Decl::from)
@@ -2345,8 +2390,11 @@ impl<I: Tokens> Parser<I> {
             return Ok(Default::default());
         }
 
+        // Declarations which are not valid in flow.
+        let typescript = self.input.syntax().typescript();
+
         match value {
-            js_word!("abstract") => {
+            js_word!("abstract") if typescript => {
                 if next || (is!(self, "class") && !self.input.had_line_break_before_cur()) {
                     if next {
                         bump!(self);
@@ -2367,7 +2415,7 @@ impl<I: Tokens> Parser<I> {
                 }
             }
 
-            js_word!("enum") => {
+            js_word!("enum") if typescript => {
                 if next || is!(self, IdentRef) {
                     if next {
                         bump!(self);
@@ -2392,7 +2440,8 @@ impl<I: Tokens> Parser<I> {
                 }
             }
 
-            js_word!("module") => {
+            // Flow only has `declare module "foo" {}`.
+            js_word!("module") if typescript || self.ctx().in_declare => {
                 if next {
                     bump!(self);
                 }
@@ -2402,7 +2451,7 @@ impl<I: Tokens> Parser<I> {
                         .parse_ts_ambient_external_module_decl(start)
                         .map(From::from)
                         .map(Some);
-                } else if next || is!(self, IdentRef) {
+                } else if typescript && (next || is!(self, IdentRef)) {
                     return self
                         .parse_ts_module_or_ns_decl(start)
                         .map(From::from)
@@ -2410,7 +2459,7 @@ impl<I: Tokens> Parser<I> {
                 }
             }
 
-            js_word!("namespace") => {
+            js_word!("namespace") if typescript => {
                 if next || is!(self, IdentRef) {
                     if next {
                         bump!(self);
@@ -2434,6 +2483,16 @@ impl<I: Tokens> Parser<I> {
                 }
             }
 
+            // `opaque type` of flow
+            ref value if self.input.syntax().flow() && &**value == "opaque" => {
+                if next || (is!(self, "type") && !self.input.had_line_break_before_cur()) {
+                    if next {
+                        bump!(self);
+                    }
+                    return self.parse_flow_opaque_type(start).map(From::from).map(Some);
+                }
+            }
+
             _ => {}
         }
 
@@ -2498,10 +2557,10 @@ impl<I: Tokens> Parser<I> {
     /// `tsParseTypeArguments`
     pub fn parse_ts_type_args(&mut self) -> PResult<TsTypeParamInstantiation> {
         trace_cur!(self, parse_ts_type_args);
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self);
-        let params = self.in_type().parse_with(|p| {
+        let params = self.in_type().flow_allow_anon_fn_type().parse_with(|p| {
             // Temporarily remove a JSX parsing context, which makes us scan different
             // tokens.
             p.ts_in_no_context(|p| {
@@ -2528,11 +2587,17 @@ impl<I: Tokens> Parser<I> {
     fn parse_ts_intersection_type_or_higher(&mut self) -> PResult<Box<TsType>> {
         trace_cur!(self, parse_ts_intersection_type_or_higher);
 
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         self.parse_ts_union_or_intersection_type(
             UnionOrIntersection::Intersection,
-            |p| p.parse_ts_type_operator_or_higher(),
+            |p| {
+                if p.input.syntax().flow() {
+                    p.parse_flow_anon_fn_type_or_higher()
+                } else {
+                    p.parse_ts_type_operator_or_higher()
+                }
+            },
             &tok!('&'),
         )
     }
@@ -2540,7 +2605,7 @@ impl<I: Tokens> Parser<I> {
     /// `tsParseUnionTypeOrHigher`
     fn parse_ts_union_type_or_higher(&mut self) -> PResult<Box<TsType>> {
         trace_cur!(self, parse_ts_union_type_or_higher);
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         self.parse_ts_union_or_intersection_type(
             UnionOrIntersection::Union,
@@ -2561,7 +2626,7 @@ impl<I: Tokens> Parser<I> {
     {
         trace_cur!(self, parse_ts_union_or_intersection_type);
 
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let start = cur_pos!(self); // include the leading operator in the start
         self.input.eat(operator);
@@ -2570,10 +2635,10 @@ impl<I: Tokens> Parser<I> {
         let ty = parse_constituent_type(self)?;
         trace_cur!(self, parse_ts_union_or_intersection_type__after_first);
 
-        if self.input.is(operator) {
+        if self.input.is(operator) && !self.is_flow_exact_object_end()? {
             let mut types = vec![ty];
 
-            while self.input.eat(operator) {
+            while !self.is_flow_exact_object_end()? && self.input.eat(operator) {
                 trace_cur!(self, parse_ts_union_or_intersection_type__constituent);
 
                 types.push(parse_constituent_type(self)?);
@@ -2603,7 +2668,7 @@ impl<I: Tokens> Parser<I> {
     where
         F: FnOnce(&mut Self) -> PResult<T>,
     {
-        debug_assert!(self.input.syntax().typescript());
+        debug_assert!(self.input.syntax().types());
 
         let cloned = self.input.token_context().clone();
 
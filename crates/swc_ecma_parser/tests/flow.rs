@@ -0,0 +1,88 @@
+use std::path::{Path, PathBuf};
+use swc_common::{errors::Handler, sync::Lrc, SourceMap};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{lexer::Lexer, FlowConfig, PResult, Parser, StringInput, Syntax};
+use testing::{run_test, StdErr};
+
+fn parse_module(cm: Lrc<SourceMap>, handler: &Handler, file_name: &Path) -> Result<Module, ()> {
+    let fm = cm
+        .load_file(file_name)
+        .unwrap_or_else(|e| panic!("failed to load {}: {}", file_name.display(), e));
+
+    let lexer = Lexer::new(
+        Syntax::Flow(FlowConfig {
+            jsx: true,
+            ..Default::default()
+        }),
+        Default::default(),
+        StringInput::from(&*fm),
+        None,
+    );
+    let mut p = Parser::new_from(lexer);
+
+    let res: PResult<Module> = p.parse_module();
+    let res = res.map_err(|e| e.into_diagnostic(handler).emit());
+
+    for e in p.take_errors() {
+        e.into_diagnostic(handler).emit();
+    }
+
+    if handler.has_errors() {
+        return Err(());
+    }
+
+    res
+}
+
+#[testing::fixture("tests/flow/**/*.js")]
+fn spec(entry: PathBuf) {
+    run_test(false, |cm, handler| {
+        let module = parse_module(cm, handler, &entry)?;
+
+        let json =
+            serde_json::to_string_pretty(&module).expect("failed to serialize module as json");
+        if StdErr::from(json)
+            .compare_to_file(format!("{}.json", entry.display()))
+            .is_err()
+        {
+            panic!()
+        }
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn reject_typescript_only_syntax() {
+    for src in [
+        "enum Foo { A }",
+        "namespace Foo {}",
+        "const a = b!;",
+        "const a = <T>b;",
+        "const a = b as T;",
+    ] {
+        let res = run_test(false, |cm, handler| {
+            let fm = cm.new_source_file(swc_common::FileName::Anon, src.into());
+            let lexer = Lexer::new(
+                Syntax::Flow(Default::default()),
+                Default::default(),
+                StringInput::from(&*fm),
+                None,
+            );
+            let mut p = Parser::new_from(lexer);
+            let res = p
+                .parse_module()
+                .map_err(|e| e.into_diagnostic(handler).emit());
+            for e in p.take_errors() {
+                e.into_diagnostic(handler).emit();
+            }
+            if handler.has_errors() {
+                return Err(());
+            }
+            res.map(drop)
+        });
+
+        assert!(res.is_err(), "flow should not accept `{}`", src);
+    }
+}
@@ -0,0 +1,4 @@
+const a = (x: number): string => String(x);
+const b = (x: number, y: string = ""): (number => string) => (z) => y;
+const c = ({ d }: Object, [e]: Array<number>) => d;
+const f = cond ? (x: number): number => x : null;
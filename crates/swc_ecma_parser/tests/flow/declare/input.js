@@ -0,0 +1,14 @@
+declare var a: number;
+declare function b(x: string): void;
+declare class C<T> {
+  m(): T;
+  p: string;
+}
+declare type D = number;
+declare module "e" {
+  declare function f(): void;
+}
+interface G {
+  +a: string;
+  b(): void;
+}
@@ -0,0 +1,6 @@
+type A = (string, number) => void;
+type B = (x: string, y?: number, ...rest: Array<string>) => void;
+type C = string => void;
+type D = (?string, ...Array<number>) => Promise<void>;
+type E = <T>(T) => T;
+type F = { m(string): void, (number): string };
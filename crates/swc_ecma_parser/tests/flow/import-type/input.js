@@ -0,0 +1,6 @@
+import type A from "a";
+import type { B, C } from "b";
+import typeof D from "d";
+import { type E, typeof F, G } from "e";
+export type { A, B };
+export type H = A;
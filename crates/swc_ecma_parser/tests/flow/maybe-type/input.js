@@ -0,0 +1,3 @@
+let a: ?string;
+let b: ?Array<?number>;
+let c: ?string[];
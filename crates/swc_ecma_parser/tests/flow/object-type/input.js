@@ -0,0 +1,6 @@
+type A = {| a: number, b?: string |};
+type B = {||};
+type C = { ...A, c: boolean, ... };
+type D = { +a: number, -b: string };
+type E = { [string]: number, [key: string]: number };
+type F = { a: * };
@@ -0,0 +1,5 @@
+opaque type A = string;
+opaque type B: string = string;
+export opaque type C<T> = Array<T>;
+declare opaque type D;
+declare opaque type E: string;
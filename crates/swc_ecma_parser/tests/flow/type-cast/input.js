@@ -0,0 +1,4 @@
+const a = (b: any);
+const c = ((d.e: any): string);
+const f = ([]: Array<string>);
+foo((g: number), h);
@@ -0,0 +1,8 @@
+class A<+T, -U, V: Object = {}> {
+  +a: T;
+  -b: U;
+  c: V;
+}
+function f<T: string>(x: T): T {
+  return x;
+}
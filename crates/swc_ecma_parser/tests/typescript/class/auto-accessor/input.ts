@@ -0,0 +1,6 @@
+class Foo {
+    accessor a = 1;
+    static accessor #b: number;
+    accessor
+    c;
+}
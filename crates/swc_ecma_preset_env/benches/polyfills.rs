@@ -27,7 +27,9 @@ fn run(b: &mut Bencher, src: &str, config: Config) {
             Mark::fresh(Mark::root()),
             Some(SingleThreadedComments::default()),
             config,
-        );
+            Default::default(),
+        )
+        .unwrap();
 
         b.iter(|| test::black_box(module.clone().fold_with(&mut folder)));
         Ok(())
@@ -1,6 +1,7 @@
 use super::builtin::BUILTINS;
-use crate::{version::should_enable, Versions};
+use crate::Versions;
 use indexmap::IndexSet;
+use preset_env_base::version::should_enable;
 use swc_atoms::js_word;
 use swc_common::{util::move_map::MoveMap, DUMMY_SP};
 use swc_ecma_ast::*;
@@ -56,6 +57,40 @@ impl Entry {
         true
     }
 
+    /// Returns true if `stmt` is `require("core-js")` and it's replaced.
+    fn add_required_all(&mut self, stmt: &Stmt) -> bool {
+        if let Stmt::Expr(ExprStmt { expr, .. }) = stmt {
+            if let Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                ref args,
+                ..
+            }) = &**expr
+            {
+                if let Expr::Ident(Ident {
+                    sym: js_word!("require"),
+                    ..
+                }) = &**callee
+                {
+                    return args.len() == 1
+                        && if let ExprOrSpread { spread: None, expr } = &args[0] {
+                            if let Expr::Lit(Lit::Str(s)) = &**expr {
+                                s.value == *"core-js"
+                                    || s.value == *"@swc/polyfill"
+                                    || s.value == *"@babel/polyfill"
+                            } else {
+                                false
+                            }
+                        } else {
+                            false
+                        }
+                        && self.add_all("@swc/polyfill");
+                }
+            }
+        }
+
+        false
+    }
+
     fn add_inner(&mut self, feature: &'static str, version: Versions) {
         if self.is_any_target || should_enable(self.target, version, true) {
             self.imports.insert(feature);
@@ -87,39 +122,28 @@ impl Fold for Entry {
         items.move_flat_map(|item| {
             let item: ModuleItem = item.fold_with(self);
 
-            if let ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) = &item {
-                if let Expr::Call(CallExpr {
-                    callee: Callee::Expr(callee),
-                    ref args,
-                    ..
-                }) = &**expr
-                {
-                    if let Expr::Ident(Ident {
-                        sym: js_word!("require"),
-                        ..
-                    }) = &**callee
-                    {
-                        if args.len() == 1
-                            && if let ExprOrSpread { spread: None, expr } = &args[0] {
-                                if let Expr::Lit(Lit::Str(s)) = &**expr {
-                                    s.value == *"core-js"
-                                        || s.value == *"@swc/polyfill"
-                                        || s.value == *"@babel/polyfill"
-                                } else {
-                                    false
-                                }
-                            } else {
-                                false
-                            }
-                            && self.add_all("@swc/polyfill")
-                        {
-                            return None;
-                        }
-                    }
+            if let ModuleItem::Stmt(stmt) = &item {
+                if self.add_required_all(stmt) {
+                    return None;
                 }
             }
 
             Some(item)
         })
     }
+
+    fn fold_script(&mut self, s: Script) -> Script {
+        let s: Script = s.fold_children_with(self);
+
+        Script {
+            body: s.body.move_flat_map(|stmt| {
+                if self.add_required_all(&stmt) {
+                    None
+                } else {
+                    Some(stmt)
+                }
+            }),
+            ..s
+        }
+    }
 }
@@ -1,9 +1,10 @@
 use super::compat::DATA as CORE_JS_COMPAT_DATA;
-use crate::{version::should_enable, Version, Versions};
+use crate::{Version, Versions};
 use indexmap::IndexSet;
 use once_cell::sync::Lazy;
+use preset_env_base::version::should_enable;
 use swc_atoms::js_word;
-use swc_common::{collections::AHashMap, DUMMY_SP};
+use swc_common::{collections::AHashMap, util::move_map::MoveMap, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{Fold, FoldWith};
 
@@ -70,7 +71,9 @@ impl Entry {
             ..
         } = self;
 
-        if *remove_regenerator && src == "regenerator-runtime/runtime.js" {
+        if *remove_regenerator
+            && (src == "regenerator-runtime/runtime" || src == "regenerator-runtime/runtime.js")
+        {
             return true;
         }
 
@@ -126,4 +129,39 @@ impl Fold for Entry {
             i
         }
     }
+
+    /// `require("core-js")` in scripts.
+    fn fold_script(&mut self, s: Script) -> Script {
+        let s: Script = s.fold_children_with(self);
+
+        Script {
+            body: s.body.move_flat_map(|stmt| {
+                if let Stmt::Expr(ExprStmt { expr, .. }) = &stmt {
+                    if let Expr::Call(CallExpr {
+                        callee: Callee::Expr(callee),
+                        args,
+                        ..
+                    }) = &**expr
+                    {
+                        if let Expr::Ident(Ident {
+                            sym: js_word!("require"),
+                            ..
+                        }) = &**callee
+                        {
+                            if let [ExprOrSpread { spread: None, expr }] = &**args {
+                                if let Expr::Lit(Lit::Str(src)) = &**expr {
+                                    if self.add(&src.value) {
+                                        return None;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                Some(stmt)
+            }),
+            ..s
+        }
+    }
 }
@@ -1,6 +1,7 @@
-pub(crate) use self::{entry::Entry, usage::UsageVisitor};
+pub(crate) use self::{entry::Entry, pure::Pure, usage::UsageVisitor};
 
 mod compat;
 mod data;
 mod entry;
+mod pure;
 mod usage;
@@ -0,0 +1,533 @@
+//! Polyfills builtins without touching globals, like
+//! `@babel/plugin-transform-runtime` with `corejs: 3`.
+//!
+//! Usages of builtins are replaced with default imports from `core-js-pure`.
+use super::{
+    data::{BUILTINS, INSTANCE_PROPERTIES, STATIC_PROPERTIES},
+    usage::is_required,
+};
+use crate::{util::DataMapExt, Versions};
+use indexmap::IndexMap;
+use std::{iter::once, mem::take};
+use swc_common::{collections::AHashSet, util::take::Take, Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{
+    collect_decls, ident::IdentLike, prepend_stmts, private_ident, quote_ident, undefined,
+    ExprFactory, Id,
+};
+use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
+
+/// Builtins which don't have a counterpart in `core-js-pure`.
+static NO_PURE_BUILTINS: &[&str] = &[
+    "ArrayBuffer",
+    "DataView",
+    "Float32Array",
+    "Float64Array",
+    "Int8Array",
+    "Int16Array",
+    "Int32Array",
+    "RegExp",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "Uint16Array",
+    "Uint32Array",
+    "fetch",
+];
+
+/// Constructors which can't be replaced, although their static methods can.
+static NO_PURE_CONSTRUCTORS: &[&str] = &["Date", "Number"];
+
+/// Instance methods exported from `core-js-pure/*/instance`.
+static PURE_INSTANCE_METHODS: &[&str] = &[
+    "at",
+    "bind",
+    "codePointAt",
+    "concat",
+    "copyWithin",
+    "endsWith",
+    "entries",
+    "every",
+    "fill",
+    "filter",
+    "find",
+    "findIndex",
+    "flat",
+    "flatMap",
+    "forEach",
+    "includes",
+    "indexOf",
+    "keys",
+    "lastIndexOf",
+    "map",
+    "matchAll",
+    "padEnd",
+    "padStart",
+    "reduce",
+    "reduceRight",
+    "repeat",
+    "replaceAll",
+    "reverse",
+    "slice",
+    "some",
+    "sort",
+    "splice",
+    "startsWith",
+    "trim",
+    "trimEnd",
+    "trimLeft",
+    "trimRight",
+    "trimStart",
+    "values",
+];
+
+pub(crate) struct Pure {
+    shipped_proposals: bool,
+    is_any_target: bool,
+    target: Versions,
+    /// Bindings declared in the file, which shadow builtins.
+    decls: AHashSet<Id>,
+    /// Source of `core-js-pure` module to the local name of its import.
+    imports: IndexMap<String, Ident, ahash::RandomState>,
+    /// Temporary variables used to call instance methods.
+    vars: Vec<VarDeclarator>,
+}
+
+impl Pure {
+    pub fn new(target: Versions, shipped_proposals: bool) -> Self {
+        Self {
+            shipped_proposals,
+            is_any_target: target.is_any_target(),
+            target,
+            decls: Default::default(),
+            imports: Default::default(),
+            vars: Default::default(),
+        }
+    }
+
+    fn is_global(&self, i: &Ident) -> bool {
+        !self.decls.contains(&i.to_id())
+    }
+
+    fn is_required(&self, features: &[&str]) -> bool {
+        features
+            .iter()
+            .any(|f| is_required(self.target, self.is_any_target, self.shipped_proposals, f))
+    }
+
+    /// Returns the local name for `core-js-pure/{stable,features}/{path}.js`.
+    fn import(&mut self, path: &str, name: &str) -> Ident {
+        let src = format!(
+            "core-js-pure/{}/{}.js",
+            if self.shipped_proposals {
+                "features"
+            } else {
+                "stable"
+            },
+            path
+        );
+
+        self.imports
+            .entry(src)
+            .or_insert_with(|| private_ident!(format!("_{}", name)))
+            .clone()
+    }
+
+    /// `Promise` -> `_Promise`
+    fn global(&mut self, i: &Ident) -> Option<Ident> {
+        if NO_PURE_BUILTINS.contains(&&*i.sym)
+            || NO_PURE_CONSTRUCTORS.contains(&&*i.sym)
+            || !self.is_global(i)
+        {
+            return None;
+        }
+
+        let features = BUILTINS.get_data(&i.sym)?;
+        if !self.is_required(features) {
+            return None;
+        }
+
+        let path = if i.sym.starts_with(|c: char| c.is_ascii_uppercase()) {
+            format!("{}/index", to_kebab_case(&i.sym))
+        } else {
+            to_kebab_case(&i.sym)
+        };
+
+        Some(self.import(&path, &i.sym))
+    }
+
+    /// `Array.from` -> `_Array$from`
+    fn static_prop(&mut self, obj: &Ident, prop: &Ident) -> Option<Ident> {
+        if NO_PURE_BUILTINS.contains(&&*obj.sym) || !self.is_global(obj) {
+            return None;
+        }
+
+        let features = STATIC_PROPERTIES.get_data(&obj.sym)?.get_data(&prop.sym)?;
+        if !self.is_required(features) {
+            return None;
+        }
+
+        let path = format!("{}/{}", to_kebab_case(&obj.sym), to_kebab_case(&prop.sym));
+
+        Some(self.import(&path, &format!("{}${}", obj.sym, prop.sym)))
+    }
+
+    /// `includes` -> `_includesInstanceProperty`
+    fn instance_prop(&mut self, prop: &Ident) -> Option<Ident> {
+        if !PURE_INSTANCE_METHODS.contains(&&*prop.sym) {
+            return None;
+        }
+
+        let features = INSTANCE_PROPERTIES.get_data(&prop.sym)?;
+        if !self.is_required(features) {
+            return None;
+        }
+
+        let path = format!("instance/{}", to_kebab_case(&prop.sym));
+
+        Some(self.import(&path, &format!("{}InstanceProperty", prop.sym)))
+    }
+
+    /// Returns `(_context = obj, _context)` if `obj` should be evaluated only
+    /// once.
+    fn alias(&mut self, obj: Box<Expr>) -> (Option<Box<Expr>>, Box<Expr>) {
+        match &*obj {
+            Expr::Ident(..) | Expr::This(..) => (None, obj),
+            _ => {
+                let tmp = private_ident!("_context");
+                self.vars.push(VarDeclarator {
+                    span: DUMMY_SP,
+                    name: tmp.clone().into(),
+                    init: None,
+                    definite: false,
+                });
+
+                (
+                    Some(Box::new(Expr::Assign(AssignExpr {
+                        span: DUMMY_SP,
+                        op: op!("="),
+                        left: PatOrExpr::Pat(tmp.clone().into()),
+                        right: obj,
+                    }))),
+                    Box::new(Expr::Ident(tmp)),
+                )
+            }
+        }
+    }
+
+    /// `obj?.includes` -> `obj === null || obj === void 0 ? void 0 : expr`
+    /// where `expr` is created by `make` from the alias of `obj`.
+    fn optional(
+        &mut self,
+        span: Span,
+        obj: Box<Expr>,
+        make: impl FnOnce(Box<Expr>) -> Box<Expr>,
+    ) -> Expr {
+        let (init, this) = self.alias(obj);
+        let left = init.unwrap_or_else(|| this.clone());
+
+        Expr::Cond(CondExpr {
+            span,
+            test: Box::new(Expr::Bin(BinExpr {
+                span: DUMMY_SP,
+                op: op!("||"),
+                left: Box::new(Expr::Bin(BinExpr {
+                    span: DUMMY_SP,
+                    op: op!("==="),
+                    left,
+                    right: Box::new(Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))),
+                })),
+                right: Box::new(Expr::Bin(BinExpr {
+                    span: DUMMY_SP,
+                    op: op!("==="),
+                    left: this.clone(),
+                    right: undefined(DUMMY_SP),
+                })),
+            })),
+            cons: undefined(DUMMY_SP),
+            alt: make(this),
+        })
+    }
+
+    /// Visits a member expression which is assigned to, without replacing it.
+    fn visit_mut_assign_target(&mut self, e: &mut Expr) {
+        match e {
+            Expr::Member(MemberExpr { obj, prop, .. }) => {
+                obj.visit_mut_with(self);
+                if let MemberProp::Computed(c) = prop {
+                    c.visit_mut_with(self);
+                }
+            }
+            _ => e.visit_mut_with(self),
+        }
+    }
+}
+
+impl VisitMut for Pure {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        match e {
+            Expr::Ident(i) => {
+                if let Some(local) = self.global(i) {
+                    *e = Expr::Ident(Ident::new(local.sym, i.span.with_ctxt(local.span.ctxt)));
+                }
+                return;
+            }
+
+            Expr::Member(MemberExpr {
+                span,
+                obj,
+                prop: MemberProp::Ident(prop),
+            }) => {
+                if let Expr::Ident(obj) = &**obj {
+                    if let Some(local) = self.static_prop(obj, prop) {
+                        *e = Expr::Ident(Ident::new(local.sym, span.with_ctxt(local.span.ctxt)));
+                        return;
+                    }
+                }
+
+                // `foo.includes` -> `_includesInstanceProperty(foo)`
+                if let Some(helper) = self.instance_prop(prop) {
+                    obj.visit_mut_with(self);
+                    *e = Expr::Call(CallExpr {
+                        span: *span,
+                        callee: helper.as_callee(),
+                        args: vec![obj.take().as_arg()],
+                        type_args: Default::default(),
+                    });
+                    return;
+                }
+            }
+
+            // `foo?.includes` ->
+            // `foo === null || foo === void 0 ? void 0 : _includesInstanceProperty(foo)`
+            Expr::OptChain(OptChainExpr { span, expr, .. }) => {
+                if let Expr::Member(MemberExpr {
+                    obj,
+                    prop: MemberProp::Ident(prop),
+                    ..
+                }) = &mut **expr
+                {
+                    if let Some(helper) = self.instance_prop(prop) {
+                        obj.visit_mut_with(self);
+                        *e = self.optional(*span, obj.take(), |this| {
+                            Box::new(Expr::Call(CallExpr {
+                                span: DUMMY_SP,
+                                callee: helper.as_callee(),
+                                args: vec![this.as_arg()],
+                                type_args: Default::default(),
+                            }))
+                        });
+                        return;
+                    }
+                }
+            }
+
+            Expr::Call(CallExpr {
+                span,
+                callee: Callee::Expr(callee),
+                args,
+                ..
+            }) => {
+                // `foo?.includes(a)` -> `foo === null || foo === void 0 ? void 0 :
+                // _includesInstanceProperty(foo).call(foo, a)`
+                if let Expr::OptChain(OptChainExpr { expr, .. }) = &mut **callee {
+                    if let Expr::Member(MemberExpr {
+                        obj,
+                        prop: MemberProp::Ident(prop),
+                        ..
+                    }) = &mut **expr
+                    {
+                        if let Some(helper) = self.instance_prop(prop) {
+                            obj.visit_mut_with(self);
+                            args.visit_mut_with(self);
+
+                            let span = *span;
+                            let args = args.take();
+                            *e = self.optional(span, obj.take(), |this| {
+                                instance_call(helper, this, DUMMY_SP, args)
+                            });
+                            return;
+                        }
+                    }
+                }
+
+                if let Expr::Member(MemberExpr {
+                    obj,
+                    prop: MemberProp::Ident(prop),
+                    ..
+                }) = &mut **callee
+                {
+                    let is_static = match &**obj {
+                        Expr::Ident(obj) => self.static_prop(obj, prop).is_some(),
+                        _ => false,
+                    };
+
+                    if !is_static {
+                        if let Some(helper) = self.instance_prop(prop) {
+                            obj.visit_mut_with(self);
+                            args.visit_mut_with(self);
+
+                            // `foo().includes(a)` ->
+                            // `(_context = foo(),
+                            // _includesInstanceProperty(_context).call(_context, a))`
+                            let (init, this) = self.alias(obj.take());
+                            let call = instance_call(helper, this, *span, args.take());
+
+                            *e = match init {
+                                Some(init) => Expr::Seq(SeqExpr {
+                                    span: *span,
+                                    exprs: vec![init, call],
+                                }),
+                                None => *call,
+                            };
+                            return;
+                        }
+                    }
+                }
+            }
+
+            _ => {}
+        }
+
+        e.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_module(&mut self, m: &mut Module) {
+        self.decls = collect_decls(&*m);
+
+        m.visit_mut_children_with(self);
+
+        if !self.vars.is_empty() {
+            prepend_stmts(
+                &mut m.body,
+                once(ModuleItem::Stmt(Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    declare: false,
+                    decls: self.vars.take(),
+                })))),
+            );
+        }
+
+        prepend_stmts(
+            &mut m.body,
+            take(&mut self.imports).into_iter().map(|(src, local)| {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                    span: DUMMY_SP,
+                    specifiers: vec![ImportSpecifier::Default(ImportDefaultSpecifier {
+                        span: DUMMY_SP,
+                        local,
+                    })],
+                    src: Str {
+                        span: DUMMY_SP,
+                        value: src.into(),
+                        has_escape: false,
+                        kind: Default::default(),
+                    },
+                    type_only: false,
+                    asserts: None,
+                }))
+            }),
+        );
+    }
+
+    fn visit_mut_pat_or_expr(&mut self, n: &mut PatOrExpr) {
+        match n {
+            PatOrExpr::Expr(e) => self.visit_mut_assign_target(e),
+            PatOrExpr::Pat(p) => match &mut **p {
+                Pat::Expr(e) => self.visit_mut_assign_target(e),
+                _ => p.visit_mut_with(self),
+            },
+        }
+    }
+
+    /// Injects `var _Promise = require("core-js-pure/stable/promise/index.js")`
+    fn visit_mut_script(&mut self, s: &mut Script) {
+        self.decls = collect_decls(&*s);
+
+        s.visit_mut_children_with(self);
+
+        let decls = take(&mut self.imports)
+            .into_iter()
+            .map(|(src, local)| VarDeclarator {
+                span: DUMMY_SP,
+                name: local.into(),
+                init: Some(Box::new(Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: quote_ident!("require").as_callee(),
+                    args: vec![Str {
+                        span: DUMMY_SP,
+                        value: src.into(),
+                        has_escape: false,
+                        kind: Default::default(),
+                    }
+                    .as_arg()],
+                    type_args: Default::default(),
+                }))),
+                definite: false,
+            })
+            .chain(self.vars.take())
+            .collect::<Vec<_>>();
+
+        if !decls.is_empty() {
+            prepend_stmts(
+                &mut s.body,
+                once(Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    declare: false,
+                    decls,
+                }))),
+            );
+        }
+    }
+
+    fn visit_mut_update_expr(&mut self, n: &mut UpdateExpr) {
+        self.visit_mut_assign_target(&mut n.arg);
+    }
+}
+
+/// `_includesInstanceProperty(this).call(this, ...args)`
+fn instance_call(helper: Ident, this: Box<Expr>, span: Span, args: Vec<ExprOrSpread>) -> Box<Expr> {
+    Box::new(
+        Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: helper.as_callee(),
+            args: vec![this.clone().as_arg()],
+            type_args: Default::default(),
+        })
+        .make_member(quote_ident!("call"))
+        .as_call(span, once(this.as_arg()).chain(args).collect()),
+    )
+}
+
+/// `getOwnPropertyNames` -> `get-own-property-names`
+fn to_kebab_case(s: &str) -> String {
+    let s = s.replace("NaN", "Nan");
+    let chars = s.chars().collect::<Vec<_>>();
+
+    let mut buf = String::with_capacity(s.len() + 4);
+    for (idx, &c) in chars.iter().enumerate() {
+        if c == '_' {
+            buf.push('-');
+            continue;
+        }
+
+        if c.is_ascii_uppercase() && idx != 0 {
+            let prev = chars[idx - 1];
+            let is_next_lower = chars.get(idx + 1).map_or(false, |c| c.is_ascii_lowercase());
+
+            if prev.is_ascii_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_ascii_uppercase() && is_next_lower)
+            {
+                buf.push('-');
+            }
+        }
+
+        buf.push(c.to_ascii_lowercase());
+    }
+
+    buf
+}
@@ -8,19 +8,42 @@ use crate::{
         },
     },
     util::DataMapExt,
-    version::should_enable,
     Versions,
 };
 use indexmap::IndexSet;
+use preset_env_base::version::should_enable;
 use swc_atoms::{js_word, JsWord};
-use swc_common::DUMMY_SP;
+use swc_common::{collections::AHashMap, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
 
+/// Returns true if `feature` (e.g. `es.promise`) should be polyfilled.
+pub(super) fn is_required(
+    target: Versions,
+    is_any_target: bool,
+    shipped_proposals: bool,
+    feature: &str,
+) -> bool {
+    if !shipped_proposals && feature.starts_with("esnext.") {
+        return false;
+    }
+
+    if !is_any_target {
+        if let Some(feature) = CORE_JS_COMPAT_DATA.get(feature) {
+            if !should_enable(target, *feature, true) {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
 pub(crate) struct UsageVisitor {
     shipped_proposals: bool,
     is_any_target: bool,
     target: Versions,
+    known_types: AHashMap<Id, Option<KnownType>>,
     pub required: IndexSet<&'static str, ahash::RandomState>,
 }
 
@@ -49,6 +72,7 @@ impl UsageVisitor {
             shipped_proposals,
             is_any_target: target.is_any_target(),
             target,
+            known_types: Default::default(),
             required: Default::default(),
         }
     }
@@ -62,23 +86,11 @@ impl UsageVisitor {
             ..
         } = self;
 
-        self.required.extend(features.iter().filter(|f| {
-            if !*shipped_proposals && f.starts_with("esnext.") {
-                return false;
-            }
-
-            let feature = CORE_JS_COMPAT_DATA.get(&***f);
-
-            if !*is_any_target {
-                if let Some(feature) = feature {
-                    if !should_enable(*target, *feature, true) {
-                        return false;
-                    }
-                }
-            }
-
-            true
-        }));
+        self.required.extend(
+            features
+                .iter()
+                .filter(|f| is_required(*target, *is_any_target, *shipped_proposals, f)),
+        );
     }
 
     fn add_builtin(&mut self, built_in: &str) {
@@ -88,7 +100,14 @@ impl UsageVisitor {
     }
     fn add_property_deps(&mut self, obj: &Expr, prop: &JsWord) {
         let obj = match obj {
-            Expr::Ident(i) => &i.sym,
+            Expr::Ident(i) => {
+                if let Some(&Some(ty)) = self.known_types.get(&i.to_id()) {
+                    self.add_instance_property_deps(ty, prop);
+                    return;
+                }
+
+                &i.sym
+            }
             _ => {
                 self.add_property_deps_inner(None, prop);
                 return;
@@ -116,6 +135,23 @@ impl UsageVisitor {
         }
     }
 
+    /// `p.includes` where `p` is known to be an array or a string.
+    fn add_instance_property_deps(&mut self, ty: KnownType, prop: &JsWord) {
+        let excluded: &[&str] = match ty {
+            KnownType::Array => &["es.string.", "esnext.string."],
+            KnownType::String => &["es.array.", "esnext.array."],
+        };
+
+        if let Some(features) = INSTANCE_PROPERTIES.get_data(prop) {
+            let features = features
+                .iter()
+                .copied()
+                .filter(|f| !excluded.iter().any(|prefix| f.starts_with(prefix)))
+                .collect::<Vec<_>>();
+            self.add(&features);
+        }
+    }
+
     fn visit_object_pat_props(&mut self, obj: &Expr, props: &[ObjectPatProp]) {
         let obj = match obj {
             Expr::Ident(i) => Some(&i.sym),
@@ -210,6 +246,18 @@ impl Visit for UsageVisitor {
         }
     }
 
+    fn visit_module(&mut self, m: &Module) {
+        self.known_types = KnownTypes::collect(m);
+
+        m.visit_children_with(self);
+    }
+
+    fn visit_script(&mut self, s: &Script) {
+        self.known_types = KnownTypes::collect(s);
+
+        s.visit_children_with(self);
+    }
+
     fn visit_member_expr(&mut self, e: &MemberExpr) {
         e.obj.visit_with(self);
         if let MemberProp::Computed(c) = &e.prop {
@@ -255,3 +303,74 @@ impl Visit for UsageVisitor {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KnownType {
+    Array,
+    String,
+}
+
+/// Finds variables which are always an array or a string, like `var p = [];`,
+/// so that `p.includes` does not require `es.string.includes`.
+#[derive(Default)]
+struct KnownTypes {
+    types: AHashMap<Id, Option<KnownType>>,
+}
+
+impl KnownTypes {
+    fn collect<T: VisitWith<Self>>(node: &T) -> AHashMap<Id, Option<KnownType>> {
+        let mut v = Self::default();
+        node.visit_with(&mut v);
+        v.types
+    }
+
+    /// `None` means the type of the variable is unknown.
+    fn add(&mut self, id: Id, ty: Option<KnownType>) {
+        let entry = self.types.entry(id).or_insert(ty);
+        if *entry != ty {
+            *entry = None;
+        }
+    }
+}
+
+impl Visit for KnownTypes {
+    noop_visit_type!();
+
+    fn visit_assign_expr(&mut self, e: &AssignExpr) {
+        e.visit_children_with(self);
+
+        if let PatOrExpr::Expr(left) = &e.left {
+            if let Expr::Ident(i) = &**left {
+                self.add(i.to_id(), None);
+            }
+        }
+    }
+
+    fn visit_binding_ident(&mut self, i: &BindingIdent) {
+        self.add(i.id.to_id(), None);
+    }
+
+    fn visit_update_expr(&mut self, e: &UpdateExpr) {
+        e.visit_children_with(self);
+
+        if let Expr::Ident(i) = &*e.arg {
+            self.add(i.to_id(), None);
+        }
+    }
+
+    fn visit_var_declarator(&mut self, d: &VarDeclarator) {
+        if let Pat::Ident(i) = &d.name {
+            let ty = match d.init.as_deref() {
+                Some(Expr::Array(..)) => Some(KnownType::Array),
+                Some(Expr::Lit(Lit::Str(..))) | Some(Expr::Tpl(..)) => Some(KnownType::String),
+                _ => None,
+            };
+
+            self.add(i.id.to_id(), ty);
+            d.init.visit_with(self);
+            return;
+        }
+
+        d.visit_children_with(self);
+    }
+}
@@ -2,27 +2,32 @@
 #![allow(dead_code)]
 #![recursion_limit = "256"]
 
-pub use self::{transform_data::Feature, version::Version};
-use anyhow::{Context, Error};
-use dashmap::DashMap;
-use once_cell::sync::Lazy;
+pub use self::transform_data::Feature;
+use anyhow::{bail, Context, Error};
+use preset_env_base::query::targets_to_versions;
+pub use preset_env_base::{
+    query::{Query, QueryOrVersion, Targets},
+    version::Version,
+    BrowserData, Versions,
+};
 use serde::Deserialize;
-use st_map::StaticMap;
 use std::path::PathBuf;
 use swc_atoms::{js_word, JsWord};
 use swc_common::{
     chain,
     collections::{AHashMap, AHashSet},
     comments::Comments,
+    util::take::Take,
     FromVariant, Mark, DUMMY_SP,
 };
 use swc_ecma_ast::*;
 use swc_ecma_transforms::{
     compat::{bugfixes, es2015, es2016, es2017, es2018, es2019, es2020, es2021, es2022, es3},
     pass::{noop, Optional},
+    Assumptions,
 };
-use swc_ecma_utils::prepend_stmts;
-use swc_ecma_visit::{Fold, FoldWith, VisitWith};
+use swc_ecma_utils::{prepend_stmts, quote_ident, ExprFactory};
+use swc_ecma_visit::{Fold, FoldWith, VisitMutWith, VisitWith};
 
 #[macro_use]
 mod util;
@@ -30,14 +35,21 @@ mod corejs2;
 mod corejs3;
 mod regenerator;
 mod transform_data;
-mod version;
 
-pub fn preset_env<C>(global_mark: Mark, comments: Option<C>, c: Config) -> impl Fold
+/// Returns an error if `c` is invalid. See [Config::validate].
+pub fn preset_env<C>(
+    global_mark: Mark,
+    comments: Option<C>,
+    c: Config,
+    assumptions: Assumptions,
+) -> Result<impl Fold, Error>
 where
     C: Comments,
 {
+    c.validate()?;
+
     let loose = c.loose;
-    let targets: Versions = targets_to_versions(c.targets).expect("failed to parse targets");
+    let targets: Versions = targets_to_versions(c.targets).context("failed to parse targets")?;
     let is_any_target = targets.is_any_target();
 
     let (include, included_modules) = FeatureOrModule::split(c.include);
@@ -98,7 +110,10 @@ where
     let pass = add!(
         pass,
         ClassProperties,
-        es2022::class_properties(es2022::class_properties::Config { loose })
+        es2022::class_properties(es2022::class_properties::Config {
+            set_public_class_fields: loose || assumptions.set_public_class_fields,
+            private_fields_as_properties: loose || assumptions.private_fields_as_properties,
+        })
     );
     let pass = add!(pass, PrivatePropertyInObject, es2022::private_in_object());
 
@@ -137,8 +152,8 @@ where
         pass,
         ObjectRestSpread,
         es2018::object_rest_spread(es2018::object_rest_spread::Config {
-            no_symbol: loose,
-            set_property: loose
+            no_symbol: loose || assumptions.object_rest_no_symbols,
+            set_property: loose || assumptions.set_spread_properties
         })
     );
 
@@ -154,16 +169,31 @@ where
         pass,
         TemplateLiterals,
         es2015::template_literal(es2015::template_literal::Config {
-            ignore_to_primitive: loose,
-            mutable_template: loose
+            ignore_to_primitive: loose || assumptions.ignore_to_primitive_hint,
+            mutable_template: loose || assumptions.mutable_template_object
         }),
         true
     );
-    let pass = add!(pass, Classes, es2015::classes(comments));
+    let pass = add!(
+        pass,
+        Classes,
+        es2015::classes(
+            comments,
+            es2015::classes::Config {
+                constant_super: loose || assumptions.constant_super,
+                no_class_calls: loose || assumptions.no_class_calls,
+                set_class_methods: loose || assumptions.set_class_methods,
+                super_is_callable_constructor: loose || assumptions.super_is_callable_constructor,
+            }
+        )
+    );
     let pass = add!(
         pass,
         Spread,
-        es2015::spread(es2015::spread::Config { loose }),
+        es2015::spread(es2015::spread::Config {
+            loose: loose || assumptions.iterable_is_array,
+            allow_array_like: assumptions.array_like_is_iterable,
+        }),
         true
     );
     let pass = add!(pass, FunctionName, es2015::function_name());
@@ -184,20 +214,26 @@ where
         pass,
         ForOf,
         es2015::for_of(es2015::for_of::Config {
-            assume_array: loose
+            assume_array: loose || assumptions.iterable_is_array,
+            skip_iterator_closing: assumptions.skip_for_of_iterator_closing,
         }),
         true
     );
     let pass = add!(
         pass,
         ComputedProperties,
-        es2015::computed_properties(es2015::computed_props::Config { loose }),
+        es2015::computed_properties(es2015::computed_props::Config {
+            loose: loose || assumptions.set_computed_properties,
+        }),
         true
     );
     let pass = add!(
         pass,
         Destructuring,
-        es2015::destructuring(es2015::destructuring::Config { loose }),
+        es2015::destructuring(es2015::destructuring::Config {
+            loose: loose || assumptions.iterable_is_array,
+            allow_array_like: assumptions.array_like_is_iterable,
+        }),
         true
     );
     let pass = add!(
@@ -233,7 +269,7 @@ where
         println!("Targets: {:?}", targets);
     }
 
-    chain!(
+    Ok(chain!(
         pass,
         Polyfills {
             mode: c.mode,
@@ -248,47 +284,7 @@ where
             includes: included_modules,
             excludes: excluded_modules,
         }
-    )
-}
-
-/// A map without allocation.
-#[derive(Debug, Default, Deserialize, Clone, Copy, StaticMap)]
-#[serde(deny_unknown_fields)]
-pub struct BrowserData<T: Default> {
-    #[serde(default)]
-    pub chrome: T,
-    #[serde(default)]
-    pub and_chr: T,
-    #[serde(default)]
-    pub and_ff: T,
-    #[serde(default)]
-    pub op_mob: T,
-    #[serde(default)]
-    pub ie: T,
-    #[serde(default)]
-    pub edge: T,
-    #[serde(default)]
-    pub firefox: T,
-    #[serde(default)]
-    pub safari: T,
-    #[serde(default)]
-    pub node: T,
-    #[serde(default)]
-    pub ios: T,
-    #[serde(default)]
-    pub samsung: T,
-    #[serde(default)]
-    pub opera: T,
-    #[serde(default)]
-    pub android: T,
-    #[serde(default)]
-    pub electron: T,
-    #[serde(default)]
-    pub phantom: T,
-    #[serde(default)]
-    pub opera_mobile: T,
-    #[serde(default)]
-    pub rhino: T,
+    ))
 }
 
 #[derive(Debug)]
@@ -302,31 +298,40 @@ struct Polyfills {
     excludes: AHashSet<String>,
 }
 
-impl Fold for Polyfills {
-    fn fold_module(&mut self, mut m: Module) -> Module {
-        let span = m.span;
-
+impl Polyfills {
+    /// Collects the modules to inject for `n`, in the order they should be
+    /// prepended.
+    fn collect<T>(&mut self, n: &mut T) -> Vec<JsWord>
+    where
+        T: VisitWith<corejs2::UsageVisitor>
+            + VisitWith<corejs3::UsageVisitor>
+            + VisitWith<regenerator::RegeneratorVisitor>
+            + FoldWith<corejs2::Entry>
+            + FoldWith<corejs3::Entry>
+            + VisitMutWith<corejs3::Pure>
+            + Take,
+    {
         let required = match self.mode {
             None => Default::default(),
             Some(Mode::Usage) => {
                 let mut r = match self.corejs {
                     Version { major: 2, .. } => {
                         let mut v = corejs2::UsageVisitor::new(self.targets);
-                        m.visit_with(&mut v);
+                        n.visit_with(&mut v);
 
                         v.required
                     }
                     Version { major: 3, .. } => {
                         let mut v =
                             corejs3::UsageVisitor::new(self.targets, self.shipped_proposals);
-                        m.visit_with(&mut v);
+                        n.visit_with(&mut v);
                         v.required
                     }
 
                     _ => unimplemented!("corejs version other than 2 / 3"),
                 };
 
-                if regenerator::is_required(&m) {
+                if regenerator::is_required(n) {
                     r.insert("regenerator-runtime/runtime.js");
                 }
 
@@ -335,20 +340,28 @@ impl Fold for Polyfills {
             Some(Mode::Entry) => match self.corejs {
                 Version { major: 2, .. } => {
                     let mut v = corejs2::Entry::new(self.targets, self.regenerator);
-                    m = m.fold_with(&mut v);
+                    *n = n.take().fold_with(&mut v);
                     v.imports
                 }
 
                 Version { major: 3, .. } => {
                     let mut v = corejs3::Entry::new(self.targets, self.corejs, !self.regenerator);
-                    m = m.fold_with(&mut v);
+                    *n = n.take().fold_with(&mut v);
                     v.imports
                 }
 
                 _ => unimplemented!("corejs version other than 2 / 3"),
             },
+            Some(Mode::UsagePure) => {
+                n.visit_mut_with(&mut corejs3::Pure::new(
+                    self.targets,
+                    self.shipped_proposals,
+                ));
+
+                Default::default()
+            }
         };
-        let required = required
+        let mut required = required
             .into_iter()
             .filter(|s| !self.excludes.contains(&**s))
             .map(|s| -> JsWord {
@@ -368,45 +381,37 @@ impl Fold for Polyfills {
             .collect::<Vec<_>>();
 
         if cfg!(debug_assertions) {
-            let mut v = required.into_iter().collect::<Vec<_>>();
-            v.sort();
-            prepend_stmts(
-                &mut m.body,
-                v.into_iter().map(|src| {
-                    ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
-                        span,
-                        specifiers: vec![],
-                        src: Str {
-                            span: DUMMY_SP,
-                            value: src,
-                            has_escape: false,
-                            kind: Default::default(),
-                        },
-                        type_only: false,
-                        asserts: None,
-                    }))
-                }),
-            );
-        } else {
-            prepend_stmts(
-                &mut m.body,
-                required.into_iter().map(|src| {
-                    ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
-                        span,
-                        specifiers: vec![],
-                        src: Str {
-                            span: DUMMY_SP,
-                            value: src,
-                            has_escape: false,
-                            kind: Default::default(),
-                        },
-                        type_only: false,
-                        asserts: None,
-                    }))
-                }),
-            );
+            required.sort();
         }
 
+        required
+    }
+}
+
+impl Fold for Polyfills {
+    fn fold_module(&mut self, mut m: Module) -> Module {
+        let span = m.span;
+
+        let required = self.collect(&mut m);
+
+        prepend_stmts(
+            &mut m.body,
+            required.into_iter().map(|src| {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                    span,
+                    specifiers: vec![],
+                    src: Str {
+                        span: DUMMY_SP,
+                        value: src,
+                        has_escape: false,
+                        kind: Default::default(),
+                    },
+                    type_only: false,
+                    asserts: None,
+                }))
+            }),
+        );
+
         m.body.retain(|item| {
             !matches!(
                 item,
@@ -424,8 +429,35 @@ impl Fold for Polyfills {
         m
     }
 
-    fn fold_script(&mut self, _: Script) -> Script {
-        unimplemented!("automatic polyfill for scripts")
+    /// Scripts can't use `import`, so polyfills are injected as
+    /// `require("core-js/modules/foo.js")`.
+    fn fold_script(&mut self, mut s: Script) -> Script {
+        let span = s.span;
+
+        let required = self.collect(&mut s);
+
+        prepend_stmts(
+            &mut s.body,
+            required.into_iter().map(|src| {
+                Stmt::Expr(ExprStmt {
+                    span,
+                    expr: Box::new(Expr::Call(CallExpr {
+                        span: DUMMY_SP,
+                        callee: quote_ident!("require").as_callee(),
+                        args: vec![Str {
+                            span: DUMMY_SP,
+                            value: src,
+                            has_escape: false,
+                            kind: Default::default(),
+                        }
+                        .as_arg()],
+                        type_args: None,
+                    })),
+                })
+            }),
+        );
+
+        s
     }
 }
 
@@ -435,57 +467,10 @@ pub enum Mode {
     Usage,
     #[serde(rename = "entry")]
     Entry,
-}
-
-pub type Versions = BrowserData<Option<Version>>;
-
-impl BrowserData<Option<Version>> {
-    pub(crate) fn is_any_target(&self) -> bool {
-        self.iter().all(|(_, v)| v.is_none())
-    }
-
-    pub(crate) fn parse_versions(distribs: Vec<browserslist::Distrib>) -> Result<Self, Error> {
-        fn remap(key: &str) -> &str {
-            match key {
-                "and_chr" => "chrome",
-                "and_ff" => "firefox",
-                "ie_mob" => "ie",
-                "ios_saf" => "ios",
-                "op_mob" => "opera",
-                _ => key,
-            }
-        }
-
-        let mut data: Versions = BrowserData::default();
-        for dist in distribs {
-            let browser = dist.name();
-            let browser = remap(browser);
-            let version = dist.version();
-            match &*browser {
-                "and_qq" | "and_uc" | "baidu" | "bb" | "kaios" | "op_mini" => continue,
-
-                _ => {}
-            }
-
-            let version = version
-                .split_once('-')
-                .map(|(version, _)| version)
-                .unwrap_or(version)
-                .parse()
-                .unwrap();
-
-            // lowest version
-            if data[&browser].map(|v| v > version).unwrap_or(true) {
-                for (k, v) in data.iter_mut() {
-                    if browser == k {
-                        *v = Some(version);
-                    }
-                }
-            }
-        }
-
-        Ok(data)
-    }
+    /// Replaces usages of builtins with imports from `core-js-pure`, which
+    /// don't modify globals.
+    #[serde(rename = "usage-pure")]
+    UsagePure,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -536,6 +521,22 @@ pub struct Config {
     pub bugfixes: bool,
 }
 
+impl Config {
+    /// Returns an error if options can't be used together.
+    pub fn validate(&self) -> Result<(), Error> {
+        if let (Some(Mode::UsagePure), Some(core_js)) = (self.mode, self.core_js) {
+            if core_js.major != 3 {
+                bail!(
+                    "`mode: \"usage-pure\"` requires core-js 3, but `coreJs` is {}",
+                    core_js.major
+                )
+            }
+        }
+
+        Ok(())
+    }
+}
+
 fn default_targets() -> Option<Targets> {
     Some(Targets::Query(Query::Single("".into())))
 }
@@ -572,128 +573,3 @@ impl FeatureOrModule {
         (features, modules)
     }
 }
-
-#[derive(Debug, Clone, Deserialize, FromVariant)]
-#[serde(untagged)]
-pub enum Targets {
-    Query(Query),
-    EsModules(EsModules),
-    Versions(Versions),
-    HashMap(AHashMap<String, QueryOrVersion>),
-}
-
-#[derive(Debug, Clone, Copy, Deserialize)]
-pub struct EsModules {
-    esmodules: bool,
-}
-
-#[derive(Debug, Clone, Deserialize, FromVariant)]
-#[serde(untagged)]
-pub enum QueryOrVersion {
-    Query(Query),
-    Version(Version),
-}
-
-#[derive(Debug, Clone, Deserialize, FromVariant, Eq, PartialEq, PartialOrd, Ord, Hash)]
-#[serde(untagged)]
-pub enum Query {
-    Single(String),
-    Multiple(Vec<String>),
-}
-
-type QueryResult = Result<Versions, Error>;
-
-impl Query {
-    fn exec(&self) -> QueryResult {
-        fn query<T>(s: &[T]) -> QueryResult
-        where
-            T: AsRef<str>,
-        {
-            let distribs = browserslist::resolve(
-                s,
-                browserslist::Opts::new()
-                    .mobile_to_desktop(true)
-                    .ignore_unknown_versions(true),
-            )
-            .with_context(|| {
-                format!(
-                    "failed to resolve browserslist query: {:?}",
-                    s.iter().map(|v| v.as_ref()).collect::<Vec<_>>()
-                )
-            })?;
-
-            let versions =
-                BrowserData::parse_versions(distribs).expect("failed to parse browser version");
-
-            Ok(versions)
-        }
-
-        static CACHE: Lazy<DashMap<Query, Versions, ahash::RandomState>> =
-            Lazy::new(Default::default);
-
-        if let Some(v) = CACHE.get(self) {
-            return Ok(*v);
-        }
-
-        let result = match *self {
-            Query::Single(ref s) => {
-                if s.is_empty() {
-                    query(&["defaults"])
-                } else {
-                    query(&[s])
-                }
-            }
-            Query::Multiple(ref s) => query(s),
-        }
-        .context("failed to execute query")?;
-
-        CACHE.insert(self.clone(), result);
-
-        Ok(result)
-    }
-}
-
-fn targets_to_versions(v: Option<Targets>) -> Result<Versions, Error> {
-    match v {
-        None => Ok(Default::default()),
-        Some(Targets::Versions(v)) => Ok(v),
-        Some(Targets::Query(q)) => q
-            .exec()
-            .context("failed to convert target query to version data"),
-        Some(Targets::HashMap(mut map)) => {
-            let q = map.remove("browsers").map(|q| match q {
-                QueryOrVersion::Query(q) => q.exec().expect("failed to run query"),
-                _ => unreachable!(),
-            });
-
-            let node = map.remove("node").map(|q| match q {
-                QueryOrVersion::Version(v) => v,
-                QueryOrVersion::Query(..) => unreachable!(),
-            });
-
-            if map.is_empty() {
-                if let Some(mut q) = q {
-                    q.node = node;
-                    return Ok(q);
-                }
-            }
-
-            unimplemented!("Targets: {:?}", map)
-        }
-        _ => unimplemented!("Option<Targets>: {:?}", v),
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::Query;
-
-    #[test]
-    fn test_empty() {
-        let res = Query::Single("".into()).exec().unwrap();
-        assert!(
-            !res.is_any_target(),
-            "empty query should return non-empty result"
-        );
-    }
-}
@@ -1,3 +1,4 @@
+use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
 
 pub(super) fn is_required<T: VisitWith<RegeneratorVisitor>>(node: &T) -> bool {
@@ -10,7 +11,21 @@ pub(super) struct RegeneratorVisitor {
     found: bool,
 }
 
-/// TODO
+/// Detects references to `regeneratorRuntime`.
+///
+/// Polyfills are injected after the compat passes, so generators and async
+/// functions are already lowered by the regenerator pass at this point, and
+/// the lowered code uses `regeneratorRuntime`.
 impl Visit for RegeneratorVisitor {
     noop_visit_type!();
+
+    fn visit_expr(&mut self, e: &Expr) {
+        e.visit_children_with(self);
+
+        if let Expr::Ident(i) = e {
+            if &*i.sym == "regeneratorRuntime" {
+                self.found = true;
+            }
+        }
+    }
 }
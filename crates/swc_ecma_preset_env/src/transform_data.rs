@@ -1,5 +1,6 @@
-use crate::{version::should_enable, BrowserData, Version, Versions};
+use crate::{BrowserData, Version, Versions};
 use once_cell::sync::Lazy;
+use preset_env_base::version::should_enable;
 use string_enum::StringEnum;
 use swc_common::collections::AHashMap;
 
@@ -1,13 +0,0 @@
-import "regenerator-runtime/runtime";
-
-function a() {
-  return regeneratorRuntime.async(function a$(_context) {
-    while (1) {
-      switch (_context.prev = _context.next) {
-        case 0:
-        case "end":
-          return _context.stop();
-      }
-    }
-  });
-}
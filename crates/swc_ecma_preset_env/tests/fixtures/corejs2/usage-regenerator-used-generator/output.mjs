@@ -0,0 +1,12 @@
+import "regenerator-runtime/runtime.js";
+import regeneratorRuntime from "regenerator-runtime";
+var _marked = regeneratorRuntime.mark(a);
+function a() {
+    return regeneratorRuntime.wrap(function a$(_ctx) {
+        while(1)switch(_ctx.prev = _ctx.next){
+            case 0:
+            case "end":
+                return _ctx.stop();
+        }
+    }, _marked);
+}
@@ -1,3 +0,0 @@
-import "core-js/modules/es.promise";
-
-async function a() {}
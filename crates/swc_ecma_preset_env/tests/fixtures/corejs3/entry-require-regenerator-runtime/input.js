@@ -0,0 +1,3 @@
+require('regenerator-runtime/runtime');
+
+1 ** 2;
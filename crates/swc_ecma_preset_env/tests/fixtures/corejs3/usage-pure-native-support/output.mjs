@@ -0,0 +1,6 @@
+const p = new Promise((resolve)=>resolve(Array.from(new Set([
+        1,
+        2
+    ])))
+);
+arr.includes(1);
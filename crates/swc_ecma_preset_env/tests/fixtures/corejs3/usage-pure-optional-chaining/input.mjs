@@ -0,0 +1,3 @@
+str?.at(0);
+const c = str?.at;
+getString()?.at(-1);
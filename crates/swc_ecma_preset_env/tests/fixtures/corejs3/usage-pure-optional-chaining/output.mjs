@@ -0,0 +1,5 @@
+import _atInstanceProperty from "core-js-pure/features/instance/at.js";
+var _context;
+str === null || str === void 0 ? void 0 : _atInstanceProperty(str).call(str, 0);
+const c = str === null || str === void 0 ? void 0 : _atInstanceProperty(str);
+(_context = getString()) === null || _context === void 0 ? void 0 : _atInstanceProperty(_context).call(_context, -1);
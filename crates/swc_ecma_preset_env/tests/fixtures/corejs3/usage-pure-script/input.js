@@ -0,0 +1,3 @@
+var p = new Promise(function (resolve) {
+  resolve([1, 2].includes(1));
+});
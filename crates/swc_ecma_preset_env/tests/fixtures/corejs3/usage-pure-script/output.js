@@ -0,0 +1,7 @@
+var _Promise = require("core-js-pure/stable/promise/index.js"), _includesInstanceProperty = require("core-js-pure/stable/instance/includes.js"), _context;
+var p = new _Promise(function(resolve) {
+    resolve((_context = [
+        1,
+        2
+    ], _includesInstanceProperty(_context).call(_context, 1)));
+});
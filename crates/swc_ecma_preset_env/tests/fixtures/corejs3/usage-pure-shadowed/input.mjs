@@ -0,0 +1,9 @@
+class Promise {}
+
+function foo(Map) {
+  return new Map();
+}
+
+new Promise();
+new WeakMap();
+Array.from = null;
@@ -0,0 +1,16 @@
+function _classCallCheck(instance, Constructor) {
+    if (!(instance instanceof Constructor)) {
+        throw new TypeError("Cannot call a class as a function");
+    }
+}
+import _WeakMap from "core-js-pure/stable/weak-map/index.js";
+var Promise = function Promise() {
+    "use strict";
+    _classCallCheck(this, Promise);
+};
+function foo(Map) {
+    return new Map();
+}
+new Promise();
+new _WeakMap();
+Array.from = null;
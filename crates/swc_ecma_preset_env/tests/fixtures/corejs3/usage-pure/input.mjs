@@ -0,0 +1,7 @@
+const p = new Promise(resolve => resolve(Array.from(new Set([1, 2]))));
+Promise.resolve(Object.values({ a: 1 }));
+
+arr.includes(1);
+getArray().includes(2);
+const fn = str.padStart;
+Math.max(1, 2);
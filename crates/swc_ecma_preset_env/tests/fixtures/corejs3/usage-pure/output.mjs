@@ -0,0 +1,20 @@
+import _Promise from "core-js-pure/stable/promise/index.js";
+import _Array$from from "core-js-pure/stable/array/from.js";
+import _Set from "core-js-pure/stable/set/index.js";
+import _Object$values from "core-js-pure/stable/object/values.js";
+import _includesInstanceProperty from "core-js-pure/stable/instance/includes.js";
+import _padStartInstanceProperty from "core-js-pure/stable/instance/pad-start.js";
+var _context;
+var p = new _Promise(function(resolve) {
+    return resolve(_Array$from(new _Set([
+        1,
+        2
+    ])));
+});
+_Promise.resolve(_Object$values({
+    a: 1
+}));
+_includesInstanceProperty(arr).call(arr, 1);
+_context = getArray(), _includesInstanceProperty(_context).call(_context, 2);
+var fn = _padStartInstanceProperty(str);
+Math.max(1, 2);
@@ -0,0 +1,3 @@
+function* a() {
+  yield 1;
+}
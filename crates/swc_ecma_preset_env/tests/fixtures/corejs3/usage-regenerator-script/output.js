@@ -0,0 +1,15 @@
+require("regenerator-runtime/runtime.js");
+var regeneratorRuntime = require("regenerator-runtime");
+var _marked = regeneratorRuntime.mark(a);
+function a() {
+    return regeneratorRuntime.wrap(function a$(_ctx) {
+        while(1)switch(_ctx.prev = _ctx.next){
+            case 0:
+                _ctx.next = 2;
+                return 1;
+            case 2:
+            case "end":
+                return _ctx.stop();
+        }
+    }, _marked);
+}
@@ -0,0 +1,2 @@
+import "core-js/modules/es.promise.js";
+async function a() {}
@@ -11,15 +11,19 @@ use std::{
     io::Read,
     path::{Path, PathBuf},
 };
+use swc_atoms::JsWord;
 use swc_common::{
     chain, collections::AHashMap, comments::SingleThreadedComments, input::StringInput,
-    FromVariant, Mark,
+    FromVariant, Mark, GLOBALS,
 };
 use swc_ecma_ast::*;
 use swc_ecma_codegen::Emitter;
 use swc_ecma_parser::{EsConfig, Parser, Syntax};
 use swc_ecma_preset_env::{preset_env, Config, FeatureOrModule, Mode, Targets, Version};
-use swc_ecma_transforms::fixer;
+use swc_ecma_transforms::{
+    fixer,
+    helpers::{inject_helpers, Helpers, HELPERS},
+};
 use swc_ecma_utils::drop_span;
 use swc_ecma_visit::{as_folder, FoldWith, VisitMut};
 use testing::{NormalizedOutput, Tester};
@@ -116,7 +120,7 @@ enum Error {
     Msg(String),
 }
 
-fn exec(c: PresetConfig, dir: PathBuf) -> Result<(), Error> {
+fn exec(c: PresetConfig, dir: PathBuf, is_script: bool) -> Result<(), Error> {
     println!("Config: {:?}", c);
 
     Tester::new()
@@ -131,6 +135,7 @@ fn exec(c: PresetConfig, dir: PathBuf) -> Result<(), Error> {
                             UseBuiltIns::Bool(false) => None,
                             UseBuiltIns::Str(ref s) if s == "usage" => Some(Mode::Usage),
                             UseBuiltIns::Str(ref s) if s == "entry" => Some(Mode::Entry),
+                            UseBuiltIns::Str(ref s) if s == "usage-pure" => Some(Mode::UsagePure),
                             v => unreachable!("invalid: {:?}", v),
                         },
                         skip: vec![],
@@ -150,15 +155,20 @@ fn exec(c: PresetConfig, dir: PathBuf) -> Result<(), Error> {
                         targets: c.targets,
                         path: std::env::current_dir().unwrap(),
                     },
-                ),
+                    Default::default(),
+                )
+                .expect("invalid config"),
                 fixer(None)
             );
 
-            let print = |m: &Module| {
+            let print = |m: &Program| {
                 let mut buf = vec![];
                 {
                     let mut emitter = Emitter {
-                        cfg: swc_ecma_codegen::Config { minify: false },
+                        cfg: swc_ecma_codegen::Config {
+                            minify: false,
+                            ..Default::default()
+                        },
                         comments: None,
                         cm: cm.clone(),
                         wr: Box::new(swc_ecma_codegen::text_writer::JsWriter::new(
@@ -169,14 +179,22 @@ fn exec(c: PresetConfig, dir: PathBuf) -> Result<(), Error> {
                         )),
                     };
 
-                    emitter.emit_module(m).expect("failed to emit module");
+                    match m {
+                        Program::Module(m) => emitter.emit_module(m),
+                        Program::Script(s) => emitter.emit_script(s),
+                    }
+                    .expect("failed to emit program");
                 }
                 String::from_utf8(buf).expect("invalid utf8 character detected")
             };
 
-            let fm = cm
-                .load_file(&dir.join("input.mjs"))
-                .expect("failed to load file");
+            let (input, output) = if is_script {
+                ("input.js", "output.js")
+            } else {
+                ("input.mjs", "output.mjs")
+            };
+
+            let fm = cm.load_file(&dir.join(input)).expect("failed to load file");
             let mut p = Parser::new(
                 Syntax::Es(EsConfig {
                     static_blocks: true,
@@ -186,15 +204,22 @@ fn exec(c: PresetConfig, dir: PathBuf) -> Result<(), Error> {
                 None,
             );
 
-            let module = p
-                .parse_module()
-                .map_err(|e| e.into_diagnostic(&handler).emit())?;
+            let program = if is_script {
+                p.parse_script().map(Program::Script)
+            } else {
+                p.parse_module().map(Program::Module)
+            }
+            .map_err(|e| e.into_diagnostic(&handler).emit())?;
 
             for e in p.take_errors() {
                 e.into_diagnostic(&handler).emit()
             }
 
-            let actual = module.fold_with(&mut pass);
+            let actual = HELPERS.set(&Helpers::new(false), || {
+                program
+                    .fold_with(&mut pass)
+                    .fold_with(&mut inject_helpers())
+            });
 
             // debug mode?
             if dir.join("stdout.txt").exists() {
@@ -208,17 +233,16 @@ fn exec(c: PresetConfig, dir: PathBuf) -> Result<(), Error> {
                 return Ok(());
             };
 
-            let actual_src = print(&actual);
             if let Ok(..) = env::var("UPDATE") {
-                NormalizedOutput::from(actual_src.clone())
-                    .compare_to_file(dir.join("output.mjs"))
+                NormalizedOutput::from(print(&actual))
+                    .compare_to_file(dir.join(output))
                     .unwrap();
             }
 
             // It's normal transform test.
             let expected = {
                 let fm = cm
-                    .load_file(&dir.join("output.mjs"))
+                    .load_file(&dir.join(output))
                     .expect("failed to load output file");
 
                 let mut p = Parser::new(
@@ -229,46 +253,28 @@ fn exec(c: PresetConfig, dir: PathBuf) -> Result<(), Error> {
                     None,
                 );
 
-                let mut m = p
-                    .parse_module()
-                    .map_err(|e| e.into_diagnostic(&handler).emit())?;
+                let m = if is_script {
+                    p.parse_script().map(Program::Script)
+                } else {
+                    p.parse_module().map(Program::Module)
+                }
+                .map_err(|e| e.into_diagnostic(&handler).emit())?;
 
                 for e in p.take_errors() {
                     e.into_diagnostic(&handler).emit()
                 }
 
-                m.body.sort_by(|a, b| match *a {
-                    ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
-                        ref specifiers,
-                        ref src,
-                        ..
-                    })) if specifiers.is_empty() && src.value.starts_with("core-js/modules") => {
-                        match *b {
-                            ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
-                                specifiers: ref rs,
-                                src: ref rsrc,
-                                ..
-                            })) if rs.is_empty() && rsrc.value.starts_with("core-js/modules") => {
-                                src.value.cmp(&rsrc.value)
-                            }
-
-                            _ => Ordering::Equal,
-                        }
-                    }
-                    _ => Ordering::Equal,
-                });
-
                 m
             };
 
-            let expected_src = print(&expected);
-
-            if drop_span(actual.fold_with(&mut as_folder(Normalizer)))
-                == drop_span(expected.fold_with(&mut as_folder(Normalizer)))
-            {
+            let actual = normalize(actual);
+            let expected = normalize(expected);
+            if actual == expected {
                 return Ok(());
             }
 
+            let actual_src = print(&actual);
+            let expected_src = print(&expected);
             if actual_src != expected_src {
                 panic!(
                     r#"assertion failed: `(left == right)`
@@ -294,6 +300,15 @@ fn read(p: &Path) -> String {
 
 #[testing::fixture("tests/fixtures/**/input.mjs")]
 fn fixture(input: PathBuf) {
+    run_fixture(input, false)
+}
+
+#[testing::fixture("tests/fixtures/**/input.js")]
+fn fixture_script(input: PathBuf) {
+    run_fixture(input, true)
+}
+
+fn run_fixture(input: PathBuf, is_script: bool) {
     let entry_dir = input.parent().unwrap().to_path_buf();
     println!("File: {}", entry_dir.display());
 
@@ -304,7 +319,91 @@ fn fixture(input: PathBuf) {
     assert_eq!(cfg.presets.len(), 1);
     let cfg = cfg.presets.into_iter().map(|v| v.1).next().unwrap();
 
-    exec(cfg, entry_dir).expect("failed to run test")
+    exec(cfg, entry_dir, is_script).expect("failed to run test")
+}
+
+#[test]
+fn usage_pure_requires_core_js_3() {
+    GLOBALS.set(&Default::default(), || {
+        let res = preset_env(
+            Mark::fresh(Mark::root()),
+            None::<SingleThreadedComments>,
+            Config {
+                mode: Some(Mode::UsagePure),
+                core_js: Some(Version {
+                    major: 2,
+                    minor: 0,
+                    patch: 0,
+                }),
+                ..Default::default()
+            },
+            Default::default(),
+        );
+
+        assert!(res.is_err());
+    });
+}
+
+/// Makes outputs of babel comparable with ours.
+///
+/// Babel omits the `.js` suffix from polyfills and does not sort them.
+fn normalize(p: Program) -> Program {
+    let mut p = drop_span(p.fold_with(&mut as_folder(Normalizer)));
+
+    match &mut p {
+        Program::Module(m) => m
+            .body
+            .sort_by(|a, b| cmp_polyfills(module_item_polyfill(a), module_item_polyfill(b))),
+        Program::Script(s) => s
+            .body
+            .sort_by(|a, b| cmp_polyfills(stmt_polyfill(a), stmt_polyfill(b))),
+    }
+
+    p
+}
+
+fn cmp_polyfills(a: Option<&JsWord>, b: Option<&JsWord>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.cmp(b),
+        _ => Ordering::Equal,
+    }
+}
+
+/// `import "core-js/modules/es.promise";`
+fn module_item_polyfill(item: &ModuleItem) -> Option<&JsWord> {
+    match item {
+        ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+            specifiers, src, ..
+        })) if specifiers.is_empty() && src.value.starts_with("core-js/modules") => {
+            Some(&src.value)
+        }
+        ModuleItem::Stmt(stmt) => stmt_polyfill(stmt),
+        _ => None,
+    }
+}
+
+/// `require("core-js/modules/es.promise");`
+fn stmt_polyfill(stmt: &Stmt) -> Option<&JsWord> {
+    if let Stmt::Expr(ExprStmt { expr, .. }) = stmt {
+        if let Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        }) = &**expr
+        {
+            if let (Expr::Ident(callee), [ExprOrSpread { spread: None, expr }]) =
+                (&**callee, &**args)
+            {
+                if let Expr::Lit(Lit::Str(src)) = &**expr {
+                    if &*callee.sym == "require" && src.value.starts_with("core-js/modules") {
+                        return Some(&src.value);
+                    }
+                }
+            }
+        }
+    }
+
+    None
 }
 
 struct Normalizer;
@@ -312,5 +411,12 @@ struct Normalizer;
 impl VisitMut for Normalizer {
     fn visit_mut_str(&mut self, n: &mut Str) {
         n.kind = Default::default();
+
+        if n.value.starts_with("core-js/modules/") || &*n.value == "regenerator-runtime/runtime.js"
+        {
+            if let Some(value) = n.value.strip_suffix(".js") {
+                n.value = value.into();
+            }
+        }
     }
 }
@@ -13,6 +13,9 @@ pub use swc_ecma_transforms_base::{
 #[cfg(feature = "swc_ecma_transforms_compat")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compat")))]
 pub use swc_ecma_transforms_compat as compat;
+#[cfg(feature = "swc_ecma_transforms_flow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "flow")))]
+pub use swc_ecma_transforms_flow as flow;
 #[cfg(feature = "swc_ecma_transforms_module")]
 #[cfg_attr(docsrs, doc(cfg(feature = "module")))]
 pub use swc_ecma_transforms_module as modules;
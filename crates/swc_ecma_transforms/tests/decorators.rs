@@ -17,7 +17,10 @@ use swc_ecma_transforms_compat::{
 };
 use swc_ecma_transforms_module::common_js;
 use swc_ecma_transforms_optimization::simplify::inlining;
-use swc_ecma_transforms_proposal::{decorators, decorators::Config};
+use swc_ecma_transforms_proposal::{
+    decorators,
+    decorators::{Config, DecoratorVersion},
+};
 use swc_ecma_transforms_testing::{test, test_exec};
 use swc_ecma_transforms_typescript::strip;
 use swc_ecma_visit::Fold;
@@ -40,7 +43,7 @@ fn syntax(decorators_before_export: bool) -> Syntax {
 fn tr() -> impl Fold {
     chain!(
         decorators(Default::default()),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     )
 }
 
@@ -2023,7 +2026,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_class_constructors_return_new_constructor_exec,
     r#"
@@ -2181,7 +2184,7 @@ export { _class1 as default }
 //  "presets": ["env"],
 //  "plugins": [
 //    ["proposal-decorators", { "legacy": true }],
-//    [class_properties(class_properties::Config { loose: false }), { "loose":
+//    [class_properties(Default::default()), { "loose":
 // true }]  ]
 //}
 //"#),
@@ -2208,7 +2211,7 @@ export { _class1 as default }
 //  "presets": ["env"],
 //  "plugins": [
 //    ["proposal-decorators", { "legacy": true }],
-//    [class_properties(class_properties::Config { loose: false }), { "loose":
+//    [class_properties(Default::default()), { "loose":
 // true }]  ]
 //}
 //"#),
@@ -2343,7 +2346,7 @@ export { _class1 as default }
 //  "presets": ["env"],
 //  "plugins": [
 //    ["proposal-decorators", { "legacy": true }],
-//    [class_properties(class_properties::Config { loose: false }), { "loose":
+//    [class_properties(Default::default()), { "loose":
 // true }]  ]
 //}
 //"#),
@@ -2480,7 +2483,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_methods_numeric_props_exec,
     r#"
@@ -2506,7 +2509,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_class_static_properties_mutate_descriptor_exec,
     r#"
@@ -2621,7 +2624,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_class_static_methods_string_props_exec,
     r#"
@@ -2647,7 +2650,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_properties_string_literal_properties_exec,
     r#"
@@ -2692,7 +2695,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_methods_mutate_descriptor_exec,
     r#"
@@ -2825,7 +2828,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_object_properties_numeric_props_exec,
     r#"
@@ -2882,7 +2885,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_properties_return_descriptor_exec,
     r#"
@@ -2999,7 +3002,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_object_properties_string_props_exec,
     r#"
@@ -3027,7 +3030,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_object_properties_return_descriptor_exec,
     r#"
@@ -3140,7 +3143,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_methods_string_props_exec,
     r#"
@@ -3166,7 +3169,7 @@ test!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_regression_8041,
     r#"
@@ -3196,7 +3199,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_methods_return_descriptor_exec,
     r#"
@@ -3331,7 +3334,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_object_ordering_reverse_order_exec,
     r#"
@@ -3375,7 +3378,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_object_methods_numeric_props_exec,
     r#"
@@ -3402,7 +3405,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_class_static_properties_return_descriptor_exec,
     r#"
@@ -3522,7 +3525,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_class_export_default_exec,
     r#"
@@ -3552,7 +3555,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_class_ordering_reverse_order_exec,
     r#"
@@ -3599,7 +3602,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_object_methods_mutate_descriptor_exec,
     r#"
@@ -3728,7 +3731,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_class_static_methods_return_descriptor_exec,
     r#"
@@ -3860,7 +3863,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_object_methods_return_descriptor_exec,
     r#"
@@ -3991,7 +3994,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_object_methods_string_props_exec,
     r#"
@@ -4019,7 +4022,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_class_prototype_properties_child_classes_properties_exec,
     r#"
@@ -4062,7 +4065,7 @@ test_exec!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     legacy_class_static_methods_mutate_descriptor_exec,
     r#"
@@ -4334,7 +4337,7 @@ test!(
             legacy: true,
             ..Default::default()
         })
-        // classes(Some(t.comments.clone())),
+        // classes(Some(t.comments.clone()), Default::default()),
     },
     issue_823_2,
     "import {Debounce} from 'lodash-decorators';
@@ -4375,7 +4378,7 @@ test!(
             legacy: true,
             ..Default::default()
         }),
-        classes(Some(t.comments.clone())),
+        classes(Some(t.comments.clone()), Default::default()),
     ),
     issue_823_3,
     "import {Debounce} from 'lodash-decorators';
@@ -4656,6 +4659,7 @@ test!(
     |_| decorators(Config {
         legacy: true,
         emit_metadata: true,
+        ..Default::default()
     }),
     legacy_metadata_generics_base,
     "@Decorate
@@ -4701,6 +4705,7 @@ test!(
     |_| decorators(Config {
         legacy: true,
         emit_metadata: true,
+        ..Default::default()
     }),
     legacy_metadata_generics_1,
     "@Decorate
@@ -4727,6 +4732,7 @@ test!(
     |_| decorators(Config {
         legacy: true,
         emit_metadata: true,
+        ..Default::default()
     }),
     legacy_metadata_nest_injection,
     "import { AppService } from './app.service';
@@ -4824,6 +4830,7 @@ test!(
     |_| decorators(Config {
         legacy: true,
         emit_metadata: true,
+        ..Default::default()
     }),
     legacy_metadata_parameter_decorated_types,
     "class Injected {}
@@ -4936,6 +4943,7 @@ test!(
     |_| decorators(Config {
         legacy: true,
         emit_metadata: true,
+        ..Default::default()
     }),
     legacy_metadata_type_serialization,
     "import { Service } from './service';
@@ -5088,6 +5096,7 @@ test!(
     |_| decorators(Config {
         legacy: true,
         emit_metadata: true,
+        ..Default::default()
     }),
     issue_1160_1,
     "
@@ -5144,8 +5153,8 @@ test!(
             legacy: true,
             ..Default::default()
         }),
-        class_properties(class_properties::Config { loose: false }),
-        classes(Some(t.comments.clone()),)
+        class_properties(Default::default()),
+        classes(Some(t.comments.clone()), Default::default())
     ),
     decorators_legacy_interop_local_define_property,
     r#"
@@ -5301,7 +5310,7 @@ test!(
             legacy: true,
             ..Default::default()
         }),
-        classes(Some(t.comments.clone())),
+        classes(Some(t.comments.clone()), Default::default()),
         function_name(),
     ),
     function_name_function_assignment,
@@ -5362,7 +5371,7 @@ test!(
             legacy: true,
             ..Default::default()
         }),
-        classes(Some(t.comments.clone())),
+        classes(Some(t.comments.clone()), Default::default()),
         function_name(),
     ),
     function_name_shorthand_property,
@@ -5423,7 +5432,7 @@ test!(
     |t| chain!(
         resolver(),
         function_name(),
-        classes(Some(t.comments.clone())),
+        classes(Some(t.comments.clone()), Default::default()),
         decorators(decorators::Config {
             legacy: true,
             ..Default::default()
@@ -5484,7 +5493,7 @@ test!(
     |t| chain!(
         resolver(),
         function_name(),
-        classes(Some(t.comments.clone())),
+        classes(Some(t.comments.clone()), Default::default()),
         decorators(decorators::Config {
             legacy: true,
             ..Default::default()
@@ -5552,7 +5561,7 @@ test!(
             ..Default::default()
         }),
         function_name(),
-        classes(Some(t.comments.clone())),
+        classes(Some(t.comments.clone()), Default::default()),
     ),
     function_name_global,
     r#"
@@ -5592,7 +5601,7 @@ test!(
             legacy: true,
             ..Default::default()
         }),
-        classes(Some(t.comments.clone())),
+        classes(Some(t.comments.clone()), Default::default()),
         function_name(),
         common_js(Mark::fresh(Mark::root()), Default::default(), None),
     ),
@@ -5642,7 +5651,7 @@ test!(
     |t| chain!(
         resolver(),
         function_name(),
-        classes(Some(t.comments.clone())),
+        classes(Some(t.comments.clone()), Default::default()),
         decorators(decorators::Config {
             legacy: true,
             ..Default::default()
@@ -5697,6 +5706,7 @@ test!(
     |_| decorators(decorators::Config {
         legacy: true,
         emit_metadata: true,
+        ..Default::default()
     }),
     issue_1421_1,
     "
@@ -5729,6 +5739,7 @@ test!(
     |_| decorators(decorators::Config {
         legacy: true,
         emit_metadata: true,
+        ..Default::default()
     }),
     issue_1456_1,
     "
@@ -5822,6 +5833,7 @@ test!(
     |_| decorators(decorators::Config {
         legacy: true,
         emit_metadata: true,
+        ..Default::default()
     }),
     issue_1278_1,
     "
@@ -5989,6 +6001,7 @@ test_exec!(
     |_| simple_strip(Config {
         legacy: true,
         emit_metadata: true,
+        ..Default::default()
     }),
     issue_1362_1,
     "
@@ -6006,6 +6019,7 @@ test!(
     |_| decorators(Config {
         legacy: true,
         emit_metadata: true,
+        ..Default::default()
     }),
     issue_2461_decorator_design_type_for_union_types,
     "const ThingDecorator: PropertyDecorator = () => {}
@@ -6033,6 +6047,213 @@ test!(
   }), _class);"
 );
 
+fn syntax_2022_03() -> Syntax {
+    Syntax::Es(EsConfig {
+        decorators: true,
+        decorators_before_export: true,
+        static_blocks: true,
+        auto_accessors: true,
+        ..Default::default()
+    })
+}
+
+fn tr_2022_03() -> impl Fold {
+    decorators(Config {
+        version: DecoratorVersion::V202203,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax_2022_03(),
+    |_| tr_2022_03(),
+    decorators_2022_03_field_and_method,
+    r#"
+class Foo {
+    @dec a = 1;
+    @dec b() {}
+}
+"#,
+    r#"
+var _init_a, _initProto;
+class Foo {
+    static {
+        [_init_a, _initProto] = _applyDecs2203(this, [[dec, 2, "b"], [dec, 0, "a"]], []);
+    }
+    a = (_initProto(this), _init_a(this, 1));
+    b() {}
+}
+"#
+);
+
+test!(
+    syntax_2022_03(),
+    |_| tr_2022_03(),
+    decorators_2022_03_static_method_and_private_field,
+    r#"
+class Foo {
+    @dec static m() {}
+    @dec #x;
+}
+"#,
+    r#"
+var _init_x, _initStatic;
+class Foo {
+    static {
+        [_init_x, _initStatic] = _applyDecs2203(this, [
+            [dec, 7, "m"],
+            [dec, 0, "x", function () { return this.#x; }, function (v) { this.#x = v; }]
+        ], []);
+        _initStatic(this);
+    }
+    static m() {}
+    #x = _init_x(this);
+}
+"#
+);
+
+test!(
+    syntax_2022_03(),
+    |_| tr_2022_03(),
+    decorators_2022_03_accessor_and_private_method,
+    r#"
+class Foo {
+    @dec accessor a = 1;
+    @dec #m() {
+        return 1;
+    }
+}
+"#,
+    r#"
+var _init_a, _call_m, _initProto;
+class Foo {
+    static {
+        [_init_a, _call_m, _initProto] = _applyDecs2203(this, [
+            [dec, 1, "a"],
+            [dec, 2, "m", function () { return 1; }]
+        ], []);
+    }
+    #m = (_initProto(this), _call_m);
+    #A = _init_a(this, 1);
+    get a() {
+        return this.#A;
+    }
+    set a(v) {
+        this.#A = v;
+    }
+}
+"#
+);
+
+test!(
+    syntax_2022_03(),
+    |_| tr_2022_03(),
+    decorators_2022_03_undecorated_accessors,
+    r#"
+class Foo {
+    accessor a = 1;
+    static accessor #b;
+}
+"#,
+    r#"
+class Foo {
+    #A = 1;
+    get a() {
+        return this.#A;
+    }
+    set a(v) {
+        this.#A = v;
+    }
+    static #B;
+    static get #b() {
+        return this.#B;
+    }
+    static set #b(v) {
+        this.#B = v;
+    }
+}
+"#
+);
+
+test!(
+    syntax_2022_03(),
+    |_| tr_2022_03(),
+    decorators_2022_03_exported_class,
+    r#"
+@dec()
+export class Foo {
+    static foo = Foo;
+}
+"#,
+    r#"
+var _dec, _Foo, _initClass;
+_dec = dec();
+class Foo {
+    static {
+        [_Foo, _initClass] = _applyDecs2203(this, [], [_dec]);
+    }
+    static foo = _Foo;
+    static {
+        _initClass();
+    }
+}
+export { _Foo as Foo };
+"#
+);
+
+test_exec!(
+    syntax_2022_03(),
+    |_| tr_2022_03(),
+    decorators_2022_03_exec,
+    r#"
+const log = [];
+
+function dec(value, ctx) {
+    log.push(ctx.kind + ":" + ctx.name);
+
+    if (ctx.kind === "field") {
+        return (v) => v * 2;
+    }
+    if (ctx.kind === "accessor") {
+        return { init: (v) => v * 10 };
+    }
+    if (ctx.kind === "method") {
+        return function () {
+            return value.call(this) + 1;
+        };
+    }
+}
+
+function tag(cls, ctx) {
+    log.push(ctx.kind + ":" + ctx.name);
+
+    return class extends cls {
+        static tagged = true;
+    };
+}
+
+@tag
+class Foo {
+    @dec a = 1;
+    @dec accessor b = 2;
+    @dec #m() {
+        return this.b;
+    }
+
+    callM() {
+        return this.#m();
+    }
+}
+
+const foo = new Foo();
+expect(foo.a).toBe(2);
+expect(foo.b).toBe(20);
+expect(foo.callM()).toBe(21);
+expect(Foo.tagged).toBe(true);
+expect(log).toEqual(["accessor:b", "method:#m", "field:a", "class:Foo"]);
+"#
+);
+
 #[testing::fixture("tests/fixture/decorator/**/exec.ts")]
 fn fixture(input: PathBuf) {
     let code = fs::read_to_string(&input).expect("failed to read file");
@@ -6052,6 +6273,7 @@ fn fixture(input: PathBuf) {
                 decorators(Config {
                     legacy: true,
                     emit_metadata: true,
+                    ..Default::default()
                 })
             )
         },
@@ -164,6 +164,7 @@ macro_rules! define_helpers {
 
 define_helpers!(Helpers {
     apply_decorated_descriptor: (),
+    apply_decs_2203: (),
     array_like_to_array: (),
     array_with_holes: (),
     array_without_holes: (array_like_to_array),
@@ -178,7 +179,7 @@ define_helpers!(Helpers {
     class_name_tdz_error: (),
     class_private_field_get: (),
     class_private_field_loose_base: (),
-    // class_private_field_loose_key: (),
+    class_private_field_loose_key: (),
     class_private_field_set: (),
     class_private_method_get: (),
     class_private_method_set: (),
@@ -205,6 +206,7 @@ define_helpers!(Helpers {
     iterable_to_array_limit: (),
     iterable_to_array_limit_loose: (),
     jsx: (),
+    maybe_array_like: (array_like_to_array),
     new_arrow_check: (),
     non_iterable_rest: (),
     non_iterable_spread: (),
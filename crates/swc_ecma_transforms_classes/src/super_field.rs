@@ -38,6 +38,15 @@ pub struct SuperFieldAccessFolder<'a> {
 
     /// `Some(mark)` if `var this2 = this`is required.
     pub this_alias_mark: Option<Mark>,
+
+    /// Assume that the super class is not changed, and access its prototype
+    /// directly instead of using `_get` and `_set`.
+    ///
+    /// https://babeljs.io/docs/en/assumptions#constantsuper
+    pub constant_super: bool,
+
+    /// Ident of the super class. Used only if `constant_super` is true.
+    pub super_class: &'a Option<Ident>,
 }
 
 macro_rules! mark_nested {
@@ -284,6 +293,10 @@ impl<'a> SuperFieldAccessFolder<'a> {
     }
 
     fn super_to_get_call(&mut self, super_token: Span, prop: SuperProp) -> Expr {
+        if self.constant_super {
+            return self.super_to_constant_member(super_token, prop);
+        }
+
         let proto_arg = get_prototype_of(if self.is_static {
             // Foo
             Expr::Ident(self.class_name.clone())
@@ -339,6 +352,24 @@ impl<'a> SuperFieldAccessFolder<'a> {
         op: AssignOp,
         rhs: Box<Expr>,
     ) -> Expr {
+        if self.constant_super && !is_update && op == op!("=") {
+            let this = match self.constructor_this_mark {
+                Some(mark) => Expr::Ident(quote_ident!(super_token.apply_mark(mark), "_this")),
+                None => Expr::This(ThisExpr { span: super_token }),
+            };
+
+            return Expr::Assign(AssignExpr {
+                span: super_token,
+                left: PatOrExpr::Expr(Box::new(Expr::Member(MemberExpr {
+                    span: super_token,
+                    obj: Box::new(this),
+                    prop: super_prop_to_member_prop(prop),
+                }))),
+                op,
+                right: rhs,
+            });
+        }
+
         let mut ref_ident = alias_ident_for(&rhs, "_ref");
         ref_ident.span = ref_ident.span.apply_mark(Mark::fresh(Mark::root()));
 
@@ -456,4 +487,41 @@ impl<'a> SuperFieldAccessFolder<'a> {
             expr
         }
     }
+
+    /// # In
+    /// ```js
+    /// super.foo
+    /// ```
+    /// # out
+    /// ```js
+    /// _Parent.prototype.foo
+    /// ```
+    fn super_to_constant_member(&mut self, super_token: Span, prop: SuperProp) -> Expr {
+        let obj = match self.super_class {
+            Some(super_class) if self.is_static => Expr::Ident(super_class.clone()),
+            Some(super_class) => super_class
+                .clone()
+                .make_member(quote_ident!("prototype")),
+            None => get_prototype_of(if self.is_static {
+                Expr::Ident(self.class_name.clone())
+            } else {
+                self.class_name
+                    .clone()
+                    .make_member(quote_ident!("prototype"))
+            }),
+        };
+
+        Expr::Member(MemberExpr {
+            span: super_token,
+            obj: Box::new(obj),
+            prop: super_prop_to_member_prop(prop),
+        })
+    }
+}
+
+fn super_prop_to_member_prop(prop: SuperProp) -> MemberProp {
+    match prop {
+        SuperProp::Ident(i) => MemberProp::Ident(i),
+        SuperProp::Computed(c) => MemberProp::Computed(c),
+    }
 }
@@ -166,11 +166,54 @@ pub(super) struct ConstructorFolder<'a> {
     /// True when recursing into other function or class.
     pub ignore_return: bool,
     pub in_injected_define_property_call: bool,
+    /// `super.foo` is handled by `SuperFieldAccessFolder` if true.
+    pub constant_super: bool,
+    /// `super()` is compiled to `_Parent.call(this) || this` if true.
+    pub super_is_callable_constructor: bool,
 }
 
 impl ConstructorFolder<'_> {
+    /// `_super.call(this, ...args)`
+    fn call_super(&self, super_var: Ident, args: &mut Vec<ExprOrSpread>) -> Box<Expr> {
+        let call = Box::new(Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: if self.is_constructor_default {
+                super_var.make_member(quote_ident!("apply")).as_callee()
+            } else {
+                super_var.make_member(quote_ident!("call")).as_callee()
+            },
+            args: if self.is_constructor_default {
+                vec![
+                    ThisExpr { span: DUMMY_SP }.as_arg(),
+                    quote_ident!("arguments").as_arg(),
+                ]
+            } else {
+                let mut call_args = vec![ThisExpr { span: DUMMY_SP }.as_arg()];
+                call_args.extend(args.take());
+
+                call_args
+            },
+            type_args: Default::default(),
+        }));
+
+        if !self.super_is_callable_constructor {
+            return call;
+        }
+
+        Box::new(Expr::Bin(BinExpr {
+            span: DUMMY_SP,
+            op: op!("||"),
+            left: call,
+            right: Box::new(Expr::This(ThisExpr { span: DUMMY_SP })),
+        }))
+    }
+
     /// `true` means it's processed and children are folded.
     fn handle_super_assignment(&mut self, e: &mut Expr) -> bool {
+        if self.constant_super {
+            return false;
+        }
+
         if let Expr::Assign(AssignExpr {
             left,
             op: op!("="),
@@ -213,6 +256,10 @@ impl ConstructorFolder<'_> {
     }
 
     fn handle_super_access(&mut self, e: &mut Expr, set_to: Option<Box<Expr>>) {
+        if self.constant_super {
+            return;
+        }
+
         if let Expr::SuperProp(SuperPropExpr { span, prop, .. }) = e {
             let this_var = quote_ident!(DUMMY_SP.apply_mark(self.mark), "_this");
             let this_super = private_ident!("_thisSuper");
@@ -314,7 +361,7 @@ impl VisitMut for ConstructorFolder<'_> {
     fn visit_mut_call_expr(&mut self, e: &mut CallExpr) {
         match &e.callee {
             Callee::Expr(callee) => match &**callee {
-                Expr::SuperProp(_) => {
+                Expr::SuperProp(_) if !self.constant_super => {
                     let old = self.cur_this_super.take();
 
                     e.visit_mut_children_with(self);
@@ -383,26 +430,7 @@ impl VisitMut for ConstructorFolder<'_> {
                 ..
             }) => {
                 let right = match self.super_var.clone() {
-                    Some(super_var) => Box::new(Expr::Call(CallExpr {
-                        span: DUMMY_SP,
-                        callee: if self.is_constructor_default {
-                            super_var.make_member(quote_ident!("apply")).as_callee()
-                        } else {
-                            super_var.make_member(quote_ident!("call")).as_callee()
-                        },
-                        args: if self.is_constructor_default {
-                            vec![
-                                ThisExpr { span: DUMMY_SP }.as_arg(),
-                                quote_ident!("arguments").as_arg(),
-                            ]
-                        } else {
-                            let mut call_args = vec![ThisExpr { span: DUMMY_SP }.as_arg()];
-                            call_args.extend(args.take());
-
-                            call_args
-                        },
-                        type_args: Default::default(),
-                    })),
+                    Some(super_var) => self.call_super(super_var, args),
 
                     None => Box::new(make_possible_return_value(ReturningMode::Prototype {
                         class_name: self.class_name.clone(),
@@ -451,26 +479,7 @@ impl VisitMut for ConstructorFolder<'_> {
             }) = &mut **expr
             {
                 let expr = match self.super_var.clone() {
-                    Some(super_var) => Box::new(Expr::Call(CallExpr {
-                        span: DUMMY_SP,
-                        callee: if self.is_constructor_default {
-                            super_var.make_member(quote_ident!("apply")).as_callee()
-                        } else {
-                            super_var.make_member(quote_ident!("call")).as_callee()
-                        },
-                        args: if self.is_constructor_default {
-                            vec![
-                                ThisExpr { span: DUMMY_SP }.as_arg(),
-                                quote_ident!("arguments").as_arg(),
-                            ]
-                        } else {
-                            let mut call_args = vec![ThisExpr { span: DUMMY_SP }.as_arg()];
-                            call_args.extend(args.take());
-
-                            call_args
-                        },
-                        type_args: Default::default(),
-                    })),
+                    Some(super_var) => self.call_super(super_var, args),
                     None => Box::new(make_possible_return_value(ReturningMode::Prototype {
                         is_constructor_default: self.is_constructor_default,
                         class_name: self.class_name.clone(),
@@ -5,8 +5,9 @@ use self::{
     },
     prop_name::HashKey,
 };
+use serde::Deserialize;
 use std::iter;
-use swc_common::{comments::Comments, util::take::Take, Mark, Spanned, DUMMY_SP};
+use swc_common::{comments::Comments, errors::HANDLER, util::take::Take, Mark, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_transforms_base::{helper, native::is_native, perf::Check};
 use swc_ecma_transforms_classes::super_field::SuperFieldAccessFolder;
@@ -23,16 +24,37 @@ use tracing::debug;
 mod constructor;
 mod prop_name;
 
-pub fn classes<C>(comments: Option<C>) -> impl Fold + VisitMut
+pub fn classes<C>(comments: Option<C>, config: Config) -> impl Fold + VisitMut
 where
     C: Comments,
 {
     as_folder(Classes {
         in_strict: false,
+        config,
         comments,
     })
 }
 
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// https://babeljs.io/docs/en/assumptions#constantsuper
+    #[serde(default)]
+    pub constant_super: bool,
+
+    /// https://babeljs.io/docs/en/assumptions#noclasscalls
+    #[serde(default)]
+    pub no_class_calls: bool,
+
+    /// https://babeljs.io/docs/en/assumptions#setclassmethods
+    #[serde(default)]
+    pub set_class_methods: bool,
+
+    /// https://babeljs.io/docs/en/assumptions#superiscallableconstructor
+    #[serde(default)]
+    pub super_is_callable_constructor: bool,
+}
+
 type IndexMap<K, V> = indexmap::IndexMap<K, V, ahash::RandomState>;
 
 /// `@babel/plugin-transform-classes`
@@ -72,6 +94,7 @@ where
     C: Comments,
 {
     in_strict: bool,
+    config: Config,
     comments: Option<C>,
 }
 
@@ -410,6 +433,15 @@ where
                 ClassMember::StaticBlock(..) => unreachable!(
                     "classes pass: static blocks\nstatic_blocks pass should remove this"
                 ),
+                ClassMember::AutoAccessor(a) => HANDLER.with(|handler| {
+                    handler
+                        .struct_span_err(
+                            a.span,
+                            "auto accessors should be lowered by the `auto_accessors` pass before \
+                             this pass",
+                        )
+                        .emit()
+                }),
             }
         }
 
@@ -435,7 +467,12 @@ where
             );
         }
 
-        let super_var = super_class_ident.as_ref().map(|_| {
+        let super_var = super_class_ident.as_ref().map(|super_class_ident| {
+            if self.config.super_is_callable_constructor {
+                // `super()` is compiled to `_Parent.call(this)`.
+                return super_class_ident.clone();
+            }
+
             let var = private_ident!("_super");
             let mut class_name_sym = class_name.clone();
             class_name_sym.span = DUMMY_SP;
@@ -535,6 +572,8 @@ where
                     super_var,
                     ignore_return: false,
                     in_injected_define_property_call: false,
+                    constant_super: self.config.constant_super,
+                    super_is_callable_constructor: self.config.super_is_callable_constructor,
                 });
 
                 insert_this |= (mode == None && !is_always_initialized)
@@ -587,6 +626,7 @@ where
             // Handle `super.XX`
             body = self.handle_super_access(
                 &class_name,
+                &super_class_ident,
                 body,
                 if is_this_declared {
                     Some(this_mark)
@@ -595,8 +635,10 @@ where
                 },
             );
 
-            // inject _classCallCheck(this, Bar);
-            inject_class_call_check(&mut body, class_name.clone());
+            if !self.config.no_class_calls {
+                // inject _classCallCheck(this, Bar);
+                inject_class_call_check(&mut body, class_name.clone());
+            }
 
             stmts.push(Stmt::Decl(Decl::Fn(FnDecl {
                 ident: class_name.clone(),
@@ -613,7 +655,7 @@ where
 
         // convert class methods
         // stmts.extend(self.fold_class_methods(class_name.clone(), priv_methods));
-        stmts.extend(self.fold_class_methods(class_name.clone(), methods));
+        stmts.extend(self.fold_class_methods(class_name.clone(), &super_class_ident, methods));
 
         if stmts.first().map(|v| !v.is_use_strict()).unwrap_or(false) && !self.in_strict {
             prepend(
@@ -666,6 +708,7 @@ where
     fn handle_super_access(
         &mut self,
         class_name: &Ident,
+        super_class_ident: &Option<Ident>,
         mut body: Vec<Stmt>,
         this_mark: Option<Mark>,
     ) -> Vec<Stmt> {
@@ -680,6 +723,8 @@ where
             in_nested_scope: false,
             in_injected_define_property_call: false,
             this_alias_mark: None,
+            constant_super: self.config.constant_super,
+            super_class: super_class_ident,
         };
 
         body.visit_mut_with(&mut folder);
@@ -716,7 +761,12 @@ where
         body
     }
 
-    fn fold_class_methods(&mut self, class_name: Ident, methods: Vec<ClassMethod>) -> Vec<Stmt> {
+    fn fold_class_methods(
+        &mut self,
+        class_name: Ident,
+        super_class_ident: &Option<Ident>,
+        methods: Vec<ClassMethod>,
+    ) -> Vec<Stmt> {
         if methods.is_empty() {
             return vec![];
         }
@@ -810,6 +860,15 @@ where
 
         let (mut props, mut static_props) = (IndexMap::default(), IndexMap::default());
 
+        let mut class_name_sym = class_name.clone();
+        class_name_sym.span = DUMMY_SP;
+        class_name_sym.span.ctxt = class_name.span.ctxt;
+
+        // `_proto` in `_proto.foo = function foo() {}`
+        let proto = private_ident!("_proto");
+        let mut is_proto_used = false;
+        let mut assigned_methods = vec![];
+
         for mut m in methods {
             let key = HashKey::from(&m.key);
             let key_prop = Box::new(mk_key_prop(&m.key));
@@ -832,6 +891,8 @@ where
                 in_nested_scope: false,
                 in_injected_define_property_call: false,
                 this_alias_mark: None,
+                constant_super: self.config.constant_super,
+                super_class: super_class_ident,
             };
             m.function.visit_mut_with(&mut folder);
 
@@ -864,6 +925,14 @@ where
                 );
             }
 
+            let member_prop = match &prop_name {
+                Expr::Ident(i) if !computed => MemberProp::Ident(i.clone()),
+                _ => MemberProp::Computed(ComputedPropName {
+                    span: DUMMY_SP,
+                    expr: Box::new(prop_name.clone()),
+                }),
+            };
+
             let value = Box::new(Expr::Fn(FnExpr {
                 ident: if m.kind == MethodKind::Method && !computed {
                     match prop_name {
@@ -879,6 +948,31 @@ where
                 function: m.function,
             }));
 
+            if self.config.set_class_methods && m.kind == MethodKind::Method {
+                // Foo.prototype.foo = function foo() {}
+                let obj = if m.is_static {
+                    class_name_sym.clone()
+                } else {
+                    is_proto_used = true;
+                    proto.clone()
+                };
+
+                assigned_methods.push(
+                    AssignExpr {
+                        span: DUMMY_SP,
+                        op: op!("="),
+                        left: PatOrExpr::Expr(Box::new(Expr::Member(MemberExpr {
+                            span: DUMMY_SP,
+                            obj: Box::new(Expr::Ident(obj)),
+                            prop: member_prop,
+                        }))),
+                        right: escape_keywords(value),
+                    }
+                    .into_stmt(),
+                );
+                continue;
+            }
+
             let data = append_to.entry(key).or_insert_with(|| Data {
                 key_prop,
                 get: None,
@@ -892,10 +986,30 @@ where
             }
         }
 
+        let mut stmts = vec![];
+
+        if is_proto_used {
+            stmts.push(Stmt::Decl(Decl::Var(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                declare: false,
+                decls: vec![VarDeclarator {
+                    span: DUMMY_SP,
+                    name: proto.into(),
+                    init: Some(Box::new(
+                        class_name_sym.make_member(quote_ident!("prototype")),
+                    )),
+                    definite: false,
+                }],
+            })));
+        }
+
+        stmts.extend(assigned_methods);
+
         if props.is_empty() && static_props.is_empty() {
-            return vec![];
+            return stmts;
         }
-        vec![mk_create_class_call(
+        stmts.push(mk_create_class_call(
             class_name,
             mk_arg_obj_for_create_class(props),
             if static_props.is_empty() {
@@ -903,7 +1017,9 @@ where
             } else {
                 Some(mk_arg_obj_for_create_class(static_props))
             },
-        )]
+        ));
+
+        stmts
     }
 }
 
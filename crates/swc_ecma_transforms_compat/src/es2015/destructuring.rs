@@ -3,7 +3,7 @@ use std::iter;
 use swc_atoms::js_word;
 use swc_common::{util::take::Take, Spanned, SyntaxContext, DUMMY_SP};
 use swc_ecma_ast::*;
-use swc_ecma_transforms_base::{helper, perf::Check};
+use swc_ecma_transforms_base::{helper, helper_expr, perf::Check};
 use swc_ecma_transforms_macros::fast_path;
 use swc_ecma_utils::{
     alias_ident_for, alias_if_required, has_rest_pat, is_literal, member_expr, private_ident,
@@ -46,6 +46,11 @@ struct Destructuring {
 pub struct Config {
     #[serde(default)]
     pub loose: bool,
+
+    /// Destructures array-like objects (objects with a `length`) even if
+    /// they are not iterable.
+    #[serde(default)]
+    pub allow_array_like: bool,
 }
 
 macro_rules! impl_for_for_stmt {
@@ -697,25 +702,9 @@ impl VisitMut for AssignFolder {
                                     // else `_slicedToArray`
                                     if elems.iter().any(|elem| matches!(elem, Some(Pat::Rest(..))))
                                     {
-                                        Box::new(Expr::Call(CallExpr {
-                                            span: DUMMY_SP,
-                                            callee: helper!(to_array, "toArray"),
-                                            args: vec![right.take().as_arg()],
-                                            type_args: Default::default(),
-                                        }))
+                                        Box::new(to_array(&self.c, right.take(), None))
                                     } else {
-                                        Box::new(
-                                            CallExpr {
-                                                span: DUMMY_SP,
-                                                callee: helper!(sliced_to_array, "slicedToArray"),
-                                                args: vec![
-                                                    right.take().as_arg(),
-                                                    elems.len().as_arg(),
-                                                ],
-                                                type_args: Default::default(),
-                                            }
-                                            .into(),
-                                        )
+                                        Box::new(to_array(&self.c, right.take(), Some(elems.len())))
                                     }
                                 }
                             }
@@ -1065,24 +1054,8 @@ fn make_ref_ident_for_array(
                 } else {
                     match elem_cnt {
                         None => v,
-                        Some(std::usize::MAX) => Box::new(
-                            CallExpr {
-                                span: DUMMY_SP,
-                                callee: helper!(to_array, "toArray"),
-                                args: vec![v.as_arg()],
-                                type_args: Default::default(),
-                            }
-                            .into(),
-                        ),
-                        Some(value) => Box::new(
-                            CallExpr {
-                                span: DUMMY_SP,
-                                callee: helper!(sliced_to_array, "slicedToArray"),
-                                args: vec![v.as_arg(), value.as_arg()],
-                                type_args: Default::default(),
-                            }
-                            .into(),
-                        ),
+                        Some(std::usize::MAX) => Box::new(to_array(&c, v, None)),
+                        Some(value) => Box::new(to_array(&c, v, Some(value))),
                     }
                 }
             }),
@@ -1206,3 +1179,38 @@ impl Check for DestructuringVisitor {
         self.found
     }
 }
+
+/// `_toArray(arr)` if `len` is [None], or `_slicedToArray(arr, len)`.
+fn to_array(c: &Config, arr: Box<Expr>, len: Option<usize>) -> Expr {
+    let mut args = vec![arr.as_arg()];
+    args.extend(len.map(|len| len.as_arg()));
+
+    if c.allow_array_like {
+        // _maybeArrayLike(_slicedToArray, arr, len)
+        args.insert(
+            0,
+            match len {
+                Some(..) => helper_expr!(sliced_to_array, "slicedToArray"),
+                None => helper_expr!(to_array, "toArray"),
+            }
+            .as_arg(),
+        );
+
+        return Expr::Call(CallExpr {
+            span: DUMMY_SP,
+            callee: helper!(maybe_array_like, "maybeArrayLike"),
+            args,
+            type_args: Default::default(),
+        });
+    }
+
+    Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: match len {
+            Some(..) => helper!(sliced_to_array, "slicedToArray"),
+            None => helper!(to_array, "toArray"),
+        },
+        args,
+        type_args: Default::default(),
+    })
+}
@@ -56,6 +56,10 @@ pub fn for_of(c: Config) -> impl Fold + VisitMut {
 #[serde(rename_all = "camelCase")]
 pub struct Config {
     pub assume_array: bool,
+
+    /// Don't call `return` of the iterator when the loop exits early.
+    #[serde(default)]
+    pub skip_iterator_closing: bool,
 }
 
 struct ForOf {
@@ -230,8 +234,81 @@ impl ForOf {
         );
 
         let iterator = quote_ident!(var_span, "_iterator");
+        // `var _iterator = foo[Symbol.iterator](), _step;`
+        let init = Some(VarDeclOrExpr::VarDecl(VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Var,
+            declare: false,
+            decls: vec![
+                VarDeclarator {
+                    span: DUMMY_SP,
+                    name: iterator.clone().into(),
+                    init: Some(Box::new(Expr::Call(CallExpr {
+                        span: DUMMY_SP,
+                        callee: right
+                            .computed_member(*member_expr!(DUMMY_SP, Symbol.iterator))
+                            .as_callee(),
+                        args: vec![],
+                        type_args: Default::default(),
+                    }))),
+                    definite: false,
+                },
+                VarDeclarator {
+                    span: DUMMY_SP,
+                    name: step.clone().into(),
+                    init: None,
+                    definite: false,
+                },
+            ],
+        }));
+        // `(_step = _iterator.next()).done`
+        let step_done = Box::new(
+            Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                left: PatOrExpr::Pat(step.into()),
+                op: op!("="),
+                // `_iterator.next()`
+                right: Box::new(Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    // `_iterator.next`
+                    callee: iterator
+                        .clone()
+                        .make_member(quote_ident!("next"))
+                        .as_callee(),
+                    args: vec![],
+                    type_args: Default::default(),
+                })),
+            })
+            .make_member(quote_ident!("done")),
+        );
+
+        if self.c.skip_iterator_closing {
+            // for (var _iterator = foo[Symbol.iterator](), _step;
+            //      !(_step = _iterator.next()).done;) {}
+            let for_stmt = Stmt::For(ForStmt {
+                span,
+                init,
+                test: Some(Box::new(Expr::Unary(UnaryExpr {
+                    span: DUMMY_SP,
+                    op: op!("!"),
+                    arg: step_done,
+                }))),
+                update: None,
+                body: Box::new(Stmt::Block(body)),
+            });
+
+            return match label {
+                Some(label) => Stmt::Labeled(LabeledStmt {
+                    span,
+                    label,
+                    body: Box::new(for_stmt),
+                }),
+                None => for_stmt,
+            };
+        }
+
         // `_iterator.return`
-        let iterator_return = Box::new(iterator.clone().make_member(quote_ident!("return")));
+        let iterator_return = Box::new(iterator.make_member(quote_ident!("return")));
 
         let normal_completion_ident = Ident::new("_iteratorNormalCompletion".into(), var_span);
         self.top_level_vars.push(VarDeclarator {
@@ -260,58 +337,17 @@ impl ForOf {
 
         let for_stmt = ForStmt {
             span,
-            init: Some(VarDeclOrExpr::VarDecl(VarDecl {
-                span: DUMMY_SP,
-                kind: VarDeclKind::Var,
-                declare: false,
-                decls: vec![
-                    VarDeclarator {
-                        span: DUMMY_SP,
-                        name: iterator.clone().into(),
-                        init: Some(Box::new(Expr::Call(CallExpr {
-                            span: DUMMY_SP,
-                            callee: right
-                                .computed_member(*member_expr!(DUMMY_SP, Symbol.iterator))
-                                .as_callee(),
-                            args: vec![],
-                            type_args: Default::default(),
-                        }))),
-                        definite: false,
-                    },
-                    VarDeclarator {
-                        span: DUMMY_SP,
-                        name: step.clone().into(),
-                        init: None,
-                        definite: false,
-                    },
-                ],
-            })),
+            init,
             // !(_iteratorNormalCompletion = (_step = _iterator.next()).done)
             test: Some(Box::new(Expr::Unary(UnaryExpr {
                 span: DUMMY_SP,
                 op: op!("!"),
-                arg: {
-                    let step_expr = Box::new(Expr::Assign(AssignExpr {
-                        span: DUMMY_SP,
-                        left: PatOrExpr::Pat(step.into()),
-                        op: op!("="),
-                        // `_iterator.next()`
-                        right: Box::new(Expr::Call(CallExpr {
-                            span: DUMMY_SP,
-                            // `_iterator.next`
-                            callee: iterator.make_member(quote_ident!("next")).as_callee(),
-                            args: vec![],
-                            type_args: Default::default(),
-                        })),
-                    }));
-
-                    Box::new(Expr::Assign(AssignExpr {
-                        span: DUMMY_SP,
-                        left: PatOrExpr::Pat(normal_completion_ident.clone().into()),
-                        op: op!("="),
-                        right: Box::new(step_expr.make_member(quote_ident!("done"))),
-                    }))
-                },
+                arg: Box::new(Expr::Assign(AssignExpr {
+                    span: DUMMY_SP,
+                    left: PatOrExpr::Pat(normal_completion_ident.clone().into()),
+                    op: op!("="),
+                    right: step_done,
+                })),
             }))),
 
             // `_iteratorNormalCompletion = true`
@@ -50,7 +50,7 @@ where
         block_scoped_functions(),
         template_literal(c.template_literal),
         new_target(),
-        classes(comments),
+        classes(comments, c.classes),
         spread(c.spread),
         // https://github.com/Microsoft/TypeScript/issues/5441
         Optional::new(object_super(), !c.typescript),
@@ -71,6 +71,9 @@ where
 #[derive(Debug, Clone, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Config {
+    #[serde(default)]
+    pub classes: classes::Config,
+
     #[serde(flatten)]
     pub computed_props: computed_props::Config,
 
@@ -3,7 +3,7 @@ use std::mem;
 use swc_atoms::js_word;
 use swc_common::{util::take::Take, Span, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
-use swc_ecma_transforms_base::{ext::ExprRefExt, helper, perf::Check};
+use swc_ecma_transforms_base::{ext::ExprRefExt, helper, helper_expr, perf::Check};
 use swc_ecma_transforms_macros::fast_path;
 use swc_ecma_utils::{
     alias_ident_for, is_literal, member_expr, prepend, quote_ident, undefined, ExprFactory,
@@ -24,6 +24,11 @@ pub fn spread(c: Config) -> impl Fold + VisitMut {
 #[serde(rename_all = "camelCase")]
 pub struct Config {
     pub loose: bool,
+
+    /// Spreads array-like objects (objects with a `length`) even if they are
+    /// not iterable.
+    #[serde(default)]
+    pub allow_array_like: bool,
 }
 
 /// es2015 - `SpreadElement`
@@ -210,6 +215,29 @@ impl Spread {
 }
 
 impl Spread {
+    /// `_toConsumableArray(expr)`
+    fn to_consumable_array(&self, span: Span, expr: Box<Expr>) -> Expr {
+        if self.c.allow_array_like {
+            // _maybeArrayLike(_toConsumableArray, expr)
+            return Expr::Call(CallExpr {
+                span,
+                callee: helper!(maybe_array_like, "maybeArrayLike"),
+                args: vec![
+                    helper_expr!(to_consumable_array, "toConsumableArray").as_arg(),
+                    expr.as_arg(),
+                ],
+                type_args: Default::default(),
+            });
+        }
+
+        Expr::Call(CallExpr {
+            span,
+            callee: helper!(to_consumable_array, "toConsumableArray"),
+            args: vec![expr.as_arg()],
+            type_args: Default::default(),
+        })
+    }
+
     fn concat_args(
         &self,
         span: Span,
@@ -287,15 +315,7 @@ impl Spread {
                                     return if self.c.loose {
                                         *expr
                                     } else {
-                                        Expr::Call(CallExpr {
-                                            span,
-                                            callee: helper!(
-                                                to_consumable_array,
-                                                "toConsumableArray"
-                                            ),
-                                            args: vec![expr.as_arg()],
-                                            type_args: Default::default(),
-                                        })
+                                        self.to_consumable_array(span, expr)
                                     };
                                 }
                                 // [].concat(arr) is shorter than _toConsumableArray(arr)
@@ -313,25 +333,11 @@ impl Spread {
                                             type_args: Default::default(),
                                         })
                                     } else {
-                                        Expr::Call(CallExpr {
-                                            span,
-                                            callee: helper!(
-                                                to_consumable_array,
-                                                "toConsumableArray"
-                                            ),
-                                            args: vec![expr.as_arg()],
-                                            type_args: Default::default(),
-                                        })
+                                        self.to_consumable_array(span, expr)
                                     };
                                 }
 
-                                Expr::Call(CallExpr {
-                                    span,
-                                    callee: helper!(to_consumable_array, "toConsumableArray"),
-                                    args: vec![expr.as_arg()],
-                                    type_args: Default::default(),
-                                })
-                                .as_arg()
+                                self.to_consumable_array(span, expr).as_arg()
                             }
                         });
                     }
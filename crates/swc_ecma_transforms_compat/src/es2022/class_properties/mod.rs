@@ -2,18 +2,27 @@
 
 use self::{
     class_name_tdz::ClassNameTdzFolder,
-    private_field::{BrandCheckHandler, FieldAccessFolder, Private, PrivateKind, PrivateRecord},
+    private_field::{
+        BrandCheckHandler, FieldAccessFolder, Private, PrivateAsPropertiesFolder, PrivateKind,
+        PrivateRecord,
+    },
     this_in_static::ThisInStaticFolder,
     used_name::UsedNameCollector,
 };
-use swc_common::{collections::AHashSet, util::take::Take, Mark, Spanned, SyntaxContext, DUMMY_SP};
+use indexmap::IndexMap;
+use serde::Deserialize;
+use swc_atoms::JsWord;
+use swc_common::{
+    collections::AHashSet, errors::HANDLER, util::take::Take, Mark, Spanned, SyntaxContext,
+    DUMMY_SP,
+};
 use swc_ecma_ast::*;
 use swc_ecma_transforms_base::{helper, perf::Check};
 use swc_ecma_transforms_classes::super_field::SuperFieldAccessFolder;
 use swc_ecma_transforms_macros::fast_path;
 use swc_ecma_utils::{
     alias_ident_for, alias_if_required, constructor::inject_after_super, default_constructor,
-    private_ident, quote_ident, undefined, ExprFactory, ModuleItemLike, StmtLike,
+    private_ident, quote_ident, quote_str, undefined, ExprFactory, ModuleItemLike, StmtLike,
 };
 use swc_ecma_visit::{
     as_folder, noop_visit_mut_type, noop_visit_type, Fold, Visit, VisitMut, VisitMutWith, VisitWith,
@@ -38,9 +47,16 @@ pub fn class_properties(config: Config) -> impl Fold + VisitMut {
     })
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Config {
-    pub loose: bool,
+    /// https://babeljs.io/docs/en/assumptions#setpublicclassfields
+    #[serde(default)]
+    pub set_public_class_fields: bool,
+
+    /// https://babeljs.io/docs/en/assumptions#privatefieldsasproperties
+    #[serde(default)]
+    pub private_fields_as_properties: bool,
 }
 
 struct ClassProperties {
@@ -353,10 +369,16 @@ impl ClassProperties {
         let mut used_key_names = vec![];
         let mut names_used_for_brand_checks = AHashSet::default();
 
-        class.body.visit_mut_with(&mut BrandCheckHandler {
-            names: &mut names_used_for_brand_checks,
-            private: &self.private,
-        });
+        if !self.config.private_fields_as_properties {
+            class.body.visit_mut_with(&mut BrandCheckHandler {
+                names: &mut names_used_for_brand_checks,
+                private: &self.private,
+            });
+        }
+
+        // Private accessors of the same name are defined at once.
+        let mut private_accessors: IndexMap<JsWord, (bool, Vec<PropOrSpread>), ahash::RandomState> =
+            Default::default();
 
         for member in class.body {
             match member {
@@ -408,6 +430,11 @@ impl ClassProperties {
                         });
                     }
 
+                    let ident_key = match &prop.key {
+                        PropName::Ident(i) => Some(i.clone()),
+                        _ => None,
+                    };
+
                     let key = match prop.key {
                         PropName::Ident(ref i) => Box::new(Expr::from(Lit::Str(Str {
                             span: i.span,
@@ -456,12 +483,39 @@ impl ClassProperties {
                             in_injected_define_property_call: false,
                             in_nested_scope: false,
                             this_alias_mark: None,
+                            constant_super: false,
+                            super_class: &None,
                         });
                         value.visit_mut_with(&mut ThisInStaticFolder {
                             ident: class_ident.clone(),
                         });
                     }
 
+                    if self.config.set_public_class_fields {
+                        // this.foo = value
+                        let obj = if prop.is_static {
+                            Expr::Ident(class_ident.clone())
+                        } else {
+                            Expr::This(ThisExpr { span: DUMMY_SP })
+                        };
+                        let assign = Box::new(Expr::Assign(AssignExpr {
+                            span: prop_span,
+                            op: op!("="),
+                            left: PatOrExpr::Expr(Box::new(match ident_key {
+                                Some(i) => obj.make_member(i),
+                                None => obj.computed_member(*key),
+                            })),
+                            right: value,
+                        }));
+
+                        if prop.is_static {
+                            extra_stmts.push(assign.into_stmt());
+                        } else {
+                            constructor_exprs.push(assign);
+                        }
+                        continue;
+                    }
+
                     let callee = helper!(define_property, "defineProperty");
 
                     if prop.is_static {
@@ -510,6 +564,47 @@ impl ClassProperties {
 
                     let value = prop.value.unwrap_or_else(|| undefined(prop_span));
 
+                    if self.config.private_fields_as_properties {
+                        extra_stmts.push(private_key_decl(ident.clone(), &prop.key.id.sym));
+
+                        let desc = ObjectLit {
+                            span: DUMMY_SP,
+                            props: vec![
+                                // writable: true
+                                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                                    key: PropName::Ident(quote_ident!("writable")),
+                                    value: Box::new(Expr::Lit(Lit::Bool(Bool {
+                                        span: DUMMY_SP,
+                                        value: true,
+                                    }))),
+                                }))),
+                                // value: value
+                                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                                    key: PropName::Ident(quote_ident!("value")),
+                                    value,
+                                }))),
+                            ],
+                        };
+
+                        if prop.is_static {
+                            extra_stmts.push(
+                                define_private_property(
+                                    Expr::Ident(class_ident.clone()),
+                                    ident,
+                                    desc,
+                                )
+                                .into_stmt(),
+                            );
+                        } else {
+                            constructor_exprs.push(Box::new(define_private_property(
+                                Expr::This(ThisExpr { span: DUMMY_SP }),
+                                ident,
+                                desc,
+                            )));
+                        }
+                        continue;
+                    }
+
                     let extra_init = if prop.is_static {
                         Box::new(Expr::Object(ObjectLit {
                             span: DUMMY_SP,
@@ -589,13 +684,45 @@ impl ClassProperties {
                     let is_static = method.is_static;
                     let prop_span = method.span;
                     let fn_name = Ident::new(
-                        method.key.id.sym.clone(),
+                        match method.kind {
+                            // Accessors are not accessed by name if private names are converted
+                            // to properties.
+                            MethodKind::Getter if self.config.private_fields_as_properties => {
+                                format!("get_{}", method.key.id.sym).into()
+                            }
+                            MethodKind::Setter if self.config.private_fields_as_properties => {
+                                format!("set_{}", method.key.id.sym).into()
+                            }
+                            _ => method.key.id.sym.clone(),
+                        },
                         method
                             .span
                             .with_ctxt(SyntaxContext::empty())
                             .apply_mark(self.private.curr_mark()),
                     );
 
+                    if self.config.private_fields_as_properties {
+                        let (_, desc) = private_accessors
+                            .entry(method.key.id.sym.clone())
+                            .or_insert_with(|| (is_static, vec![]));
+
+                        desc.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                            key: PropName::Ident(quote_ident!(match method.kind {
+                                MethodKind::Method => "value",
+                                MethodKind::Getter => "get",
+                                MethodKind::Setter => "set",
+                            })),
+                            value: Box::new(Expr::Ident(fn_name.clone())),
+                        }))));
+
+                        private_method_fn_decls.push(Stmt::Decl(Decl::Fn(FnDecl {
+                            ident: fn_name,
+                            function: method.function,
+                            declare: false,
+                        })));
+                        continue;
+                    }
+
                     let should_use_map =
                         matches!(method.kind, MethodKind::Getter | MethodKind::Setter)
                             && names_used_for_brand_checks.contains(&method.key.id.sym)
@@ -691,9 +818,52 @@ impl ClassProperties {
                 ClassMember::StaticBlock(..) => unreachable!(
                     "classes pass: static blocks\nstatic_blocks pass should remove this"
                 ),
+
+                ClassMember::AutoAccessor(a) => HANDLER.with(|handler| {
+                    handler
+                        .struct_span_err(
+                            a.span,
+                            "auto accessors should be lowered by the `auto_accessors` pass before \
+                             this pass",
+                        )
+                        .emit()
+                }),
             }
         }
 
+        // Private methods should be available while initializing fields.
+        let mut private_method_exprs = vec![];
+        for (name, (is_static, props)) in private_accessors {
+            let key = Ident::new(
+                format!("_{}", name).into(),
+                DUMMY_SP.apply_mark(self.private.curr_mark()),
+            );
+            vars.push(VarDeclarator {
+                span: DUMMY_SP,
+                name: key.clone().into(),
+                init: Some(Box::new(private_key_init(&name))),
+                definite: false,
+            });
+
+            let desc = ObjectLit {
+                span: DUMMY_SP,
+                props,
+            };
+            if is_static {
+                extra_stmts.push(
+                    define_private_property(Expr::Ident(class_ident.clone()), key, desc)
+                        .into_stmt(),
+                );
+            } else {
+                private_method_exprs.push(Box::new(define_private_property(
+                    Expr::This(ThisExpr { span: DUMMY_SP }),
+                    key,
+                    desc,
+                )));
+            }
+        }
+        constructor_exprs.splice(0..0, private_method_exprs);
+
         let constructor_exprs = {
             typescript_constructor_properties.extend(constructor_exprs);
             typescript_constructor_properties
@@ -704,19 +874,29 @@ impl ClassProperties {
             members.push(ClassMember::Constructor(c));
         }
 
-        private_method_fn_decls.visit_mut_with(&mut FieldAccessFolder {
-            private: &self.private,
-            vars: vec![],
-            in_assign_pat: false,
-        });
+        if self.config.private_fields_as_properties {
+            extra_stmts.extend(private_method_fn_decls);
 
-        extra_stmts.extend(private_method_fn_decls);
+            let mut folder = PrivateAsPropertiesFolder {
+                private: &self.private,
+            };
+            extra_stmts.visit_mut_with(&mut folder);
+            members.visit_mut_with(&mut folder);
+        } else {
+            private_method_fn_decls.visit_mut_with(&mut FieldAccessFolder {
+                private: &self.private,
+                vars: vec![],
+                in_assign_pat: false,
+            });
 
-        members.visit_mut_with(&mut FieldAccessFolder {
-            private: &self.private,
-            vars: vec![],
-            in_assign_pat: false,
-        });
+            extra_stmts.extend(private_method_fn_decls);
+
+            members.visit_mut_with(&mut FieldAccessFolder {
+                private: &self.private,
+                vars: vec![],
+                in_assign_pat: false,
+            });
+        }
 
         self.private.pop();
 
@@ -840,3 +1020,40 @@ impl Check for ShouldWork {
         self.found
     }
 }
+
+/// `var _x = _classPrivateFieldLooseKey("x");`
+fn private_key_decl(key: Ident, name: &JsWord) -> Stmt {
+    Stmt::Decl(Decl::Var(VarDecl {
+        span: DUMMY_SP,
+        kind: VarDeclKind::Var,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: key.into(),
+            init: Some(Box::new(private_key_init(name))),
+            definite: false,
+        }],
+        declare: false,
+    }))
+}
+
+/// `_classPrivateFieldLooseKey("x")`
+fn private_key_init(name: &JsWord) -> Expr {
+    Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: helper!(class_private_field_loose_key, "classPrivateFieldLooseKey"),
+        args: vec![quote_str!(name.clone()).as_arg()],
+        type_args: Default::default(),
+    })
+}
+
+/// `Object.defineProperty(obj, _x, desc)`
+fn define_private_property(obj: Expr, key: Ident, desc: ObjectLit) -> Expr {
+    Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: quote_ident!("Object")
+            .make_member(quote_ident!("defineProperty"))
+            .as_callee(),
+        args: vec![obj.as_arg(), key.as_arg(), desc.as_arg()],
+        type_args: Default::default(),
+    })
+}
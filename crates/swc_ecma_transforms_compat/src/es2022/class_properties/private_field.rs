@@ -120,6 +120,71 @@ impl VisitMut for BrandCheckHandler<'_> {
     }
 }
 
+/// Converts private names to properties with unique keys.
+///
+/// https://babeljs.io/docs/en/assumptions#privatefieldsasproperties
+pub(super) struct PrivateAsPropertiesFolder<'a> {
+    pub private: &'a PrivateRecord,
+}
+
+impl PrivateAsPropertiesFolder<'_> {
+    fn key(&self, n: &PrivateName) -> Ident {
+        let (mark, _, _) = self.private.get(&n.id.sym);
+
+        Ident::new(format!("_{}", n.id.sym).into(), n.id.span.apply_mark(mark))
+    }
+}
+
+impl VisitMut for PrivateAsPropertiesFolder<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        if let Expr::Bin(BinExpr {
+            span,
+            op: op!("in"),
+            left,
+            right,
+        }) = e
+        {
+            if let Expr::PrivateName(n) = &**left {
+                // Object.prototype.hasOwnProperty.call(obj, _foo)
+                *e = Expr::Call(CallExpr {
+                    span: *span,
+                    callee: quote_ident!("Object")
+                        .make_member(quote_ident!("prototype"))
+                        .make_member(quote_ident!("hasOwnProperty"))
+                        .make_member(quote_ident!("call"))
+                        .as_callee(),
+                    args: vec![right.take().as_arg(), self.key(n).as_arg()],
+                    type_args: Default::default(),
+                });
+            }
+        }
+    }
+
+    fn visit_mut_member_expr(&mut self, e: &mut MemberExpr) {
+        e.visit_mut_children_with(self);
+
+        if let MemberProp::PrivateName(n) = &e.prop {
+            let key = self.key(n);
+
+            // _classPrivateFieldLooseBase(obj, _foo)[_foo]
+            e.obj = Box::new(Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: helper!(class_private_field_loose_base, "classPrivateFieldLooseBase"),
+                args: vec![e.obj.take().as_arg(), key.clone().as_arg()],
+                type_args: Default::default(),
+            }));
+            e.prop = MemberProp::Computed(ComputedPropName {
+                span: n.span,
+                expr: Box::new(Expr::Ident(key)),
+            });
+        }
+    }
+}
+
 pub(super) struct FieldAccessFolder<'a> {
     pub vars: Vec<VarDeclarator>,
     pub private: &'a PrivateRecord,
@@ -13,7 +13,10 @@ pub fn es2022(config: Config) -> impl Fold {
     chain!(
         static_blocks(),
         class_properties(class_properties::Config {
-            loose: config.loose,
+            set_public_class_fields: config.loose
+                || config.class_properties.set_public_class_fields,
+            private_fields_as_properties: config.loose
+                || config.class_properties.private_fields_as_properties,
         }),
         private_in_object(),
     )
@@ -21,5 +24,7 @@ pub fn es2022(config: Config) -> impl Fold {
 
 #[derive(Debug, Clone, Default)]
 pub struct Config {
+    /// Enables all assumptions of `class_properties`.
     pub loose: bool,
+    pub class_properties: class_properties::Config,
 }
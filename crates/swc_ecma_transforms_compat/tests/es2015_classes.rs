@@ -16,13 +16,13 @@ fn syntax() -> Syntax {
 }
 
 fn tr(tester: &Tester) -> impl Fold {
-    classes(Some(tester.comments.clone()))
+    classes(Some(tester.comments.clone()), Default::default())
 }
 
 fn spec_tr(tester: &Tester) -> impl Fold {
     chain!(
         resolver(),
-        classes(Some(tester.comments.clone())),
+        classes(Some(tester.comments.clone()), Default::default()),
         spread(spread::Config {
             ..Default::default()
         }),
@@ -5168,7 +5168,10 @@ function (Array) {
 // extend_builtins_imported_babel_plugin_transform_builtin_classes
 test_exec!(
     syntax(),
-    |t| chain!(classes(Some(t.comments.clone())), block_scoping()),
+    |t| chain!(
+        classes(Some(t.comments.clone()), Default::default()),
+        block_scoping()
+    ),
     extend_builtins_imported_babel_plugin_transform_builtin_classes_exec,
     r#"
 // Imported from
@@ -5469,7 +5472,10 @@ expect(obj.test).toBe(3);
 // extend_builtins_spec
 test_exec!(
     syntax(),
-    |t| chain!(classes(Some(t.comments.clone())), block_scoping()),
+    |t| chain!(
+        classes(Some(t.comments.clone()), Default::default()),
+        block_scoping()
+    ),
     extend_builtins_spec_exec,
     r#"
 class List extends Array {}
@@ -6006,7 +6012,10 @@ expect(obj.test).toBe(3);
 // extend_builtins_builtin_objects_throw_when_wrapped
 test_exec!(
     syntax(),
-    |t| chain!(classes(Some(t.comments.clone())), block_scoping()),
+    |t| chain!(
+        classes(Some(t.comments.clone()), Default::default()),
+        block_scoping()
+    ),
     extend_builtins_builtin_objects_throw_when_wrapped_exec,
     r#"
 // JSON is wrapped because it starts with an uppercase letter, but it
@@ -6055,7 +6064,10 @@ test_exec!(
     // Just don't do this.
     ignore,
     syntax(),
-    |t| chain!(classes(Some(t.comments.clone())), block_scoping()),
+    |t| chain!(
+        classes(Some(t.comments.clone()), Default::default()),
+        block_scoping()
+    ),
     extend_builtins_overwritten_null_exec,
     r#"
 var env = {
@@ -6076,7 +6088,10 @@ test_exec!(
     // Just don't do this. With is evil.
     ignore,
     syntax(),
-    |t| chain!(classes(Some(t.comments.clone())), block_scoping()),
+    |t| chain!(
+        classes(Some(t.comments.clone()), Default::default()),
+        block_scoping()
+    ),
     extend_builtins_super_called_exec,
     r#"
 var called = false;
@@ -6100,7 +6115,7 @@ with (env) {
 
 test_exec!(
     syntax(),
-    |t| classes(Some(t.comments.clone())),
+    |t| classes(Some(t.comments.clone()), Default::default()),
     issue_846,
     r#"
 class SomeClass {
@@ -6123,7 +6138,7 @@ expect(obj.anotherMethod()).toBe(2);
 
 test!(
     syntax(),
-    |t| classes(Some(t.comments.clone())),
+    |t| classes(Some(t.comments.clone()), Default::default()),
     issue_1490_1,
     "
     class ColouredCanvasElement extends CanvasElement {
@@ -6157,7 +6172,7 @@ test!(
 
 test!(
     syntax(),
-    |t| classes(Some(t.comments.clone())),
+    |t| classes(Some(t.comments.clone()), Default::default()),
     issue_1490_2,
     "
   class ColouredCanvasElement extends CanvasElement {
@@ -6191,7 +6206,7 @@ test!(
 
 test!(
     syntax(),
-    |t| classes(Some(t.comments.clone())),
+    |t| classes(Some(t.comments.clone()), Default::default()),
     super_binding,
     "
   class Foo {}
@@ -6229,7 +6244,7 @@ test!(
 
 test_exec!(
     syntax(),
-    |t| classes(Some(t.comments.clone())),
+    |t| classes(Some(t.comments.clone()), Default::default()),
     super_binding_exec,
     "
   class Foo {}
@@ -6245,7 +6260,7 @@ test_exec!(
 
 test!(
     syntax(),
-    |t| classes(Some(t.comments.clone())),
+    |t| classes(Some(t.comments.clone()), Default::default()),
     issue_1617_1,
     "
     class A extends B {
@@ -6278,7 +6293,7 @@ test!(
 
 test!(
     syntax(),
-    |t| classes(Some(t.comments.clone())),
+    |t| classes(Some(t.comments.clone()), Default::default()),
     issue_1617_2,
     "
   class A extends B {
@@ -6311,7 +6326,7 @@ test!(
 
 test!(
     syntax(),
-    |t| classes(Some(t.comments.clone())),
+    |t| classes(Some(t.comments.clone()), Default::default()),
     issue_1660_1,
     "
     class A {
@@ -6328,7 +6343,7 @@ test!(
 
 test!(
     syntax(),
-    |t| classes(Some(t.comments.clone())),
+    |t| classes(Some(t.comments.clone()), Default::default()),
     issue_1660_2,
     "
     const foo = class {run(){}};
@@ -6353,7 +6368,7 @@ test!(
 
 test!(
     syntax(),
-    |t| classes(Some(t.comments.clone())),
+    |t| classes(Some(t.comments.clone()), Default::default()),
     issue_1660_3,
     "
     console.log(class { run() { } });
@@ -6382,7 +6397,7 @@ test!(
         let global_mark = Mark::fresh(Mark::root());
 
         chain!(
-            es2022::es2022(es2022::Config { loose: false }),
+            es2022::es2022(Default::default()),
             es2018::es2018(Default::default()),
             es2017::es2017(),
             es2016::es2016(),
@@ -6423,7 +6438,7 @@ test!(
         let global_mark = Mark::fresh(Mark::root());
 
         chain!(
-            class_properties(class_properties::Config { loose: false }),
+            class_properties(Default::default()),
             es2015::es2015(
                 global_mark,
                 Some(t.comments.clone()),
@@ -6506,7 +6521,7 @@ test!(
         let global_mark = Mark::fresh(Mark::root());
 
         chain!(
-            class_properties(class_properties::Config { loose: false }),
+            class_properties(Default::default()),
             es2015::es2015(
                 global_mark,
                 Some(t.comments.clone()),
@@ -6553,7 +6568,7 @@ test!(
         let global_mark = Mark::fresh(Mark::root());
 
         chain!(
-            class_properties(class_properties::Config { loose: false }),
+            class_properties(Default::default()),
             es2015::es2015(
                 global_mark,
                 Some(t.comments.clone()),
@@ -6594,6 +6609,187 @@ var Extended = function(Base) {
     "
 );
 
+fn assumptions_tr(t: &Tester, config: classes::Config) -> impl Fold {
+    chain!(resolver(), classes(Some(t.comments.clone()), config))
+}
+
+test!(
+    syntax(),
+    |t| assumptions_tr(
+        t,
+        classes::Config {
+            no_class_calls: true,
+            ..Default::default()
+        }
+    ),
+    assumption_no_class_calls,
+    "
+class Foo {
+  bar() {}
+}
+",
+    "
+let Foo = function () {
+    'use strict';
+    function Foo() {}
+    _createClass(Foo, [{
+        key: 'bar',
+        value: function bar() {}
+    }]);
+    return Foo;
+}();
+"
+);
+
+test!(
+    syntax(),
+    |t| assumptions_tr(
+        t,
+        classes::Config {
+            set_class_methods: true,
+            ..Default::default()
+        }
+    ),
+    assumption_set_class_methods,
+    "
+class Foo {
+  bar() {}
+  static baz() {}
+  get qux() {}
+}
+",
+    "
+let Foo = function () {
+    'use strict';
+    function Foo() {
+        _classCallCheck(this, Foo);
+    }
+    var _proto = Foo.prototype;
+    _proto.bar = function bar() {};
+    Foo.baz = function baz() {};
+    _createClass(Foo, [{
+        key: 'qux',
+        get: function () {}
+    }]);
+    return Foo;
+}();
+"
+);
+
+test!(
+    syntax(),
+    |t| assumptions_tr(
+        t,
+        classes::Config {
+            constant_super: true,
+            ..Default::default()
+        }
+    ),
+    assumption_constant_super,
+    "
+class Foo extends Bar {
+  constructor() {
+    super();
+    super.x = 1;
+  }
+  foo() {
+    return super.foo();
+  }
+  static bar() {
+    return super.bar;
+  }
+}
+",
+    "
+let Foo = function (Bar) {
+    'use strict';
+    _inherits(Foo, Bar);
+    var _super = _createSuper(Foo);
+    function Foo() {
+        _classCallCheck(this, Foo);
+        var _this = _super.call(this);
+        _this.x = 1;
+        return _this;
+    }
+    _createClass(Foo, [{
+        key: 'foo',
+        value: function foo() {
+            return Bar.prototype.foo.call(this);
+        }
+    }], [{
+        key: 'bar',
+        value: function bar() {
+            return Bar.bar;
+        }
+    }]);
+    return Foo;
+}(Bar);
+"
+);
+
+test!(
+    syntax(),
+    |t| assumptions_tr(
+        t,
+        classes::Config {
+            super_is_callable_constructor: true,
+            ..Default::default()
+        }
+    ),
+    assumption_super_is_callable_constructor,
+    "
+class Foo extends Bar {
+  constructor() {
+    super();
+  }
+}
+",
+    "
+let Foo = function (Bar) {
+    'use strict';
+    _inherits(Foo, Bar);
+    function Foo() {
+        _classCallCheck(this, Foo);
+        return Bar.call(this) || this;
+    }
+    return Foo;
+}(Bar);
+"
+);
+
+test_exec!(
+    syntax(),
+    |t| assumptions_tr(
+        t,
+        classes::Config {
+            constant_super: true,
+            no_class_calls: true,
+            set_class_methods: true,
+            super_is_callable_constructor: true,
+        }
+    ),
+    assumption_all_exec,
+    "
+class Bar {
+  constructor(x) {
+    this.x = x;
+  }
+  foo() {
+    return this.x;
+  }
+}
+class Foo extends Bar {
+  constructor() {
+    super(1);
+  }
+  foo() {
+    return super.foo() + 1;
+  }
+}
+expect(new Foo().foo()).toBe(2);
+"
+);
+
 #[testing::fixture("tests/fixture/classes/**/exec.js")]
 fn exec(input: PathBuf) {
     let src = read_to_string(&input).unwrap();
@@ -6601,8 +6797,8 @@ fn exec(input: PathBuf) {
         Default::default(),
         |t| {
             chain!(
-                class_properties(class_properties::Config { loose: false }),
-                classes(Some(t.comments.clone()))
+                class_properties(Default::default()),
+                classes(Some(t.comments.clone()), Default::default())
             )
         },
         &src,
@@ -6617,8 +6813,8 @@ fn fixture(input: PathBuf) {
         Default::default(),
         &|t| {
             chain!(
-                class_properties(class_properties::Config { loose: false }),
-                classes(Some(t.comments.clone()))
+                class_properties(Default::default()),
+                classes(Some(t.comments.clone()), Default::default())
             )
         },
         &input,
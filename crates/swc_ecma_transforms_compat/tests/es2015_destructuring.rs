@@ -18,7 +18,13 @@ fn syntax() -> Syntax {
 }
 
 fn tr() -> impl Fold {
-    chain!(resolver(), destructuring(Config { loose: true }))
+    chain!(
+        resolver(),
+        destructuring(Config {
+            loose: true,
+            ..Default::default()
+        })
+    )
 }
 
 test!(
@@ -43,7 +49,10 @@ test!(
 
 test!(
     syntax(),
-    |_| destructuring(Config { loose: false }),
+    |_| destructuring(Config {
+        loose: false,
+        ..Default::default()
+    }),
     need_to_array,
     r#"const [x, y, ...z] = o;"#,
     r#"const _o = _toArray(o), x = _o[0], y = _o[1], z = _o.slice(2);"#,
@@ -52,7 +61,37 @@ test!(
 
 test!(
     syntax(),
-    |_| destructuring(Config { loose: true }),
+    |_| destructuring(Config {
+        allow_array_like: true,
+        ..Default::default()
+    }),
+    allow_array_like,
+    r#"const [x, y] = o;"#,
+    r#"const _o = _maybeArrayLike(_slicedToArray, o, 2), x = _o[0], y = _o[1];"#,
+    ok_if_code_eq
+);
+
+test_exec!(
+    syntax(),
+    |_| destructuring(Config {
+        allow_array_like: true,
+        ..Default::default()
+    }),
+    allow_array_like_exec,
+    r#"
+const [x, y, ...z] = { length: 3, 0: "a", 1: "b", 2: "c" };
+expect(x).toBe("a");
+expect(y).toBe("b");
+expect(z).toEqual(["c"]);
+"#
+);
+
+test!(
+    syntax(),
+    |_| destructuring(Config {
+        loose: true,
+        ..Default::default()
+    }),
     need_to_array_loose,
     r#"const [x, y, ...z] = o;"#,
     r#"const x = o[0], y = o[1], z = o.slice(2);"#,
@@ -61,7 +100,10 @@ test!(
 
 test!(
     syntax(),
-    |_| destructuring(Config { loose: false }),
+    |_| destructuring(Config {
+        loose: false,
+        ..Default::default()
+    }),
     issue_2841,
     r#"function foo(a,b)
     {
@@ -76,7 +118,10 @@ test!(
 
 test!(
     syntax(),
-    |_| destructuring(Config { loose: true }),
+    |_| destructuring(Config {
+        loose: true,
+        ..Default::default()
+    }),
     issue_2841_loose,
     r#"function foo(a,b)
     {
@@ -456,7 +501,10 @@ console.log((ref = [123], x = ref[0], ref));"#
 
 test_exec!(
     syntax(),
-    |_| destructuring(Config { loose: true }),
+    |_| destructuring(Config {
+        loose: true,
+        ..Default::default()
+    }),
     chained,
     r#"var a, b, c, d;
 ({ a, b } = ({ c, d } = { a: 1, b: 2, c: 3, d: 4}));
@@ -650,7 +698,10 @@ test!(
 test_exec!(
     ignore,
     syntax(),
-    |_| destructuring(Config { loose: true }),
+    |_| destructuring(Config {
+        loose: true,
+        ..Default::default()
+    }),
     fn_key_with_obj_rest_spread,
     r#"const { [(() => 1)()]: a, ...rest } = { 1: "a" };
 
@@ -675,7 +726,10 @@ for(let i = 0, length = list.length; i < length; i++){
 
 test_exec!(
     syntax(),
-    |_| destructuring(Config { loose: true }),
+    |_| destructuring(Config {
+        loose: true,
+        ..Default::default()
+    }),
     babel_issue_5090,
     r#"const assign = function([...arr], index, value) {
   arr[index] = value;
@@ -766,7 +820,10 @@ var x = coords.x, y = coords.y, foo = 'bar';"#
 test_exec!(
     ignore,
     syntax(),
-    |_| destructuring(Config { loose: true }),
+    |_| destructuring(Config {
+        loose: true,
+        ..Default::default()
+    }),
     number_key_with_object_spread,
     r#"const foo = {
   1: "a",
@@ -783,7 +840,10 @@ expect(rest).toEqual({ 2: "b", 3: "c" });"#
 test_exec!(
     ignore,
     syntax(),
-    |_| destructuring(Config { loose: true }),
+    |_| destructuring(Config {
+        loose: true,
+        ..Default::default()
+    }),
     spread_generator,
     r#"function* f() {
   for (var i = 0; i < 3; i++) {
@@ -1630,7 +1690,10 @@ ref = _slicedToArray(f(), 2), a = ref[0], b = ref[1], ref;
 test!(
     syntax(),
     |_| chain!(
-        destructuring(Config { loose: true }),
+        destructuring(Config {
+            loose: true,
+            ..Default::default()
+        }),
         spread(spread::Config {
             ..Default::default()
         }),
@@ -1805,7 +1868,10 @@ test!(
             ..Default::default()
         }),
         parameters(Default::default()),
-        destructuring(Config { loose: true }),
+        destructuring(Config {
+            loose: true,
+            ..Default::default()
+        }),
         block_scoping(),
         object_rest_spread(Default::default()),
     ),
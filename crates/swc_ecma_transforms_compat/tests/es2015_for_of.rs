@@ -289,10 +289,49 @@ try {
     ok_if_code_eq
 );
 
+test!(
+    syntax(),
+    |_| for_of(Config {
+        skip_iterator_closing: true,
+        ..Default::default()
+    }),
+    skip_iterator_closing,
+    r#"
+foo: for (const x of xs) {
+  if (x) break foo;
+}
+"#,
+    r#"
+foo: for(var _iterator = xs[Symbol.iterator](), _step; !(_step = _iterator.next()).done;){
+  const x = _step.value;
+  if (x) break foo;
+}
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| for_of(Config {
+        skip_iterator_closing: true,
+        ..Default::default()
+    }),
+    skip_iterator_closing_exec,
+    r#"
+const values = [];
+for (const v of new Set([1, 2, 3])) {
+  values.push(v);
+}
+expect(values).toEqual([1, 2, 3]);
+"#
+);
+
 // for_of_as_array_for_of
 test!(
     syntax(),
-    |_| for_of(Config { assume_array: true }),
+    |_| for_of(Config {
+        assume_array: true,
+        ..Default::default()
+    }),
     for_of_as_array_for_of,
     r#"
 let elm;
@@ -316,7 +355,10 @@ for(let _i = 0; _i < array.length; _i++){
 // for_of_as_array_for_of_array_pattern
 test!(
     syntax(),
-    |_| for_of(Config { assume_array: true }),
+    |_| for_of(Config {
+        assume_array: true,
+        ..Default::default()
+    }),
     for_of_as_array_for_of_array_pattern,
     r#"
 let elm;
@@ -339,7 +381,10 @@ for(let _i = 0; _i < array.length; _i++){
 // regression_redeclare_array_8913
 test!(
     syntax(),
-    |_| for_of(Config { assume_array: true }),
+    |_| for_of(Config {
+        assume_array: true,
+        ..Default::default()
+    }),
     regression_redeclare_array_8913,
     r#"
 function f(...t) {
@@ -363,7 +408,10 @@ function f(...t) {
 // for_of_as_array_for_of_declaration_array_pattern
 test!(
     syntax(),
-    |_| for_of(Config { assume_array: true }),
+    |_| for_of(Config {
+        assume_array: true,
+        ..Default::default()
+    }),
     for_of_as_array_for_of_declaration_array_pattern,
     r#"
 for (const [elm] of array) {
@@ -382,7 +430,10 @@ for(let _i = 0; _i < array.length; _i++){
 // for_of_as_array_for_of_expression
 test!(
     syntax(),
-    |_| for_of(Config { assume_array: true }),
+    |_| for_of(Config {
+        assume_array: true,
+        ..Default::default()
+    }),
     for_of_as_array_for_of_expression,
     r#"
 let i;
@@ -403,7 +454,10 @@ for(let _i = 0; _i < items.length; _i++){
 // for_of_as_array_for_of_declaration
 test!(
     syntax(),
-    |_| for_of(Config { assume_array: true }),
+    |_| for_of(Config {
+        assume_array: true,
+        ..Default::default()
+    }),
     for_of_as_array_for_of_declaration,
     r#"
 for (const elm of array) {
@@ -444,7 +498,10 @@ expect(results).toEqual([1, 2, 3]);
 // for_of_as_array_for_of_static_declaration
 test!(
     syntax(),
-    |_| for_of(Config { assume_array: true }),
+    |_| for_of(Config {
+        assume_array: true,
+        ..Default::default()
+    }),
     for_of_as_array_for_of_static_declaration,
     r#"
 const array = [];
@@ -468,7 +525,10 @@ for(let _i = 0; _i < array.length; _i++){
 // for_of_as_array_for_of_static
 test!(
     syntax(),
-    |_| for_of(Config { assume_array: true }),
+    |_| for_of(Config {
+        assume_array: true,
+        ..Default::default()
+    }),
     for_of_as_array_for_of_static,
     r#"
 const array = [];
@@ -494,7 +554,10 @@ for (let _i = 0; _i < array.length; _i++) {
 // for_of_as_array_for_of_import_es2015
 test!(
     syntax(),
-    |_| for_of(Config { assume_array: true }),
+    |_| for_of(Config {
+        assume_array: true,
+        ..Default::default()
+    }),
     for_of_as_array_for_of_import_es2015,
     r#"
 import { array } from "foo";
@@ -559,7 +622,10 @@ try {
 // regression_if_label_3858
 test!(
     syntax(),
-    |_| for_of(Config { assume_array: true }),
+    |_| for_of(Config {
+        assume_array: true,
+        ..Default::default()
+    }),
     regression_if_label_3858,
     r#"
 if ( true )
@@ -590,6 +656,7 @@ fn fixture(input: PathBuf) {
                 resolver_with_mark(top_level_mark),
                 for_of(Config {
                     assume_array: false,
+                    ..Default::default()
                 })
             )
         },
@@ -18,7 +18,10 @@ fn tr(c: Config) -> impl Fold {
     chain!(
         resolver(),
         parameters(c),
-        destructuring(destructuring::Config { loose: false }),
+        destructuring(destructuring::Config {
+            loose: false,
+            ..Default::default()
+        }),
         block_scoping(),
     )
 }
@@ -180,7 +183,10 @@ foo(1, 2, 3);"#
 
 test!(
     syntax(),
-    |t| chain!(classes(Some(t.comments.clone())), tr(Default::default())),
+    |t| chain!(
+        classes(Some(t.comments.clone()), Default::default()),
+        tr(Default::default())
+    ),
     default_iife_4253,
     r#"class Ref {
   constructor(id = ++Ref.nextID) {
@@ -216,7 +222,10 @@ expect(new Ref().id).toBe(2);"#
 
 test!(
     syntax(),
-    |t| chain!(classes(Some(t.comments.clone())), tr(Default::default())),
+    |t| chain!(
+        classes(Some(t.comments.clone()), Default::default()),
+        tr(Default::default())
+    ),
     default_iife_self,
     r#"class Ref {
   constructor(ref = Ref) {
@@ -1245,7 +1254,7 @@ test!(
     syntax(),
     |t| chain!(
         tr(Default::default()),
-        classes(Some(t.comments.clone())),
+        classes(Some(t.comments.clone()), Default::default()),
         spread(Default::default())
     ),
     rest_nested_iife,
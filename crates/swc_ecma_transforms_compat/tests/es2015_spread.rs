@@ -20,6 +20,31 @@ test!(
 (_instance = instance)[name].apply(_instance, _toConsumableArray(args));"
 );
 
+test!(
+    ::swc_ecma_parser::Syntax::default(),
+    |_| spread(spread::Config {
+        allow_array_like: true,
+        ..Default::default()
+    }),
+    allow_array_like,
+    "ca(a, ...b, ...c)",
+    "ca.apply(void 0, [a].concat(_maybeArrayLike(_toConsumableArray, b), \
+     _maybeArrayLike(_toConsumableArray, c)));"
+);
+
+test_exec!(
+    ::swc_ecma_parser::Syntax::default(),
+    |_| spread(spread::Config {
+        allow_array_like: true,
+        ..Default::default()
+    }),
+    allow_array_like_exec,
+    "
+const arrayLike = { length: 2, 0: 'a', 1: 'b' };
+expect([...arrayLike]).toEqual(['a', 'b']);
+"
+);
+
 test!(
     ::swc_ecma_parser::Syntax::default(),
     |_| tr(),
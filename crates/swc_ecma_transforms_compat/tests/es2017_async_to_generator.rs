@@ -41,7 +41,10 @@ fn tr() -> impl Fold {
         ParenRemover,
         arrow(),
         parameters(Default::default()),
-        destructuring(destructuring::Config { loose: false }),
+        destructuring(destructuring::Config {
+            loose: false,
+            ..Default::default()
+        }),
         function_name(),
         async_to_generator(),
         fixer(None)
@@ -1422,7 +1425,10 @@ test!(
     |_| chain!(
         async_to_generator(),
         parameters(Default::default()),
-        destructuring(destructuring::Config { loose: false }),
+        destructuring(destructuring::Config {
+            loose: false,
+            ..Default::default()
+        }),
     ),
     regression_4943,
     r#"
@@ -2289,10 +2295,7 @@ test!(
 
 test_exec!(
     Syntax::default(),
-    |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        async_to_generator()
-    ),
+    |_| chain!(class_properties(Default::default()), async_to_generator()),
     issue_1341_1_exec,
     "
     class A {
@@ -2345,10 +2348,7 @@ test!(
 
 test_exec!(
     Syntax::default(),
-    |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        async_to_generator()
-    ),
+    |_| chain!(class_properties(Default::default()), async_to_generator()),
     issue_1341_2_exec,
     "
   class A {
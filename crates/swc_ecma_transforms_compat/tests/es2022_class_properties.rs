@@ -30,8 +30,8 @@ fn tr(t: &Tester) -> impl Fold {
     chain!(
         resolver(),
         function_name(),
-        class_properties(class_properties::Config { loose: false }),
-        classes(Some(t.comments.clone())),
+        class_properties(Default::default()),
+        classes(Some(t.comments.clone()), Default::default()),
         block_scoping(),
         reserved_words(false),
     )
@@ -163,7 +163,7 @@ test!(
     |_| chain!(
         resolver(),
         function_name(),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
     ),
     private_class_method,
     r#"
@@ -2841,10 +2841,7 @@ var _x = {
 
 test!(
     syntax(),
-    |_| chain!(
-        resolver(),
-        class_properties(class_properties::Config { loose: false })
-    ),
+    |_| chain!(resolver(), class_properties(Default::default())),
     issue_308,
     "function bar(props) {}
 class Foo {
@@ -2874,8 +2871,8 @@ test!(
     syntax(),
     |t| chain!(
         resolver(),
-        class_properties(class_properties::Config { loose: false }),
-        classes(Some(t.comments.clone()))
+        class_properties(Default::default()),
+        classes(Some(t.comments.clone()), Default::default())
     ),
     issue_342,
     "class Foo {
@@ -2903,7 +2900,7 @@ test!(
     syntax(),
     |_| chain!(
         resolver(),
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
         block_scoping()
     ),
     issue_443,
@@ -2930,10 +2927,7 @@ _defineProperty(foo, 'MODE', MODE);"
 // public_regression_t7364
 test!(
     syntax(),
-    |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        async_to_generator()
-    ),
+    |_| chain!(class_properties(Default::default()), async_to_generator()),
     public_regression_t7364,
     r#"
 class MyClass {
@@ -2989,10 +2983,7 @@ export default class MyClass3 {
 // private_regression_t6719
 test!(
     syntax(),
-    |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        block_scoping()
-    ),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_regression_t6719,
     r#"
 function withContext(ComposedComponent) {
@@ -3078,10 +3069,7 @@ function withContext(ComposedComponent) {
 // private_reevaluated
 test!(
     syntax(),
-    |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        block_scoping()
-    ),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_reevaluated,
     r#"
 function classFactory() {
@@ -3145,10 +3133,7 @@ function classFactory() {
 // private_static
 test!(
     syntax(),
-    |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        block_scoping()
-    ),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_static,
     r#"
 class Foo {
@@ -3195,8 +3180,8 @@ expect(Foo.test()).toBe("foo");
 test!(
     syntax(),
     |t| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        classes(Some(t.comments.clone())),
+        class_properties(Default::default()),
+        classes(Some(t.comments.clone()), Default::default()),
         block_scoping()
     ),
     private_destructuring_object_pattern_1,
@@ -3237,10 +3222,7 @@ var _client = new WeakMap();
 // private_static_inherited
 test!(
     syntax(),
-    |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        block_scoping()
-    ),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_static_inherited,
     r#"
 class Base {
@@ -3319,7 +3301,7 @@ class Sub2 extends Base {}
 // private_destructuring_object_pattern_1_exec
 test_exec!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     private_destructuring_object_pattern_1_exec,
     r#"
 class Foo {
@@ -3346,10 +3328,7 @@ expect(foo.z).toBe('bar');
 // private_static_undefined
 test!(
     syntax(),
-    |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        block_scoping()
-    ),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_static_undefined,
     r#"
 class Foo {
@@ -3389,8 +3368,8 @@ var _bar = {
 test!(
     syntax(),
     |t| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        classes(Some(t.comments.clone())),
+        class_properties(Default::default()),
+        classes(Some(t.comments.clone()), Default::default()),
         block_scoping()
     ),
     private_destructuring_array_pattern,
@@ -3426,10 +3405,7 @@ var _client = new WeakMap();
 // private_regression_t2983
 test!(
     syntax(),
-    |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        block_scoping()
-    ),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_regression_t2983,
     r#"
 call(class {
@@ -3467,7 +3443,7 @@ export { _class as default }
 test!(
     syntax(),
     |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
         async_to_generator(),
         block_scoping()
     ),
@@ -3542,8 +3518,8 @@ export { MyClass3 as default };
 test!(
     syntax(),
     |t| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        classes(Some(t.comments.clone())),
+        class_properties(Default::default()),
+        classes(Some(t.comments.clone()), Default::default()),
         block_scoping()
     ),
     private_destructuring_array_pattern_1,
@@ -3580,7 +3556,7 @@ var _client = new WeakMap();
 // regression_8882_exec
 test_exec!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     regression_8882_exec,
     r#"
 const classes = [];
@@ -3612,7 +3588,7 @@ for(let i=0; i<= 10; ++i) {
 //// regression_6154
 //test!(syntax(),|_| tr("{
 //  "presets": ["env"],
-//  "plugins": class_properties(class_properties::Config { loose: false })
+//  "plugins": class_properties(Default::default())
 //}
 //"), regression_6154, r#"
 //class Test {
@@ -3718,10 +3694,7 @@ for(let i=0; i<= 10; ++i) {
 // private_static_export
 test!(
     syntax(),
-    |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        block_scoping()
-    ),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_static_export,
     r#"
 export class MyClass {
@@ -3753,8 +3726,8 @@ export { MyClass2 as default }
 test!(
     syntax(),
     |t| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        classes(Some(t.comments.clone()))
+        class_properties(Default::default()),
+        classes(Some(t.comments.clone()), Default::default())
     ),
     static_property_tdz_edgest_case,
     r#"
@@ -3782,8 +3755,8 @@ _defineProperty(A, _x, void 0);
 test!(
     syntax(),
     |t| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        classes(Some(t.comments.clone()))
+        class_properties(Default::default()),
+        classes(Some(t.comments.clone()), Default::default())
     ),
     static_property_tdz_false_alarm,
     r#"
@@ -3803,10 +3776,7 @@ _defineProperty(A, "A", 123)
 // regression_6153
 test!(
     syntax(),
-    |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        arrow()
-    ),
+    |_| chain!(class_properties(Default::default()), arrow()),
     regression_6153,
     r#"
 () => {
@@ -3911,10 +3881,7 @@ var qux = (function () {
 // regression_7371
 test!(
     syntax(),
-    |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        arrow()
-    ),
+    |_| chain!(class_properties(Default::default()), arrow()),
     regression_7371,
     r#"
 "use strict";
@@ -4125,8 +4092,8 @@ new ComputedField();
 test!(
     syntax(),
     |t| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        classes(Some(t.comments.clone())),
+        class_properties(Default::default()),
+        classes(Some(t.comments.clone()), Default::default()),
         block_scoping()
     ),
     private_canonical,
@@ -4216,7 +4183,7 @@ var _y = new WeakMap();
 // regression_8882
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     regression_8882,
     r#"
 const classes = [];
@@ -4269,8 +4236,8 @@ for(let i = 0; i <= 10; ++i){
 test!(
     syntax(),
     |t| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        classes(Some(t.comments.clone())),
+        class_properties(Default::default()),
+        classes(Some(t.comments.clone()), Default::default()),
         block_scoping()
     ),
     private_destructuring_array_pattern_3,
@@ -4305,7 +4272,7 @@ var _client = new WeakMap();
 // public_static_super_exec
 test_exec!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     public_static_super_exec,
     r#"
 class A {
@@ -4331,8 +4298,8 @@ expect(getPropA()).toBe(1);
 test!(
     syntax(),
     |t| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        classes(Some(t.comments.clone())),
+        class_properties(Default::default()),
+        classes(Some(t.comments.clone()), Default::default()),
         block_scoping()
     ),
     private_destructuring_array_pattern_2,
@@ -4367,10 +4334,7 @@ var _client = new WeakMap();
 // private_non_block_arrow_func
 test!(
     syntax(),
-    |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        block_scoping()
-    ),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_non_block_arrow_func,
     r#"
 export default param =>
@@ -4410,7 +4374,7 @@ export default ((param)=>{
 // regression_8110
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     regression_8110,
     r#"
 const field = Symbol('field');
@@ -4436,7 +4400,7 @@ class A{
 // public_computed_without_block_exec
 test_exec!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     public_computed_without_block_exec,
     r#"
 const createClass = (k) => class { [k()] = 2 };
@@ -4451,9 +4415,9 @@ expect(instance.foo).toBe(2);
 test!(
     syntax(),
     |t| chain!(
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
         exponentation(),
-        classes(Some(t.comments.clone())),
+        classes(Some(t.comments.clone()), Default::default()),
         block_scoping(),
     ),
     private_instance,
@@ -4484,8 +4448,8 @@ var _bar = new WeakMap();
 test!(
     syntax(),
     |t| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        classes(Some(t.comments.clone()))
+        class_properties(Default::default()),
+        classes(Some(t.comments.clone()), Default::default())
     ),
     static_property_tdz_general,
     r#"
@@ -4511,10 +4475,7 @@ _defineProperty(C, _ref, 3);
 // public_native_classes
 test!(
     syntax(),
-    |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        block_scoping()
-    ),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     public_native_classes,
     r#"
 class Foo {
@@ -4566,10 +4527,7 @@ test!(
     // Seems useless, while being hard to implement.
     ignore,
     syntax(),
-    |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        block_scoping()
-    ),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_static_infer_name,
     r#"
 var Foo = class {
@@ -4591,10 +4549,7 @@ var Foo = (_temp = _class = class Foo {}, _num = {
 // regression_7951
 test!(
     syntax(),
-    |_| chain!(
-        resolver(),
-        class_properties(class_properties::Config { loose: false })
-    ),
+    |_| chain!(resolver(), class_properties(Default::default())),
     regression_7951,
     r#"
 export class Foo extends Bar {
@@ -4620,10 +4575,7 @@ _defineProperty(Foo, "foo", {});
 // private_native_classes
 test!(
     syntax(),
-    |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        block_scoping()
-    ),
+    |_| chain!(class_properties(Default::default()), block_scoping()),
     private_native_classes,
     r#"
 class Foo {
@@ -4672,8 +4624,8 @@ var _bar = new WeakMap();
 test!(
     syntax(),
     |t| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        classes(Some(t.comments.clone())),
+        class_properties(Default::default()),
+        classes(Some(t.comments.clone()), Default::default()),
         block_scoping()
     ),
     public_computed_without_block,
@@ -4698,7 +4650,7 @@ var createClass = (k)=>{
 // private_destructuring_array_pattern_2_exec
 test_exec!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     private_destructuring_array_pattern_2_exec,
     r#"
 class Foo {
@@ -4724,8 +4676,8 @@ expect(foo.getClient()).toEqual(['bar', 'baz', 'quu']);
 test!(
     syntax(),
     |t| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        classes(Some(t.comments.clone())),
+        class_properties(Default::default()),
+        classes(Some(t.comments.clone()), Default::default()),
         block_scoping()
     ),
     public_static_super,
@@ -4775,7 +4727,7 @@ _defineProperty(B, "getPropA", () => _get(_getPrototypeOf(B), "prop", B));
 // private_destructuring_array_pattern_exec
 test_exec!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     private_destructuring_array_pattern_exec,
     r#"
 class Foo {
@@ -4799,7 +4751,7 @@ expect(foo.getClient()).toBe('bar');
 // private_destructuring_array_pattern_1_exec
 test_exec!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     private_destructuring_array_pattern_1_exec,
     r#"
 class Foo {
@@ -4825,10 +4777,7 @@ expect(foo.y).toBe('bar');
 
 test!(
     ts(),
-    |_| chain!(
-        resolver(),
-        class_properties(class_properties::Config { loose: false })
-    ),
+    |_| chain!(resolver(), class_properties(Default::default())),
     issue_890_1,
     "const DURATION = 1000
 
@@ -4858,7 +4807,7 @@ export class HygieneTest {
 
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     issue_1306_1,
     r#"
   class Animal {
@@ -4892,7 +4841,7 @@ test!(
 
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     issue_1306_2,
     r#"
 class Animal {
@@ -4926,7 +4875,7 @@ var _name = new WeakMap();
 
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     issue_1333_1,
     "
   class Foo {
@@ -4961,7 +4910,7 @@ test!(
 
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     issue_1333_2,
     "
   class Test {
@@ -5146,7 +5095,7 @@ test!(
 
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     issue_1333_3,
     "
     class Test {
@@ -5205,7 +5154,7 @@ test!(
 
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     issue_1333_4,
     "
   class Test {
@@ -5251,7 +5200,7 @@ test!(
 
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     issue_1333_5,
     "
     class Test {
@@ -5279,7 +5228,7 @@ test!(
 
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     issue_1333_6,
     "
     class Test {
@@ -5307,7 +5256,7 @@ test!(
 
 test!(
     syntax(),
-    |_| { class_properties(class_properties::Config { loose: false }) },
+    |_| { class_properties(Default::default()) },
     issue_1660_1,
     "
     console.log(class { run() { } });
@@ -5322,7 +5271,7 @@ test!(
 
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     issue_3055_1,
     "
 export class Node {
@@ -5361,10 +5310,7 @@ function baz(child) {}
 
 test!(
     syntax(),
-    |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        async_to_generator()
-    ),
+    |_| chain!(class_properties(Default::default()), async_to_generator()),
     issue_1694_1,
     "
     class MyClass {
@@ -5392,10 +5338,7 @@ test!(
 
 test!(
     syntax(),
-    |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        async_to_generator()
-    ),
+    |_| chain!(class_properties(Default::default()), async_to_generator()),
     issue_1694_2,
     "
 class MyClass {
@@ -5421,10 +5364,7 @@ class MyClass {
 
 test!(
     syntax(),
-    |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
-        async_to_generator()
-    ),
+    |_| chain!(class_properties(Default::default()), async_to_generator()),
     issue_1702_1,
     "
     class Foo {
@@ -5473,7 +5413,7 @@ test!(
 
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     issue_1711_1,
     "
     class Foo {
@@ -5505,7 +5445,7 @@ test!(
 
 test_exec!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     issue_1742_1,
     "
     class Foo {
@@ -5530,7 +5470,7 @@ test_exec!(
 test_exec!(
     syntax(),
     |_| chain!(
-        class_properties(class_properties::Config { loose: false }),
+        class_properties(Default::default()),
         template_literal(Default::default())
     ),
     issue_1742_2,
@@ -5556,7 +5496,7 @@ test_exec!(
 
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     issue_1742_3,
     "
     class Foo {
@@ -5601,7 +5541,7 @@ test!(
 
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     issue_1869_1,
     "
     class TestClass {
@@ -5631,7 +5571,7 @@ test!(
 
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     issue_1869_2,
     "
     var _class;
@@ -5664,7 +5604,7 @@ test!(
 
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     issue_2021_1,
     "
     class Item extends Component {
@@ -5687,7 +5627,7 @@ test!(
 
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     issue_3229_1,
     "
 class A {
@@ -5720,7 +5660,7 @@ var _D = new WeakMap();
 
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     issue_3229_2,
     "
 class A {
@@ -5755,7 +5695,7 @@ var _b = new WeakMap();
 
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     issue_3368,
     "
 class A {
@@ -5801,7 +5741,7 @@ function bar() {}
 
 test!(
     syntax(),
-    |_| class_properties(class_properties::Config { loose: false }),
+    |_| class_properties(Default::default()),
     nested_class_in_arrow,
     "
 const a = () => class {
@@ -5832,12 +5772,132 @@ const a = ()=>{
 "
 );
 
+test!(
+    syntax(),
+    |_| chain!(
+        resolver(),
+        class_properties(class_properties::Config {
+            set_public_class_fields: true,
+            ..Default::default()
+        })
+    ),
+    assumption_set_public_class_fields,
+    "
+class Foo {
+  a = 1;
+  ['b'] = 2;
+  static c = 3;
+}
+",
+    "
+var _ref = 'b';
+class Foo {
+    constructor() {
+        this.a = 1;
+        this[_ref] = 2;
+    }
+}
+Foo.c = 3;
+"
+);
+
+test!(
+    Syntax::Es(EsConfig {
+        private_in_object: true,
+        ..Default::default()
+    }),
+    |_| chain!(
+        resolver(),
+        class_properties(class_properties::Config {
+            private_fields_as_properties: true,
+            ..Default::default()
+        })
+    ),
+    assumption_private_fields_as_properties,
+    "
+class Foo {
+  #a = 1;
+  static #b = 2;
+  #c() {}
+  get #d() {}
+  set #d(v) {}
+  test(other) {
+    this.#c();
+    return #a in other ? this.#a + Foo.#b + this.#d : 0;
+  }
+}
+",
+    "
+var _c = _classPrivateFieldLooseKey('c'), _d = _classPrivateFieldLooseKey('d');
+class Foo {
+    test(other) {
+        _classPrivateFieldLooseBase(this, _c)[_c]();
+        return Object.prototype.hasOwnProperty.call(other, _a) ? _classPrivateFieldLooseBase(this, \
+     _a)[_a] + _classPrivateFieldLooseBase(Foo, _b)[_b] + _classPrivateFieldLooseBase(this, \
+     _d)[_d] : 0;
+    }
+    constructor() {
+        Object.defineProperty(this, _c, {
+            value: c
+        });
+        Object.defineProperty(this, _d, {
+            get: get_d,
+            set: set_d
+        });
+        Object.defineProperty(this, _a, {
+            writable: true,
+            value: 1
+        });
+    }
+}
+var _a = _classPrivateFieldLooseKey('a');
+var _b = _classPrivateFieldLooseKey('b');
+Object.defineProperty(Foo, _b, {
+    writable: true,
+    value: 2
+});
+function c() {}
+function get_d() {}
+function set_d(v) {}
+"
+);
+
+test_exec!(
+    Syntax::Es(EsConfig {
+        private_in_object: true,
+        ..Default::default()
+    }),
+    |_| class_properties(class_properties::Config {
+        set_public_class_fields: true,
+        private_fields_as_properties: true,
+    }),
+    assumption_all_exec,
+    "
+class Foo {
+  a = 1;
+  #b = 2;
+  static #c = 3;
+  #d() {
+    return this.#b;
+  }
+  get #e() {
+    return Foo.#c;
+  }
+  test(other) {
+    return #b in other && this.a + this.#d() + this.#e === 6;
+  }
+}
+expect(new Foo().test(new Foo())).toBe(true);
+expect(new Foo().test({})).toBe(false);
+"
+);
+
 #[testing::fixture("tests/fixture/classes/**/exec.js")]
 fn exec(input: PathBuf) {
     let src = read_to_string(&input).unwrap();
     compare_stdout(
         Default::default(),
-        |_| class_properties(class_properties::Config { loose: false }),
+        |_| class_properties(Default::default()),
         &src,
     );
 }
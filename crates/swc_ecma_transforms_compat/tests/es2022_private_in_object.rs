@@ -55,7 +55,10 @@ fn fixture(input: PathBuf) {
                             pass = Box::new(chain!(
                                 pass,
                                 class_properties(class_properties::Config {
-                                    loose: input.to_string_lossy().contains("private-loose")
+                                    private_fields_as_properties: input
+                                        .to_string_lossy()
+                                        .contains("private-loose"),
+                                    ..Default::default()
                                 })
                             ));
                         }
@@ -67,14 +70,20 @@ fn fixture(input: PathBuf) {
                             pass = Box::new(chain!(
                                 pass,
                                 class_properties(class_properties::Config {
-                                    loose: input.to_string_lossy().contains("private-loose")
+                                    private_fields_as_properties: input
+                                        .to_string_lossy()
+                                        .contains("private-loose"),
+                                    ..Default::default()
                                 })
                             ));
                         }
                     }
 
                     "transform-classes" => {
-                        pass = Box::new(chain!(pass, classes(Some(t.comments.clone()))));
+                        pass = Box::new(chain!(
+                            pass,
+                            classes(Some(t.comments.clone()), Default::default())
+                        ));
                     }
 
                     _ => {
@@ -0,0 +1,5 @@
+#![deny(clippy::all)]
+
+pub use self::strip::*;
+
+pub mod strip;
@@ -0,0 +1,266 @@
+use swc_common::{errors::HANDLER, util::take::Take};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Strips flow type annotations out.
+///
+/// Flow is parsed into typescript nodes, so this pass removes them. Unlike
+/// `swc_ecma_transforms_typescript::strip`, imports are removed only if they
+/// are type-only, because flow does not elide unused imports.
+///
+/// Class properties without initializers are removed, like
+/// `@babel/plugin-transform-flow-strip-types` does.
+pub fn strip() -> impl Fold + VisitMut {
+    as_folder(Strip)
+}
+
+#[derive(Debug, Default)]
+struct Strip;
+
+impl VisitMut for Strip {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        for item in items.iter_mut() {
+            if let ModuleItem::ModuleDecl(decl) = item {
+                remove_type_only_specifiers(decl);
+            }
+        }
+
+        items.retain(|item| match item {
+            ModuleItem::ModuleDecl(decl) => !is_type_only_module_decl(decl),
+            ModuleItem::Stmt(stmt) => !is_type_only_stmt(stmt),
+        });
+
+        items.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.retain(|stmt| !is_type_only_stmt(stmt));
+
+        stmts.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_class(&mut self, class: &mut Class) {
+        class.visit_mut_children_with(self);
+
+        class.implements.clear();
+        class.type_params = None;
+        class.super_type_params = None;
+        class.is_abstract = false;
+    }
+
+    fn visit_mut_class_members(&mut self, members: &mut Vec<ClassMember>) {
+        members.retain(|member| match member {
+            ClassMember::TsIndexSignature(..) => false,
+            ClassMember::ClassProp(prop) => {
+                !prop.declare && (prop.value.is_some() || !prop.decorators.is_empty())
+            }
+            ClassMember::Method(method) => method.function.body.is_some(),
+            ClassMember::Constructor(constructor) => constructor.body.is_some(),
+            _ => true,
+        });
+
+        members.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_class_prop(&mut self, prop: &mut ClassProp) {
+        prop.visit_mut_children_with(self);
+
+        prop.type_ann = None;
+        prop.is_optional = false;
+        prop.readonly = false;
+        prop.definite = false;
+    }
+
+    fn visit_mut_private_prop(&mut self, prop: &mut PrivateProp) {
+        prop.visit_mut_children_with(self);
+
+        prop.type_ann = None;
+        prop.is_optional = false;
+        prop.readonly = false;
+        prop.definite = false;
+    }
+
+    fn visit_mut_function(&mut self, f: &mut Function) {
+        f.visit_mut_children_with(self);
+
+        // `this` parameter
+        f.params.retain(|param| match &param.pat {
+            Pat::Ident(i) => &*i.id.sym != "this",
+            _ => true,
+        });
+        f.type_params = None;
+        f.return_type = None;
+    }
+
+    fn visit_mut_arrow_expr(&mut self, f: &mut ArrowExpr) {
+        f.visit_mut_children_with(self);
+
+        f.type_params = None;
+        f.return_type = None;
+    }
+
+    fn visit_mut_getter_prop(&mut self, prop: &mut GetterProp) {
+        prop.visit_mut_children_with(self);
+
+        prop.type_ann = None;
+    }
+
+    fn visit_mut_binding_ident(&mut self, i: &mut BindingIdent) {
+        i.visit_mut_children_with(self);
+
+        i.id.optional = false;
+        i.type_ann = None;
+    }
+
+    fn visit_mut_array_pat(&mut self, pat: &mut ArrayPat) {
+        pat.visit_mut_children_with(self);
+
+        pat.optional = false;
+        pat.type_ann = None;
+    }
+
+    fn visit_mut_object_pat(&mut self, pat: &mut ObjectPat) {
+        pat.visit_mut_children_with(self);
+
+        pat.optional = false;
+        pat.type_ann = None;
+    }
+
+    fn visit_mut_rest_pat(&mut self, pat: &mut RestPat) {
+        pat.visit_mut_children_with(self);
+
+        pat.type_ann = None;
+    }
+
+    fn visit_mut_assign_pat(&mut self, pat: &mut AssignPat) {
+        pat.visit_mut_children_with(self);
+
+        pat.type_ann = None;
+    }
+
+    fn visit_mut_var_declarator(&mut self, d: &mut VarDeclarator) {
+        d.visit_mut_children_with(self);
+
+        d.definite = false;
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        // Type casts
+        while let Expr::TsAs(TsAsExpr { expr, .. })
+        | Expr::TsTypeAssertion(TsTypeAssertion { expr, .. })
+        | Expr::TsConstAssertion(TsConstAssertion { expr, .. })
+        | Expr::TsNonNull(TsNonNullExpr { expr, .. }) = e
+        {
+            *e = *expr.take();
+        }
+    }
+
+    fn visit_mut_call_expr(&mut self, e: &mut CallExpr) {
+        e.visit_mut_children_with(self);
+
+        e.type_args = None;
+    }
+
+    fn visit_mut_new_expr(&mut self, e: &mut NewExpr) {
+        e.visit_mut_children_with(self);
+
+        e.type_args = None;
+    }
+
+    fn visit_mut_tagged_tpl(&mut self, e: &mut TaggedTpl) {
+        e.visit_mut_children_with(self);
+
+        e.type_params = None;
+    }
+
+    fn visit_mut_jsx_opening_element(&mut self, e: &mut JSXOpeningElement) {
+        e.visit_mut_children_with(self);
+
+        e.type_args = None;
+    }
+}
+
+/// Removes type-only specifiers, and marks the declaration as type-only if
+/// all specifiers are removed.
+fn remove_type_only_specifiers(decl: &mut ModuleDecl) {
+    match decl {
+        ModuleDecl::Import(import) => {
+            let had_specifiers = !import.specifiers.is_empty();
+            import.specifiers.retain(|s| {
+                !matches!(
+                    s,
+                    ImportSpecifier::Named(ImportNamedSpecifier {
+                        is_type_only: true,
+                        ..
+                    })
+                )
+            });
+
+            if had_specifiers && import.specifiers.is_empty() {
+                import.type_only = true;
+            }
+        }
+        ModuleDecl::ExportNamed(export) => {
+            let had_specifiers = !export.specifiers.is_empty();
+            export.specifiers.retain(|s| {
+                !matches!(
+                    s,
+                    ExportSpecifier::Named(ExportNamedSpecifier {
+                        is_type_only: true,
+                        ..
+                    })
+                )
+            });
+
+            if had_specifiers && export.specifiers.is_empty() {
+                export.type_only = true;
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_type_only_module_decl(decl: &ModuleDecl) -> bool {
+    match decl {
+        ModuleDecl::Import(import) => import.type_only,
+        ModuleDecl::ExportNamed(export) => export.type_only,
+        ModuleDecl::ExportDecl(export) => is_type_only_decl(&export.decl),
+        ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+            decl: DefaultDecl::TsInterfaceDecl(..),
+            ..
+        }) => true,
+        _ => false,
+    }
+}
+
+fn is_type_only_stmt(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Decl(decl) => is_type_only_decl(decl),
+        _ => false,
+    }
+}
+
+fn is_type_only_decl(decl: &Decl) -> bool {
+    match decl {
+        Decl::TsInterface(..) | Decl::TsTypeAlias(..) | Decl::TsModule(..) => true,
+        Decl::Class(c) => c.declare,
+        Decl::Fn(f) => f.declare || f.function.body.is_none(),
+        Decl::Var(v) => v.declare,
+        // Enums of typescript are not valid in flow, so they can only come from
+        // other passes.
+        Decl::TsEnum(e) => {
+            if !e.declare {
+                HANDLER.with(|handler| {
+                    handler
+                        .struct_span_err(e.span, "enums are not supported by flow")
+                        .emit()
+                });
+            }
+            true
+        }
+    }
+}
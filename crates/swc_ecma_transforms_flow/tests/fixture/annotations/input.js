@@ -0,0 +1,12 @@
+// @flow
+function foo<T: Object>(this: Window, a: ?string, b?: number, ...rest: Array<T>): Promise<void> {
+  const c: { a: number, b: string } = { a: 1, b: "" };
+  let d: (string, number) => void;
+  let e: string => void = (x) => {};
+}
+
+const f = <T>(x: T): T => x;
+const g = ({ h }: Props, [i]: Array<number> = []) => h + i;
+const j = (k: number): (number => string) => (l) => String(k + l);
+const m = new Map<string, number>();
+const n = <Component<Props> prop="value" />;
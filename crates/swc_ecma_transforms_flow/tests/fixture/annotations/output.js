@@ -0,0 +1,16 @@
+function foo(a, b, ...rest) {
+    const c = {
+        a: 1,
+        b: ""
+    };
+    let d;
+    let e = (x)=>{};
+}
+const f = (x)=>x
+;
+const g = ({ h  }, [i] = [])=>h + i
+;
+const j = (k)=>(l)=>String(k + l)
+;
+const m = new Map();
+const n = <Component prop="value"/>;
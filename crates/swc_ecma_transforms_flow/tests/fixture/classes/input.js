@@ -0,0 +1,15 @@
+class A<+T> extends B<T> implements C, D<T> {
+  +a: T;
+  -b: number = 1;
+  c: string;
+  static d: ?string;
+  static e: number = 2;
+  #f: number;
+  #g: number = 3;
+  declare h: string;
+  @dec i: number;
+
+  m<U>(x: U): T {
+    return (x: any);
+  }
+}
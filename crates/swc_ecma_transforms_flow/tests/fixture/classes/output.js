@@ -0,0 +1,10 @@
+class A extends B {
+    b = 1;
+    static e = 2;
+    #f;
+    #g = 3;
+    i;
+    m(x) {
+        return x;
+    }
+}
@@ -0,0 +1,23 @@
+type A = string;
+opaque type B: string = string;
+export type C = {| a: A, b?: B |};
+export opaque type D = number;
+interface E {
+  +a: string;
+}
+declare var f: number;
+declare function g(x: string): void;
+declare class H {
+  m(): void;
+}
+declare module "i" {
+  declare function j(): void;
+}
+declare type K = number;
+
+function l() {
+  type M = string;
+  return 1;
+}
+
+export const n = 1;
@@ -0,0 +1,4 @@
+function l() {
+    return 1;
+}
+export const n = 1;
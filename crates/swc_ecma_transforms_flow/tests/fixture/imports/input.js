@@ -0,0 +1,13 @@
+import type A from "a";
+import type { B } from "b";
+import typeof C from "c";
+import { type D, typeof E } from "d";
+import { type F, G } from "f";
+import H, { type I } from "h";
+import "side-effect";
+
+export type { A, B };
+export { type D, G };
+export type J = number;
+
+G(H);
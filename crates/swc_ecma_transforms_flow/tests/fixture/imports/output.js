@@ -0,0 +1,5 @@
+import { G } from "f";
+import H from "h";
+import "side-effect";
+export { G };
+G(H);
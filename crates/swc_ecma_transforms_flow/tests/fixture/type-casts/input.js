@@ -0,0 +1,4 @@
+const a = (b: any);
+const c = ((d.e: any): string);
+foo((f: number), g);
+const h = ({}: { [string]: number });
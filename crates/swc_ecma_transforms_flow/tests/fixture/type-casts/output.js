@@ -0,0 +1,4 @@
+const a = b;
+const c = d.e;
+foo(f, g);
+const h = {};
@@ -0,0 +1,18 @@
+use std::path::PathBuf;
+use swc_ecma_parser::{FlowConfig, Syntax};
+use swc_ecma_transforms_flow::strip;
+use swc_ecma_transforms_testing::test_fixture;
+
+#[testing::fixture("tests/fixture/**/input.js")]
+fn fixture(input: PathBuf) {
+    let output = input.with_file_name("output.js");
+    test_fixture(
+        Syntax::Flow(FlowConfig {
+            jsx: true,
+            decorators: true,
+        }),
+        &|_| strip(),
+        &input,
+        &output,
+    );
+}
@@ -1,7 +1,7 @@
 pub use super::util::Config;
 use super::util::{
-    define_es_module, define_property, has_use_strict, initialize_to_undefined, make_descriptor,
-    make_require_call, use_strict, ModulePass, Scope,
+    define_es_module, define_reexport, has_use_strict, initialize_to_undefined, make_require_call,
+    use_strict, ModulePass, Scope,
 };
 use crate::path::{ImportResolver, NoopImportResolver};
 use indexmap::IndexSet;
@@ -17,7 +17,7 @@ use swc_common::{
 use swc_ecma_ast::*;
 use swc_ecma_transforms_base::helper;
 use swc_ecma_utils::{
-    ident::IdentLike, member_expr, private_ident, quote_ident, quote_str, var::VarCollector,
+    ident::IdentLike, member_expr, private_ident, quote_ident, var::VarCollector,
     DestructuringFinder, ExprFactory, IsDirective,
 };
 use swc_ecma_visit::{noop_fold_type, noop_visit_type, Fold, FoldWith, Visit, VisitWith};
@@ -224,7 +224,10 @@ where
                     if !self.config.strict && !emitted_esmodule {
                         emitted_esmodule = true;
 
-                        stmts.push(ModuleItem::Stmt(define_es_module(quote_ident!("exports"))));
+                        stmts.push(ModuleItem::Stmt(define_es_module(
+                            quote_ident!("exports"),
+                            self.config.enumerable_module_meta,
+                        )));
                     }
 
                     let mut scope_ref_mut = self.scope.borrow_mut();
@@ -666,23 +669,23 @@ where
                                         .into(),
                                     );
                                 } else {
-                                    stmts.push(
-                                        define_property(vec![
-                                            quote_ident!("exports").as_arg(),
-                                            {
-                                                // export { foo }
-                                                //  -> 'foo'
-
-                                                // export { foo as bar }
-                                                //  -> 'bar'
-                                                let i = exported.unwrap_or(orig).clone();
-                                                Lit::Str(quote_str!(i.span, i.sym)).as_arg()
-                                            },
-                                            make_descriptor(value).as_arg(),
-                                        ])
-                                        .into_stmt()
-                                        .into(),
-                                    );
+                                    let stmt = define_reexport(
+                                        quote_ident!("exports"),
+                                        // export { foo } -> 'foo'
+                                        // export { foo as bar } -> 'bar'
+                                        exported.unwrap_or(orig).clone(),
+                                        value,
+                                        self.config.constant_reexports,
+                                    )
+                                    .into();
+
+                                    // Getters can be defined before `require` calls, but
+                                    // assignments should come after them.
+                                    if self.config.constant_reexports {
+                                        extra_stmts.push(stmt);
+                                    } else {
+                                        stmts.push(stmt);
+                                    }
                                 }
                             }
                         }
@@ -697,7 +700,7 @@ where
         let mut scope_ref_mut = self.scope.borrow_mut();
         let scope = &mut *scope_ref_mut;
 
-        if !initialized.is_empty() {
+        if !initialized.is_empty() && !self.config.no_incomplete_ns_import_detection {
             stmts.push(
                 initialize_to_undefined(quote_ident!("exports"), initialized)
                     .into_stmt()
@@ -824,6 +827,7 @@ where
                     quote_ident!("exports"),
                     exported_names.clone(),
                     export,
+                    self.config.constant_reexports,
                 )));
             }
         }
@@ -1,7 +1,7 @@
 #![deny(clippy::all)]
 #![deny(unused)]
 
-pub use self::{amd::amd, common_js::common_js, umd::umd};
+pub use self::{amd::amd, common_js::common_js, system_js::system_js, umd::umd};
 
 #[macro_use]
 pub mod util;
@@ -11,4 +11,5 @@ pub mod hoist;
 pub mod import_analysis;
 pub mod path;
 pub mod rewriter;
+pub mod system_js;
 pub mod umd;
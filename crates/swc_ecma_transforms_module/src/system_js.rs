@@ -0,0 +1,1111 @@
+use super::util::{local_name_for_src, use_strict};
+use crate::path::{ImportResolver, NoopImportResolver};
+use anyhow::Context;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use swc_atoms::{js_word, JsWord};
+use swc_common::{
+    collections::{AHashMap, AHashSet},
+    util::take::Take,
+    FileName, DUMMY_SP,
+};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{
+    find_ids, ident::IdentLike, private_ident, quote_ident, quote_str, undefined, ExprFactory, Id,
+};
+use swc_ecma_visit::{
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Visit, VisitMut, VisitMutWith, VisitWith,
+};
+
+pub fn system_js(config: Config) -> impl Fold + VisitMut {
+    as_folder(SystemJs {
+        config,
+        resolver: None::<(NoopImportResolver, _)>,
+    })
+}
+
+pub fn system_js_with_resolver<R>(
+    resolver: R,
+    base: FileName,
+    config: Config,
+) -> impl Fold + VisitMut
+where
+    R: ImportResolver,
+{
+    as_folder(SystemJs {
+        config,
+        resolver: Some((resolver, base)),
+    })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct Config {
+    /// The global object used to register the module.
+    ///
+    /// Defaults to `System`.
+    #[serde(default = "default_system_global")]
+    pub system_global: String,
+
+    /// If `false`, `this` at the top level is replaced with `undefined`.
+    #[serde(default)]
+    pub allow_top_level_this: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            system_global: default_system_global(),
+            allow_top_level_this: false,
+        }
+    }
+}
+
+fn default_system_global() -> String {
+    "System".into()
+}
+
+struct SystemJs<R>
+where
+    R: ImportResolver,
+{
+    config: Config,
+
+    resolver: Option<(R, FileName)>,
+}
+
+/// A module listed in the dependencies of `System.register`.
+struct Dep {
+    /// Parameter of the setter.
+    param: Ident,
+
+    /// Statements assigning imported bindings.
+    imports: Vec<Stmt>,
+
+    /// `export { foo as bar } from 'src'`
+    ///   -> `bar: _src.foo`
+    reexports: Vec<PropOrSpread>,
+
+    /// `export * from 'src'`
+    export_all: bool,
+}
+
+impl Dep {
+    fn new(src: &JsWord) -> Self {
+        Dep {
+            param: private_ident!(local_name_for_src(src)),
+            imports: Default::default(),
+            reexports: Default::default(),
+            export_all: false,
+        }
+    }
+
+    /// `_src.foo`, or `_src` if `name` is `None`.
+    fn member(&self, name: Option<&JsWord>) -> Expr {
+        match name {
+            Some(name) => member_of(self.param.clone(), name),
+            None => Expr::Ident(self.param.clone()),
+        }
+    }
+
+    /// Creates
+    ///
+    /// ```js
+    /// function (_src) {
+    ///     foo = _src.foo;
+    ///     _export({ bar: _src.foo });
+    /// }
+    /// ```
+    fn into_setter(self, export: &Ident) -> Expr {
+        let mut stmts = self.imports;
+
+        if self.export_all {
+            // var _exportObj = {};
+            // for (var _key in _src) {
+            //     if (_key !== "default" && _key !== "__esModule")
+            //         _exportObj[_key] = _src[_key];
+            // }
+            let export_obj = private_ident!("_exportObj");
+            let key = private_ident!("_key");
+
+            stmts.push(Stmt::Decl(Decl::Var(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                decls: vec![VarDeclarator {
+                    span: DUMMY_SP,
+                    name: export_obj.clone().into(),
+                    init: Some(Box::new(Expr::Object(ObjectLit {
+                        span: DUMMY_SP,
+                        props: vec![],
+                    }))),
+                    definite: false,
+                }],
+                declare: false,
+            })));
+            stmts.push(Stmt::ForIn(ForInStmt {
+                span: DUMMY_SP,
+                left: VarDeclOrPat::VarDecl(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    decls: vec![VarDeclarator {
+                        span: DUMMY_SP,
+                        name: key.clone().into(),
+                        init: None,
+                        definite: false,
+                    }],
+                    declare: false,
+                }),
+                right: Box::new(Expr::Ident(self.param.clone())),
+                body: Box::new(Stmt::If(IfStmt {
+                    span: DUMMY_SP,
+                    test: Box::new(
+                        key.clone()
+                            .make_bin(op!("!=="), quote_str!("default"))
+                            .make_bin(
+                                op!("&&"),
+                                key.clone().make_bin(op!("!=="), quote_str!("__esModule")),
+                            ),
+                    ),
+                    cons: Box::new(
+                        AssignExpr {
+                            span: DUMMY_SP,
+                            left: PatOrExpr::Expr(Box::new(
+                                export_obj.clone().computed_member(key.clone()),
+                            )),
+                            op: op!("="),
+                            right: Box::new(self.param.clone().computed_member(key)),
+                        }
+                        .into_stmt(),
+                    ),
+                    alt: None,
+                })),
+            }));
+
+            // _exportObj.bar = _src.foo;
+            for prop in self.reexports {
+                if let PropOrSpread::Prop(prop) = prop {
+                    if let Prop::KeyValue(KeyValueProp { key, value }) = *prop {
+                        let left = match key {
+                            PropName::Ident(key) => export_obj.clone().make_member(key),
+                            PropName::Str(key) => export_obj.clone().computed_member(key),
+                            _ => unreachable!(),
+                        };
+
+                        stmts.push(
+                            AssignExpr {
+                                span: DUMMY_SP,
+                                left: PatOrExpr::Expr(Box::new(left)),
+                                op: op!("="),
+                                right: value,
+                            }
+                            .into_stmt(),
+                        );
+                    }
+                }
+            }
+
+            stmts.push(
+                export
+                    .clone()
+                    .as_call(DUMMY_SP, vec![export_obj.as_arg()])
+                    .into_stmt(),
+            );
+        } else if !self.reexports.is_empty() {
+            stmts.push(
+                export
+                    .clone()
+                    .as_call(
+                        DUMMY_SP,
+                        vec![ObjectLit {
+                            span: DUMMY_SP,
+                            props: self.reexports,
+                        }
+                        .as_arg()],
+                    )
+                    .into_stmt(),
+            );
+        }
+
+        Expr::Fn(FnExpr {
+            ident: None,
+            function: Function {
+                params: vec![Param {
+                    span: DUMMY_SP,
+                    decorators: Default::default(),
+                    pat: self.param.into(),
+                }],
+                decorators: Default::default(),
+                span: DUMMY_SP,
+                body: Some(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts,
+                }),
+                is_generator: false,
+                is_async: false,
+                type_params: Default::default(),
+                return_type: Default::default(),
+            },
+        })
+    }
+}
+
+impl<R> VisitMut for SystemJs<R>
+where
+    R: ImportResolver,
+{
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, module: &mut Module) {
+        let export = private_ident!("_export");
+        let context = private_ident!("_context");
+
+        let mut deps: IndexMap<JsWord, Dep, ahash::RandomState> = Default::default();
+        // Imported binding -> (source, imported name). `None` means a namespace import.
+        let mut imported: AHashMap<Id, (JsWord, Option<JsWord>)> = Default::default();
+        // Local binding -> exported names
+        let mut exported: AHashMap<Id, Vec<JsWord>> = Default::default();
+
+        let mut vars = HoistedVars::default();
+
+        // Collect imports and exported names first, because a binding may be
+        // exported before it's declared.
+        for item in &module.body {
+            let decl = match item {
+                ModuleItem::ModuleDecl(decl) => decl,
+                _ => continue,
+            };
+
+            match decl {
+                ModuleDecl::Import(import) => {
+                    if import.type_only {
+                        continue;
+                    }
+
+                    let src = &import.src.value;
+                    let dep = deps.entry(src.clone()).or_insert_with(|| Dep::new(src));
+
+                    for s in &import.specifiers {
+                        let (local, name) = match s {
+                            ImportSpecifier::Named(ImportNamedSpecifier {
+                                is_type_only: true,
+                                ..
+                            }) => continue,
+                            ImportSpecifier::Named(ImportNamedSpecifier {
+                                local,
+                                imported,
+                                ..
+                            }) => (
+                                local,
+                                Some(
+                                    imported
+                                        .as_ref()
+                                        .map(export_name)
+                                        .unwrap_or_else(|| local.sym.clone()),
+                                ),
+                            ),
+                            ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) => {
+                                (local, Some(js_word!("default")))
+                            }
+                            ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                                (local, None)
+                            }
+                        };
+
+                        // foo = _src.foo;
+                        dep.imports.push(
+                            AssignExpr {
+                                span: DUMMY_SP,
+                                left: PatOrExpr::Pat(local.clone().into()),
+                                op: op!("="),
+                                right: Box::new(dep.member(name.as_ref())),
+                            }
+                            .into_stmt(),
+                        );
+                        vars.push(local.clone());
+                        imported.insert(local.to_id(), (src.clone(), name));
+                    }
+                }
+
+                ModuleDecl::ExportNamed(NamedExport { src: Some(src), .. })
+                | ModuleDecl::ExportAll(ExportAll { src, .. }) => {
+                    deps.entry(src.value.clone())
+                        .or_insert_with(|| Dep::new(&src.value));
+                }
+
+                ModuleDecl::ExportNamed(NamedExport {
+                    specifiers,
+                    src: None,
+                    type_only: false,
+                    ..
+                }) => {
+                    for s in specifiers {
+                        if let ExportSpecifier::Named(ExportNamedSpecifier {
+                            orig: ModuleExportName::Ident(orig),
+                            exported: name,
+                            is_type_only: false,
+                            ..
+                        }) = s
+                        {
+                            let name = name
+                                .as_ref()
+                                .map(export_name)
+                                .unwrap_or_else(|| orig.sym.clone());
+
+                            if !imported.contains_key(&orig.to_id()) {
+                                exported.entry(orig.to_id()).or_default().push(name);
+                            }
+                        }
+                    }
+                }
+
+                ModuleDecl::ExportDecl(ExportDecl { decl, .. }) => {
+                    let ids: Vec<Ident> = match decl {
+                        Decl::Class(ClassDecl { ident, .. }) | Decl::Fn(FnDecl { ident, .. }) => {
+                            vec![ident.clone()]
+                        }
+                        Decl::Var(var) => find_ids(&var.decls),
+                        _ => vec![],
+                    };
+
+                    for id in ids {
+                        exported.entry(id.to_id()).or_default().push(id.sym);
+                    }
+                }
+
+                ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    decl:
+                        DefaultDecl::Class(ClassExpr {
+                            ident: Some(ident), ..
+                        })
+                        | DefaultDecl::Fn(FnExpr {
+                            ident: Some(ident), ..
+                        }),
+                    ..
+                }) => {
+                    exported
+                        .entry(ident.to_id())
+                        .or_default()
+                        .push(js_word!("default"));
+                }
+
+                _ => {}
+            }
+        }
+
+        // Declared in the outer function, so functions can access them.
+        let mut hoisted_fns = vec![];
+        // `_export` calls for hoisted functions.
+        let mut hoisted_exports = vec![];
+        // Body of `execute`.
+        let mut execute = vec![];
+        // `export default function () {}`
+        let mut default_export = None;
+
+        let mut handle_decl = |decl: Decl, execute: &mut Vec<Stmt>, vars: &mut HoistedVars| {
+            match decl {
+                Decl::Fn(f) => {
+                    for name in exported.get(&f.ident.to_id()).into_iter().flatten() {
+                        hoisted_exports
+                            .push(export_call(&export, name.clone(), f.ident.clone()).into_stmt());
+                    }
+                    hoisted_fns.push(Stmt::Decl(Decl::Fn(f)));
+                }
+
+                // class Foo {}
+                //   -> Foo = class Foo {};
+                Decl::Class(ClassDecl { ident, class, .. }) => {
+                    vars.push(ident.clone());
+                    execute.push(
+                        AssignExpr {
+                            span: DUMMY_SP,
+                            left: PatOrExpr::Pat(ident.clone().into()),
+                            op: op!("="),
+                            right: Box::new(Expr::Class(ClassExpr {
+                                ident: Some(ident),
+                                class,
+                            })),
+                        }
+                        .into_stmt(),
+                    );
+                }
+
+                Decl::Var(var) => {
+                    let mut exprs = vec![];
+
+                    for decl in var.decls {
+                        let ids: Vec<Ident> = find_ids(&decl.name);
+
+                        match decl.init {
+                            Some(init) => exprs.push(Expr::Assign(AssignExpr {
+                                span: decl.span,
+                                left: PatOrExpr::Pat(Box::new(decl.name)),
+                                op: op!("="),
+                                right: init,
+                            })),
+                            None => {
+                                // export let foo;
+                                //   -> _export("foo", void 0);
+                                for id in &ids {
+                                    for name in exported.get(&id.to_id()).into_iter().flatten() {
+                                        exprs.push(export_call(
+                                            &export,
+                                            name.clone(),
+                                            *undefined(DUMMY_SP),
+                                        ));
+                                    }
+                                }
+                            }
+                        }
+
+                        for id in ids {
+                            vars.push(id);
+                        }
+                    }
+
+                    if let Some(expr) = seq(exprs) {
+                        execute.push(Stmt::Expr(ExprStmt {
+                            span: var.span,
+                            expr,
+                        }));
+                    }
+                }
+
+                _ => {}
+            }
+        };
+
+        for item in module.body.take() {
+            match item {
+                ModuleItem::Stmt(Stmt::Decl(decl)) => handle_decl(decl, &mut execute, &mut vars),
+
+                ModuleItem::Stmt(stmt) => execute.push(stmt),
+
+                ModuleItem::ModuleDecl(decl) => match decl {
+                    ModuleDecl::ExportDecl(ExportDecl { decl, .. }) => {
+                        handle_decl(decl, &mut execute, &mut vars)
+                    }
+
+                    ModuleDecl::ExportDefaultDecl(ExportDefaultDecl { decl, .. }) => match decl {
+                        DefaultDecl::Fn(FnExpr { ident, function }) => {
+                            // export default function () {}
+                            //   -> function _default() {}
+                            //      _export("default", _default);
+                            let ident = match ident {
+                                Some(ident) => ident,
+                                None => {
+                                    let ident = private_ident!("_default");
+                                    default_export = Some(
+                                        export_call(&export, js_word!("default"), ident.clone())
+                                            .into_stmt(),
+                                    );
+                                    ident
+                                }
+                            };
+
+                            handle_decl(
+                                Decl::Fn(FnDecl {
+                                    ident,
+                                    function,
+                                    declare: false,
+                                }),
+                                &mut execute,
+                                &mut vars,
+                            );
+                        }
+
+                        DefaultDecl::Class(ClassExpr {
+                            ident: Some(ident),
+                            class,
+                        }) => handle_decl(
+                            Decl::Class(ClassDecl {
+                                ident,
+                                class,
+                                declare: false,
+                            }),
+                            &mut execute,
+                            &mut vars,
+                        ),
+
+                        DefaultDecl::Class(class) => execute
+                            .push(export_call(&export, js_word!("default"), class).into_stmt()),
+
+                        DefaultDecl::TsInterfaceDecl(..) => {}
+                    },
+
+                    // export default foo;
+                    //   -> _export("default", foo);
+                    ModuleDecl::ExportDefaultExpr(ExportDefaultExpr { expr, .. }) => {
+                        execute.push(export_call(&export, js_word!("default"), *expr).into_stmt())
+                    }
+
+                    ModuleDecl::ExportNamed(NamedExport {
+                        specifiers,
+                        src: Some(src),
+                        type_only: false,
+                        ..
+                    }) => {
+                        let dep = &mut deps[&src.value];
+
+                        for s in specifiers {
+                            let (name, value) = match s {
+                                ExportSpecifier::Namespace(ExportNamespaceSpecifier {
+                                    name,
+                                    ..
+                                }) => (export_name(&name), dep.member(None)),
+                                ExportSpecifier::Default(ExportDefaultSpecifier { exported }) => {
+                                    (exported.sym, dep.member(Some(&js_word!("default"))))
+                                }
+                                ExportSpecifier::Named(ExportNamedSpecifier {
+                                    is_type_only: true,
+                                    ..
+                                }) => continue,
+                                ExportSpecifier::Named(ExportNamedSpecifier {
+                                    orig,
+                                    exported,
+                                    ..
+                                }) => {
+                                    let orig = export_name(&orig);
+
+                                    (
+                                        exported
+                                            .as_ref()
+                                            .map(export_name)
+                                            .unwrap_or_else(|| orig.clone()),
+                                        dep.member(Some(&orig)),
+                                    )
+                                }
+                            };
+
+                            dep.reexports.push(key_value(name, value));
+                        }
+                    }
+
+                    // Exports of imported bindings are handled by setters.
+                    ModuleDecl::ExportNamed(NamedExport {
+                        specifiers,
+                        src: None,
+                        type_only: false,
+                        ..
+                    }) => {
+                        for s in specifiers {
+                            if let ExportSpecifier::Named(ExportNamedSpecifier {
+                                orig: ModuleExportName::Ident(orig),
+                                exported: name,
+                                is_type_only: false,
+                                ..
+                            }) = s
+                            {
+                                if let Some((src, imported_name)) = imported.get(&orig.to_id()) {
+                                    let dep = &mut deps[src];
+                                    let name = name
+                                        .as_ref()
+                                        .map(export_name)
+                                        .unwrap_or_else(|| orig.sym.clone());
+                                    let value = dep.member(imported_name.as_ref());
+
+                                    dep.reexports.push(key_value(name, value));
+                                }
+                            }
+                        }
+                    }
+
+                    ModuleDecl::ExportAll(ExportAll { src, .. }) => {
+                        deps[&src.value].export_all = true;
+                    }
+
+                    _ => {}
+                },
+            }
+        }
+
+        // Variables declared by `var` in blocks should be visible to hoisted
+        // functions.
+        execute.visit_mut_with(&mut VarHoister { vars: &mut vars });
+
+        let is_async = {
+            let mut v = TopLevelAwaitFinder { found: false };
+            execute.visit_with(&mut v);
+            v.found
+        };
+
+        let mut rewriter = Rewriter {
+            export: &export,
+            context: &context,
+            exported: &exported,
+            in_top_level: true,
+            allow_top_level_this: self.config.allow_top_level_this,
+        };
+        hoisted_fns.visit_mut_with(&mut rewriter);
+        execute.visit_mut_with(&mut rewriter);
+
+        // ====================
+        //  Emit
+        // ====================
+
+        let mut stmts = vec![use_strict()];
+
+        if !vars.vars.is_empty() {
+            stmts.push(Stmt::Decl(Decl::Var(VarDecl {
+                span: DUMMY_SP,
+                kind: VarDeclKind::Var,
+                decls: vars
+                    .vars
+                    .into_iter()
+                    .map(|id| VarDeclarator {
+                        span: DUMMY_SP,
+                        name: id.into(),
+                        init: None,
+                        definite: false,
+                    })
+                    .collect(),
+                declare: false,
+            })));
+        }
+
+        stmts.extend(hoisted_fns);
+        stmts.extend(hoisted_exports);
+        stmts.extend(default_export);
+
+        let mut dep_srcs = vec![];
+        let mut setters = vec![];
+        for (src, dep) in deps {
+            let src = match &self.resolver {
+                Some((resolver, base)) => resolver
+                    .resolve_import(base, &src)
+                    .with_context(|| format!("failed to resolve `{}`", src))
+                    .unwrap(),
+                None => src,
+            };
+
+            dep_srcs.push(Some(quote_str!(src).as_arg()));
+            setters.push(Some(dep.into_setter(&export).as_arg()));
+        }
+
+        // return {
+        //     setters: [...],
+        //     execute: function () {...}
+        // };
+        stmts.push(Stmt::Return(ReturnStmt {
+            span: DUMMY_SP,
+            arg: Some(Box::new(Expr::Object(ObjectLit {
+                span: DUMMY_SP,
+                props: vec![
+                    key_value(
+                        "setters".into(),
+                        Expr::Array(ArrayLit {
+                            span: DUMMY_SP,
+                            elems: setters,
+                        }),
+                    ),
+                    key_value(
+                        "execute".into(),
+                        Expr::Fn(FnExpr {
+                            ident: None,
+                            function: Function {
+                                params: vec![],
+                                decorators: Default::default(),
+                                span: DUMMY_SP,
+                                body: Some(BlockStmt {
+                                    span: DUMMY_SP,
+                                    stmts: execute,
+                                }),
+                                is_generator: false,
+                                is_async,
+                                type_params: Default::default(),
+                                return_type: Default::default(),
+                            },
+                        }),
+                    ),
+                ],
+            }))),
+        }));
+
+        // System.register([...], function (_export, _context) {...});
+        let register = CallExpr {
+            span: DUMMY_SP,
+            callee: quote_ident!(&*self.config.system_global)
+                .make_member(quote_ident!("register"))
+                .as_callee(),
+            args: vec![
+                ArrayLit {
+                    span: DUMMY_SP,
+                    elems: dep_srcs,
+                }
+                .as_arg(),
+                FnExpr {
+                    ident: None,
+                    function: Function {
+                        params: vec![export, context]
+                            .into_iter()
+                            .map(|i| Param {
+                                span: DUMMY_SP,
+                                decorators: Default::default(),
+                                pat: i.into(),
+                            })
+                            .collect(),
+                        decorators: Default::default(),
+                        span: DUMMY_SP,
+                        body: Some(BlockStmt {
+                            span: DUMMY_SP,
+                            stmts,
+                        }),
+                        is_generator: false,
+                        is_async: false,
+                        type_params: Default::default(),
+                        return_type: Default::default(),
+                    },
+                }
+                .as_arg(),
+            ],
+            type_args: Default::default(),
+        };
+
+        module.body = vec![register.into_stmt().into()];
+    }
+}
+
+/// Variables declared in the outer function.
+#[derive(Default)]
+struct HoistedVars {
+    vars: Vec<Ident>,
+    declared: AHashSet<Id>,
+}
+
+impl HoistedVars {
+    fn push(&mut self, id: Ident) {
+        if self.declared.insert(id.to_id()) {
+            self.vars.push(id);
+        }
+    }
+}
+
+/// Converts `var` declarations which are not in a function into assignments.
+struct VarHoister<'a> {
+    vars: &'a mut HoistedVars,
+}
+
+impl VarHoister<'_> {
+    /// Returns `None` if there's no initializer.
+    fn hoist_var(&mut self, var: VarDecl) -> Option<Box<Expr>> {
+        let mut exprs = vec![];
+
+        for decl in var.decls {
+            for id in find_ids(&decl.name) {
+                self.vars.push(id);
+            }
+
+            if let Some(init) = decl.init {
+                exprs.push(Expr::Assign(AssignExpr {
+                    span: decl.span,
+                    left: PatOrExpr::Pat(Box::new(decl.name)),
+                    op: op!("="),
+                    right: init,
+                }));
+            }
+        }
+
+        seq(exprs)
+    }
+}
+
+impl VisitMut for VarHoister<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_arrow_expr(&mut self, _: &mut ArrowExpr) {}
+
+    fn visit_mut_class(&mut self, _: &mut Class) {}
+
+    fn visit_mut_for_stmt(&mut self, n: &mut ForStmt) {
+        n.visit_mut_children_with(self);
+
+        if let Some(VarDeclOrExpr::VarDecl(var)) = &mut n.init {
+            if var.kind == VarDeclKind::Var {
+                n.init = self.hoist_var(var.take()).map(VarDeclOrExpr::Expr);
+            }
+        }
+    }
+
+    fn visit_mut_function(&mut self, _: &mut Function) {}
+
+    fn visit_mut_stmt(&mut self, n: &mut Stmt) {
+        n.visit_mut_children_with(self);
+
+        if let Stmt::Decl(Decl::Var(var)) = n {
+            if var.kind == VarDeclKind::Var {
+                let span = var.span;
+                *n = match self.hoist_var(var.take()) {
+                    Some(expr) => Stmt::Expr(ExprStmt { span, expr }),
+                    None => Stmt::Empty(EmptyStmt { span }),
+                };
+            }
+        }
+    }
+
+    fn visit_mut_var_decl_or_pat(&mut self, n: &mut VarDeclOrPat) {
+        n.visit_mut_children_with(self);
+
+        if let VarDeclOrPat::VarDecl(var) = n {
+            if var.kind == VarDeclKind::Var && var.decls.len() == 1 {
+                let decl = var.decls.pop().unwrap();
+                for id in find_ids(&decl.name) {
+                    self.vars.push(id);
+                }
+
+                *n = VarDeclOrPat::Pat(decl.name);
+            }
+        }
+    }
+}
+
+struct TopLevelAwaitFinder {
+    found: bool,
+}
+
+impl Visit for TopLevelAwaitFinder {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_await_expr(&mut self, _: &AwaitExpr) {
+        self.found = true;
+    }
+
+    fn visit_class(&mut self, _: &Class) {}
+
+    fn visit_for_of_stmt(&mut self, n: &ForOfStmt) {
+        self.found |= n.await_token.is_some();
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_function(&mut self, _: &Function) {}
+}
+
+/// Handles live bindings of exports, `import.meta`, `import()` and `this`.
+struct Rewriter<'a> {
+    export: &'a Ident,
+    context: &'a Ident,
+    exported: &'a AHashMap<Id, Vec<JsWord>>,
+    in_top_level: bool,
+    allow_top_level_this: bool,
+}
+
+impl Rewriter<'_> {
+    fn exported_names(&self, i: &Ident) -> Option<&[JsWord]> {
+        self.exported.get(&i.to_id()).map(|v| &**v)
+    }
+
+    /// `_export("b", _export("a", expr))`
+    fn wrap(&self, names: &[JsWord], mut expr: Expr) -> Expr {
+        for name in names {
+            expr = export_call(self.export, name.clone(), expr);
+        }
+        expr
+    }
+}
+
+impl VisitMut for Rewriter<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_callee(&mut self, n: &mut Callee) {
+        n.visit_mut_children_with(self);
+
+        // import('foo')
+        //   -> _context.import('foo')
+        if let Callee::Import(..) = n {
+            *n = self
+                .context
+                .clone()
+                .make_member(quote_ident!("import"))
+                .as_callee();
+        }
+    }
+
+    fn visit_mut_class(&mut self, n: &mut Class) {
+        let old = self.in_top_level;
+        self.in_top_level = false;
+        n.visit_mut_children_with(self);
+        self.in_top_level = old;
+    }
+
+    fn visit_mut_expr(&mut self, n: &mut Expr) {
+        n.visit_mut_children_with(self);
+
+        match n {
+            // import.meta
+            //   -> _context.meta
+            Expr::MetaProp(MetaPropExpr {
+                kind: MetaPropKind::ImportMeta,
+                ..
+            }) => {
+                *n = self.context.clone().make_member(quote_ident!("meta"));
+            }
+
+            Expr::This(ThisExpr { span }) if self.in_top_level && !self.allow_top_level_this => {
+                *n = *undefined(*span);
+            }
+
+            Expr::Assign(assign) => {
+                let ident = match &assign.left {
+                    PatOrExpr::Pat(pat) => match &**pat {
+                        Pat::Ident(i) => Some(&i.id),
+                        Pat::Expr(e) => e.as_ident(),
+                        _ => None,
+                    },
+                    PatOrExpr::Expr(e) => e.as_ident(),
+                };
+
+                match ident {
+                    // foo = 1
+                    //   -> _export("foo", foo = 1)
+                    Some(i) => {
+                        if let Some(names) = self.exported_names(i) {
+                            let names = names.to_vec();
+                            *n = self.wrap(&names, n.take());
+                        }
+                    }
+
+                    // [foo, bar] = baz
+                    //   -> [foo, bar] = baz, _export("foo", foo), _export("bar", bar)
+                    None => {
+                        let ids: Vec<Ident> = find_ids(&assign.left);
+                        let exports = ids
+                            .into_iter()
+                            .filter_map(|i| {
+                                let names = self.exported_names(&i)?;
+                                Some(Box::new(self.wrap(names, Expr::Ident(i))))
+                            })
+                            .collect::<Vec<_>>();
+
+                        if !exports.is_empty() {
+                            let mut exprs = vec![Box::new(n.take())];
+                            exprs.extend(exports);
+
+                            *n = Expr::Seq(SeqExpr {
+                                span: DUMMY_SP,
+                                exprs,
+                            });
+                        }
+                    }
+                }
+            }
+
+            Expr::Update(UpdateExpr {
+                arg, op, prefix, ..
+            }) => {
+                let i = match arg.as_ident() {
+                    Some(i) => i,
+                    None => return,
+                };
+                let names = match self.exported_names(i) {
+                    Some(names) => names.to_vec(),
+                    None => return,
+                };
+
+                if *prefix {
+                    // ++foo
+                    //   -> _export("foo", ++foo)
+                    *n = self.wrap(&names, n.take());
+                } else {
+                    // foo++
+                    //   -> (_export("foo", foo + 1), foo++)
+                    let value = i.clone().make_bin(
+                        match op {
+                            op!("++") => op!(bin, "+"),
+                            op!("--") => op!(bin, "-"),
+                        },
+                        Expr::Lit(Lit::Num(Number {
+                            span: DUMMY_SP,
+                            value: 1.0,
+                        })),
+                    );
+
+                    *n = Expr::Seq(SeqExpr {
+                        span: DUMMY_SP,
+                        exprs: vec![Box::new(self.wrap(&names, value)), Box::new(n.take())],
+                    });
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    fn visit_mut_function(&mut self, n: &mut Function) {
+        let old = self.in_top_level;
+        self.in_top_level = false;
+        n.visit_mut_children_with(self);
+        self.in_top_level = old;
+    }
+
+    fn visit_mut_getter_prop(&mut self, n: &mut GetterProp) {
+        let old = self.in_top_level;
+        self.in_top_level = false;
+        n.visit_mut_children_with(self);
+        self.in_top_level = old;
+    }
+
+    fn visit_mut_setter_prop(&mut self, n: &mut SetterProp) {
+        let old = self.in_top_level;
+        self.in_top_level = false;
+        n.visit_mut_children_with(self);
+        self.in_top_level = old;
+    }
+}
+
+/// `_export("name", value)`
+fn export_call(export: &Ident, name: JsWord, value: impl Into<Expr>) -> Expr {
+    export
+        .clone()
+        .as_call(DUMMY_SP, vec![quote_str!(name).as_arg(), value.as_arg()])
+}
+
+fn export_name(n: &ModuleExportName) -> JsWord {
+    match n {
+        ModuleExportName::Ident(i) => i.sym.clone(),
+        ModuleExportName::Str(s) => s.value.clone(),
+    }
+}
+
+/// `obj.name` or `obj["name"]`
+fn member_of(obj: Ident, name: &JsWord) -> Expr {
+    if is_ident_name(name) {
+        obj.make_member(quote_ident!(name.clone()))
+    } else {
+        obj.computed_member(quote_str!(name.clone()))
+    }
+}
+
+fn key_value(key: JsWord, value: Expr) -> PropOrSpread {
+    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+        key: if is_ident_name(&key) {
+            PropName::Ident(quote_ident!(key))
+        } else {
+            PropName::Str(quote_str!(key))
+        },
+        value: Box::new(value),
+    })))
+}
+
+/// Reserved words are allowed as property names.
+fn is_ident_name(s: &str) -> bool {
+    let mut chars = s.chars();
+
+    match chars.next() {
+        Some(first) => Ident::is_valid_start(first) && chars.all(Ident::is_valid_continue),
+        None => false,
+    }
+}
+
+fn seq(exprs: Vec<Expr>) -> Option<Box<Expr>> {
+    let mut exprs = exprs.into_iter().map(Box::new).collect::<Vec<_>>();
+
+    match exprs.len() {
+        0 => None,
+        1 => exprs.pop(),
+        _ => Some(Box::new(Expr::Seq(SeqExpr {
+            span: DUMMY_SP,
+            exprs,
+        }))),
+    }
+}
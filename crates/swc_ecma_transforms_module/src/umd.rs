@@ -1,8 +1,8 @@
 use self::config::BuiltConfig;
 pub use self::config::Config;
 use super::util::{
-    self, define_es_module, define_property, has_use_strict, initialize_to_undefined,
-    local_name_for_src, make_descriptor, make_require_call, use_strict, Exports, ModulePass, Scope,
+    self, define_es_module, define_reexport, has_use_strict, initialize_to_undefined,
+    local_name_for_src, make_require_call, use_strict, Exports, ModulePass, Scope,
 };
 use crate::path::{ImportResolver, NoopImportResolver};
 use indexmap::IndexSet;
@@ -128,7 +128,10 @@ where
                     has_export = true;
                     if !self.config.config.strict && !emitted_esmodule {
                         emitted_esmodule = true;
-                        stmts.push(define_es_module(exports_ident.clone()));
+                        stmts.push(define_es_module(
+                            exports_ident.clone(),
+                            self.config.config.enumerable_module_meta,
+                        ));
                     }
 
                     macro_rules! init_export {
@@ -427,22 +430,14 @@ where
                                         .into_stmt(),
                                     );
                                 } else {
-                                    stmts.push(
-                                        define_property(vec![
-                                            exports_ident.clone().as_arg(),
-                                            {
-                                                // export { foo }
-                                                //  -> 'foo'
-
-                                                // export { foo as bar }
-                                                //  -> 'bar'
-                                                let i = exported.unwrap_or(orig);
-                                                Lit::Str(quote_str!(i.span, i.sym)).as_arg()
-                                            },
-                                            make_descriptor(value).as_arg(),
-                                        ])
-                                        .into_stmt(),
-                                    );
+                                    stmts.push(define_reexport(
+                                        exports_ident.clone(),
+                                        // export { foo } -> 'foo'
+                                        // export { foo as bar } -> 'bar'
+                                        exported.unwrap_or(orig),
+                                        value,
+                                        self.config.config.constant_reexports,
+                                    ));
                                 }
                             }
                         }
@@ -535,10 +530,11 @@ where
                 exports_ident.clone(),
                 exported_names.clone(),
                 export,
+                self.config.config.constant_reexports,
             ));
         }
 
-        if !initialized.is_empty() {
+        if !initialized.is_empty() && !self.config.config.no_incomplete_ns_import_detection {
             stmts.push(initialize_to_undefined(exports_ident, initialized).into_stmt());
         }
 
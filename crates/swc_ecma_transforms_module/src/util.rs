@@ -42,6 +42,31 @@ pub struct Config {
     pub no_interop: bool,
     #[serde(default)]
     pub ignore_dynamic: bool,
+
+    /// Re-exports are assigned to `exports` instead of being defined as
+    /// getters.
+    ///
+    /// https://babeljs.io/docs/en/assumptions#constantreexports
+    #[serde(default)]
+    pub constant_reexports: bool,
+
+    /// `__esModule` is assigned to `exports` instead of being defined as a
+    /// non-enumerable property.
+    ///
+    /// https://babeljs.io/docs/en/assumptions#enumerablemodulemeta
+    #[serde(default)]
+    pub enumerable_module_meta: bool,
+
+    /// Exports are not initialized to `undefined` before the module body is
+    /// evaluated.
+    ///
+    /// This follows the semantics of babel. Namespace imports (`import * as
+    /// ns`) still use the wildcard interop helper, as the imported module may
+    /// be a CommonJS module.
+    ///
+    /// https://babeljs.io/docs/en/assumptions#noincompletensimportdetection
+    #[serde(default)]
+    pub no_incomplete_ns_import_detection: bool,
 }
 
 impl Default for Config {
@@ -52,6 +77,9 @@ impl Default for Config {
             lazy: Lazy::default(),
             no_interop: false,
             ignore_dynamic: false,
+            constant_reexports: false,
+            enumerable_module_meta: false,
+            no_incomplete_ns_import_detection: false,
         }
     }
 }
@@ -154,10 +182,13 @@ impl Scope {
     ///
     /// # Parameters
     /// - `exported_names` Ident of the object literal.
+    /// - `constant` If true, `exports[key] = _foo[key]` is used instead of a
+    ///   getter.
     pub fn handle_export_all(
         exports: Ident,
         exported_names: Option<Ident>,
         imported: Ident,
+        constant: bool,
     ) -> Stmt {
         let key_ident = private_ident!("key");
 
@@ -240,15 +271,23 @@ impl Scope {
                         alt: None,
                     }))
                 })
-                .chain(iter::once(
+                .chain(iter::once(if constant {
+                    AssignExpr {
+                        span: DUMMY_SP,
+                        left: PatOrExpr::Expr(Box::new(exports.computed_member(key_ident.clone()))),
+                        op: op!("="),
+                        right: Box::new(imported.clone().computed_member(key_ident)),
+                    }
+                    .into_stmt()
+                } else {
                     define_property(vec![
                         exports.as_arg(),
                         key_ident.clone().as_arg(),
                         make_descriptor(Box::new(imported.clone().computed_member(key_ident)))
                             .as_arg(),
                     ])
-                    .into_stmt(),
-                ))
+                    .into_stmt()
+                }))
                 .collect(),
             }),
             return_type: Default::default(),
@@ -851,7 +890,19 @@ pub(super) fn define_property(args: Vec<ExprOrSpread>) -> Expr {
 ///       value: true
 ///  });
 /// ```
-pub(super) fn define_es_module(exports: Ident) -> Stmt {
+///
+/// or `exports.__esModule = true;` if `enumerable` is true.
+pub(super) fn define_es_module(exports: Ident, enumerable: bool) -> Stmt {
+    if enumerable {
+        return AssignExpr {
+            span: DUMMY_SP,
+            left: PatOrExpr::Expr(Box::new(exports.make_member(quote_ident!("__esModule")))),
+            op: op!("="),
+            right: true.into(),
+        }
+        .into_stmt();
+    }
+
     define_property(vec![
         exports.as_arg(),
         Lit::Str(quote_str!("__esModule")).as_arg(),
@@ -916,6 +967,42 @@ pub(super) fn initialize_to_undefined(
     rhs
 }
 
+/// Creates
+///
+/// ```js
+/// Object.defineProperty(exports, "foo", {
+///     enumerable: true,
+///     get: function () {
+///         return _bar.foo;
+///     }
+/// });
+/// ```
+///
+/// or `exports.foo = _bar.foo;` if `constant` is true.
+pub(super) fn define_reexport(
+    exports: Ident,
+    name: Ident,
+    value: Box<Expr>,
+    constant: bool,
+) -> Stmt {
+    if constant {
+        return AssignExpr {
+            span: DUMMY_SP,
+            left: PatOrExpr::Expr(Box::new(exports.make_member(name))),
+            op: op!("="),
+            right: value,
+        }
+        .into_stmt();
+    }
+
+    define_property(vec![
+        exports.as_arg(),
+        Lit::Str(quote_str!(name.span, name.sym)).as_arg(),
+        make_descriptor(value).as_arg(),
+    ])
+    .into_stmt()
+}
+
 pub(super) fn make_descriptor(get_expr: Box<Expr>) -> ObjectLit {
     let get_fn_body = Some(BlockStmt {
         span: DUMMY_SP,
@@ -19,6 +19,25 @@ fn tr(config: Config) -> impl Fold {
     chain!(resolver(), amd(config))
 }
 
+test!(
+    syntax(),
+    |_| tr(Config {
+        config: util::Config {
+            constant_reexports: true,
+            enumerable_module_meta: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    }),
+    assumptions,
+    "export { foo } from 'foo';",
+    "define(['exports', 'foo'], function(_exports, _foo) {
+    'use strict';
+    _exports.__esModule = true;
+    _exports.foo = _foo.foo;
+});"
+);
+
 test!(
     syntax(),
     |_| tr(Config {
@@ -1200,7 +1219,10 @@ test!(
 test!(
     syntax(),
     |_| chain!(
-        for_of(for_of::Config { assume_array: true }),
+        for_of(for_of::Config {
+            assume_array: true,
+            ..Default::default()
+        }),
         amd(Default::default())
     ),
     for_of_as_array_for_of_import_amd,
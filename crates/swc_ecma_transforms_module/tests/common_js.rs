@@ -707,6 +707,83 @@ exports.default = _default;
 "#
 );
 
+test!(
+    syntax(),
+    |_| tr(Config {
+        constant_reexports: true,
+        ..Default::default()
+    }),
+    assumption_constant_reexports,
+    r#"
+export { foo, default as bar } from "foo";
+export * from "baz";
+"#,
+    r#"
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+var _exportNames = {
+    foo: true,
+    bar: true
+};
+var _foo = _interopRequireDefault(require("foo"));
+var _baz = require("baz");
+Object.keys(_baz).forEach(function(key) {
+    if (key === "default" || key === "__esModule") return;
+    if (Object.prototype.hasOwnProperty.call(_exportNames, key)) return;
+    if (key in exports && exports[key] === _baz[key]) return;
+    exports[key] = _baz[key];
+});
+exports.foo = _foo.foo;
+exports.bar = _foo.default;
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(Config {
+        enumerable_module_meta: true,
+        no_incomplete_ns_import_detection: true,
+        ..Default::default()
+    }),
+    assumption_enumerable_module_meta,
+    r#"
+export const foo = 1;
+export default function bar() {}
+"#,
+    r#"
+"use strict";
+exports.__esModule = true;
+exports.default = bar;
+const foo = 1;
+exports.foo = foo;
+function bar() {}
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(Config {
+        no_incomplete_ns_import_detection: true,
+        ..Default::default()
+    }),
+    assumption_no_incomplete_ns_import_detection,
+    r#"
+import * as ns from "foo";
+export const bar = ns.bar;
+"#,
+    r#"
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+var ns = _interopRequireWildcard(require("foo"));
+const bar = ns.bar;
+exports.bar = bar;
+"#
+);
+
 // no_interop_import_default_only
 test!(
     syntax(),
@@ -4438,7 +4515,7 @@ test!(
     |t| chain!(
         resolver(),
         block_scoping(),
-        classes(Some(t.comments.clone()),),
+        classes(Some(t.comments.clone()), Default::default()),
         destructuring(Default::default()),
         common_js(Mark::fresh(Mark::root()), Default::default(), None)
     ),
@@ -4501,7 +4578,10 @@ instance.call();",
 test!(
     syntax(),
     |_| chain!(
-        for_of(for_of::Config { assume_array: true }),
+        for_of(for_of::Config {
+            assume_array: true,
+            ..Default::default()
+        }),
         common_js(Mark::fresh(Mark::root()), Default::default(), None)
     ),
     for_of_as_array_for_of_import_commonjs,
@@ -4532,7 +4612,10 @@ test!(
     |_| chain!(
         resolver(),
         object_rest_spread(Default::default()),
-        destructuring(destructuring::Config { loose: false }),
+        destructuring(destructuring::Config {
+            loose: false,
+            ..Default::default()
+        }),
         common_js(Mark::fresh(Mark::root()), Default::default(), None),
     ),
     regression_t7178,
@@ -4567,7 +4650,7 @@ console.log(props);
 test!(
     syntax(),
     |t| chain!(
-        classes(Some(t.comments.clone()),),
+        classes(Some(t.comments.clone()), Default::default()),
         parameters(Default::default()),
         destructuring(Default::default()),
         block_scoping(),
@@ -4803,7 +4886,8 @@ test!(
         strict: true,
         strict_mode: true,
         lazy: Lazy::Bool(false),
-        ignore_dynamic: false
+        ignore_dynamic: false,
+        ..Default::default()
     }),
     issue_1480_1,
     "
@@ -4824,7 +4908,8 @@ test!(
         strict: true,
         strict_mode: true,
         lazy: Lazy::Bool(false),
-        ignore_dynamic: false
+        ignore_dynamic: false,
+        ..Default::default()
     }),
     issue_1480_2,
     "
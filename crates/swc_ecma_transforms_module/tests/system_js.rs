@@ -0,0 +1,312 @@
+use swc_common::chain;
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms_base::resolver::resolver;
+use swc_ecma_transforms_module::system_js::{system_js, Config};
+use swc_ecma_transforms_testing::test;
+use swc_ecma_visit::Fold;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        ..Default::default()
+    })
+}
+
+fn tr(config: Config) -> impl Fold {
+    chain!(resolver(), system_js(config))
+}
+
+test!(
+    syntax(),
+    |_| tr(Default::default()),
+    imports,
+    r#"
+import "side-effect";
+import foo, { bar as baz, "a-b" as ab } from "foo";
+import * as ns from "ns";
+
+console.log(foo, baz, ab, ns);
+"#,
+    r#"
+System.register(["side-effect", "foo", "ns"], function (_export, _context) {
+    "use strict";
+    var foo, baz, ab, ns;
+    return {
+        setters: [function (_sideEffect) {}, function (_foo) {
+            foo = _foo.default;
+            baz = _foo.bar;
+            ab = _foo["a-b"];
+        }, function (_ns) {
+            ns = _ns;
+        }],
+        execute: function () {
+            console.log(foo, baz, ab, ns);
+        }
+    };
+});
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(Default::default()),
+    export_decls,
+    r#"
+export var a = 1, b;
+export let { c, d: [e] } = obj;
+export function f() {
+    a = 2;
+}
+export class G {}
+"#,
+    r#"
+System.register([], function (_export, _context) {
+    "use strict";
+    var a, b, c, e, G;
+    function f() {
+        _export("a", a = 2);
+    }
+    _export("f", f);
+    return {
+        setters: [],
+        execute: function () {
+            _export("a", a = 1), _export("b", void 0);
+            ({ c, d: [e] } = obj), _export("c", c), _export("e", e);
+            _export("G", G = class G {});
+        }
+    };
+});
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(Default::default()),
+    live_bindings,
+    r#"
+let count = 0;
+let other = 0;
+export { count, count as total };
+
+export function increment() {
+    count++;
+    ++count;
+    count += 1;
+    other++;
+}
+"#,
+    r#"
+System.register([], function (_export, _context) {
+    "use strict";
+    var count, other;
+    function increment() {
+        _export("total", _export("count", count + 1)), count++;
+        _export("total", _export("count", ++count));
+        _export("total", _export("count", count += 1));
+        other++;
+    }
+    _export("increment", increment);
+    return {
+        setters: [],
+        execute: function () {
+            _export("total", _export("count", count = 0));
+            other = 0;
+        }
+    };
+});
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(Default::default()),
+    export_default_expr,
+    r#"
+export default foo + 1;
+"#,
+    r#"
+System.register([], function (_export, _context) {
+    "use strict";
+    return {
+        setters: [],
+        execute: function () {
+            _export("default", foo + 1);
+        }
+    };
+});
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(Default::default()),
+    export_default_fn,
+    r#"
+export default function () {}
+"#,
+    r#"
+System.register([], function (_export, _context) {
+    "use strict";
+    function _default() {}
+    _export("default", _default);
+    return {
+        setters: [],
+        execute: function () {}
+    };
+});
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(Default::default()),
+    export_default_class,
+    r#"
+export default class Foo {}
+"#,
+    r#"
+System.register([], function (_export, _context) {
+    "use strict";
+    var Foo;
+    return {
+        setters: [],
+        execute: function () {
+            _export("default", Foo = class Foo {});
+        }
+    };
+});
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(Default::default()),
+    reexports,
+    r#"
+import { foo } from "foo";
+export { foo };
+export { bar, baz as "b-z" } from "bar";
+export * as ns from "ns";
+export * from "all";
+export { qux } from "all";
+"#,
+    r#"
+System.register(["foo", "bar", "ns", "all"], function (_export, _context) {
+    "use strict";
+    var foo;
+    return {
+        setters: [function (_foo) {
+            foo = _foo.foo;
+            _export({
+                foo: _foo.foo
+            });
+        }, function (_bar) {
+            _export({
+                bar: _bar.bar,
+                "b-z": _bar.baz
+            });
+        }, function (_ns) {
+            _export({
+                ns: _ns
+            });
+        }, function (_all) {
+            var _exportObj = {};
+            for (var _key in _all)
+                if (_key !== "default" && _key !== "__esModule") _exportObj[_key] = _all[_key];
+            _exportObj.qux = _all.qux;
+            _export(_exportObj);
+        }],
+        execute: function () {}
+    };
+});
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(Default::default()),
+    context,
+    r#"
+const { default: dep } = await import("./dep.js");
+console.log(import.meta.url, this, dep);
+function f() {
+    return this;
+}
+"#,
+    r#"
+System.register([], function (_export, _context) {
+    "use strict";
+    var dep;
+    function f() {
+        return this;
+    }
+    return {
+        setters: [],
+        execute: async function () {
+            ({ default: dep } = await _context.import("./dep.js"));
+            console.log(_context.meta.url, void 0, dep);
+        }
+    };
+});
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(Config {
+        system_global: "SystemJS".into(),
+        allow_top_level_this: true,
+    }),
+    custom_config,
+    r#"
+console.log(this);
+"#,
+    r#"
+SystemJS.register([], function (_export, _context) {
+    "use strict";
+    return {
+        setters: [],
+        execute: function () {
+            console.log(this);
+        }
+    };
+});
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(Default::default()),
+    hoist_nested_vars,
+    r#"
+if (cond) {
+    var a = 1, b;
+    let c = 2;
+}
+for (var i = 0; i < 10; i++) {}
+for (var key in obj) {}
+export function get() {
+    return [a, b, i, key];
+}
+"#,
+    r#"
+System.register([], function (_export, _context) {
+    "use strict";
+    var a, b, i, key;
+    function get() {
+        return [a, b, i, key];
+    }
+    _export("get", get);
+    return {
+        setters: [],
+        execute: function () {
+            if (cond) {
+                a = 1;
+                let c = 2;
+            }
+            for (i = 0; i < 10; i++) {}
+            for (key in obj) {}
+        }
+    };
+});
+"#
+);
@@ -418,7 +418,8 @@ test!(
         chain!(
             decorators(decorators::Config {
                 legacy: true,
-                emit_metadata: false
+                emit_metadata: false,
+                ..Default::default()
             }),
             resolver_with_mark(mark),
             strip(mark),
@@ -468,7 +469,8 @@ test!(
         chain!(
             decorators(decorators::Config {
                 legacy: true,
-                emit_metadata: false
+                emit_metadata: false,
+                ..Default::default()
             }),
             resolver_with_mark(mark),
             strip(mark),
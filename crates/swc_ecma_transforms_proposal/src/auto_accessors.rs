@@ -0,0 +1,229 @@
+use swc_atoms::JsWord;
+use swc_common::{collections::AHashSet, util::take::Take, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::quote_ident;
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+/// Lowers auto accessors (`accessor a = 1`) to a private field with a getter
+/// and a setter.
+///
+/// This pass does not depend on the version of the decorators proposal.
+/// Decorators of an auto accessor are moved to the getter, so it should be
+/// applied before the decorators pass unless the decorators pass handles auto
+/// accessors by itself.
+pub fn auto_accessors() -> impl Fold + VisitMut {
+    as_folder(AutoAccessors)
+}
+
+struct AutoAccessors;
+
+impl VisitMut for AutoAccessors {
+    noop_visit_mut_type!();
+
+    fn visit_mut_class(&mut self, class: &mut Class) {
+        class.visit_mut_children_with(self);
+
+        if !class
+            .body
+            .iter()
+            .any(|member| matches!(member, ClassMember::AutoAccessor(..)))
+        {
+            return;
+        }
+
+        let mut private_names = collect_private_names(class);
+        let mut body = Vec::with_capacity(class.body.len() + 2);
+
+        for member in class.body.take() {
+            match member {
+                ClassMember::AutoAccessor(a) => {
+                    let storage = PrivateName {
+                        span: DUMMY_SP,
+                        id: quote_ident!(unique_private_name(&mut private_names)),
+                    };
+
+                    body.extend(lower_auto_accessor(a, storage));
+                }
+                _ => body.push(member),
+            }
+        }
+
+        class.body = body;
+    }
+}
+
+/// Returns the storage field, the getter and the setter for `a`.
+pub(crate) fn lower_auto_accessor(a: AutoAccessor, storage: PrivateName) -> [ClassMember; 3] {
+    let getter = Function {
+        decorators: a.decorators,
+        ..function(vec![], return_stmt(private_get(&storage)))
+    };
+    let setter = setter_function(private_set(&storage));
+    let field = storage_prop(storage, a.is_static, a.value);
+
+    match a.key {
+        Key::Public(key) => [
+            field,
+            ClassMember::Method(ClassMethod {
+                span: a.span,
+                key: key.clone(),
+                function: getter,
+                kind: MethodKind::Getter,
+                is_static: a.is_static,
+                accessibility: a.accessibility,
+                is_abstract: false,
+                is_optional: false,
+                is_override: false,
+            }),
+            ClassMember::Method(ClassMethod {
+                span: a.span,
+                key,
+                function: setter,
+                kind: MethodKind::Setter,
+                is_static: a.is_static,
+                accessibility: a.accessibility,
+                is_abstract: false,
+                is_optional: false,
+                is_override: false,
+            }),
+        ],
+        Key::Private(key) => [
+            field,
+            ClassMember::PrivateMethod(PrivateMethod {
+                span: a.span,
+                key: key.clone(),
+                function: getter,
+                kind: MethodKind::Getter,
+                is_static: a.is_static,
+                accessibility: a.accessibility,
+                is_abstract: false,
+                is_optional: false,
+                is_override: false,
+            }),
+            ClassMember::PrivateMethod(PrivateMethod {
+                span: a.span,
+                key,
+                function: setter,
+                kind: MethodKind::Setter,
+                is_static: a.is_static,
+                accessibility: a.accessibility,
+                is_abstract: false,
+                is_optional: false,
+                is_override: false,
+            }),
+        ],
+    }
+}
+
+pub(crate) fn collect_private_names(class: &Class) -> AHashSet<JsWord> {
+    class
+        .body
+        .iter()
+        .filter_map(|member| match member {
+            ClassMember::PrivateProp(p) => Some(p.key.id.sym.clone()),
+            ClassMember::PrivateMethod(m) => Some(m.key.id.sym.clone()),
+            ClassMember::AutoAccessor(AutoAccessor {
+                key: Key::Private(k),
+                ..
+            }) => Some(k.id.sym.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns `A`, `B`, ..., `Z`, `A1`, ... which is not used by the class.
+pub(crate) fn unique_private_name(names: &mut AHashSet<JsWord>) -> JsWord {
+    let mut i = 0;
+    loop {
+        let letter = (b'A' + (i % 26) as u8) as char;
+        let name: JsWord = if i < 26 {
+            letter.to_string().into()
+        } else {
+            format!("{}{}", letter, i / 26).into()
+        };
+
+        if names.insert(name.clone()) {
+            return name;
+        }
+        i += 1;
+    }
+}
+
+/// `this.#a`
+pub(crate) fn private_get(key: &PrivateName) -> Box<Expr> {
+    Box::new(Expr::Member(MemberExpr {
+        span: DUMMY_SP,
+        obj: Box::new(Expr::This(ThisExpr { span: DUMMY_SP })),
+        prop: MemberProp::PrivateName(key.clone()),
+    }))
+}
+
+/// `this.#a = v`
+pub(crate) fn private_set(key: &PrivateName) -> Box<Expr> {
+    Box::new(Expr::Assign(AssignExpr {
+        span: DUMMY_SP,
+        op: op!("="),
+        left: PatOrExpr::Expr(private_get(key)),
+        right: Box::new(Expr::Ident(quote_ident!("v"))),
+    }))
+}
+
+pub(crate) fn setter_function(body: Box<Expr>) -> Function {
+    function(
+        vec![Param {
+            span: DUMMY_SP,
+            decorators: vec![],
+            pat: Pat::Ident(quote_ident!("v").into()),
+        }],
+        Stmt::Expr(ExprStmt {
+            span: DUMMY_SP,
+            expr: body,
+        }),
+    )
+}
+
+pub(crate) fn function(params: Vec<Param>, stmt: Stmt) -> Function {
+    Function {
+        params,
+        decorators: vec![],
+        span: DUMMY_SP,
+        body: Some(BlockStmt {
+            span: DUMMY_SP,
+            stmts: vec![stmt],
+        }),
+        is_generator: false,
+        is_async: false,
+        type_params: None,
+        return_type: None,
+    }
+}
+
+pub(crate) fn return_stmt(arg: Box<Expr>) -> Stmt {
+    Stmt::Return(ReturnStmt {
+        span: DUMMY_SP,
+        arg: Some(arg),
+    })
+}
+
+/// The private field used as the storage of an auto accessor.
+pub(crate) fn storage_prop(
+    key: PrivateName,
+    is_static: bool,
+    value: Option<Box<Expr>>,
+) -> ClassMember {
+    ClassMember::PrivateProp(PrivateProp {
+        span: DUMMY_SP,
+        key,
+        value,
+        type_ann: None,
+        is_static,
+        decorators: vec![],
+        computed: false,
+        accessibility: None,
+        is_abstract: false,
+        is_optional: false,
+        is_override: false,
+        readonly: false,
+        definite: false,
+    })
+}
@@ -1,5 +1,5 @@
 use either::Either;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::{iter, mem::take};
 use swc_common::{Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
@@ -13,6 +13,7 @@ use swc_ecma_utils::{
 use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, Visit, VisitWith};
 
 mod legacy;
+mod v2022_03;
 
 /// ## Simple class decorator
 ///
@@ -61,10 +62,15 @@ pub fn decorators(c: Config) -> impl Fold {
         if c.emit_metadata {
             unimplemented!("emitting decorator metadata while using new proposal")
         }
-        Either::Right(Decorators {
-            is_in_strict: false,
-            vars: Default::default(),
-        })
+        match c.version {
+            DecoratorVersion::V201809 => Either::Right(Either::Left(Decorators {
+                is_in_strict: false,
+                vars: Default::default(),
+            })),
+            DecoratorVersion::V202203 => {
+                Either::Right(Either::Right(self::v2022_03::decorator_2022_03()))
+            }
+        }
     }
 }
 
@@ -74,6 +80,28 @@ pub struct Config {
     pub legacy: bool,
     #[serde(default)]
     pub emit_metadata: bool,
+
+    /// The version of the proposal. Ignored if `legacy` is true.
+    #[serde(default)]
+    pub version: DecoratorVersion,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DecoratorVersion {
+    /// The proposal implemented by the `_decorate` helper.
+    #[serde(rename = "2018-09")]
+    V201809,
+
+    /// The proposal with `accessor` and `context` objects, implemented by the
+    /// `_applyDecs2203` helper.
+    #[serde(rename = "2022-03")]
+    V202203,
+}
+
+impl Default for DecoratorVersion {
+    fn default() -> Self {
+        DecoratorVersion::V201809
+    }
 }
 
 #[derive(Debug, Default)]
@@ -326,6 +354,8 @@ impl Decorators {
                     in_nested_scope: false,
                     in_injected_define_property_call: false,
                     this_alias_mark: None,
+                    constant_super: false,
+                    super_class: &None,
                 });
 
                 let method = method.fold_with(&mut folder);
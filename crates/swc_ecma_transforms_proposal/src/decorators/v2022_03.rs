@@ -0,0 +1,871 @@
+use std::mem::take;
+
+use swc_atoms::JsWord;
+use swc_common::{collections::AHashMap, util::take::Take, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::helper;
+use swc_ecma_utils::{
+    constructor::inject_after_super, default_constructor, prepend, private_ident, quote_ident,
+    quote_str, undefined, ExprFactory,
+};
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
+
+use crate::auto_accessors::{
+    collect_private_names, function, lower_auto_accessor, private_get, private_set, return_stmt,
+    setter_function, storage_prop, unique_private_name,
+};
+
+const FIELD: u8 = 0;
+const ACCESSOR: u8 = 1;
+const METHOD: u8 = 2;
+const GETTER: u8 = 3;
+const SETTER: u8 = 4;
+/// Added to the kind of static members.
+const STATIC: u8 = 5;
+
+/// Decorators with the semantics of the `2022-03` version of the proposal,
+/// including auto accessors.
+///
+/// Decorators are applied by `_applyDecs2203` in a static block, which is the
+/// first member of the transformed class.
+pub(super) fn decorator_2022_03() -> impl VisitMut + Fold {
+    as_folder(Decorator202203::default())
+}
+
+#[derive(Default)]
+struct Decorator202203 {
+    /// Variables declared at the start of the current statement list.
+    extra_vars: Vec<VarDeclarator>,
+
+    /// Expressions which should be evaluated before the class, like
+    /// decorators and computed keys.
+    pre_class_inits: Vec<Box<Expr>>,
+}
+
+/// An element of the second argument of `_applyDecs2203`.
+struct DecInfo {
+    elems: Vec<Box<Expr>>,
+    /// Variables which are assigned from the return value of `_applyDecs2203`.
+    locals: Vec<Ident>,
+}
+
+#[derive(Default)]
+struct ClassState {
+    static_non_fields: Vec<DecInfo>,
+    proto_non_fields: Vec<DecInfo>,
+    static_fields: Vec<DecInfo>,
+    proto_fields: Vec<DecInfo>,
+}
+
+impl ClassState {
+    fn push(&mut self, is_static: bool, kind: u8, elems: Vec<Box<Expr>>, locals: Vec<Ident>) {
+        let info = DecInfo {
+            elems: {
+                let mut elems = elems;
+                let kind = if is_static { kind + STATIC } else { kind };
+                elems.insert(1, Box::new(Expr::from(kind as f64)));
+                elems
+            },
+            locals,
+        };
+
+        match (is_static, kind == FIELD) {
+            (true, false) => self.static_non_fields.push(info),
+            (false, false) => self.proto_non_fields.push(info),
+            (true, true) => self.static_fields.push(info),
+            (false, true) => self.proto_fields.push(info),
+        }
+    }
+}
+
+impl Decorator202203 {
+    /// Hoists decorators which are not identifiers, so they are evaluated
+    /// before the class.
+    fn preserve_decorators(&mut self, decorators: Vec<Decorator>) -> Vec<Box<Expr>> {
+        decorators
+            .into_iter()
+            .map(|dec| {
+                if dec.expr.is_ident() {
+                    return dec.expr;
+                }
+
+                let ident = private_ident!("_dec");
+                self.hoist(&ident, dec.expr);
+                Box::new(Expr::Ident(ident))
+            })
+            .collect()
+    }
+
+    /// Returns the name of the member passed to `_applyDecs2203`.
+    ///
+    /// Computed keys are hoisted, so they are evaluated only once.
+    fn preserve_key(&mut self, key: &mut PropName) -> Box<Expr> {
+        match key {
+            PropName::Ident(i) => Box::new(Expr::Lit(Lit::Str(quote_str!(i.span, i.sym.clone())))),
+            PropName::Str(s) => Box::new(Expr::Lit(Lit::Str(s.clone()))),
+            PropName::Num(n) => Box::new(Expr::Lit(Lit::Num(n.clone()))),
+            PropName::BigInt(b) => Box::new(Expr::Lit(Lit::BigInt(b.clone()))),
+            PropName::Computed(c) => {
+                if !c.expr.is_lit() {
+                    let ident = private_ident!("_computedKey");
+                    self.hoist(&ident, c.expr.take());
+                    c.expr = Box::new(Expr::Ident(ident));
+                }
+
+                c.expr.clone()
+            }
+        }
+    }
+
+    /// Declares `ident` and assigns `value` to it before the class.
+    fn hoist(&mut self, ident: &Ident, value: Box<Expr>) {
+        self.extra_vars.push(var_declarator(ident.clone()));
+        self.pre_class_inits.push(Box::new(Expr::Assign(AssignExpr {
+            span: DUMMY_SP,
+            op: op!("="),
+            left: PatOrExpr::Pat(Box::new(Pat::Ident(ident.clone().into()))),
+            right: value,
+        })));
+    }
+
+    /// Returns the identifier which holds the decorated class if the class
+    /// has decorators.
+    fn transform_class(&mut self, class: &mut Class, class_ident: Option<&Ident>) -> Option<Ident> {
+        if !needs_transform(class) {
+            return None;
+        }
+
+        let class_decs = self.preserve_decorators(take(&mut class.decorators));
+
+        let mut state = ClassState::default();
+        let mut private_names = collect_private_names(class);
+        let mut unshifted = vec![];
+        let mut body = Vec::with_capacity(class.body.len() + 2);
+
+        for member in class.body.take() {
+            match member {
+                ClassMember::ClassProp(mut p) if !p.decorators.is_empty() => {
+                    let decs = self.preserve_decorators(take(&mut p.decorators));
+                    let name = self.preserve_key(&mut p.key);
+                    let init = private_ident!(format!("_init_{}", name_hint(&p.key)));
+
+                    p.value = Some(init_call(&init, p.value.take()));
+                    state.push(p.is_static, FIELD, vec![dec_expr(decs), name], vec![init]);
+
+                    body.push(ClassMember::ClassProp(p));
+                }
+
+                ClassMember::PrivateProp(mut p) if !p.decorators.is_empty() => {
+                    let decs = self.preserve_decorators(take(&mut p.decorators));
+                    let init = private_ident!(format!("_init_{}", p.key.id.sym));
+
+                    p.value = Some(init_call(&init, p.value.take()));
+                    state.push(
+                        p.is_static,
+                        FIELD,
+                        vec![
+                            dec_expr(decs),
+                            private_key_name(&p.key),
+                            getter_fn(&p.key),
+                            setter_fn(&p.key),
+                        ],
+                        vec![init],
+                    );
+
+                    body.push(ClassMember::PrivateProp(p));
+                }
+
+                ClassMember::AutoAccessor(mut a) => {
+                    let storage = PrivateName {
+                        span: DUMMY_SP,
+                        id: quote_ident!(unique_private_name(&mut private_names)),
+                    };
+                    let decs = take(&mut a.decorators);
+
+                    match a.key {
+                        Key::Public(mut key) => {
+                            if !decs.is_empty() {
+                                let decs = self.preserve_decorators(decs);
+                                let name = self.preserve_key(&mut key);
+                                let init = private_ident!(format!("_init_{}", name_hint(&key)));
+
+                                a.value = Some(init_call(&init, a.value.take()));
+                                state.push(
+                                    a.is_static,
+                                    ACCESSOR,
+                                    vec![dec_expr(decs), name],
+                                    vec![init],
+                                );
+                            }
+
+                            a.key = Key::Public(key);
+                            body.extend(lower_auto_accessor(a, storage));
+                        }
+
+                        Key::Private(..) if decs.is_empty() => {
+                            body.extend(lower_auto_accessor(a, storage));
+                        }
+
+                        Key::Private(key) => {
+                            let decs = self.preserve_decorators(decs);
+                            let init = private_ident!(format!("_init_{}", key.id.sym));
+                            let get = private_ident!(format!("_get_{}", key.id.sym));
+                            let set = private_ident!(format!("_set_{}", key.id.sym));
+
+                            let value = Some(init_call(&init, a.value.take()));
+                            state.push(
+                                a.is_static,
+                                ACCESSOR,
+                                vec![
+                                    dec_expr(decs),
+                                    private_key_name(&key),
+                                    getter_fn(&storage),
+                                    setter_fn(&storage),
+                                ],
+                                vec![init, get.clone(), set.clone()],
+                            );
+
+                            let getter = function(
+                                vec![],
+                                return_stmt(Box::new(get.as_call(
+                                    DUMMY_SP,
+                                    vec![ThisExpr { span: DUMMY_SP }.as_arg()],
+                                ))),
+                            );
+                            let setter = setter_function(Box::new(set.as_call(
+                                DUMMY_SP,
+                                vec![
+                                    ThisExpr { span: DUMMY_SP }.as_arg(),
+                                    quote_ident!("v").as_arg(),
+                                ],
+                            )));
+
+                            body.push(storage_prop(storage, a.is_static, value));
+                            body.push(ClassMember::PrivateMethod(PrivateMethod {
+                                span: a.span,
+                                key: key.clone(),
+                                function: getter,
+                                kind: MethodKind::Getter,
+                                is_static: a.is_static,
+                                accessibility: None,
+                                is_abstract: false,
+                                is_optional: false,
+                                is_override: false,
+                            }));
+                            body.push(ClassMember::PrivateMethod(PrivateMethod {
+                                span: a.span,
+                                key,
+                                function: setter,
+                                kind: MethodKind::Setter,
+                                is_static: a.is_static,
+                                accessibility: None,
+                                is_abstract: false,
+                                is_optional: false,
+                                is_override: false,
+                            }));
+                        }
+                    }
+                }
+
+                ClassMember::Method(mut m) if !m.function.decorators.is_empty() => {
+                    let decs = self.preserve_decorators(take(&mut m.function.decorators));
+                    let name = self.preserve_key(&mut m.key);
+
+                    state.push(
+                        m.is_static,
+                        method_kind(m.kind),
+                        vec![dec_expr(decs), name],
+                        vec![],
+                    );
+
+                    body.push(ClassMember::Method(m));
+                }
+
+                ClassMember::PrivateMethod(mut m) if !m.function.decorators.is_empty() => {
+                    let decs = self.preserve_decorators(take(&mut m.function.decorators));
+                    let call = private_ident!(format!("_call_{}", m.key.id.sym));
+
+                    // The decorated function is passed to `_applyDecs2203`, and the member
+                    // becomes a proxy to the function returned from it.
+                    let original = Box::new(Expr::Fn(FnExpr {
+                        ident: None,
+                        function: m.function.take(),
+                    }));
+                    state.push(
+                        m.is_static,
+                        method_kind(m.kind),
+                        vec![dec_expr(decs), private_key_name(&m.key), original],
+                        vec![call.clone()],
+                    );
+
+                    match m.kind {
+                        MethodKind::Method => {
+                            unshifted.push(ClassMember::PrivateProp(PrivateProp {
+                                span: m.span,
+                                key: m.key,
+                                value: Some(Box::new(Expr::Ident(call))),
+                                type_ann: None,
+                                is_static: m.is_static,
+                                decorators: vec![],
+                                computed: false,
+                                accessibility: None,
+                                is_abstract: false,
+                                is_optional: false,
+                                is_override: false,
+                                readonly: false,
+                                definite: false,
+                            }));
+                        }
+                        MethodKind::Getter => {
+                            m.function = function(
+                                vec![],
+                                return_stmt(Box::new(call.as_call(
+                                    DUMMY_SP,
+                                    vec![ThisExpr { span: DUMMY_SP }.as_arg()],
+                                ))),
+                            );
+                            body.push(ClassMember::PrivateMethod(m));
+                        }
+                        MethodKind::Setter => {
+                            m.function = setter_function(Box::new(call.as_call(
+                                DUMMY_SP,
+                                vec![
+                                    ThisExpr { span: DUMMY_SP }.as_arg(),
+                                    quote_ident!("v").as_arg(),
+                                ],
+                            )));
+                            body.push(ClassMember::PrivateMethod(m));
+                        }
+                    }
+                }
+
+                _ => body.push(member),
+            }
+        }
+
+        let init_proto = if state.proto_non_fields.is_empty() {
+            None
+        } else {
+            Some(private_ident!("_initProto"))
+        };
+        let init_static = if state.static_non_fields.is_empty() {
+            None
+        } else {
+            Some(private_ident!("_initStatic"))
+        };
+        let new_class = if class_decs.is_empty() {
+            None
+        } else {
+            let name = class_ident.map_or("Class", |i| &*i.sym);
+            Some((
+                private_ident!(format!("_{}", name)),
+                private_ident!("_initClass"),
+            ))
+        };
+
+        let dec_infos = state
+            .static_non_fields
+            .into_iter()
+            .chain(state.proto_non_fields)
+            .chain(state.static_fields)
+            .chain(state.proto_fields);
+
+        let mut locals = vec![];
+        let mut elems = vec![];
+        for info in dec_infos {
+            locals.extend(info.locals);
+            elems.push(Some(
+                ArrayLit {
+                    span: DUMMY_SP,
+                    elems: info.elems.into_iter().map(arg).collect(),
+                }
+                .as_arg(),
+            ));
+        }
+
+        if !elems.is_empty() || new_class.is_some() {
+            locals.extend(init_proto.clone());
+            locals.extend(init_static.clone());
+            if let Some((new_class, init_class)) = &new_class {
+                locals.push(new_class.clone());
+                locals.push(init_class.clone());
+            }
+
+            self.extra_vars
+                .extend(locals.iter().cloned().map(var_declarator));
+
+            // [_init_a, _initProto] = _applyDecs2203(this, [...], [...]);
+            let apply = Expr::Assign(AssignExpr {
+                span: DUMMY_SP,
+                op: op!("="),
+                left: PatOrExpr::Pat(Box::new(Pat::Array(ArrayPat {
+                    span: DUMMY_SP,
+                    elems: locals
+                        .into_iter()
+                        .map(|local| Some(Pat::Ident(local.into())))
+                        .collect(),
+                    optional: false,
+                    type_ann: None,
+                }))),
+                right: Box::new(Expr::Call(CallExpr {
+                    span: DUMMY_SP,
+                    callee: helper!(apply_decs_2203, "applyDecs2203"),
+                    args: vec![
+                        ThisExpr { span: DUMMY_SP }.as_arg(),
+                        ArrayLit {
+                            span: DUMMY_SP,
+                            elems,
+                        }
+                        .as_arg(),
+                        ArrayLit {
+                            span: DUMMY_SP,
+                            elems: class_decs.into_iter().map(arg).collect(),
+                        }
+                        .as_arg(),
+                    ],
+                    type_args: None,
+                })),
+            });
+
+            let mut stmts = vec![apply.into_stmt()];
+            if let Some(init_static) = init_static {
+                stmts.push(
+                    init_static
+                        .as_call(DUMMY_SP, vec![ThisExpr { span: DUMMY_SP }.as_arg()])
+                        .into_stmt(),
+                );
+            }
+
+            unshifted.insert(0, static_block(stmts));
+        }
+
+        if let Some(init_proto) = init_proto {
+            let call =
+                Box::new(init_proto.as_call(DUMMY_SP, vec![ThisExpr { span: DUMMY_SP }.as_arg()]));
+            inject_init_proto(&mut unshifted, &mut body, class.super_class.is_some(), call);
+        }
+
+        if let Some((_, init_class)) = &new_class {
+            body.push(static_block(vec![init_class
+                .clone()
+                .as_call(DUMMY_SP, vec![])
+                .into_stmt()]));
+        }
+
+        unshifted.extend(body);
+        class.body = unshifted;
+
+        new_class.map(|(new_class, _)| new_class)
+    }
+
+    /// Handles class declarations in a statement list. Returns the statements
+    /// to be inserted before `class`, and the identifier which holds the
+    /// decorated class.
+    fn transform_class_decl(&mut self, class: &mut ClassDecl) -> (Vec<Stmt>, Option<Ident>) {
+        class.visit_mut_with(self);
+
+        let new_class = self.transform_class(&mut class.class, Some(&class.ident));
+
+        let pre = if self.pre_class_inits.is_empty() {
+            vec![]
+        } else {
+            vec![Expr::Seq(SeqExpr {
+                span: DUMMY_SP,
+                exprs: take(&mut self.pre_class_inits),
+            })
+            .into_stmt()]
+        };
+
+        (pre, new_class)
+    }
+}
+
+impl VisitMut for Decorator202203 {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        if let Expr::Class(c) = e {
+            let new_class = self.transform_class(&mut c.class, c.ident.as_ref());
+
+            if new_class.is_none() && self.pre_class_inits.is_empty() {
+                return;
+            }
+
+            let mut exprs = take(&mut self.pre_class_inits);
+            exprs.push(Box::new(e.take()));
+            exprs.extend(new_class.map(|i| Box::new(Expr::Ident(i))));
+
+            *e = Expr::Seq(SeqExpr {
+                span: DUMMY_SP,
+                exprs,
+            });
+        }
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        let old_vars = take(&mut self.extra_vars);
+
+        let mut renames = AHashMap::default();
+        let mut new = Vec::with_capacity(items.len());
+
+        for item in take(items) {
+            match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::Class(mut c))) => {
+                    let (pre, new_class) = self.transform_class_decl(&mut c);
+                    new.extend(pre.into_iter().map(ModuleItem::Stmt));
+                    if let Some(new_class) = new_class {
+                        renames.insert(c.ident.to_id(), new_class);
+                    }
+                    new.push(ModuleItem::Stmt(Stmt::Decl(Decl::Class(c))));
+                }
+
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    span,
+                    decl: Decl::Class(mut c),
+                })) => {
+                    let (pre, new_class) = self.transform_class_decl(&mut c);
+                    new.extend(pre.into_iter().map(ModuleItem::Stmt));
+
+                    match new_class {
+                        Some(new_class) => {
+                            // export { _Foo as Foo }
+                            let export = export_as(new_class.clone(), c.ident.clone());
+                            renames.insert(c.ident.to_id(), new_class);
+                            new.push(ModuleItem::Stmt(Stmt::Decl(Decl::Class(c))));
+                            new.push(export);
+                        }
+                        None => {
+                            new.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                                span,
+                                decl: Decl::Class(c),
+                            })))
+                        }
+                    }
+                }
+
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    span,
+                    decl: DefaultDecl::Class(ClassExpr { ident, class }),
+                })) if needs_transform(&class) => {
+                    let mut c = ClassDecl {
+                        ident: ident.unwrap_or_else(|| private_ident!("_default")),
+                        declare: false,
+                        class,
+                    };
+                    let (pre, new_class) = self.transform_class_decl(&mut c);
+                    new.extend(pre.into_iter().map(ModuleItem::Stmt));
+
+                    match new_class {
+                        Some(new_class) => {
+                            // export { _Foo as default }
+                            let export = export_as(new_class.clone(), quote_ident!("default"));
+                            renames.insert(c.ident.to_id(), new_class);
+                            new.push(ModuleItem::Stmt(Stmt::Decl(Decl::Class(c))));
+                            new.push(export);
+                        }
+                        None => new.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(
+                            ExportDefaultDecl {
+                                span,
+                                decl: DefaultDecl::Class(ClassExpr {
+                                    ident: Some(c.ident),
+                                    class: c.class,
+                                }),
+                            },
+                        ))),
+                    }
+                }
+
+                mut item => {
+                    item.visit_mut_with(self);
+                    new.push(item);
+                }
+            }
+        }
+
+        if !renames.is_empty() {
+            new.visit_mut_with(&mut ClassNameRenamer { renames: &renames });
+        }
+
+        let vars = std::mem::replace(&mut self.extra_vars, old_vars);
+        if !vars.is_empty() {
+            prepend(
+                &mut new,
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    declare: false,
+                    decls: vars,
+                }))),
+            );
+        }
+
+        *items = new;
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        let old_vars = take(&mut self.extra_vars);
+
+        let mut renames = AHashMap::default();
+        let mut new = Vec::with_capacity(stmts.len());
+
+        for stmt in take(stmts) {
+            match stmt {
+                Stmt::Decl(Decl::Class(mut c)) => {
+                    let (pre, new_class) = self.transform_class_decl(&mut c);
+                    new.extend(pre);
+                    if let Some(new_class) = new_class {
+                        renames.insert(c.ident.to_id(), new_class);
+                    }
+                    new.push(Stmt::Decl(Decl::Class(c)));
+                }
+
+                mut stmt => {
+                    stmt.visit_mut_with(self);
+                    new.push(stmt);
+                }
+            }
+        }
+
+        if !renames.is_empty() {
+            new.visit_mut_with(&mut ClassNameRenamer { renames: &renames });
+        }
+
+        let vars = std::mem::replace(&mut self.extra_vars, old_vars);
+        if !vars.is_empty() {
+            prepend(
+                &mut new,
+                Stmt::Decl(Decl::Var(VarDecl {
+                    span: DUMMY_SP,
+                    kind: VarDeclKind::Var,
+                    declare: false,
+                    decls: vars,
+                })),
+            );
+        }
+
+        *stmts = new;
+    }
+}
+
+/// Replaces references to decorated classes with the variable which holds
+/// the decorated class.
+struct ClassNameRenamer<'a> {
+    renames: &'a AHashMap<Id, Ident>,
+}
+
+impl VisitMut for ClassNameRenamer<'_> {
+    noop_visit_mut_type!();
+
+    /// The binding of the class itself is preserved.
+    fn visit_mut_class_decl(&mut self, n: &mut ClassDecl) {
+        n.class.visit_mut_with(self);
+    }
+
+    fn visit_mut_export_named_specifier(&mut self, n: &mut ExportNamedSpecifier) {
+        if let ModuleExportName::Ident(orig) = &mut n.orig {
+            if let Some(new) = self.renames.get(&orig.to_id()) {
+                if n.exported.is_none() {
+                    n.exported = Some(ModuleExportName::Ident(orig.clone()));
+                }
+                *orig = new.clone();
+            }
+        }
+    }
+
+    fn visit_mut_ident(&mut self, i: &mut Ident) {
+        if let Some(new) = self.renames.get(&i.to_id()) {
+            *i = new.clone();
+        }
+    }
+
+    fn visit_mut_member_prop(&mut self, n: &mut MemberProp) {
+        if let MemberProp::Computed(c) = n {
+            c.visit_mut_with(self);
+        }
+    }
+
+    fn visit_mut_prop(&mut self, n: &mut Prop) {
+        match n {
+            Prop::Shorthand(i) => {
+                if let Some(new) = self.renames.get(&i.to_id()) {
+                    let prop = Prop::KeyValue(KeyValueProp {
+                        key: PropName::Ident(i.clone()),
+                        value: Box::new(Expr::Ident(new.clone())),
+                    });
+                    *n = prop;
+                }
+            }
+            _ => n.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_prop_name(&mut self, n: &mut PropName) {
+        if let PropName::Computed(c) = n {
+            c.visit_mut_with(self);
+        }
+    }
+
+    fn visit_mut_super_prop(&mut self, n: &mut SuperProp) {
+        if let SuperProp::Computed(c) = n {
+            c.visit_mut_with(self);
+        }
+    }
+}
+
+fn needs_transform(class: &Class) -> bool {
+    !class.decorators.is_empty()
+        || class.body.iter().any(|member| match member {
+            ClassMember::ClassProp(p) => !p.decorators.is_empty(),
+            ClassMember::PrivateProp(p) => !p.decorators.is_empty(),
+            ClassMember::Method(m) => !m.function.decorators.is_empty(),
+            ClassMember::PrivateMethod(m) => !m.function.decorators.is_empty(),
+            ClassMember::AutoAccessor(..) => true,
+            _ => false,
+        })
+}
+
+fn name_hint(key: &PropName) -> JsWord {
+    match key {
+        PropName::Ident(i) => i.sym.clone(),
+        PropName::Str(s) if Ident::verify_symbol(&s.value).is_ok() => s.value.clone(),
+        _ => "computedKey".into(),
+    }
+}
+
+fn method_kind(kind: MethodKind) -> u8 {
+    match kind {
+        MethodKind::Method => METHOD,
+        MethodKind::Getter => GETTER,
+        MethodKind::Setter => SETTER,
+    }
+}
+
+fn dec_expr(mut decs: Vec<Box<Expr>>) -> Box<Expr> {
+    if decs.len() == 1 {
+        return decs.remove(0);
+    }
+
+    Box::new(Expr::Array(ArrayLit {
+        span: DUMMY_SP,
+        elems: decs.into_iter().map(arg).collect(),
+    }))
+}
+
+fn arg(expr: Box<Expr>) -> Option<ExprOrSpread> {
+    Some(ExprOrSpread { spread: None, expr })
+}
+
+fn private_key_name(key: &PrivateName) -> Box<Expr> {
+    Box::new(Expr::Lit(Lit::Str(quote_str!(key.id.sym.clone()))))
+}
+
+fn var_declarator(name: Ident) -> VarDeclarator {
+    VarDeclarator {
+        span: DUMMY_SP,
+        name: Pat::Ident(name.into()),
+        init: None,
+        definite: false,
+    }
+}
+
+/// `_init_a(this, value)`
+fn init_call(init: &Ident, value: Option<Box<Expr>>) -> Box<Expr> {
+    let mut args = vec![ThisExpr { span: DUMMY_SP }.as_arg()];
+    args.extend(value.map(|expr| ExprOrSpread { spread: None, expr }));
+
+    Box::new(init.clone().as_call(DUMMY_SP, args))
+}
+
+/// `function () { return this.#a; }`
+fn getter_fn(key: &PrivateName) -> Box<Expr> {
+    Box::new(Expr::Fn(FnExpr {
+        ident: None,
+        function: function(vec![], return_stmt(private_get(key))),
+    }))
+}
+
+/// `function (v) { this.#a = v; }`
+fn setter_fn(key: &PrivateName) -> Box<Expr> {
+    Box::new(Expr::Fn(FnExpr {
+        ident: None,
+        function: setter_function(private_set(key)),
+    }))
+}
+
+fn static_block(stmts: Vec<Stmt>) -> ClassMember {
+    ClassMember::StaticBlock(StaticBlock {
+        span: DUMMY_SP,
+        body: BlockStmt {
+            span: DUMMY_SP,
+            stmts,
+        },
+    })
+}
+
+/// Calls `_initProto(this)` before the first instance field is initialized,
+/// or in the constructor if there's no instance field.
+fn inject_init_proto(
+    unshifted: &mut [ClassMember],
+    body: &mut Vec<ClassMember>,
+    has_super: bool,
+    call: Box<Expr>,
+) {
+    let field = unshifted
+        .iter_mut()
+        .chain(body.iter_mut())
+        .find_map(|member| match member {
+            ClassMember::ClassProp(ClassProp {
+                is_static: false,
+                value,
+                ..
+            })
+            | ClassMember::PrivateProp(PrivateProp {
+                is_static: false,
+                value,
+                ..
+            }) => Some(value),
+            _ => None,
+        });
+
+    if let Some(value) = field {
+        let init = value.take().unwrap_or_else(|| undefined(DUMMY_SP));
+        *value = Some(Box::new(Expr::Seq(SeqExpr {
+            span: DUMMY_SP,
+            exprs: vec![call, init],
+        })));
+        return;
+    }
+
+    let constructor = body.iter_mut().find_map(|member| match member {
+        ClassMember::Constructor(c) if c.body.is_some() => Some(c),
+        _ => None,
+    });
+
+    match constructor {
+        Some(c) => inject_after_super(c, vec![call]),
+        None => {
+            let mut c = default_constructor(has_super);
+            inject_after_super(&mut c, vec![call]);
+            body.push(ClassMember::Constructor(c));
+        }
+    }
+}
+
+/// `export { local as exported }`
+fn export_as(local: Ident, exported: Ident) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+        span: DUMMY_SP,
+        specifiers: vec![ExportNamedSpecifier {
+            span: DUMMY_SP,
+            orig: ModuleExportName::Ident(local),
+            exported: Some(ModuleExportName::Ident(exported)),
+            is_type_only: false,
+        }
+        .into()],
+        src: None,
+        type_only: false,
+        asserts: None,
+    }))
+}
@@ -1,10 +1,11 @@
 #![deny(clippy::all)]
 
 pub use self::{
-    decorators::decorators, export_default_from::export_default_from,
-    import_assertions::import_assertions,
+    auto_accessors::auto_accessors, decorators::decorators,
+    export_default_from::export_default_from, import_assertions::import_assertions,
 };
 
+mod auto_accessors;
 pub mod decorators;
 mod export_default_from;
 mod import_assertions;
@@ -0,0 +1,137 @@
+use swc_ecma_parser::{EsConfig, Syntax};
+use swc_ecma_transforms_proposal::auto_accessors;
+use swc_ecma_transforms_testing::{test, test_exec};
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsConfig {
+        decorators: true,
+        auto_accessors: true,
+        ..Default::default()
+    })
+}
+
+test!(
+    syntax(),
+    |_| auto_accessors(),
+    public_and_private,
+    r#"
+class Foo {
+    #A = 0;
+    accessor a = 1;
+    static accessor #b;
+    accessor ["c"] = 2;
+}
+"#,
+    r#"
+class Foo {
+    #A = 0;
+    #B = 1;
+    get a() {
+        return this.#B;
+    }
+    set a(v) {
+        this.#B = v;
+    }
+    static #C;
+    static get #b() {
+        return this.#C;
+    }
+    static set #b(v) {
+        this.#C = v;
+    }
+    #D = 2;
+    get ["c"]() {
+        return this.#D;
+    }
+    set ["c"](v) {
+        this.#D = v;
+    }
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| auto_accessors(),
+    decorators_are_moved_to_getter,
+    r#"
+class Foo {
+    @dec
+    accessor a;
+}
+"#,
+    r#"
+class Foo {
+    #A;
+    @dec
+    get a() {
+        return this.#A;
+    }
+    set a(v) {
+        this.#A = v;
+    }
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| auto_accessors(),
+    nested_class,
+    r#"
+class Foo {
+    accessor a = class {
+        accessor b;
+    };
+}
+"#,
+    r#"
+class Foo {
+    #A = class {
+        #A;
+        get b() {
+            return this.#A;
+        }
+        set b(v) {
+            this.#A = v;
+        }
+    };
+    get a() {
+        return this.#A;
+    }
+    set a(v) {
+        this.#A = v;
+    }
+}
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| auto_accessors(),
+    exec,
+    r#"
+class Foo {
+    accessor a = 1;
+    static accessor #b = 2;
+
+    static getB() {
+        return Foo.#b;
+    }
+
+    static setB(v) {
+        Foo.#b = v;
+    }
+}
+
+const foo = new Foo();
+expect(foo.a).toBe(1);
+foo.a = 3;
+expect(foo.a).toBe(3);
+expect(Object.keys(foo)).toEqual([]);
+
+expect(Foo.getB()).toBe(2);
+Foo.setB(4);
+expect(Foo.getB()).toBe(4);
+"#
+);
@@ -12,12 +12,12 @@ use swc_ecma_ast::*;
 use swc_ecma_transforms_react::{parse_expr_for_jsx, JsxDirectives};
 use swc_ecma_utils::{
     constructor::inject_after_super, default_constructor, ident::IdentLike, member_expr, prepend,
-    private_ident, quote_ident, replace_ident, var::VarCollector, ExprFactory, Id, ModuleItemLike,
-    StmtLike,
+    private_ident, quote_ident, quote_str, replace_ident, var::VarCollector, ExprFactory, Id,
+    ModuleItemLike, StmtLike,
 };
 use swc_ecma_visit::{
-    as_folder, noop_visit_mut_type, visit_obj_and_computed, Fold, Visit, VisitMut, VisitMutWith,
-    VisitWith,
+    as_folder, noop_visit_mut_type, noop_visit_type, visit_obj_and_computed, Fold, Visit, VisitMut,
+    VisitMutWith, VisitWith,
 };
 
 /// Value does not contain TsLit::Bool
@@ -79,6 +79,32 @@ pub struct Config {
     /// Note: this pass handle jsx directives in comments
     #[serde(default)]
     pub pragma_frag: Option<String>,
+
+    /// Replace accesses to members of `const enum`s declared in the current
+    /// file with their values, and remove the enums if they are not exported.
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub inline_const_enums: bool,
+
+    /// Values of `const enum`s declared in other modules, keyed by the module
+    /// specifier used in imports, then by the exported name of the enum and
+    /// then by the name of the member.
+    ///
+    /// Accesses to members of imported const enums found in this map are
+    /// replaced with their values, so that the imports can be removed.
+    ///
+    /// As relative specifiers depend on the importing file, this is a per-file
+    /// config and should be built for each file being transformed.
+    #[serde(default)]
+    pub const_enums: AHashMap<JsWord, AHashMap<JsWord, AHashMap<JsWord, ConstEnumValue>>>,
+}
+
+/// A value of a member of a `const enum`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ConstEnumValue {
+    Str(JsWord),
+    Num(f64),
 }
 
 pub fn strip_with_config(config: Config, top_level_mark: Mark) -> impl Fold + VisitMut {
@@ -762,150 +788,6 @@ where
 
     /// Returns `(var_decl, init)`.
     fn handle_enum(&mut self, e: TsEnumDecl, module_name: Option<&Ident>) -> (Option<Decl>, Stmt) {
-        /// Called only for enums.
-        ///
-        /// If both of the default value and the initialization is None, this
-        /// method returns [Err].
-        fn compute(
-            e: &TsEnumDecl,
-            span: Span,
-            values: &mut EnumValues,
-            default: Option<i64>,
-            init: Option<&Expr>,
-        ) -> Result<TsLit, ()> {
-            fn compute_bin(
-                e: &TsEnumDecl,
-                span: Span,
-                values: &mut EnumValues,
-                expr: &BinExpr,
-            ) -> Result<TsLit, ()> {
-                let l = compute(e, span, values, None, Some(&expr.left))?;
-                let r = compute(e, span, values, None, Some(&expr.right))?;
-
-                Ok(match (l, r) {
-                    (
-                        TsLit::Number(Number { value: l, .. }),
-                        TsLit::Number(Number { value: r, .. }),
-                    ) => {
-                        TsLit::Number(Number {
-                            span,
-                            value: match expr.op {
-                                op!(bin, "+") => l + r,
-                                op!(bin, "-") => l - r,
-                                op!("*") => l * r,
-                                op!("/") => l / r,
-
-                                // TODO
-                                op!("&") => ((l.round() as i64) & (r.round() as i64)) as _,
-                                op!("|") => ((l.round() as i64) | (r.round() as i64)) as _,
-                                op!("^") => ((l.round() as i64) ^ (r.round() as i64)) as _,
-
-                                op!("<<") => ((l.round() as i64) << (r.round() as i64)) as _,
-                                op!(">>") => ((l.round() as i64) >> (r.round() as i64)) as _,
-                                // TODO: Verify this
-                                op!(">>>") => ((l.round() as u64) >> (r.round() as u64)) as _,
-                                _ => return Err(()),
-                            },
-                        })
-                    }
-                    (TsLit::Str(l), TsLit::Str(r)) if expr.op == op!(bin, "+") => TsLit::Str(Str {
-                        span,
-                        value: format!("{}{}", l.value, r.value).into(),
-                        has_escape: l.has_escape || r.has_escape,
-                        kind: Default::default(),
-                    }),
-                    (TsLit::Number(l), TsLit::Str(r)) if expr.op == op!(bin, "+") => {
-                        TsLit::Str(Str {
-                            span,
-                            value: format!("{}{}", l.value, r.value).into(),
-                            has_escape: r.has_escape,
-                            kind: Default::default(),
-                        })
-                    }
-                    (TsLit::Str(l), TsLit::Number(r)) if expr.op == op!(bin, "+") => {
-                        TsLit::Str(Str {
-                            span,
-                            value: format!("{}{}", l.value, r.value).into(),
-                            has_escape: l.has_escape,
-                            kind: Default::default(),
-                        })
-                    }
-                    _ => return Err(()),
-                })
-            }
-
-            if let Some(expr) = init {
-                match expr {
-                    Expr::Lit(Lit::Str(s)) => return Ok(TsLit::Str(s.clone())),
-                    Expr::Lit(Lit::Num(s)) => return Ok(TsLit::Number(*s)),
-                    Expr::Bin(ref bin) => return compute_bin(e, span, values, bin),
-                    Expr::Paren(ref paren) => {
-                        return compute(e, span, values, default, Some(&paren.expr))
-                    }
-
-                    Expr::Ident(ref id) => {
-                        if let Some(Some(v)) = values.get(&id.sym) {
-                            return Ok(v.clone());
-                        }
-                        //
-                        for m in e.members.iter() {
-                            match m.id {
-                                TsEnumMemberId::Str(Str { value: ref sym, .. })
-                                | TsEnumMemberId::Ident(Ident { ref sym, .. }) => {
-                                    if *sym == id.sym {
-                                        return compute(e, span, values, None, m.init.as_deref());
-                                    }
-                                }
-                            }
-                        }
-                        return Err(());
-                    }
-                    Expr::Unary(ref expr) => {
-                        let v = compute(e, span, values, None, Some(&expr.arg))?;
-                        match v {
-                            TsLit::BigInt(BigInt { .. }) => {}
-                            TsLit::Number(Number { value: v, .. }) => {
-                                return Ok(TsLit::Number(Number {
-                                    span,
-                                    value: match expr.op {
-                                        op!(unary, "+") => v,
-                                        op!(unary, "-") => -v,
-                                        op!("!") => {
-                                            if v == 0.0f64 {
-                                                0.0
-                                            } else {
-                                                1.0
-                                            }
-                                        }
-                                        op!("~") => (!(v as i32)) as f64,
-                                        _ => return Err(()),
-                                    },
-                                }))
-                            }
-                            TsLit::Str(_) => {}
-                            TsLit::Bool(_) => {}
-                            TsLit::Tpl(_) => {}
-                        }
-                    }
-
-                    Expr::Tpl(ref t) if t.exprs.is_empty() => {
-                        if let Some(v) = &t.quasis[0].cooked {
-                            return Ok(v.clone().into());
-                        }
-                    }
-
-                    _ => {}
-                }
-            } else if let Some(value) = default {
-                return Ok(TsLit::Number(Number {
-                    span,
-                    value: value as _,
-                }));
-            }
-
-            Err(())
-        }
-
         // let Foo;
         // (function (Foo) {
         //     Foo[Foo["a"] = 0] = "a";
@@ -934,50 +816,43 @@ where
             .into_iter()
             .map(|m| -> Result<_, ()> {
                 let id_span = m.id.span();
-                let val = compute(&e, id_span, &mut values, Some(default), m.init.as_deref())
-                    .map(|val| {
-                        if let TsLit::Number(n) = val {
-                            default = n.value as i64 + 1;
-                        }
-                        values.insert(
-                            match &m.id {
-                                TsEnumMemberId::Ident(i) => i.sym.clone(),
-                                TsEnumMemberId::Str(s) => s.value.clone(),
-                            },
-                            Some(val.clone()),
-                        );
-
-                        match val {
-                            TsLit::Number(v) => Expr::Lit(Lit::Num(v)),
-                            TsLit::Str(v) => Expr::Lit(Lit::Str(v)),
-                            TsLit::Bool(v) => Expr::Lit(Lit::Bool(v)),
-                            TsLit::Tpl(v) => {
-                                Expr::Lit(Lit::Str(v.quasis.into_iter().next().unwrap().raw))
+                let val =
+                    compute_enum_member(&e, id_span, &mut values, Some(default), m.init.as_deref())
+                        .map(|val| {
+                            if let TsLit::Number(n) = val {
+                                default = n.value as i64 + 1;
                             }
-                            TsLit::BigInt(v) => Expr::Lit(Lit::BigInt(v)),
-                        }
-                    })
-                    .or_else(|err| match &m.init {
-                        None => Err(err),
-                        Some(v) => {
-                            let mut v = *v.clone();
-                            let mut visitor = EnumValuesVisitor {
-                                previous: &values,
-                                ident: &id,
-                            };
-                            visitor.visit_mut_expr(&mut v);
-
                             values.insert(
                                 match &m.id {
                                     TsEnumMemberId::Ident(i) => i.sym.clone(),
                                     TsEnumMemberId::Str(s) => s.value.clone(),
                                 },
-                                None,
+                                Some(val.clone()),
                             );
 
-                            Ok(v)
-                        }
-                    })?;
+                            Expr::Lit(enum_value_to_lit(val))
+                        })
+                        .or_else(|err| match &m.init {
+                            None => Err(err),
+                            Some(v) => {
+                                let mut v = *v.clone();
+                                let mut visitor = EnumValuesVisitor {
+                                    previous: &values,
+                                    ident: &id,
+                                };
+                                visitor.visit_mut_expr(&mut v);
+
+                                values.insert(
+                                    match &m.id {
+                                        TsEnumMemberId::Ident(i) => i.sym.clone(),
+                                        TsEnumMemberId::Str(s) => s.value.clone(),
+                                    },
+                                    None,
+                                );
+
+                                Ok(v)
+                            }
+                        })?;
 
                 Ok((m, val))
             })
@@ -2174,6 +2049,8 @@ where
             }
         }
 
+        inline_const_enums(module, &self.config);
+
         module.visit_mut_children_with(self);
         if !self.uninitialized_vars.is_empty() {
             prepend(
@@ -2554,6 +2431,8 @@ where
             }
         }
 
+        inline_const_enums(n, &self.config);
+
         n.visit_mut_children_with(self);
 
         if !self.uninitialized_vars.is_empty() {
@@ -2718,6 +2597,159 @@ fn create_prop_pat(obj: &Ident, pat: Pat) -> Pat {
     }
 }
 
+fn enum_value_to_lit(v: TsLit) -> Lit {
+    match v {
+        TsLit::Number(v) => Lit::Num(v),
+        TsLit::Str(v) => Lit::Str(v),
+        TsLit::Bool(v) => Lit::Bool(v),
+        TsLit::Tpl(v) => Lit::Str(v.quasis.into_iter().next().unwrap().raw),
+        TsLit::BigInt(v) => Lit::BigInt(v),
+    }
+}
+
+/// Called only for enums.
+///
+/// If both of the default value and the initialization is None, this
+/// method returns [Err].
+fn compute_enum_member(
+    e: &TsEnumDecl,
+    span: Span,
+    values: &mut EnumValues,
+    default: Option<i64>,
+    init: Option<&Expr>,
+) -> Result<TsLit, ()> {
+    fn compute_bin(
+        e: &TsEnumDecl,
+        span: Span,
+        values: &mut EnumValues,
+        expr: &BinExpr,
+    ) -> Result<TsLit, ()> {
+        let l = compute_enum_member(e, span, values, None, Some(&expr.left))?;
+        let r = compute_enum_member(e, span, values, None, Some(&expr.right))?;
+
+        Ok(match (l, r) {
+            (TsLit::Number(Number { value: l, .. }), TsLit::Number(Number { value: r, .. })) => {
+                TsLit::Number(Number {
+                    span,
+                    value: match expr.op {
+                        op!(bin, "+") => l + r,
+                        op!(bin, "-") => l - r,
+                        op!("*") => l * r,
+                        op!("/") => l / r,
+
+                        // TODO
+                        op!("&") => ((l.round() as i64) & (r.round() as i64)) as _,
+                        op!("|") => ((l.round() as i64) | (r.round() as i64)) as _,
+                        op!("^") => ((l.round() as i64) ^ (r.round() as i64)) as _,
+
+                        op!("<<") => ((l.round() as i64) << (r.round() as i64)) as _,
+                        op!(">>") => ((l.round() as i64) >> (r.round() as i64)) as _,
+                        // TODO: Verify this
+                        op!(">>>") => ((l.round() as u64) >> (r.round() as u64)) as _,
+                        _ => return Err(()),
+                    },
+                })
+            }
+            (TsLit::Str(l), TsLit::Str(r)) if expr.op == op!(bin, "+") => TsLit::Str(Str {
+                span,
+                value: format!("{}{}", l.value, r.value).into(),
+                has_escape: l.has_escape || r.has_escape,
+                kind: Default::default(),
+            }),
+            (TsLit::Number(l), TsLit::Str(r)) if expr.op == op!(bin, "+") => TsLit::Str(Str {
+                span,
+                value: format!("{}{}", l.value, r.value).into(),
+                has_escape: r.has_escape,
+                kind: Default::default(),
+            }),
+            (TsLit::Str(l), TsLit::Number(r)) if expr.op == op!(bin, "+") => TsLit::Str(Str {
+                span,
+                value: format!("{}{}", l.value, r.value).into(),
+                has_escape: l.has_escape,
+                kind: Default::default(),
+            }),
+            _ => return Err(()),
+        })
+    }
+
+    if let Some(expr) = init {
+        match expr {
+            Expr::Lit(Lit::Str(s)) => return Ok(TsLit::Str(s.clone())),
+            Expr::Lit(Lit::Num(s)) => return Ok(TsLit::Number(*s)),
+            Expr::Bin(ref bin) => return compute_bin(e, span, values, bin),
+            Expr::Paren(ref paren) => {
+                return compute_enum_member(e, span, values, default, Some(&paren.expr))
+            }
+
+            Expr::Ident(ref id) => {
+                if let Some(Some(v)) = values.get(&id.sym) {
+                    return Ok(v.clone());
+                }
+                //
+                for m in e.members.iter() {
+                    match m.id {
+                        TsEnumMemberId::Str(Str { value: ref sym, .. })
+                        | TsEnumMemberId::Ident(Ident { ref sym, .. }) => {
+                            if *sym == id.sym {
+                                return compute_enum_member(
+                                    e,
+                                    span,
+                                    values,
+                                    None,
+                                    m.init.as_deref(),
+                                );
+                            }
+                        }
+                    }
+                }
+                return Err(());
+            }
+            Expr::Unary(ref expr) => {
+                let v = compute_enum_member(e, span, values, None, Some(&expr.arg))?;
+                match v {
+                    TsLit::BigInt(BigInt { .. }) => {}
+                    TsLit::Number(Number { value: v, .. }) => {
+                        return Ok(TsLit::Number(Number {
+                            span,
+                            value: match expr.op {
+                                op!(unary, "+") => v,
+                                op!(unary, "-") => -v,
+                                op!("!") => {
+                                    if v == 0.0f64 {
+                                        0.0
+                                    } else {
+                                        1.0
+                                    }
+                                }
+                                op!("~") => (!(v as i32)) as f64,
+                                _ => return Err(()),
+                            },
+                        }))
+                    }
+                    TsLit::Str(_) => {}
+                    TsLit::Bool(_) => {}
+                    TsLit::Tpl(_) => {}
+                }
+            }
+
+            Expr::Tpl(ref t) if t.exprs.is_empty() => {
+                if let Some(v) = &t.quasis[0].cooked {
+                    return Ok(v.clone().into());
+                }
+            }
+
+            _ => {}
+        }
+    } else if let Some(value) = default {
+        return Ok(TsLit::Number(Number {
+            span,
+            value: value as _,
+        }));
+    }
+
+    Err(())
+}
+
 struct EnumValuesVisitor<'a> {
     ident: &'a Ident,
     previous: &'a EnumValues,
@@ -2745,3 +2777,274 @@ impl VisitMut for EnumValuesVisitor<'_> {
         }
     }
 }
+
+/// Replaces accesses to members of const enums with their values, and removes
+/// declarations of local const enums which are not referenced anymore.
+///
+/// Exported const enums are preserved, because other modules may use them.
+fn inline_const_enums<N>(n: &mut N, config: &Config)
+where
+    N: for<'a> VisitWith<ConstEnumCollector<'a>>
+        + VisitMutWith<ConstEnumInliner>
+        + VisitWith<ConstEnumUsageFinder>
+        + VisitMutWith<ConstEnumRemover>,
+{
+    if !config.inline_const_enums && config.const_enums.is_empty() {
+        return;
+    }
+
+    let mut collector = ConstEnumCollector {
+        config,
+        values: Default::default(),
+    };
+    n.visit_with(&mut collector);
+
+    let values: AHashMap<_, _> = collector
+        .values
+        .into_iter()
+        .filter_map(|(id, values)| Some((id, values?)))
+        .collect();
+    if values.is_empty() {
+        return;
+    }
+
+    let inlined = values.keys().cloned().collect();
+    n.visit_mut_with(&mut ConstEnumInliner { values });
+
+    let mut finder = ConstEnumUsageFinder {
+        used: Default::default(),
+    };
+    n.visit_with(&mut finder);
+
+    n.visit_mut_with(&mut ConstEnumRemover {
+        inlined,
+        used: finder.used,
+    });
+}
+
+/// Computes the values of all members of a const enum.
+///
+/// Returns [None] if any of the members is not a constant.
+fn const_enum_values(e: &TsEnumDecl) -> Option<AHashMap<JsWord, Lit>> {
+    let mut default = 0;
+    let mut values = Default::default();
+    let mut lits = AHashMap::default();
+
+    for m in &e.members {
+        let val = compute_enum_member(
+            e,
+            m.id.span(),
+            &mut values,
+            Some(default),
+            m.init.as_deref(),
+        )
+        .ok()?;
+        if let TsLit::Number(n) = &val {
+            default = n.value as i64 + 1;
+        }
+
+        let name = match &m.id {
+            TsEnumMemberId::Ident(i) => i.sym.clone(),
+            TsEnumMemberId::Str(s) => s.value.clone(),
+        };
+        values.insert(name.clone(), Some(val.clone()));
+        lits.insert(name, enum_value_to_lit(val));
+    }
+
+    Some(lits)
+}
+
+/// Collects values of const enums declared in the file if
+/// [Config::inline_const_enums] is enabled, and of imported const enums known
+/// from [Config::const_enums].
+///
+/// [None] means that the enum cannot be inlined.
+struct ConstEnumCollector<'a> {
+    config: &'a Config,
+    values: AHashMap<Id, Option<AHashMap<JsWord, Lit>>>,
+}
+
+impl Visit for ConstEnumCollector<'_> {
+    noop_visit_type!();
+
+    fn visit_import_decl(&mut self, import: &ImportDecl) {
+        let enums = match self.config.const_enums.get(&import.src.value) {
+            Some(v) => v,
+            None => return,
+        };
+
+        for s in &import.specifiers {
+            let s = match s {
+                ImportSpecifier::Named(s) => s,
+                _ => continue,
+            };
+            let name = match &s.imported {
+                Some(ModuleExportName::Ident(imported)) => &imported.sym,
+                Some(ModuleExportName::Str(imported)) => &imported.value,
+                None => &s.local.sym,
+            };
+
+            if let Some(members) = enums.get(name) {
+                let values = members
+                    .iter()
+                    .map(|(member, value)| {
+                        let lit = match value {
+                            ConstEnumValue::Str(s) => Lit::Str(quote_str!(s.clone())),
+                            ConstEnumValue::Num(n) => Lit::Num(Number {
+                                span: DUMMY_SP,
+                                value: *n,
+                            }),
+                        };
+                        (member.clone(), lit)
+                    })
+                    .collect();
+
+                self.values.insert(s.local.to_id(), Some(values));
+            }
+        }
+    }
+
+    fn visit_ts_enum_decl(&mut self, e: &TsEnumDecl) {
+        if !self.config.inline_const_enums || !e.is_const {
+            return;
+        }
+
+        let values = const_enum_values(e);
+        // Declarations of an enum are merged.
+        let entry = self
+            .values
+            .entry(e.id.to_id())
+            .or_insert_with(|| Some(Default::default()));
+        match (entry, values) {
+            (Some(entry), Some(values)) => entry.extend(values),
+            (entry, _) => *entry = None,
+        }
+    }
+}
+
+struct ConstEnumInliner {
+    values: AHashMap<Id, AHashMap<JsWord, Lit>>,
+}
+
+impl ConstEnumInliner {
+    /// Returns the value of `e` if it's an access to a member of a const enum.
+    fn value_of(&self, e: &Expr) -> Option<Lit> {
+        let (obj, member) = match e {
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(Ident { sym, .. }),
+                ..
+            }) => (obj, sym),
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Computed(ComputedPropName { expr, .. }),
+                ..
+            }) => match &**expr {
+                Expr::Lit(Lit::Str(Str { value, .. })) => (obj, value),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let obj = match &**obj {
+            Expr::Ident(obj) => obj,
+            _ => return None,
+        };
+
+        self.values.get(&obj.to_id())?.get(member).cloned()
+    }
+}
+
+impl VisitMut for ConstEnumInliner {
+    noop_visit_mut_type!();
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        let lit = match self.value_of(e) {
+            Some(lit) => lit,
+            None => return,
+        };
+        let span = e.span();
+
+        *e = match lit {
+            // Negative numbers are not literals, so `E.A ** 2` should become
+            // `(-1) ** 2` instead of `-1 ** 2`.
+            Lit::Num(Number { value, .. }) if value < 0.0 => Expr::Unary(UnaryExpr {
+                span,
+                op: op!(unary, "-"),
+                arg: Box::new(Expr::Lit(Lit::Num(Number {
+                    span,
+                    value: -value,
+                }))),
+            }),
+            Lit::Num(n) => Expr::Lit(Lit::Num(Number { span, ..n })),
+            Lit::Str(s) => Expr::Lit(Lit::Str(Str { span, ..s })),
+            lit => Expr::Lit(lit),
+        };
+    }
+}
+
+/// Finds const enums which are still referenced as values.
+struct ConstEnumUsageFinder {
+    used: AHashSet<Id>,
+}
+
+impl Visit for ConstEnumUsageFinder {
+    noop_visit_type!();
+
+    fn visit_ident(&mut self, i: &Ident) {
+        self.used.insert(i.to_id());
+    }
+
+    visit_obj_and_computed!();
+
+    fn visit_prop_name(&mut self, n: &PropName) {
+        if let PropName::Computed(e) = n {
+            e.visit_with(self)
+        }
+    }
+
+    fn visit_ts_enum_decl(&mut self, e: &TsEnumDecl) {
+        for m in &e.members {
+            m.init.visit_with(self);
+        }
+    }
+}
+
+/// Removes declarations of inlined const enums which are not exported and not
+/// used.
+struct ConstEnumRemover {
+    inlined: AHashSet<Id>,
+    used: AHashSet<Id>,
+}
+
+impl ConstEnumRemover {
+    fn should_remove(&self, s: &Stmt) -> bool {
+        match s {
+            Stmt::Decl(Decl::TsEnum(e)) => {
+                let id = e.id.to_id();
+                e.is_const && self.inlined.contains(&id) && !self.used.contains(&id)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl VisitMut for ConstEnumRemover {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        items.retain(|item| match item {
+            ModuleItem::Stmt(s) => !self.should_remove(s),
+            _ => true,
+        });
+
+        items.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.retain(|s| !self.should_remove(s));
+
+        stmts.visit_mut_children_with(self);
+    }
+}
@@ -76,7 +76,10 @@ test!(
         parameters(parameters::Config {
             ignore_function_length: true
         }),
-        destructuring(destructuring::Config { loose: false }),
+        destructuring(destructuring::Config {
+            loose: false,
+            ..Default::default()
+        }),
         block_scoping(),
     ),
     fn_len_default_assignment_with_types,
@@ -4466,6 +4469,131 @@ var Namespace;
     "#
 );
 
+test_with_config!(
+    inline_const_enums_local,
+    strip::Config {
+        inline_const_enums: true,
+        no_empty_export: true,
+        ..Default::default()
+    },
+    "
+    const enum Direction {
+        Up = 1,
+        Down,
+        Left = 'left',
+        Right = Up << 3,
+    }
+    console.log(Direction.Up, Direction['Down'], Direction.Left, Direction.Right);
+    ",
+    "
+    console.log(1, 2, 'left', 8);
+    "
+);
+
+test_with_config!(
+    inline_const_enums_exported,
+    strip::Config {
+        inline_const_enums: true,
+        no_empty_export: true,
+        ..Default::default()
+    },
+    "
+    export const enum E {
+        A = -1,
+        B = A * 2,
+    }
+    console.log(E.A, E.B);
+    ",
+    r#"
+    export var E;
+    (function (E) {
+        E[E["A"] = -1] = "A";
+        E[E["B"] = -2] = "B";
+    })(E || (E = {}));
+    console.log(-1, -2);
+    "#
+);
+
+test_with_config!(
+    inline_const_enums_referenced,
+    strip::Config {
+        inline_const_enums: true,
+        no_empty_export: true,
+        ..Default::default()
+    },
+    "
+    const enum E {
+        A,
+    }
+    console.log(E.A);
+    export { E };
+    ",
+    r#"
+    var E;
+    (function (E) {
+        E[E["A"] = 0] = "A";
+    })(E || (E = {}));
+    console.log(0);
+    export { E };
+    "#
+);
+
+test_with_config!(
+    inline_const_enums_disabled,
+    strip::Config {
+        no_empty_export: true,
+        ..Default::default()
+    },
+    "
+    const enum E {
+        A,
+    }
+    console.log(E.A);
+    ",
+    r#"
+    var E;
+    (function (E) {
+        E[E["A"] = 0] = "A";
+    })(E || (E = {}));
+    console.log(E.A);
+    "#
+);
+
+test_with_config!(
+    inline_const_enums_imported,
+    strip::Config {
+        const_enums: vec![(
+            "./status".into(),
+            vec![(
+                "Status".into(),
+                vec![
+                    ("Ok".into(), strip::ConstEnumValue::Num(200.0)),
+                    ("Error".into(), strip::ConstEnumValue::Str("error".into())),
+                ]
+                .into_iter()
+                .collect(),
+            )]
+            .into_iter()
+            .collect(),
+        )]
+        .into_iter()
+        .collect(),
+        no_empty_export: true,
+        ..Default::default()
+    },
+    "
+    import { Status } from './status';
+    import { Other as Status2 } from './other';
+    import { Status as Status3 } from './status3';
+    console.log(Status.Ok, Status['Error'], Status2.Ok, Status3.Ok);
+    ",
+    "
+    import { Other as Status2 } from './other';
+    import { Status as Status3 } from './status3';
+    console.log(200, 'error', Status2.Ok, Status3.Ok);
+    "
+);
+
 #[testing::fixture("tests/fixture/**/input.ts")]
 #[testing::fixture("tests/fixture/**/input.tsx")]
 fn exec(input: PathBuf) {
@@ -505,6 +505,7 @@ define!({
         TsIndexSignature(TsIndexSignature),
         Empty(EmptyStmt),
         StaticBlock(StaticBlock),
+        AutoAccessor(AutoAccessor),
     }
 
     pub struct ClassProp {
@@ -575,6 +576,19 @@ define!({
         pub span: Span,
         pub body: BlockStmt,
     }
+    pub enum Key {
+        Private(PrivateName),
+        Public(PropName),
+    }
+    pub struct AutoAccessor {
+        pub span: Span,
+        pub key: Key,
+        pub value: Option<Box<Expr>>,
+        pub type_ann: Option<TsTypeAnn>,
+        pub is_static: bool,
+        pub decorators: Vec<Decorator>,
+        pub accessibility: Option<Accessibility>,
+    }
     pub enum MethodKind {
         Method,
         Getter,
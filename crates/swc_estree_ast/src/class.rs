@@ -225,6 +225,35 @@ pub struct ClassPrivateProperty {
     pub type_annotation: Option<Box<TypeAnnotOrNoop>>,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+#[ast_serde]
+pub enum ClassAccessorPropertyKey {
+    #[tag("PrivateName")]
+    Private(PrivateName),
+    #[tag("*")]
+    Public(ObjectKey),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[ast_serde("ClassAccessorProperty")]
+pub struct ClassAccessorProperty {
+    #[serde(flatten)]
+    pub base: BaseNode,
+    pub key: ClassAccessorPropertyKey,
+    #[serde(default)]
+    pub value: Option<Box<Expression>>,
+    #[serde(default)]
+    pub type_annotation: Option<Box<TypeAnnotOrNoop>>,
+    #[serde(default)]
+    pub decorators: Option<Vec<Decorator>>,
+    #[serde(default)]
+    pub computed: Option<bool>,
+    #[serde(default, rename = "static")]
+    pub is_static: Option<bool>,
+    #[serde(default)]
+    pub accessibility: Option<Access>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 #[ast_serde("ClassPrivateMethod")]
 pub struct ClassPrivateMethod {
@@ -391,6 +420,8 @@ pub enum ClassBodyEl {
     Prop(ClassProperty),
     #[tag("ClassPrivateProperty")]
     PrivateProp(ClassPrivateProperty),
+    #[tag("ClassAccessorProperty")]
+    AccessorProp(ClassAccessorProperty),
     #[tag("TSDeclareMethod")]
     TSMethod(TSDeclareMethod),
     #[tag("TSIndexSignature")]
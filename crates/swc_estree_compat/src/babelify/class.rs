@@ -2,13 +2,13 @@ use crate::babelify::{extract_class_body_span, Babelify, Context};
 use copyless::BoxHelper;
 use serde_json::value::Value;
 use swc_ecma_ast::{
-    Class, ClassMember, ClassMethod, ClassProp, Constructor, Decorator, MethodKind, PrivateMethod,
-    PrivateProp, StaticBlock,
+    AutoAccessor, Class, ClassMember, ClassMethod, ClassProp, Constructor, Decorator, Key,
+    MethodKind, PrivateMethod, PrivateProp, StaticBlock,
 };
 use swc_estree_ast::{
-    ClassBody, ClassBodyEl, ClassExpression, ClassMethod as BabelClassMethod, ClassMethodKind,
-    ClassPrivateMethod, ClassPrivateProperty, ClassProperty, Decorator as BabelDecorator,
-    StaticBlock as BabelStaticBlock,
+    ClassAccessorProperty, ClassAccessorPropertyKey, ClassBody, ClassBodyEl, ClassExpression,
+    ClassMethod as BabelClassMethod, ClassMethodKind, ClassPrivateMethod, ClassPrivateProperty,
+    ClassProperty, Decorator as BabelDecorator, StaticBlock as BabelStaticBlock,
 };
 
 impl Babelify for Class {
@@ -59,6 +59,7 @@ impl Babelify for ClassMember {
                 &self
             ),
             ClassMember::StaticBlock(s) => ClassBodyEl::StaticBlock(s.babelify(ctx)),
+            ClassMember::AutoAccessor(a) => ClassBodyEl::AccessorProp(a.babelify(ctx)),
         }
     }
 }
@@ -110,6 +111,35 @@ impl Babelify for PrivateProp {
     }
 }
 
+impl Babelify for AutoAccessor {
+    type Output = ClassAccessorProperty;
+
+    fn babelify(self, ctx: &Context) -> Self::Output {
+        let (key, computed) = match self.key {
+            Key::Private(k) => (ClassAccessorPropertyKey::Private(k.babelify(ctx)), false),
+            Key::Public(k) => {
+                let computed = k.is_computed();
+                (ClassAccessorPropertyKey::Public(k.babelify(ctx)), computed)
+            }
+        };
+
+        ClassAccessorProperty {
+            base: ctx.base(self.span),
+            key,
+            value: self
+                .value
+                .map(|val| Box::alloc().init(val.babelify(ctx).into())),
+            type_annotation: self
+                .type_ann
+                .map(|ann| Box::alloc().init(ann.babelify(ctx).into())),
+            decorators: Some(self.decorators.babelify(ctx)),
+            computed: Some(computed),
+            is_static: Some(self.is_static),
+            accessibility: self.accessibility.map(|access| access.babelify(ctx)),
+        }
+    }
+}
+
 impl Babelify for ClassMethod {
     type Output = BabelClassMethod;
 
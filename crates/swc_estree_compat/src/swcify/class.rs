@@ -2,7 +2,8 @@ use super::Context;
 use crate::swcify::Swcify;
 use swc_ecma_ast::{ClassMember, Function, MethodKind, ParamOrTsParamProp, TsExprWithTypeArgs};
 use swc_estree_ast::{
-    ClassBody, ClassBodyEl, ClassImpl, ClassMethodKind, TSExpressionWithTypeArguments,
+    ClassAccessorPropertyKey, ClassBody, ClassBodyEl, ClassImpl, ClassMethodKind,
+    TSExpressionWithTypeArguments,
 };
 
 impl Swcify for ClassBody {
@@ -22,6 +23,7 @@ impl Swcify for ClassBodyEl {
             ClassBodyEl::PrivateMethod(v) => v.swcify(ctx).into(),
             ClassBodyEl::Prop(v) => v.swcify(ctx).into(),
             ClassBodyEl::PrivateProp(v) => v.swcify(ctx).into(),
+            ClassBodyEl::AccessorProp(v) => swc_ecma_ast::ClassMember::AutoAccessor(v.swcify(ctx)),
             _ => {
                 unimplemented!("swcify: {:?}", self)
             }
@@ -165,15 +167,34 @@ impl Swcify for swc_estree_ast::ClassPrivateProperty {
     }
 }
 
+impl Swcify for swc_estree_ast::ClassAccessorProperty {
+    type Output = swc_ecma_ast::AutoAccessor;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let key = match self.key {
+            ClassAccessorPropertyKey::Private(k) => swc_ecma_ast::Key::Private(k.swcify(ctx)),
+            ClassAccessorPropertyKey::Public(k) => swc_ecma_ast::Key::Public(k.swcify(ctx)),
+        };
+
+        swc_ecma_ast::AutoAccessor {
+            span: ctx.span(&self.base),
+            key,
+            value: self.value.swcify(ctx),
+            type_ann: self.type_annotation.swcify(ctx).flatten(),
+            is_static: self.is_static.unwrap_or(false),
+            decorators: self.decorators.swcify(ctx).unwrap_or_default(),
+            accessibility: self.accessibility.swcify(ctx),
+        }
+    }
+}
+
 impl Swcify for ClassImpl {
     type Output = TsExprWithTypeArgs;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
         match self {
             ClassImpl::TSExpr(v) => v.swcify(ctx),
-            ClassImpl::Implements(_) => {
-                unreachable!()
-            }
+            ClassImpl::Implements(v) => v.swcify(ctx),
         }
     }
 }
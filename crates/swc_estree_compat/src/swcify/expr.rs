@@ -28,7 +28,7 @@ use swc_estree_ast::{
     YieldExpression,
 };
 
-use super::Context;
+use super::{flow::type_args_from_params, Context};
 
 impl Swcify for Expression {
     type Output = Box<Expr>;
@@ -73,6 +73,7 @@ impl Swcify for Expression {
             Expression::TSAs(e) => e.swcify(ctx).into(),
             Expression::TSTypeAssertion(e) => e.swcify(ctx).into(),
             Expression::TSNonNull(e) => e.swcify(ctx).into(),
+            Expression::TypeCast(e) => e.swcify(ctx).into(),
             _ => {
                 unimplemented!("swcify: {:?}", self)
             }
@@ -728,7 +729,7 @@ impl Swcify for TaggedTemplateExprTypeParams {
 
     fn swcify(self, ctx: &Context) -> Self::Output {
         match self {
-            TaggedTemplateExprTypeParams::Flow(_) => unimplemented!("flow types"),
+            TaggedTemplateExprTypeParams::Flow(v) => type_args_from_params(v, ctx),
             TaggedTemplateExprTypeParams::TS(v) => v.swcify(ctx),
         }
     }
@@ -824,10 +825,14 @@ impl Swcify for OptionalCallExpression {
 }
 
 impl Swcify for TypeCastExpression {
-    type Output = !;
+    type Output = TsAsExpr;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow type cast")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsAsExpr {
+            span: ctx.span(&self.base),
+            expr: self.expression.swcify(ctx),
+            type_ann: self.type_annotation.swcify(ctx).type_ann,
+        }
     }
 }
 
@@ -0,0 +1,663 @@
+//! Flow types are converted into the equivalent typescript nodes, like the
+//! parser of swc does. e.g. `?T` is converted into `T | null | undefined`.
+use super::Context;
+use crate::swcify::Swcify;
+use swc_common::{Span, Spanned, DUMMY_SP};
+use swc_ecma_ast::{
+    BindingIdent, Bool, ClassMember, ClassMethod, ClassProp, Expr, Function, Ident, Lit,
+    MemberExpr, MemberProp, MethodKind, Number, Param, Pat, RestPat, Str, TsArrayType,
+    TsCallSignatureDecl, TsEntityName, TsExprWithTypeArgs, TsFnOrConstructorType, TsFnParam,
+    TsFnType, TsGetterSignature, TsIndexSignature, TsIntersectionType, TsKeywordType,
+    TsKeywordTypeKind, TsLit, TsLitType, TsMethodSignature, TsPropertySignature, TsQualifiedName,
+    TsSetterSignature, TsThisType, TsTupleElement, TsTupleType, TsType, TsTypeAnn, TsTypeElement,
+    TsTypeLit, TsTypeParam, TsTypeParamDecl, TsTypeParamInstantiation, TsTypeQuery,
+    TsTypeQueryExpr, TsTypeRef, TsUnionOrIntersectionType, TsUnionType,
+};
+use swc_estree_ast::{
+    ClassImplements, FlowType, FunctionTypeAnnotation, FunctionTypeParam, IdOrQualifiedId,
+    IdOrString, InterfaceExtends, ObjectTypeAnnotProp, ObjectTypeAnnotation, ObjectTypeIndexer,
+    ObjectTypePropKind, ObjectTypeProperty, PlusOrMinus, TypeAnnotation, TypeParameter,
+    TypeParameterDeclaration, TypeParameterInstantiation, Variance,
+};
+
+impl Swcify for FlowType {
+    type Output = Box<TsType>;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        Box::new(match self {
+            FlowType::Any(v) => keyword(ctx.span(&v.base), TsKeywordTypeKind::TsAnyKeyword),
+            FlowType::Array(v) => TsType::TsArrayType(TsArrayType {
+                span: ctx.span(&v.base),
+                elem_type: v.element_type.swcify(ctx),
+            }),
+            FlowType::Boolean(v) => keyword(ctx.span(&v.base), TsKeywordTypeKind::TsBooleanKeyword),
+            FlowType::BooleanLiteral(v) => {
+                let span = ctx.span(&v.base);
+                TsType::TsLitType(TsLitType {
+                    span,
+                    lit: TsLit::Bool(Bool {
+                        span,
+                        value: v.value,
+                    }),
+                })
+            }
+            FlowType::NullLiteral(v) => {
+                keyword(ctx.span(&v.base), TsKeywordTypeKind::TsNullKeyword)
+            }
+            // Existential type, `*`
+            FlowType::Exists(v) => keyword(ctx.span(&v.base), TsKeywordTypeKind::TsAnyKeyword),
+            FlowType::Function(v) => {
+                TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(v.swcify(ctx)))
+            }
+            FlowType::Generic(v) => TsType::TsTypeRef(TsTypeRef {
+                span: ctx.span(&v.base),
+                type_name: v.id.swcify(ctx),
+                type_params: v.type_parameters.swcify(ctx),
+            }),
+            FlowType::Interface(v) => {
+                let span = ctx.span(&v.base);
+                let body = v.body.swcify(ctx);
+                let extends = v.extends.unwrap_or_default();
+                if extends.is_empty() {
+                    return body;
+                }
+
+                let mut types = extends
+                    .into_iter()
+                    .map(|e| {
+                        let e = e.swcify(ctx);
+                        Box::new(TsType::TsTypeRef(TsTypeRef {
+                            span: e.span,
+                            type_name: e.expr,
+                            type_params: e.type_args,
+                        }))
+                    })
+                    .collect::<Vec<_>>();
+                types.push(body);
+
+                TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(
+                    TsIntersectionType { span, types },
+                ))
+            }
+            FlowType::Intersection(v) => TsType::TsUnionOrIntersectionType(
+                TsUnionOrIntersectionType::TsIntersectionType(TsIntersectionType {
+                    span: ctx.span(&v.base),
+                    types: v.types.swcify(ctx),
+                }),
+            ),
+            FlowType::Mixed(v) => keyword(ctx.span(&v.base), TsKeywordTypeKind::TsUnknownKeyword),
+            FlowType::Empty(v) => keyword(ctx.span(&v.base), TsKeywordTypeKind::TsNeverKeyword),
+            // `?T`
+            FlowType::Nullable(v) => {
+                let span = ctx.span(&v.base);
+                TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(
+                    TsUnionType {
+                        span,
+                        types: vec![
+                            v.type_annotation.swcify(ctx),
+                            Box::new(keyword(span, TsKeywordTypeKind::TsNullKeyword)),
+                            Box::new(keyword(span, TsKeywordTypeKind::TsUndefinedKeyword)),
+                        ],
+                    },
+                ))
+            }
+            FlowType::NumerLiteral(v) => {
+                let span = ctx.span(&v.base);
+                TsType::TsLitType(TsLitType {
+                    span,
+                    lit: TsLit::Number(Number {
+                        span,
+                        value: v.value,
+                    }),
+                })
+            }
+            FlowType::Number(v) => keyword(ctx.span(&v.base), TsKeywordTypeKind::TsNumberKeyword),
+            FlowType::Object(v) => return v.swcify(ctx),
+            FlowType::StringLiteral(v) => {
+                let span = ctx.span(&v.base);
+                TsType::TsLitType(TsLitType {
+                    span,
+                    lit: TsLit::Str(Str {
+                        span,
+                        value: v.value,
+                        has_escape: false,
+                        kind: Default::default(),
+                    }),
+                })
+            }
+            FlowType::String(v) => keyword(ctx.span(&v.base), TsKeywordTypeKind::TsStringKeyword),
+            FlowType::Symbol(v) => keyword(ctx.span(&v.base), TsKeywordTypeKind::TsSymbolKeyword),
+            FlowType::This(v) => TsType::TsThisType(TsThisType {
+                span: ctx.span(&v.base),
+            }),
+            FlowType::Tuple(v) => TsType::TsTupleType(TsTupleType {
+                span: ctx.span(&v.base),
+                elem_types: v
+                    .types
+                    .swcify(ctx)
+                    .into_iter()
+                    .map(|ty| TsTupleElement {
+                        span: ty.span(),
+                        label: None,
+                        ty: *ty,
+                    })
+                    .collect(),
+            }),
+            FlowType::Typeof(v) => match *v.argument {
+                FlowType::Generic(arg) => TsType::TsTypeQuery(TsTypeQuery {
+                    span: ctx.span(&v.base),
+                    expr_name: TsTypeQueryExpr::TsEntityName(arg.id.swcify(ctx)),
+                }),
+                // Typescript does not have `typeof` for types other than identifiers, so we
+                // use the argument as is.
+                arg => return arg.swcify(ctx),
+            },
+            FlowType::Union(v) => TsType::TsUnionOrIntersectionType(
+                TsUnionOrIntersectionType::TsUnionType(TsUnionType {
+                    span: ctx.span(&v.base),
+                    types: v.types.swcify(ctx),
+                }),
+            ),
+            FlowType::Void(v) => keyword(ctx.span(&v.base), TsKeywordTypeKind::TsVoidKeyword),
+        })
+    }
+}
+
+fn keyword(span: Span, kind: TsKeywordTypeKind) -> TsType {
+    TsType::TsKeywordType(TsKeywordType { span, kind })
+}
+
+impl Swcify for TypeAnnotation {
+    type Output = TsTypeAnn;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypeAnn {
+            span: ctx.span(&self.base),
+            type_ann: self.type_annotation.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TypeParameterDeclaration {
+    type Output = TsTypeParamDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypeParamDecl {
+            span: ctx.span(&self.base),
+            params: self.params.swcify(ctx),
+        }
+    }
+}
+
+/// Variance is dropped, and bounds are converted into constraints.
+impl Swcify for TypeParameter {
+    type Output = TsTypeParam;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        TsTypeParam {
+            span,
+            name: Ident::new(self.name, span),
+            constraint: self.bound.swcify(ctx).map(|bound| bound.type_ann),
+            default: self.default.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TypeParameterInstantiation {
+    type Output = TsTypeParamInstantiation;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypeParamInstantiation {
+            span: ctx.span(&self.base),
+            params: self.params.swcify(ctx),
+        }
+    }
+}
+
+/// Converts type parameters used as type arguments, like ones of tagged
+/// templates.
+pub(super) fn type_args_from_params(
+    decl: TypeParameterDeclaration,
+    ctx: &Context,
+) -> TsTypeParamInstantiation {
+    TsTypeParamInstantiation {
+        span: ctx.span(&decl.base),
+        params: decl
+            .params
+            .into_iter()
+            .map(|param| {
+                let span = ctx.span(&param.base);
+                Box::new(TsType::TsTypeRef(TsTypeRef {
+                    span,
+                    type_name: TsEntityName::Ident(Ident::new(param.name, span)),
+                    type_params: None,
+                }))
+            })
+            .collect(),
+    }
+}
+
+/// Converts type arguments used as type parameters, like ones of `declare
+/// class`.
+pub(super) fn type_params_from_args(
+    args: TypeParameterInstantiation,
+    ctx: &Context,
+) -> TsTypeParamDecl {
+    TsTypeParamDecl {
+        span: ctx.span(&args.base),
+        params: args
+            .params
+            .swcify(ctx)
+            .into_iter()
+            .map(|param| match *param {
+                TsType::TsTypeRef(TsTypeRef {
+                    span,
+                    type_name: TsEntityName::Ident(name),
+                    type_params: None,
+                }) => TsTypeParam {
+                    span,
+                    name,
+                    constraint: None,
+                    default: None,
+                },
+                _ => unreachable!("type parameter should be an identifier: {:?}", param),
+            })
+            .collect(),
+    }
+}
+
+impl Swcify for IdOrQualifiedId {
+    type Output = TsEntityName;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            IdOrQualifiedId::Id(v) => TsEntityName::Ident(v.swcify(ctx).id),
+            IdOrQualifiedId::QualifiedId(v) => {
+                TsEntityName::TsQualifiedName(Box::new(TsQualifiedName {
+                    left: v.qualification.swcify(ctx),
+                    right: v.id.swcify(ctx).id,
+                }))
+            }
+        }
+    }
+}
+
+impl Swcify for InterfaceExtends {
+    type Output = TsExprWithTypeArgs;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsExprWithTypeArgs {
+            span: ctx.span(&self.base),
+            expr: self.id.swcify(ctx),
+            type_args: self.type_parameters.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for ClassImplements {
+    type Output = TsExprWithTypeArgs;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsExprWithTypeArgs {
+            span: ctx.span(&self.base),
+            expr: TsEntityName::Ident(self.id.swcify(ctx).id),
+            type_args: self.type_parameters.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for FunctionTypeAnnotation {
+    type Output = TsFnType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        let mut params = self
+            .params
+            .into_iter()
+            .enumerate()
+            .map(|(idx, param)| TsFnParam::Ident(fn_type_param(param, idx, ctx)))
+            .collect::<Vec<_>>();
+
+        if let Some(rest) = self.rest {
+            let span = ctx.span(&rest.base);
+            let BindingIdent { id, type_ann } = fn_type_param(*rest, params.len(), ctx);
+            params.push(TsFnParam::Rest(RestPat {
+                span,
+                dot3_token: DUMMY_SP,
+                arg: Box::new(Pat::Ident(id.into())),
+                type_ann,
+            }));
+        }
+
+        let return_type = self.return_type.swcify(ctx);
+
+        TsFnType {
+            span,
+            params,
+            type_params: self.type_parameters.swcify(ctx),
+            type_ann: TsTypeAnn {
+                span: return_type.span(),
+                type_ann: return_type,
+            },
+        }
+    }
+}
+
+/// Names of parameters are optional in flow, so parameters without a name are
+/// named like `arg0`, as the parser of swc does.
+fn fn_type_param(param: FunctionTypeParam, idx: usize, ctx: &Context) -> BindingIdent {
+    let span = ctx.span(&param.base);
+    let mut id = match param.name {
+        Some(name) => name.swcify(ctx).id,
+        None => Ident::new(format!("arg{}", idx).into(), span),
+    };
+    id.optional = param.optional.unwrap_or_default();
+
+    let type_ann = param.type_annotation.swcify(ctx);
+
+    BindingIdent {
+        id,
+        type_ann: Some(TsTypeAnn {
+            span: type_ann.span(),
+            type_ann,
+        }),
+    }
+}
+
+/// Spreads are converted into an intersection type, and internal slots are
+/// dropped because typescript does not have them.
+impl Swcify for ObjectTypeAnnotation {
+    type Output = Box<TsType>;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+
+        let mut members = vec![];
+        let mut spreads = vec![];
+
+        for prop in self.properties {
+            match prop {
+                ObjectTypeAnnotProp::Prop(prop) => members.push(prop.swcify(ctx)),
+                ObjectTypeAnnotProp::Spread(spread) => spreads.push(spread.argument.swcify(ctx)),
+            }
+        }
+
+        members.extend(
+            self.indexers
+                .unwrap_or_default()
+                .into_iter()
+                .map(|indexer| TsTypeElement::TsIndexSignature(indexer.swcify(ctx))),
+        );
+
+        members.extend(
+            self.call_properties
+                .unwrap_or_default()
+                .into_iter()
+                .map(|prop| {
+                    let span = ctx.span(&prop.base);
+                    let f = expect_fn_type(prop.value, ctx);
+                    TsTypeElement::TsCallSignatureDecl(TsCallSignatureDecl {
+                        span,
+                        params: f.params,
+                        type_ann: Some(f.type_ann),
+                        type_params: f.type_params,
+                    })
+                }),
+        );
+
+        let lit = Box::new(TsType::TsTypeLit(TsTypeLit { span, members }));
+        if spreads.is_empty() {
+            return lit;
+        }
+
+        let mut types = spreads;
+        types.push(lit);
+
+        Box::new(TsType::TsUnionOrIntersectionType(
+            TsUnionOrIntersectionType::TsIntersectionType(TsIntersectionType { span, types }),
+        ))
+    }
+}
+
+impl Swcify for ObjectTypeProperty {
+    type Output = TsTypeElement;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        let readonly = is_covariant(&self.variance);
+        let key = prop_key(self.key, ctx);
+
+        match self.kind {
+            ObjectTypePropKind::Get => {
+                let f = expect_fn_type(self.value, ctx);
+                TsTypeElement::TsGetterSignature(TsGetterSignature {
+                    span,
+                    readonly,
+                    key,
+                    computed: false,
+                    optional: self.optional,
+                    type_ann: Some(f.type_ann),
+                })
+            }
+            ObjectTypePropKind::Set => {
+                let f = expect_fn_type(self.value, ctx);
+                TsTypeElement::TsSetterSignature(TsSetterSignature {
+                    span,
+                    readonly,
+                    key,
+                    computed: false,
+                    optional: self.optional,
+                    param: f
+                        .params
+                        .into_iter()
+                        .next()
+                        .expect("setter should have a parameter"),
+                })
+            }
+            ObjectTypePropKind::Init if self.method => {
+                let f = expect_fn_type(self.value, ctx);
+                TsTypeElement::TsMethodSignature(TsMethodSignature {
+                    span,
+                    readonly,
+                    key,
+                    computed: false,
+                    optional: self.optional,
+                    params: f.params,
+                    type_ann: Some(f.type_ann),
+                    type_params: f.type_params,
+                })
+            }
+            ObjectTypePropKind::Init => {
+                let type_ann = self.value.swcify(ctx);
+                TsTypeElement::TsPropertySignature(TsPropertySignature {
+                    span,
+                    readonly,
+                    key,
+                    computed: false,
+                    optional: self.optional,
+                    init: None,
+                    params: vec![],
+                    type_ann: Some(TsTypeAnn {
+                        span: type_ann.span(),
+                        type_ann,
+                    }),
+                    type_params: None,
+                })
+            }
+        }
+    }
+}
+
+/// `[K]: V` is converted into `[key: K]: V`, as the parser of swc does.
+impl Swcify for ObjectTypeIndexer {
+    type Output = TsIndexSignature;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        let key = self.key.swcify(ctx);
+        let key_span = key.span();
+        let id = match self.id {
+            Some(id) => id.swcify(ctx).id,
+            None => Ident::new(
+                "key".into(),
+                Span::new(key_span.lo, key_span.lo, Default::default()),
+            ),
+        };
+        let value = self.value.swcify(ctx);
+
+        TsIndexSignature {
+            params: vec![TsFnParam::Ident(BindingIdent {
+                id,
+                type_ann: Some(TsTypeAnn {
+                    span: key_span,
+                    type_ann: key,
+                }),
+            })],
+            type_ann: Some(TsTypeAnn {
+                span: value.span(),
+                type_ann: value,
+            }),
+            readonly: is_covariant(&self.variance),
+            is_static: self.is_static,
+            span,
+        }
+    }
+}
+
+/// Converts the body of `declare class`.
+///
+/// Call properties, spreads and internal slots are dropped, because classes of
+/// typescript cannot have them.
+pub(super) fn class_members(body: ObjectTypeAnnotation, ctx: &Context) -> Vec<ClassMember> {
+    let mut members = vec![];
+
+    for prop in body.properties {
+        let prop = match prop {
+            ObjectTypeAnnotProp::Prop(prop) => prop,
+            ObjectTypeAnnotProp::Spread(..) => continue,
+        };
+
+        let span = ctx.span(&prop.base);
+        let key = prop.key.swcify(ctx);
+
+        let kind = match prop.kind {
+            ObjectTypePropKind::Get => MethodKind::Getter,
+            ObjectTypePropKind::Set => MethodKind::Setter,
+            ObjectTypePropKind::Init if prop.method => MethodKind::Method,
+            ObjectTypePropKind::Init => {
+                let type_ann = prop.value.swcify(ctx);
+                members.push(ClassMember::ClassProp(ClassProp {
+                    span,
+                    key,
+                    value: None,
+                    type_ann: Some(TsTypeAnn {
+                        span: type_ann.span(),
+                        type_ann,
+                    }),
+                    is_static: prop.is_static,
+                    decorators: vec![],
+                    accessibility: None,
+                    is_abstract: false,
+                    is_optional: prop.optional,
+                    is_override: false,
+                    readonly: is_covariant(&prop.variance),
+                    declare: false,
+                    definite: false,
+                }));
+                continue;
+            }
+        };
+
+        let f = expect_fn_type(prop.value, ctx);
+        members.push(ClassMember::Method(ClassMethod {
+            span,
+            key,
+            function: Function {
+                params: f
+                    .params
+                    .into_iter()
+                    .map(|param| Param {
+                        span: param.span(),
+                        decorators: vec![],
+                        pat: fn_param_to_pat(param),
+                    })
+                    .collect(),
+                decorators: vec![],
+                span,
+                body: None,
+                is_generator: false,
+                is_async: false,
+                type_params: f.type_params,
+                return_type: Some(f.type_ann),
+            },
+            kind,
+            is_static: prop.is_static,
+            accessibility: None,
+            is_abstract: false,
+            is_optional: prop.optional,
+            is_override: false,
+        }));
+    }
+
+    members.extend(
+        body.indexers
+            .unwrap_or_default()
+            .into_iter()
+            .map(|indexer| ClassMember::TsIndexSignature(indexer.swcify(ctx))),
+    );
+
+    members
+}
+
+pub(super) fn fn_param_to_pat(param: TsFnParam) -> Pat {
+    match param {
+        TsFnParam::Ident(v) => Pat::Ident(v),
+        TsFnParam::Array(v) => Pat::Array(v),
+        TsFnParam::Rest(v) => Pat::Rest(v),
+        TsFnParam::Object(v) => Pat::Object(v),
+    }
+}
+
+/// Values of methods, getters and setters are always function types.
+pub(super) fn expect_fn_type(ty: FlowType, ctx: &Context) -> TsFnType {
+    match ty {
+        FlowType::Function(f) => f.swcify(ctx),
+        _ => unreachable!("expected a function type: {:?}", ty),
+    }
+}
+
+/// Typescript does not have write-only properties, so only `+` is preserved
+/// as `readonly`.
+fn is_covariant(variance: &Option<Variance>) -> bool {
+    matches!(
+        variance,
+        Some(Variance {
+            kind: PlusOrMinus::Plus,
+            ..
+        })
+    )
+}
+
+fn prop_key(key: IdOrString, ctx: &Context) -> Box<Expr> {
+    Box::new(match key {
+        IdOrString::Id(v) => Expr::Ident(v.swcify(ctx).id),
+        IdOrString::String(v) => Expr::Lit(Lit::Str(v.swcify(ctx))),
+    })
+}
+
+/// Converts the name of a class in `declare class A extends B.C {}` into an
+/// expression.
+pub(super) fn entity_to_expr(name: TsEntityName) -> Box<Expr> {
+    Box::new(match name {
+        TsEntityName::Ident(i) => Expr::Ident(i),
+        TsEntityName::TsQualifiedName(q) => {
+            let q = *q;
+            Expr::Member(MemberExpr {
+                span: q.span(),
+                obj: entity_to_expr(q.left),
+                prop: MemberProp::Ident(q.right),
+            })
+        }
+    })
+}
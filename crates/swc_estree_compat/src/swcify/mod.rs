@@ -4,6 +4,7 @@ use std::fmt::Debug;
 mod class;
 mod ctx;
 mod expr;
+mod flow;
 mod jsx;
 mod lit;
 mod pat;
@@ -1,27 +1,35 @@
-use super::Context;
+use super::{
+    flow::{class_members, entity_to_expr, fn_param_to_pat, type_params_from_args},
+    Context,
+};
 use crate::swcify::Swcify;
-use swc_common::DUMMY_SP;
+use swc_atoms::js_word;
+use swc_common::{Spanned, DUMMY_SP};
 use swc_ecma_ast::{
-    BlockStmt, BreakStmt, ClassDecl, ClassExpr, ContinueStmt, DebuggerStmt, Decl, DefaultDecl,
-    DoWhileStmt, EmptyStmt, ExportAll, ExportDecl, ExportDefaultDecl, ExportDefaultExpr,
-    ExportNamedSpecifier, Expr, ExprStmt, FnDecl, FnExpr, ForInStmt, ForOfStmt, ForStmt, IfStmt,
-    ImportDecl, ImportNamedSpecifier, ImportSpecifier, ImportStarAsSpecifier, KeyValueProp,
-    LabeledStmt, Lit, ModuleDecl, ModuleItem, NamedExport, ObjectLit, Pat, Prop, PropName,
-    PropOrSpread, ReturnStmt, Stmt, SwitchStmt, ThrowStmt, TryStmt, TsExportAssignment,
-    TsInterfaceDecl, TsModuleDecl, TsTypeAliasDecl, VarDecl, VarDeclKind, VarDeclOrExpr,
-    VarDeclOrPat, VarDeclarator, WhileStmt, WithStmt,
+    BindingIdent, BlockStmt, BreakStmt, ClassDecl, ClassExpr, ContinueStmt, DebuggerStmt, Decl,
+    DefaultDecl, DoWhileStmt, EmptyStmt, ExportAll, ExportDecl, ExportDefaultDecl,
+    ExportDefaultExpr, ExportNamedSpecifier, ExportSpecifier, Expr, ExprStmt, FnDecl, FnExpr,
+    ForInStmt, ForOfStmt, ForStmt, Function, Ident, IfStmt, ImportDecl, ImportNamedSpecifier,
+    ImportSpecifier, ImportStarAsSpecifier, KeyValueProp, LabeledStmt, Lit, MemberExpr, MemberProp,
+    ModuleDecl, ModuleItem, NamedExport, ObjectLit, Param, Pat, Prop, PropName, PropOrSpread,
+    ReturnStmt, Stmt, SwitchStmt, ThrowStmt, TryStmt, TsAsExpr, TsExportAssignment,
+    TsFnOrConstructorType, TsInterfaceBody, TsInterfaceDecl, TsKeywordType, TsKeywordTypeKind,
+    TsModuleBlock, TsModuleDecl, TsModuleName, TsNamespaceBody, TsType, TsTypeAliasDecl, VarDecl,
+    VarDeclKind, VarDeclOrExpr, VarDeclOrPat, VarDeclarator, WhileStmt, WithStmt,
 };
 use swc_estree_ast::{
     BlockStatement, BreakStatement, ClassDeclaration, ContinueStatement, DebuggerStatement,
-    DeclareClass, DeclareExportAllDeclaration, DeclareExportDeclaration, DeclareFunction,
-    DeclareInterface, DeclareModule, DeclareModuleExports, DeclareTypeAlias, DeclareVariable,
-    DoWhileStatement, EmptyStatement, ExportAllDeclaration, ExportDefaultDeclType,
-    ExportDefaultDeclaration, ExportKind, ExportNamedDeclaration, ExpressionStatement,
-    ForInStatement, ForOfStatement, ForStatement, ForStmtInit, ForStmtLeft, FunctionDeclaration,
-    IdOrString, IfStatement, ImportAttribute, ImportDeclaration, ImportKind,
-    ImportNamespaceSpecifier, ImportSpecifierType, LabeledStatement, ReturnStatement, Statement,
-    SwitchStatement, ThrowStatement, TryStatement, VariableDeclaration, VariableDeclarationKind,
-    VariableDeclarator, WhileStatement, WithStatement,
+    DeclareClass, DeclareExportAllDeclaration, DeclareExportDeclSpecifier,
+    DeclareExportDeclaration, DeclareFunction, DeclareInterface, DeclareModule,
+    DeclareModuleExports, DeclareOpaqueType, DeclareTypeAlias, DeclareVariable, DoWhileStatement,
+    EmptyStatement, ExportAllDeclaration, ExportDefaultDeclType, ExportDefaultDeclaration,
+    ExportKind, ExportNamedDeclaration, ExpressionStatement, Flow, FlowType, ForInStatement,
+    ForOfStatement, ForStatement, ForStmtInit, ForStmtLeft, FunctionDeclaration, IdOrString,
+    IfStatement, ImportAttribute, ImportDeclaration, ImportKind, ImportNamespaceSpecifier,
+    ImportSpecifierType, InterfaceDeclaration, LabeledStatement, ObjectTypeAnnotation, OpaqueType,
+    ReturnStatement, Statement, SwitchStatement, ThrowStatement, TryStatement, TypeAlias,
+    VariableDeclaration, VariableDeclarationKind, VariableDeclarator, WhileStatement,
+    WithStatement,
 };
 
 impl Swcify for BlockStatement {
@@ -85,12 +93,14 @@ impl Swcify for Statement {
             }
             Statement::DeclTypeAlias(v) => Stmt::Decl(Decl::from(v.swcify(ctx))),
             Statement::DeclVar(v) => Stmt::Decl(Decl::from(v.swcify(ctx))),
-            Statement::DeclExportDeclaration(v) => {
-                return ModuleItem::ModuleDecl(ModuleDecl::from(v.swcify(ctx)))
-            }
+            Statement::DeclExportDeclaration(v) => return ModuleItem::ModuleDecl(v.swcify(ctx)),
             Statement::DeclExportAllDeclaration(v) => {
                 return ModuleItem::ModuleDecl(ModuleDecl::from(v.swcify(ctx)))
             }
+            Statement::DeclOpaqueType(v) => Stmt::Decl(Decl::from(v.swcify(ctx))),
+            Statement::InterfaceDecl(v) => Stmt::Decl(Decl::from(v.swcify(ctx))),
+            Statement::OpaqueType(v) => Stmt::Decl(Decl::from(v.swcify(ctx))),
+            Statement::TypeAlias(v) => Stmt::Decl(Decl::from(v.swcify(ctx))),
             _ => {
                 todo!("swcify: {:?}", self)
             }
@@ -417,9 +427,9 @@ impl Swcify for ClassDeclaration {
                 body: self.body.swcify(ctx),
                 super_class: self.super_class.swcify(ctx),
                 is_abstract: self.is_abstract.unwrap_or_default(),
-                type_params: None,
-                super_type_params: None,
-                implements: Default::default(),
+                type_params: self.type_parameters.swcify(ctx).flatten(),
+                super_type_params: self.super_type_parameters.swcify(ctx),
+                implements: self.implements.swcify(ctx).unwrap_or_default(),
             },
         }
     }
@@ -697,64 +707,368 @@ impl Swcify for ImportNamespaceSpecifier {
 impl Swcify for DeclareClass {
     type Output = ClassDecl;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        let mut extends = self.extends.unwrap_or_default().into_iter();
+        let (super_class, super_type_params) = match extends.next() {
+            Some(e) => {
+                let e = e.swcify(ctx);
+                (Some(entity_to_expr(e.expr)), e.type_args)
+            }
+            None => (None, None),
+        };
+
+        ClassDecl {
+            ident: self.id.swcify(ctx).id,
+            declare: true,
+            class: swc_ecma_ast::Class {
+                span,
+                decorators: Default::default(),
+                body: class_members(self.body, ctx),
+                super_class,
+                is_abstract: false,
+                type_params: self
+                    .type_parameters
+                    .map(|params| type_params_from_args(params, ctx)),
+                super_type_params,
+                implements: self.implements.swcify(ctx).unwrap_or_default(),
+            },
+        }
     }
 }
 
+/// The type of a declared function is stored in the type annotation of `id`.
 impl Swcify for DeclareFunction {
     type Output = FnDecl;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        let BindingIdent { id, type_ann } = self.id.swcify(ctx);
+        let f = match type_ann.map(|ty| *ty.type_ann) {
+            Some(TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(f))) => f,
+            ty => unreachable!("declared function should have a function type: {:?}", ty),
+        };
+
+        FnDecl {
+            ident: id,
+            declare: true,
+            function: Function {
+                params: f
+                    .params
+                    .into_iter()
+                    .map(|param| Param {
+                        span: param.span(),
+                        decorators: Default::default(),
+                        pat: fn_param_to_pat(param),
+                    })
+                    .collect(),
+                decorators: Default::default(),
+                span,
+                body: None,
+                is_generator: false,
+                is_async: false,
+                type_params: f.type_params,
+                return_type: Some(f.type_ann),
+            },
+        }
     }
 }
 
 impl Swcify for DeclareInterface {
     type Output = TsInterfaceDecl;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        TsInterfaceDecl {
+            span,
+            id: self.id.swcify(ctx).id,
+            declare: true,
+            type_params: self
+                .type_parameters
+                .map(|params| type_params_from_args(params, ctx)),
+            extends: self.extends.swcify(ctx).unwrap_or_default(),
+            body: interface_body(self.body, ctx),
+        }
+    }
+}
+
+impl Swcify for InterfaceDeclaration {
+    type Output = TsInterfaceDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        TsInterfaceDecl {
+            span,
+            id: self.id.swcify(ctx).id,
+            declare: false,
+            type_params: self.type_parameters.swcify(ctx),
+            extends: self.extends.swcify(ctx).unwrap_or_default(),
+            body: interface_body(self.body, ctx),
+        }
+    }
+}
+
+fn interface_body(body: ObjectTypeAnnotation, ctx: &Context) -> TsInterfaceBody {
+    let span = ctx.span(&body.base);
+    match *body.swcify(ctx) {
+        TsType::TsTypeLit(lit) => TsInterfaceBody {
+            span,
+            body: lit.members,
+        },
+        ty => unreachable!("body of an interface cannot have spreads: {:?}", ty),
     }
 }
 
 impl Swcify for DeclareModule {
     type Output = TsModuleDecl;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsModuleDecl {
+            span: ctx.span(&self.base),
+            declare: true,
+            global: false,
+            id: match self.id {
+                IdOrString::Id(v) => TsModuleName::Ident(v.swcify(ctx).id),
+                IdOrString::String(v) => TsModuleName::Str(v.swcify(ctx)),
+            },
+            body: Some(TsNamespaceBody::TsModuleBlock(TsModuleBlock {
+                span: ctx.span(&self.body.base),
+                body: self.body.body.swcify(ctx),
+            })),
+        }
     }
 }
 
+/// `declare module.exports: T` is converted into `export = module.exports as
+/// T`.
 impl Swcify for DeclareModuleExports {
     type Output = TsExportAssignment;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        TsExportAssignment {
+            span,
+            expr: Box::new(Expr::TsAs(TsAsExpr {
+                span,
+                expr: Box::new(Expr::Member(MemberExpr {
+                    span,
+                    obj: Box::new(Expr::Ident(Ident::new(js_word!("module"), span))),
+                    prop: MemberProp::Ident(Ident::new("exports".into(), span)),
+                })),
+                type_ann: self.type_annotation.swcify(ctx).type_ann,
+            })),
+        }
     }
 }
 
 impl Swcify for DeclareTypeAlias {
     type Output = TsTypeAliasDecl;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypeAliasDecl {
+            span: ctx.span(&self.base),
+            declare: true,
+            id: self.id.swcify(ctx).id,
+            type_params: self.type_parameters.swcify(ctx),
+            type_ann: self.right.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TypeAlias {
+    type Output = TsTypeAliasDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypeAliasDecl {
+            span: ctx.span(&self.base),
+            declare: false,
+            id: self.id.swcify(ctx).id,
+            type_params: self.type_parameters.swcify(ctx),
+            type_ann: self.right.swcify(ctx),
+        }
+    }
+}
+
+/// Opaque types are converted into a type alias, which aliases the underlying
+/// type if present, and the supertype otherwise.
+impl Swcify for OpaqueType {
+    type Output = TsTypeAliasDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypeAliasDecl {
+            span: ctx.span(&self.base),
+            declare: false,
+            id: self.id.swcify(ctx).id,
+            type_params: self.type_parameters.swcify(ctx),
+            type_ann: self.impltype.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for DeclareOpaqueType {
+    type Output = TsTypeAliasDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let id = self.id.swcify(ctx).id;
+        let type_ann = match self.supertype {
+            Some(ty) => ty.swcify(ctx),
+            None => Box::new(TsType::TsKeywordType(TsKeywordType {
+                span: id.span,
+                kind: TsKeywordTypeKind::TsUnknownKeyword,
+            })),
+        };
+
+        TsTypeAliasDecl {
+            span: ctx.span(&self.base),
+            declare: true,
+            id,
+            type_params: self.type_parameters.swcify(ctx),
+            type_ann,
+        }
     }
 }
 
 impl Swcify for DeclareVariable {
     type Output = VarDecl;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        VarDecl {
+            span,
+            kind: VarDeclKind::Var,
+            declare: true,
+            decls: vec![VarDeclarator {
+                span,
+                name: Pat::Ident(self.id.swcify(ctx)),
+                init: None,
+                definite: false,
+            }],
+        }
     }
 }
 
 impl Swcify for DeclareExportDeclaration {
-    type Output = ExportDecl;
+    type Output = ModuleDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+
+        let declaration = match self.declaration {
+            Some(declaration) => *declaration,
+            None => {
+                return NamedExport {
+                    span,
+                    specifiers: self
+                        .specifiers
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|specifier| match specifier {
+                            DeclareExportDeclSpecifier::Export(v) => {
+                                ExportSpecifier::from(v.swcify(ctx))
+                            }
+                            DeclareExportDeclSpecifier::Namespace(v) => {
+                                ExportSpecifier::from(v.swcify(ctx))
+                            }
+                        })
+                        .collect(),
+                    src: self.source.swcify(ctx),
+                    type_only: false,
+                    asserts: None,
+                }
+                .into();
+            }
+        };
+
+        if !self.default.unwrap_or_default() {
+            return ExportDecl {
+                span,
+                decl: flow_decl(declaration, ctx),
+            }
+            .into();
+        }
+
+        match declaration {
+            Flow::DeclareClass(v) => {
+                let d = v.swcify(ctx);
+                ExportDefaultDecl {
+                    span,
+                    decl: DefaultDecl::Class(ClassExpr {
+                        ident: Some(d.ident),
+                        class: d.class,
+                    }),
+                }
+                .into()
+            }
+            Flow::DeclareFunction(v) => {
+                let d = v.swcify(ctx);
+                ExportDefaultDecl {
+                    span,
+                    decl: DefaultDecl::Fn(FnExpr {
+                        ident: Some(d.ident),
+                        function: d.function,
+                    }),
+                }
+                .into()
+            }
+            // `declare export default T;` is converted into `export default undefined as
+            // T`, because typescript cannot export a type as the default export.
+            declaration => ExportDefaultExpr {
+                span,
+                expr: Box::new(Expr::TsAs(TsAsExpr {
+                    span,
+                    expr: Box::new(Expr::Ident(Ident::new(js_word!("undefined"), span))),
+                    type_ann: flow_type(declaration).swcify(ctx),
+                })),
+            }
+            .into(),
+        }
+    }
+}
+
+/// Converts the declaration of `declare export`.
+fn flow_decl(flow: Flow, ctx: &Context) -> Decl {
+    match flow {
+        Flow::DeclareClass(v) => v.swcify(ctx).into(),
+        Flow::DeclareFunction(v) => v.swcify(ctx).into(),
+        Flow::DeclareInterface(v) => v.swcify(ctx).into(),
+        Flow::DeclareTypeAlias(v) => v.swcify(ctx).into(),
+        Flow::DeclareOpaqueType(v) => v.swcify(ctx).into(),
+        Flow::DeclareVariable(v) => v.swcify(ctx).into(),
+        Flow::InterfaceDeclaration(v) => v.swcify(ctx).into(),
+        Flow::OpaqueType(v) => v.swcify(ctx).into(),
+        Flow::TypeAlias(v) => v.swcify(ctx).into(),
+        _ => unreachable!("expected a declaration: {:?}", flow),
+    }
+}
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
+/// Converts the type of `declare export default`.
+fn flow_type(flow: Flow) -> FlowType {
+    match flow {
+        Flow::AnyTypeAnnotation(v) => FlowType::Any(v),
+        Flow::ArrayTypeAnnotation(v) => FlowType::Array(v),
+        Flow::BooleanTypeAnnotation(v) => FlowType::Boolean(v),
+        Flow::BooleanLiteralTypeAnnotation(v) => FlowType::BooleanLiteral(v),
+        Flow::NullLiteralTypeAnnotation(v) => FlowType::NullLiteral(v),
+        Flow::ExistsTypeAnnotation(v) => FlowType::Exists(v),
+        Flow::FunctionTypeAnnotation(v) => FlowType::Function(v),
+        Flow::GenericTypeAnnotation(v) => FlowType::Generic(v),
+        Flow::InterfaceTypeAnnotation(v) => FlowType::Interface(v),
+        Flow::IntersectionTypeAnnotation(v) => FlowType::Intersection(v),
+        Flow::MixedTypeAnnotation(v) => FlowType::Mixed(v),
+        Flow::EmptyTypeAnnotation(v) => FlowType::Empty(v),
+        Flow::NullableTypeAnnotation(v) => FlowType::Nullable(v),
+        Flow::NumberLiteralTypeAnnotation(v) => FlowType::NumerLiteral(v),
+        Flow::NumberTypeAnnotation(v) => FlowType::Number(v),
+        Flow::ObjectTypeAnnotation(v) => FlowType::Object(v),
+        Flow::StringLiteralTypeAnnotation(v) => FlowType::StringLiteral(v),
+        Flow::StringTypeAnnotation(v) => FlowType::String(v),
+        Flow::SymbolTypeAnnotation(v) => FlowType::Symbol(v),
+        Flow::ThisTypeAnnotation(v) => FlowType::This(v),
+        Flow::TupleTypeAnnotation(v) => FlowType::Tuple(v),
+        Flow::TypeofTypeAnnotation(v) => FlowType::Typeof(v),
+        Flow::UnionTypeAnnotation(v) => FlowType::Union(v),
+        Flow::VoidTypeAnnotation(v) => FlowType::Void(v),
+        _ => unreachable!("expected a type: {:?}", flow),
     }
 }
 
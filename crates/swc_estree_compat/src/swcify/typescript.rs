@@ -5,7 +5,7 @@ use swc_ecma_ast::{
     TsTypeParamDecl, TsTypeParamInstantiation,
 };
 use swc_estree_ast::{
-    Access, FlowType, SuperTypeParams, TSEntityName, TSQualifiedName, TSType, TSTypeAnnotation,
+    Access, SuperTypeParams, TSEntityName, TSQualifiedName, TSType, TSTypeAnnotation,
     TSTypeParameter, TSTypeParameterDeclaration, TSTypeParameterInstantiation, TypeAnnotOrNoop,
     TypeParamDeclOrNoop,
 };
@@ -21,20 +21,12 @@ impl Swcify for TSTypeParameterInstantiation {
     }
 }
 
-impl Swcify for FlowType {
-    type Output = !;
-
-    fn swcify(self, _: &Context) -> Self::Output {
-        unreachable!("swc does not support flow types")
-    }
-}
-
 impl Swcify for TypeParamDeclOrNoop {
     type Output = Option<TsTypeParamDecl>;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
         match self {
-            TypeParamDeclOrNoop::Flow(_) => None,
+            TypeParamDeclOrNoop::Flow(v) => Some(v.swcify(ctx)),
             TypeParamDeclOrNoop::TS(v) => Some(v.swcify(ctx)),
             TypeParamDeclOrNoop::Noop(_) => None,
         }
@@ -71,7 +63,7 @@ impl Swcify for TypeAnnotOrNoop {
 
     fn swcify(self, ctx: &Context) -> Self::Output {
         match self {
-            TypeAnnotOrNoop::Flow(_) => None,
+            TypeAnnotOrNoop::Flow(v) => Some(v.swcify(ctx)),
             TypeAnnotOrNoop::TS(v) => Some(v.swcify(ctx)),
             TypeAnnotOrNoop::Noop(_) => None,
         }
@@ -102,7 +94,7 @@ impl Swcify for SuperTypeParams {
 
     fn swcify(self, ctx: &Context) -> Self::Output {
         match self {
-            SuperTypeParams::Flow(_) => unimplemented!("flow type"),
+            SuperTypeParams::Flow(v) => v.swcify(ctx),
             SuperTypeParams::TS(v) => v.swcify(ctx),
         }
     }
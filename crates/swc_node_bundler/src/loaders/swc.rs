@@ -1,10 +1,11 @@
 use crate::loaders::json::load_json_as_module;
 use anyhow::{bail, Context, Error};
+use dashmap::DashMap;
 use helpers::Helpers;
-use std::{collections::HashMap, env, sync::Arc};
+use std::{collections::HashMap, env, path::Path, sync::Arc};
 use swc::{
     config::{GlobalInliningPassEnvs, InputSourceMap, IsModule, JscConfig, TransformConfig},
-    try_with_handler,
+    sourcemap, try_with_handler,
 };
 use swc_atoms::JsWord;
 use swc_bundler::{Load, ModuleData};
@@ -12,7 +13,7 @@ use swc_common::{
     collections::AHashMap,
     errors::{Handler, HANDLER},
     sync::Lrc,
-    FileName, DUMMY_SP,
+    FileName, SourceFile, DUMMY_SP,
 };
 use swc_ecma_ast::{EsVersion, Expr, Lit, Module, Program, Str};
 use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax};
@@ -30,11 +31,71 @@ use swc_ecma_visit::FoldWith;
 pub struct SwcLoader {
     compiler: Arc<swc::Compiler>,
     options: swc::config::Options,
+    /// Serialized input source maps of loaded files, as
+    /// [sourcemap::SourceMap] is not thread-safe.
+    input_source_maps: DashMap<FileName, Vec<u8>>,
 }
 
 impl SwcLoader {
     pub fn new(compiler: Arc<swc::Compiler>, options: swc::config::Options) -> Self {
-        SwcLoader { compiler, options }
+        SwcLoader {
+            compiler,
+            options,
+            input_source_maps: Default::default(),
+        }
+    }
+
+    /// Returns input source maps of the files loaded so far.
+    ///
+    /// This can be passed to [swc::Compiler::print_bundle] to map bundles
+    /// back to the original sources.
+    pub fn input_source_maps(&self) -> AHashMap<FileName, sourcemap::SourceMap> {
+        self.input_source_maps
+            .iter()
+            .map(|entry| {
+                let map = sourcemap::SourceMap::from_slice(entry.value())
+                    .expect("serialized source map should be valid");
+                (entry.key().clone(), map)
+            })
+            .collect()
+    }
+
+    /// Loads the input source map of `fm` if `inputSourceMap` is enabled.
+    fn load_input_source_map(&self, fm: &SourceFile) -> Result<(), Error> {
+        match &self.options.config.input_source_map {
+            InputSourceMap::Bool(false) => return Ok(()),
+            // A source map passed as a string is not applicable to all files of a bundle.
+            InputSourceMap::Str(s) if s != "inline" => return Ok(()),
+            // Files without an inline source map are allowed.
+            InputSourceMap::Str(..)
+                if !fm
+                    .src
+                    .contains("sourceMappingURL=data:application/json;base64,") =>
+            {
+                return Ok(())
+            }
+            _ => {}
+        }
+
+        let map = self
+            .compiler
+            .get_orig_src_map(fm, &self.options.config.input_source_map, true)
+            .with_context(|| format!("failed to load input source map of `{}`", fm.name))?;
+
+        if let Some(mut map) = map {
+            if let FileName::Real(path) = &fm.name {
+                if let Some(dir) = path.parent() {
+                    resolve_sources(&mut map, dir);
+                }
+            }
+
+            let mut buf = vec![];
+            map.to_writer(&mut buf)
+                .context("failed to serialize input source map")?;
+            self.input_source_maps.insert(fm.name.clone(), buf);
+        }
+
+        Ok(())
     }
 
     fn env_map(&self) -> Lrc<AHashMap<JsWord, Expr>> {
@@ -147,6 +208,8 @@ impl SwcLoader {
 
         tracing::trace!("JsLoader.load: loaded");
 
+        self.load_input_source_map(&fm)?;
+
         let program = if fm.name.to_string().contains("node_modules") {
             let program = self.compiler.parse_js(
                 fm.clone(),
@@ -268,6 +331,21 @@ impl SwcLoader {
     }
 }
 
+/// Makes relative paths in `sources` of `map` absolute, as the map is merged
+/// into the source map of a bundle.
+fn resolve_sources(map: &mut sourcemap::SourceMap, dir: &Path) {
+    for idx in 0..map.get_source_count() {
+        let resolved = match map.get_source(idx) {
+            Some(src) if !src.contains("://") && Path::new(src).is_relative() => {
+                dir.join(src).display().to_string()
+            }
+            _ => continue,
+        };
+
+        map.set_source(idx, &resolved);
+    }
+}
+
 impl Load for SwcLoader {
     fn load(&self, name: &FileName) -> Result<ModuleData, Error> {
         try_with_handler(self.compiler.cm.clone(), false, |handler| {
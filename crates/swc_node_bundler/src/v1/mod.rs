@@ -54,6 +54,11 @@ pub struct Config {
 
     #[serde(default)]
     pub preserve_symlinks: bool,
+
+    /// Emit targets of dynamic imports and modules shared by bundles as
+    /// separate bundles.
+    #[serde(default)]
+    pub code_splitting: bool,
 }
 
 impl Config {
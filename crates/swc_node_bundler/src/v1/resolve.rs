@@ -26,6 +26,14 @@ pub struct ResolveConfig {
 
     #[serde(default = "default_symlinks")]
     pub symlinks: bool,
+
+    /// Condition names used for `exports` and `imports` of package.json.
+    /// `default` always matches, and `import` or `require` matches depending on
+    /// how the module is imported.
+    ///
+    /// Defaults to the name of the target, i.e. `browser` or `node`.
+    #[serde(default)]
+    pub condition_names: Option<Vec<String>>,
 }
 
 fn default_extensions() -> Vec<JsWord> {
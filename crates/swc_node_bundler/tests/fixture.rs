@@ -3,7 +3,7 @@ use std::{
     collections::HashMap,
     fs::{create_dir_all, read_dir},
     io::{self},
-    path::PathBuf,
+    path::{Path, PathBuf},
     sync::Arc,
 };
 use swc::{config::SourceMapsConfig, resolver::environment_resolver};
@@ -26,26 +26,8 @@ fn pass(input_dir: PathBuf) {
 
     let _ = create_dir_all(entry.join("output"));
 
-    let entries = read_dir(&input_dir)
-        .unwrap()
-        .filter(|e| match e {
-            Ok(e) => e
-                .path()
-                .file_name()
-                .unwrap()
-                .to_string_lossy()
-                .starts_with("entry"),
-            _ => false,
-        })
-        .map(|e| -> Result<_, io::Error> {
-            let e = e?;
-            Ok((
-                e.file_name().to_string_lossy().to_string(),
-                FileName::Real(e.path()),
-            ))
-        })
-        .collect::<Result<HashMap<_, _>, _>>()
-        .unwrap();
+    let mut entries = HashMap::default();
+    collect_entries(&input_dir, &input_dir, &mut entries).unwrap();
 
     testing::run_test2(false, |cm, _handler| {
         let compiler = Arc::new(swc::Compiler::new(cm.clone()));
@@ -62,12 +44,13 @@ fn pass(input_dir: PathBuf) {
                 compiler.globals(),
                 cm.clone(),
                 &loader,
-                environment_resolver(TargetEnv::Node, Default::default(), false),
+                environment_resolver(TargetEnv::Node, Default::default(), false, None),
                 Config {
                     require: true,
                     disable_inliner: true,
                     module: Default::default(),
                     external_modules: NODE_BUILTINS.to_vec().into_iter().map(From::from).collect(),
+                    code_splitting: true,
                     ..Default::default()
                 },
                 Box::new(Hook),
@@ -93,6 +76,7 @@ fn pass(input_dir: PathBuf) {
                         None,
                         false,
                         Some(true.into()),
+                        None,
                     )
                     .expect("failed to print?")
                     .code;
@@ -102,10 +86,7 @@ fn pass(input_dir: PathBuf) {
                     BundleKind::Dynamic => format!("dynamic.{}.js", bundled.id).into(),
                 };
 
-                let output_path = entry
-                    .join("output")
-                    .join(name.file_name().unwrap())
-                    .with_extension("js");
+                let output_path = entry.join("output").join(name).with_extension("js");
 
                 println!("Printing {}", output_path.display());
 
@@ -140,6 +121,40 @@ fn pass(input_dir: PathBuf) {
     .expect("failed to process a module");
 }
 
+/// Files starting with `entry` are entries, named by the path relative to
+/// `input`.
+fn collect_entries(
+    input_dir: &Path,
+    dir: &Path,
+    entries: &mut HashMap<String, FileName>,
+) -> Result<(), io::Error> {
+    for e in read_dir(dir)? {
+        let path = e?.path();
+        if path.is_dir() {
+            collect_entries(input_dir, &path, entries)?;
+            continue;
+        }
+
+        if path
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("entry")
+        {
+            let name = path
+                .strip_prefix(input_dir)
+                .unwrap()
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            entries.insert(name, FileName::Real(path));
+        }
+    }
+
+    Ok(())
+}
+
 struct Hook;
 
 impl swc_bundler::Hook for Hook {
@@ -0,0 +1,5 @@
+import { log } from './log';
+
+log('main');
+
+import('./lazy').then(({ run }) => run());
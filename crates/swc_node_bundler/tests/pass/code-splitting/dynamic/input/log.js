@@ -0,0 +1,3 @@
+export function log(msg) {
+    console.log(msg);
+}
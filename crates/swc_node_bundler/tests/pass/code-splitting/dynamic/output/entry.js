@@ -0,0 +1,4 @@
+import { log } from "./log-3fas3gqa7jgpd.js";
+log('main');
+import("./lazy-19j89v3pgp7io.js").then(({ run  })=>run()
+);
@@ -0,0 +1,5 @@
+import { log } from './log';
+
+log('main');
+
+import('./pages/lazy').then(({ run }) => run());
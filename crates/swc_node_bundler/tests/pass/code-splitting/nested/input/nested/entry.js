@@ -0,0 +1 @@
+import('../pages/lazy').then(({ run }) => run());
@@ -0,0 +1,4 @@
+import { log } from "./log-3fas3gqa7jgpd.js";
+log('main');
+import("./pages/lazy-orgwzdkqvbc4.js").then(({ run  })=>run()
+);
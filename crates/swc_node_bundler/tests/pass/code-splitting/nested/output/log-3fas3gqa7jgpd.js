@@ -0,0 +1,4 @@
+function log(msg) {
+    console.log(msg);
+}
+export { log as log };
@@ -0,0 +1,2 @@
+import("../pages/lazy-orgwzdkqvbc4.js").then(({ run  })=>run()
+);
@@ -0,0 +1,5 @@
+import { log } from "../log-3fas3gqa7jgpd.js";
+function run() {
+    log('lazy');
+}
+export { run as run };
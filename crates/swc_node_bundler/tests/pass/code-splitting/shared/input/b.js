@@ -0,0 +1,5 @@
+import { button } from './button';
+
+export function render() {
+    return button('b');
+}
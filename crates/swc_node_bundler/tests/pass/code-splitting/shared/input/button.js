@@ -0,0 +1,5 @@
+import { escape } from './escape';
+
+export function button(label) {
+    return `<button>${escape(label)}</button>`;
+}
@@ -0,0 +1,6 @@
+const routes = {
+    a: () => import('./a'),
+    b: () => import('./b'),
+};
+
+routes[location.hash.slice(1)]().then((page) => page.render());
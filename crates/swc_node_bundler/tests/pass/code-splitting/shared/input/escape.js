@@ -0,0 +1,3 @@
+export function escape(s) {
+    return s.replace(/</g, '&lt;');
+}
@@ -0,0 +1,5 @@
+import { button } from "./button-c7imwvfdyrp4.js";
+function render() {
+    return button('a');
+}
+export { render as render };
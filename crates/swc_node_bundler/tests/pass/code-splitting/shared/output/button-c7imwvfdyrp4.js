@@ -0,0 +1,7 @@
+function escape(s) {
+    return s.replace(/</g, '&lt;');
+}
+function button(label) {
+    return `<button>${escape(label)}</button>`;
+}
+export { button as button };
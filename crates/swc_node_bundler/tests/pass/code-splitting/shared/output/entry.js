@@ -0,0 +1,7 @@
+const routes = {
+    a: ()=>import("./a-f9y8p5x5istn.js")
+    ,
+    b: ()=>import("./b-2of7hsfcry8js.js")
+};
+routes[location.hash.slice(1)]().then((page)=>page.render()
+);
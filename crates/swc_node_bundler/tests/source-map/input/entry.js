@@ -0,0 +1,3 @@
+import { add } from './lib';
+
+console.log(add(1, 2));
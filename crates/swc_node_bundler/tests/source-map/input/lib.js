@@ -0,0 +1,5 @@
+// Compiled from lib.ts
+export function add(a, b) {
+    return a + b;
+}
+//# sourceMappingURL=lib.js.map
@@ -0,0 +1,119 @@
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
+use swc::{
+    config::{InputSourceMap, Options},
+    resolver::environment_resolver,
+    sourcemap::SourceMap,
+};
+use swc_bundler::{Bundler, Config, Hook, ModuleRecord};
+use swc_common::{FileName, Span, GLOBALS};
+use swc_ecma_ast::{EsVersion, KeyValueProp};
+use swc_ecma_loader::TargetEnv;
+use swc_node_bundler::loaders::swc::SwcLoader;
+
+#[test]
+fn input_source_map() {
+    let input_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("source-map")
+        .join("input");
+
+    testing::run_test2(false, |cm, _handler| {
+        let compiler = Arc::new(swc::Compiler::new(cm.clone()));
+
+        GLOBALS.set(compiler.globals(), || {
+            let loader = SwcLoader::new(
+                compiler.clone(),
+                Options {
+                    swcrc: true,
+                    config: swc::config::Config {
+                        input_source_map: InputSourceMap::Bool(true),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            );
+            let mut bundler = Bundler::new(
+                compiler.globals(),
+                cm.clone(),
+                &loader,
+                environment_resolver(TargetEnv::Node, Default::default(), false, None),
+                Config {
+                    require: true,
+                    disable_inliner: true,
+                    ..Default::default()
+                },
+                Box::new(NoopHook),
+            );
+
+            let mut entries = HashMap::default();
+            entries.insert(
+                "main".to_string(),
+                FileName::Real(input_dir.join("entry.js")),
+            );
+
+            let mut modules = bundler.bundle(entries).expect("failed to bundle");
+            assert_eq!(modules.len(), 1);
+            let bundle = modules.pop().unwrap();
+
+            let output = compiler
+                .print_bundle(
+                    &bundle.module,
+                    EsVersion::Es2020,
+                    false,
+                    &loader.input_source_maps(),
+                )
+                .expect("failed to print");
+            let map = SourceMap::from_slice(output.map.unwrap().as_bytes()).unwrap();
+
+            let find = |code: &str, source: &str| {
+                let idx = output.code.find(code).unwrap();
+                let line = output.code[..idx].matches('\n').count() as u32;
+                let col = (idx - output.code[..idx].rfind('\n').map_or(0, |v| v + 1)) as u32;
+
+                let token = map.lookup_token(line, col).unwrap();
+                assert!(
+                    token.get_source().unwrap().ends_with(source),
+                    "`{}` should be mapped to {}, but got {:?}",
+                    code,
+                    source,
+                    token.get_source()
+                );
+                token
+            };
+
+            // Mapped to the original TypeScript file.
+            let token = find("return a + b", "lib.ts");
+            assert_eq!(token.get_src_line(), 1);
+            assert_eq!(token.get_src_col(), 4);
+
+            let lib_ts = map
+                .sources()
+                .position(|src| src.ends_with("lib.ts"))
+                .unwrap();
+            assert_eq!(
+                map.get_source_contents(lib_ts as u32),
+                Some(&*std::fs::read_to_string(input_dir.join("lib.ts")).unwrap())
+            );
+
+            // Files without input source maps are mapped as-is.
+            let token = find("console.log", "entry.js");
+            assert_eq!(token.get_src_line(), 2);
+            assert_eq!(token.get_src_col(), 0);
+
+            Ok(())
+        })
+    })
+    .unwrap();
+}
+
+struct NoopHook;
+
+impl Hook for NoopHook {
+    fn get_import_meta_props(
+        &self,
+        _: Span,
+        _: &ModuleRecord,
+    ) -> Result<Vec<KeyValueProp>, anyhow::Error> {
+        Ok(vec![])
+    }
+}
@@ -0,0 +1,93 @@
+use swc_common::{
+    comments::{Comment, Comments},
+    BytePos,
+};
+
+use crate::interop::{pass_to_host, read_returned_result_from_host};
+
+extern "C" {
+    fn __add_leading_comment_proxy(byte_pos: u32, bytes_ptr: i32, bytes_ptr_len: i32);
+    fn __has_leading_comments_proxy(byte_pos: u32) -> i32;
+    fn __move_leading_comments_proxy(from_byte_pos: u32, to_byte_pos: u32);
+    fn __take_leading_comments_proxy(byte_pos: u32, allocated_ret_ptr: i32) -> i32;
+    fn __get_leading_comments_proxy(byte_pos: u32, allocated_ret_ptr: i32) -> i32;
+    fn __add_trailing_comment_proxy(byte_pos: u32, bytes_ptr: i32, bytes_ptr_len: i32);
+    fn __has_trailing_comments_proxy(byte_pos: u32) -> i32;
+    fn __move_trailing_comments_proxy(from_byte_pos: u32, to_byte_pos: u32);
+    fn __take_trailing_comments_proxy(byte_pos: u32, allocated_ret_ptr: i32) -> i32;
+    fn __get_trailing_comments_proxy(byte_pos: u32, allocated_ret_ptr: i32) -> i32;
+    fn __add_pure_comment_proxy(byte_pos: u32);
+}
+
+/// An implementation of [Comments] in plugin's context, which proxies every
+/// operation into the comments of the file host is transforming.
+///
+/// If host does not have comments for the file, operations are no-op.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PluginCommentsProxy;
+
+impl Comments for PluginCommentsProxy {
+    fn add_leading(&self, pos: BytePos, cmt: Comment) {
+        self.add_leading_comments(pos, vec![cmt]);
+    }
+
+    fn add_leading_comments(&self, pos: BytePos, comments: Vec<Comment>) {
+        pass_to_host(&comments, |ptr, len| unsafe {
+            __add_leading_comment_proxy(pos.0, ptr, len)
+        });
+    }
+
+    fn has_leading(&self, pos: BytePos) -> bool {
+        unsafe { __has_leading_comments_proxy(pos.0) != 0 }
+    }
+
+    fn move_leading(&self, from: BytePos, to: BytePos) {
+        unsafe { __move_leading_comments_proxy(from.0, to.0) }
+    }
+
+    fn take_leading(&self, pos: BytePos) -> Option<Vec<Comment>> {
+        read_returned_result_from_host(|ret_ptr| unsafe {
+            __take_leading_comments_proxy(pos.0, ret_ptr)
+        })
+    }
+
+    fn get_leading(&self, pos: BytePos) -> Option<Vec<Comment>> {
+        read_returned_result_from_host(|ret_ptr| unsafe {
+            __get_leading_comments_proxy(pos.0, ret_ptr)
+        })
+    }
+
+    fn add_trailing(&self, pos: BytePos, cmt: Comment) {
+        self.add_trailing_comments(pos, vec![cmt]);
+    }
+
+    fn add_trailing_comments(&self, pos: BytePos, comments: Vec<Comment>) {
+        pass_to_host(&comments, |ptr, len| unsafe {
+            __add_trailing_comment_proxy(pos.0, ptr, len)
+        });
+    }
+
+    fn has_trailing(&self, pos: BytePos) -> bool {
+        unsafe { __has_trailing_comments_proxy(pos.0) != 0 }
+    }
+
+    fn move_trailing(&self, from: BytePos, to: BytePos) {
+        unsafe { __move_trailing_comments_proxy(from.0, to.0) }
+    }
+
+    fn take_trailing(&self, pos: BytePos) -> Option<Vec<Comment>> {
+        read_returned_result_from_host(|ret_ptr| unsafe {
+            __take_trailing_comments_proxy(pos.0, ret_ptr)
+        })
+    }
+
+    fn get_trailing(&self, pos: BytePos) -> Option<Vec<Comment>> {
+        read_returned_result_from_host(|ret_ptr| unsafe {
+            __get_trailing_comments_proxy(pos.0, ret_ptr)
+        })
+    }
+
+    fn add_pure_comment(&self, pos: BytePos) {
+        unsafe { __add_pure_comment_proxy(pos.0) }
+    }
+}
@@ -1,7 +1,16 @@
-use swc_common::{errors::Diagnostic, plugin::Serialized};
+use swc_common::{
+    errors::Diagnostic,
+    plugin::{PluginContext, Serialized},
+};
+
+use crate::{
+    comments_proxy::PluginCommentsProxy, interop::read_returned_result_from_host,
+    source_map_proxy::PluginSourceMapProxy,
+};
 
 extern "C" {
     fn __emit_diagnostics(bytes_ptr: i32, bytes_ptr_len: i32);
+    fn __get_transform_context(allocated_ret_ptr: i32) -> i32;
     fn __free(bytes_ptr: i32, size: i32) -> i32;
 }
 
@@ -29,14 +38,28 @@ impl Diagnostics {
 /// diagnostics back to the host.
 pub struct HostContext {
     pub diagnostics: Diagnostics,
+    /// Comments of the file being transformed. This can be passed to the
+    /// transforms accepting [swc_common::comments::Comments].
+    pub comments: PluginCommentsProxy,
+    /// Source map of the host, to resolve spans of the file being transformed.
+    pub source_map: PluginSourceMapProxy,
 }
 
 impl HostContext {
     pub fn new() -> HostContext {
         HostContext {
             diagnostics: Diagnostics {},
+            comments: PluginCommentsProxy,
+            source_map: PluginSourceMapProxy,
         }
     }
+
+    /// Returns metadata of the file being transformed, such as the filename
+    /// and whether it's a module.
+    pub fn transform_context(&self) -> PluginContext {
+        read_returned_result_from_host(|ret_ptr| unsafe { __get_transform_context(ret_ptr) })
+            .expect("Host should provide the transform context")
+    }
 }
 
 impl Default for HostContext {
@@ -0,0 +1,52 @@
+use std::convert::TryInto;
+
+use swc_common::plugin::Serialized;
+
+/// A struct host writes the pointer and the length of the bytes it allocated
+/// in the guest's memory space.
+#[repr(C)]
+#[derive(Default)]
+struct AllocatedBytesPtr(i32, i32);
+
+/// Serialize `value`, then pass its pointer and length to the host's imported
+/// fn.
+pub(crate) fn pass_to_host<T>(value: &T, f: impl FnOnce(i32, i32))
+where
+    T: rkyv::Serialize<rkyv::ser::serializers::AllocSerializer<512>>,
+{
+    let serialized = Serialized::serialize(value).expect("Should able to serialize value");
+    let serialized = serialized.as_ref();
+
+    f(
+        serialized.as_ptr() as _,
+        serialized
+            .len()
+            .try_into()
+            .expect("Should able to convert size of serialized value"),
+    );
+}
+
+/// Calls the host's imported fn returning a value, then reconstruct it from the
+/// bytes host allocated in the guest's memory space.
+///
+/// `f` receives a pointer host writes the pointer and the length of the
+/// allocated bytes into, and should return `0` if there's no value.
+pub(crate) fn read_returned_result_from_host<T>(f: impl FnOnce(i32) -> i32) -> Option<T>
+where
+    T: rkyv::Archive,
+    T::Archived: rkyv::Deserialize<T, rkyv::Infallible>,
+{
+    let mut allocated = AllocatedBytesPtr::default();
+
+    if f(&mut allocated as *mut AllocatedBytesPtr as _) == 0 {
+        return None;
+    }
+
+    let (ptr, len) = (allocated.0, allocated.1);
+    // Host allocated bytes via `__alloc`: take ownership of it to free once
+    // deserialization completes.
+    let bytes = unsafe { Vec::from_raw_parts(ptr as *mut u8, len as usize, len as usize) };
+    let serialized = Serialized::new_for_plugin(&bytes[..], len);
+
+    Some(Serialized::deserialize(&serialized).expect("Should able to deserialize returned value"))
+}
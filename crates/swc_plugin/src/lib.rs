@@ -1,8 +1,8 @@
 // Reexports
 pub use swc_common::{
     chain,
-    plugin::{PluginError, Serialized},
-    DUMMY_SP,
+    plugin::{PluginContext, PluginError, Serialized},
+    Mark, SyntaxContext, DUMMY_SP,
 };
 pub mod ast {
     pub use swc_atoms::*;
@@ -10,11 +10,24 @@ pub mod ast {
     pub use swc_ecma_visit::*;
 }
 
+pub mod comments {
+    pub use crate::comments_proxy::PluginCommentsProxy;
+    pub use swc_common::comments::{Comment, CommentKind, Comments};
+}
+
+pub mod source_map {
+    pub use crate::source_map_proxy::PluginSourceMapProxy;
+    pub use swc_common::{plugin::PluginLoc, BytePos, Span};
+}
+
 pub mod errors {
     pub use swc_common::errors::{Diagnostic, Level};
 }
 
+mod comments_proxy;
 mod context;
+mod interop;
+mod source_map_proxy;
 pub mod environment {
     pub use crate::context::*;
 }
@@ -0,0 +1,30 @@
+use swc_common::{plugin::PluginLoc, BytePos, Span};
+
+use crate::interop::read_returned_result_from_host;
+
+extern "C" {
+    fn __lookup_char_pos_proxy(byte_pos: u32, allocated_ret_ptr: i32) -> i32;
+    fn __span_to_snippet_proxy(span_lo: u32, span_hi: u32, allocated_ret_ptr: i32) -> i32;
+}
+
+/// Proxies operations into the [SourceMap](swc_common::SourceMap) of the host,
+/// which allows resolving spans of the file being transformed.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PluginSourceMapProxy;
+
+impl PluginSourceMapProxy {
+    /// Lookup source information about a [BytePos].
+    ///
+    /// Returns `None` for dummy positions.
+    pub fn lookup_char_pos(&self, pos: BytePos) -> Option<PluginLoc> {
+        read_returned_result_from_host(|ret_ptr| unsafe { __lookup_char_pos_proxy(pos.0, ret_ptr) })
+    }
+
+    /// Returns the source snippet as [String] corresponding to the given
+    /// [Span], or `None` if it cannot be resolved.
+    pub fn span_to_snippet(&self, span: Span) -> Option<String> {
+        read_returned_result_from_host(|ret_ptr| unsafe {
+            __span_to_snippet_proxy(span.lo.0, span.hi.0, ret_ptr)
+        })
+    }
+}
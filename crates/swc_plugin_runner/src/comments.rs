@@ -0,0 +1,158 @@
+//! Host functions backing `swc_plugin::comments::PluginCommentsProxy`.
+//!
+//! Positions are passed as raw `u32` of [BytePos]. Comments are passed as
+//! serialized `Vec<Comment>`.
+//!
+//! Functions returning comments return `0` if there's no comment. See
+//! [write_into_allocated_ret_ptr] for the details.
+use crate::{copy_bytes_into_host, trap, write_into_allocated_ret_ptr, HostEnvironment};
+use swc_common::{
+    comments::{Comment, Comments},
+    plugin::Serialized,
+    BytePos,
+};
+use swc_node_comments::SwcComments;
+use wasmer::{Exports, Function, RuntimeError, Store};
+
+/// Reads `Vec<Comment>` serialized by guest.
+fn read_comments(
+    env: &HostEnvironment,
+    bytes_ptr: i32,
+    bytes_ptr_len: i32,
+) -> Result<Vec<Comment>, RuntimeError> {
+    let memory = env.guest_memory()?;
+    let bytes = copy_bytes_into_host(memory, bytes_ptr, bytes_ptr_len)?;
+    let serialized = Serialized::new_for_plugin(&bytes[..], bytes_ptr_len);
+
+    Serialized::deserialize(&serialized)
+        .map_err(|err| trap(format!("Failed to deserialize comments: {:?}", err)))
+}
+
+/// Writes `comments` into guest's memory, and returns `1` if there was any.
+fn write_comments(
+    env: &HostEnvironment,
+    comments: Option<Vec<Comment>>,
+    ret_ptr: i32,
+) -> Result<i32, RuntimeError> {
+    let serialized = comments
+        .map(|comments| Serialized::serialize(&comments))
+        .transpose()
+        .map_err(|err| trap(format!("Failed to serialize comments: {:?}", err)))?;
+
+    write_into_allocated_ret_ptr(env, serialized, ret_ptr)
+}
+
+fn with_comments<F, Ret>(env: &HostEnvironment, op: F) -> Option<Ret>
+where
+    F: FnOnce(&SwcComments) -> Ret,
+{
+    env.comments.as_ref().map(op)
+}
+
+fn add_leading_comment_proxy(
+    env: &HostEnvironment,
+    pos: u32,
+    bytes_ptr: i32,
+    len: i32,
+) -> Result<(), RuntimeError> {
+    let comments = read_comments(env, bytes_ptr, len)?;
+    with_comments(env, |c| c.add_leading_comments(BytePos(pos), comments));
+
+    Ok(())
+}
+
+fn has_leading_comments_proxy(env: &HostEnvironment, pos: u32) -> i32 {
+    with_comments(env, |c| c.has_leading(BytePos(pos))).unwrap_or(false) as i32
+}
+
+fn move_leading_comments_proxy(env: &HostEnvironment, from: u32, to: u32) {
+    with_comments(env, |c| c.move_leading(BytePos(from), BytePos(to)));
+}
+
+fn take_leading_comments_proxy(
+    env: &HostEnvironment,
+    pos: u32,
+    ret_ptr: i32,
+) -> Result<i32, RuntimeError> {
+    let comments = with_comments(env, |c| c.take_leading(BytePos(pos))).flatten();
+    write_comments(env, comments, ret_ptr)
+}
+
+fn get_leading_comments_proxy(
+    env: &HostEnvironment,
+    pos: u32,
+    ret_ptr: i32,
+) -> Result<i32, RuntimeError> {
+    let comments = with_comments(env, |c| c.get_leading(BytePos(pos))).flatten();
+    write_comments(env, comments, ret_ptr)
+}
+
+fn add_trailing_comment_proxy(
+    env: &HostEnvironment,
+    pos: u32,
+    bytes_ptr: i32,
+    len: i32,
+) -> Result<(), RuntimeError> {
+    let comments = read_comments(env, bytes_ptr, len)?;
+    with_comments(env, |c| c.add_trailing_comments(BytePos(pos), comments));
+
+    Ok(())
+}
+
+fn has_trailing_comments_proxy(env: &HostEnvironment, pos: u32) -> i32 {
+    with_comments(env, |c| c.has_trailing(BytePos(pos))).unwrap_or(false) as i32
+}
+
+fn move_trailing_comments_proxy(env: &HostEnvironment, from: u32, to: u32) {
+    with_comments(env, |c| c.move_trailing(BytePos(from), BytePos(to)));
+}
+
+fn take_trailing_comments_proxy(
+    env: &HostEnvironment,
+    pos: u32,
+    ret_ptr: i32,
+) -> Result<i32, RuntimeError> {
+    let comments = with_comments(env, |c| c.take_trailing(BytePos(pos))).flatten();
+    write_comments(env, comments, ret_ptr)
+}
+
+fn get_trailing_comments_proxy(
+    env: &HostEnvironment,
+    pos: u32,
+    ret_ptr: i32,
+) -> Result<i32, RuntimeError> {
+    let comments = with_comments(env, |c| c.get_trailing(BytePos(pos))).flatten();
+    write_comments(env, comments, ret_ptr)
+}
+
+fn add_pure_comment_proxy(env: &HostEnvironment, pos: u32) {
+    with_comments(env, |c| c.add_pure_comment(BytePos(pos)));
+}
+
+/// Registers host functions for comments into `exports`.
+pub(crate) fn register(store: &Store, env: &HostEnvironment, exports: &mut Exports) {
+    macro_rules! register {
+        ($($name:ident),*) => {
+            $(
+                exports.insert(
+                    concat!("__", stringify!($name)),
+                    Function::new_native_with_env(store, env.clone(), $name),
+                );
+            )*
+        };
+    }
+
+    register!(
+        add_leading_comment_proxy,
+        has_leading_comments_proxy,
+        move_leading_comments_proxy,
+        take_leading_comments_proxy,
+        get_leading_comments_proxy,
+        add_trailing_comment_proxy,
+        has_trailing_comments_proxy,
+        move_trailing_comments_proxy,
+        take_trailing_comments_proxy,
+        get_trailing_comments_proxy,
+        add_pure_comment_proxy
+    );
+}
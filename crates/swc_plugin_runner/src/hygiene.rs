@@ -0,0 +1,80 @@
+//! Host functions backing the hygiene operations of `swc_common` in plugins,
+//! so marks and syntax contexts created by plugins are registered into the
+//! hygiene data of the host.
+use crate::{write_pair_into_guest, HostEnvironment};
+use swc_common::{Mark, SyntaxContext};
+use wasmer::{Exports, Function, RuntimeError, Store};
+
+fn mark_fresh_proxy(parent: u32) -> u32 {
+    Mark::fresh(Mark::from_u32(parent)).as_u32()
+}
+
+fn mark_parent_proxy(self_mark: u32) -> u32 {
+    Mark::from_u32(self_mark).parent().as_u32()
+}
+
+fn mark_is_descendant_of_proxy(self_mark: u32, ancestor: u32) -> u32 {
+    Mark::from_u32(self_mark).is_descendant_of(Mark::from_u32(ancestor)) as u32
+}
+
+fn mark_least_ancestor_proxy(a: u32, b: u32) -> u32 {
+    Mark::least_ancestor(Mark::from_u32(a), Mark::from_u32(b)).as_u32()
+}
+
+fn syntax_context_apply_mark_proxy(self_syntax_context: u32, mark: u32) -> u32 {
+    SyntaxContext::from_u32(self_syntax_context)
+        .apply_mark(Mark::from_u32(mark))
+        .as_u32()
+}
+
+/// Writes the updated syntax context and the removed mark into
+/// `allocated_ret_ptr` as two `u32`s.
+fn syntax_context_remove_mark_proxy(
+    env: &HostEnvironment,
+    self_syntax_context: u32,
+    allocated_ret_ptr: i32,
+) -> Result<(), RuntimeError> {
+    let mut ctxt = SyntaxContext::from_u32(self_syntax_context);
+    let mark = ctxt.remove_mark();
+
+    let memory = env.guest_memory()?;
+    write_pair_into_guest(memory, allocated_ret_ptr, (ctxt.as_u32(), mark.as_u32()))
+}
+
+fn syntax_context_outer_proxy(self_syntax_context: u32) -> u32 {
+    SyntaxContext::from_u32(self_syntax_context)
+        .outer()
+        .as_u32()
+}
+
+/// Registers host functions for the hygiene into `exports`.
+pub(crate) fn register(store: &Store, env: &HostEnvironment, exports: &mut Exports) {
+    exports.insert(
+        "__mark_fresh_proxy",
+        Function::new_native(store, mark_fresh_proxy),
+    );
+    exports.insert(
+        "__mark_parent_proxy",
+        Function::new_native(store, mark_parent_proxy),
+    );
+    exports.insert(
+        "__mark_is_descendant_of_proxy",
+        Function::new_native(store, mark_is_descendant_of_proxy),
+    );
+    exports.insert(
+        "__mark_least_ancestor_proxy",
+        Function::new_native(store, mark_least_ancestor_proxy),
+    );
+    exports.insert(
+        "__syntax_context_apply_mark_proxy",
+        Function::new_native(store, syntax_context_apply_mark_proxy),
+    );
+    exports.insert(
+        "__syntax_context_remove_mark_proxy",
+        Function::new_native_with_env(store, env.clone(), syntax_context_remove_mark_proxy),
+    );
+    exports.insert(
+        "__syntax_context_outer_proxy",
+        Function::new_native(store, syntax_context_outer_proxy),
+    );
+}
@@ -1,4 +1,5 @@
 use std::{
+    fmt::Display,
     path::{Path, PathBuf},
     sync::Arc,
 };
@@ -10,57 +11,186 @@ use resolve::PluginCache;
 use swc_common::{
     collections::AHashMap,
     errors::{Diagnostic, HANDLER},
-    plugin::{PluginError, Serialized},
+    plugin::{PluginContext, PluginError, Serialized},
+    SourceMap,
 };
+use swc_node_comments::SwcComments;
 use wasmer::{
-    imports, Array, Exports, Function, Instance, LazyInit, Memory, Module, Store, WasmPtr,
+    Array, Exports, Function, ImportObject, Instance, LazyInit, Memory, Module, NativeFunc,
+    RuntimeError, WasmPtr,
 };
 use wasmer_cache::{Cache, Hash};
 use wasmer_wasi::{is_wasi_module, WasiState};
 
+pub use crate::limits::PluginLimits;
+
+mod comments;
+mod hygiene;
+mod limits;
 pub mod resolve;
+mod source_map;
+
+/// Creates a trap for an invalid request from guest.
+///
+/// Host functions return this instead of panicking, so the request aborts the
+/// plugin and `msg` is reported as the error of the plugin.
+fn trap(msg: impl Display) -> RuntimeError {
+    RuntimeError::new(msg.to_string())
+}
 
-fn copy_bytes_into_host(memory: &Memory, bytes_ptr: i32, bytes_ptr_len: i32) -> Vec<u8> {
+fn copy_bytes_into_host(
+    memory: &Memory,
+    bytes_ptr: i32,
+    bytes_ptr_len: i32,
+) -> Result<Vec<u8>, RuntimeError> {
     let ptr: WasmPtr<u8, Array> = WasmPtr::new(bytes_ptr as _);
 
     // Deref & read through plugin's wasm memory space via returned ptr
     let derefed_ptr = ptr
         .deref(memory, 0, bytes_ptr_len as u32)
-        .expect("Should able to deref from given ptr");
+        .ok_or_else(|| trap("Bytes passed by the plugin are out of the bounds of its memory"))?;
 
-    derefed_ptr
+    Ok(derefed_ptr
         .iter()
         .enumerate()
         .take(bytes_ptr_len as usize)
         .map(|(_size, cell)| cell.get())
-        .collect::<Vec<u8>>()
+        .collect::<Vec<u8>>())
+}
+
+/// Copy host's serialized bytes into guest (plugin)'s memory allocated via
+/// `alloc`. Returns the pointer and the length of allocated bytes, which should
+/// be freed by whoever owns it.
+fn write_bytes_into_guest(
+    memory: &Memory,
+    alloc: &NativeFunc<u32, i32>,
+    serialized_bytes: &Serialized,
+) -> Result<(i32, i32), Error> {
+    let serialized = serialized_bytes.as_ref();
+    let serialized_len = serialized.len();
+
+    let allocated_ptr = alloc.call(serialized_len.try_into()?)?;
+
+    // Note: it's important to get a view from memory _after_ alloc completes
+    let view = memory.view::<u8>();
+
+    // loop over the Wasm memory view's bytes, assign bytes value of alignedvec from
+    // serialized
+    let ptr_start: usize = allocated_ptr.try_into()?;
+    let cells = view
+        .get(ptr_start..ptr_start + serialized_len)
+        .context("Memory allocated by `__alloc` is out of the bounds of guest memory")?;
+    for (cell, byte) in cells.iter().zip(serialized.iter()) {
+        cell.set(*byte)
+    }
+
+    Ok((allocated_ptr, serialized_len.try_into()?))
+}
+
+/// Writes `serialized` into guest's memory allocated via `__alloc`, then stores
+/// the pointer and the length of it into `allocated_ret_ptr`.
+///
+/// Returns `0` if there's nothing to return, `1` otherwise. Guest owns
+/// returned bytes and should free them.
+fn write_into_allocated_ret_ptr(
+    env: &HostEnvironment,
+    serialized: Option<Serialized>,
+    allocated_ret_ptr: i32,
+) -> Result<i32, RuntimeError> {
+    let serialized = match serialized {
+        Some(serialized) => serialized,
+        None => return Ok(0),
+    };
+
+    let memory = env.guest_memory()?;
+    let alloc = env
+        .alloc_guest_memory_ref()
+        .ok_or_else(|| trap("`__alloc` is not exported by the plugin"))?;
+
+    let (ptr, len) = write_bytes_into_guest(memory, alloc, &serialized)
+        .map_err(|err| trap(format!("Failed to write bytes into the plugin: {:?}", err)))?;
+
+    write_pair_into_guest(memory, allocated_ret_ptr, (ptr as u32, len as u32))?;
+
+    Ok(1)
+}
+
+/// Writes a pair of `u32` into `allocated_ret_ptr`, which points a `#[repr(C)]`
+/// struct of two 32-bit fields allocated by guest.
+fn write_pair_into_guest(
+    memory: &Memory,
+    allocated_ret_ptr: i32,
+    pair: (u32, u32),
+) -> Result<(), RuntimeError> {
+    let view = memory.view::<u8>();
+    let ret_ptr = allocated_ret_ptr as u32 as usize;
+    let cells = view
+        .get(ret_ptr..ret_ptr + 8)
+        .ok_or_else(|| trap("Pointer passed by the plugin is out of the bounds of its memory"))?;
+    for (cell, byte) in cells.iter().zip(
+        pair.0
+            .to_le_bytes()
+            .iter()
+            .chain(pair.1.to_le_bytes().iter()),
+    ) {
+        cell.set(*byte);
+    }
+
+    Ok(())
 }
 
 /// Set plugin's transformed result into host's enviroment.
 /// This is an `imported` fn - when we instantiate plugin module, we inject this
 /// fn into pluging's export space. Once transform completes, plugin will call
 /// this to set its result back to host.
-fn set_transform_result(env: &HostEnvironment, bytes_ptr: i32, bytes_ptr_len: i32) {
+fn set_transform_result(
+    env: &HostEnvironment,
+    bytes_ptr: i32,
+    bytes_ptr_len: i32,
+) -> Result<(), RuntimeError> {
     if let Some(memory) = env.memory_ref() {
-        (*env.transform_result.lock()) = copy_bytes_into_host(memory, bytes_ptr, bytes_ptr_len);
+        (*env.transform_result.lock()) = copy_bytes_into_host(memory, bytes_ptr, bytes_ptr_len)?;
     }
+
+    Ok(())
 }
 
-fn emit_diagnostics(env: &HostEnvironment, bytes_ptr: i32, bytes_ptr_len: i32) {
+fn emit_diagnostics(
+    env: &HostEnvironment,
+    bytes_ptr: i32,
+    bytes_ptr_len: i32,
+) -> Result<(), RuntimeError> {
     if let Some(memory) = env.memory_ref() {
         if HANDLER.is_set() {
-            HANDLER.with(|handler| {
-                let diagnostics_bytes = copy_bytes_into_host(memory, bytes_ptr, bytes_ptr_len);
-                let serialized = Serialized::new_for_plugin(&diagnostics_bytes[..], bytes_ptr_len);
-                let diagnostic = Serialized::deserialize::<Diagnostic>(&serialized)
-                    .expect("Should able to be deserialized into diagnsotic");
+            let diagnostics_bytes = copy_bytes_into_host(memory, bytes_ptr, bytes_ptr_len)?;
+            let serialized = Serialized::new_for_plugin(&diagnostics_bytes[..], bytes_ptr_len);
+            let diagnostic = Serialized::deserialize::<Diagnostic>(&serialized).map_err(|err| {
+                trap(format!(
+                    "Failed to deserialize a diagnostic of the plugin: {:?}",
+                    err
+                ))
+            })?;
 
+            HANDLER.with(|handler| {
                 let mut builder =
                     swc_common::errors::DiagnosticBuilder::new_diagnostic(handler, diagnostic);
                 builder.emit();
             })
         }
     }
+
+    Ok(())
+}
+
+/// Returns [PluginContext] of the file being transformed.
+fn get_transform_context(
+    env: &HostEnvironment,
+    allocated_ret_ptr: i32,
+) -> Result<i32, RuntimeError> {
+    let serialized = Serialized::serialize(&*env.plugin_context)
+        .map_err(|err| trap(format!("Failed to serialize PluginContext: {:?}", err)))?;
+
+    write_into_allocated_ret_ptr(env, Some(serialized), allocated_ret_ptr)
 }
 
 #[derive(wasmer::WasmerEnv, Clone)]
@@ -71,7 +201,23 @@ fn emit_diagnostics(env: &HostEnvironment, bytes_ptr: i32, bytes_ptr_len: i32) {
 struct HostEnvironment {
     #[wasmer(export)]
     memory: wasmer::LazyInit<Memory>,
+    /// `__alloc` exported by guest, to allocate memory for the values host
+    /// returns.
+    #[wasmer(export(name = "__alloc"))]
+    alloc_guest_memory: wasmer::LazyInit<NativeFunc<u32, i32>>,
     transform_result: Arc<Mutex<Vec<u8>>>,
+    /// Comments of the file being transformed, if any.
+    comments: Option<SwcComments>,
+    source_map: Arc<SourceMap>,
+    plugin_context: Arc<PluginContext>,
+}
+
+impl HostEnvironment {
+    /// Memory of guest, which is initialized when the plugin is instantiated.
+    fn guest_memory(&self) -> Result<&Memory, RuntimeError> {
+        self.memory_ref()
+            .ok_or_else(|| trap("Memory of the plugin is not initialized"))
+    }
 }
 
 /// Load plugin from specified path.
@@ -90,6 +236,10 @@ struct HostEnvironment {
 fn load_plugin(
     plugin_path: &Path,
     cache: &mut Option<PluginCache>,
+    comments: Option<SwcComments>,
+    source_map: Arc<SourceMap>,
+    plugin_context: PluginContext,
+    limits: &PluginLimits,
 ) -> Result<(Instance, Arc<Mutex<Vec<u8>>>), Error> {
     static BYTE_CACHE: Lazy<Mutex<AHashMap<PathBuf, Arc<Vec<u8>>>>> = Lazy::new(Default::default);
 
@@ -114,7 +264,7 @@ fn load_plugin(
     };
 
     // TODO: can we share store instances across each plugin binaries?
-    let wasmer_store = Store::default();
+    let wasmer_store = limits.create_store();
 
     let load_from_cache = |c: &mut PluginCache, hash: Hash| match c {
         PluginCache::File(filesystem_cache) => unsafe {
@@ -126,7 +276,10 @@ fn load_plugin(
         PluginCache::File(filesystem_cache) => filesystem_cache.store(hash, module),
     };
 
-    let hash = Hash::generate(&module_bytes);
+    let hash = match limits.cache_key() {
+        Some(key) => Hash::generate(&[&module_bytes[..], &key[..]].concat()),
+        None => Hash::generate(&module_bytes),
+    };
 
     let load_cold_wasm_bytes =
         || Module::new(&wasmer_store, module_bytes.as_ref()).context("Cannot compile plugin");
@@ -160,26 +313,44 @@ fn load_plugin(
     return match module {
         Ok(module) => {
             let transform_result: Arc<Mutex<Vec<u8>>> = Arc::new(Mutex::new(vec![]));
-            let set_transform_result_fn_decl = Function::new_native_with_env(
-                &wasmer_store,
-                HostEnvironment {
-                    memory: LazyInit::default(),
-                    transform_result: transform_result.clone(),
-                },
-                set_transform_result,
-            );
+            let host_env = HostEnvironment {
+                memory: LazyInit::default(),
+                alloc_guest_memory: LazyInit::default(),
+                transform_result: transform_result.clone(),
+                comments,
+                source_map,
+                plugin_context: Arc::new(plugin_context),
+            };
 
-            let emit_diagnostics_fn_decl = Function::new_native_with_env(
-                &wasmer_store,
-                HostEnvironment {
-                    memory: LazyInit::default(),
-                    transform_result: transform_result.clone(),
-                },
-                emit_diagnostics,
+            // This'll inject few interfaces into plugin's namespace to let plugin
+            // communicate with host
+            let mut env = Exports::new();
+            env.insert(
+                "__set_transform_result",
+                Function::new_native_with_env(
+                    &wasmer_store,
+                    host_env.clone(),
+                    set_transform_result,
+                ),
             );
+            env.insert(
+                "__emit_diagnostics",
+                Function::new_native_with_env(&wasmer_store, host_env.clone(), emit_diagnostics),
+            );
+            env.insert(
+                "__get_transform_context",
+                Function::new_native_with_env(
+                    &wasmer_store,
+                    host_env.clone(),
+                    get_transform_context,
+                ),
+            );
+            comments::register(&wasmer_store, &host_env, &mut env);
+            hygiene::register(&wasmer_store, &host_env, &mut env);
+            source_map::register(&wasmer_store, &host_env, &mut env);
 
             // Plugin binary can be either wasm32-wasi or wasm32-unknown-unknown
-            let import_object = if is_wasi_module(&module) {
+            let mut import_object = if is_wasi_module(&module) {
                 // Create the `WasiEnv`.
                 let mut wasi_env = WasiState::new(
                     plugin_path
@@ -190,26 +361,14 @@ fn load_plugin(
                 .finalize()?;
 
                 // Generate an `ImportObject` from wasi_env
-                let mut import_object = wasi_env.import_object(&module)?;
-
-                // This'll inject few interfaces into plugin's namespace to let plugin
-                // communicate with host
-                let mut env = Exports::new();
-                env.insert("__set_transform_result", set_transform_result_fn_decl);
-                env.insert("__emit_diagnostics", emit_diagnostics_fn_decl);
-                import_object.register("env", env);
-                import_object
+                wasi_env.import_object(&module)?
             }
             // Not able to detect wasi version in binary - assume plugin targets
             // wasm32-unknown-unknown
             else {
-                imports! {
-                    "env" => {
-                        "__set_transform_result" => set_transform_result_fn_decl,
-                        "__emit_diagnostics" => emit_diagnostics_fn_decl
-                    }
-                }
+                ImportObject::new()
             };
+            import_object.register("env", env);
 
             Instance::new(&module, &import_object)
                 .map(|i| (i, transform_result))
@@ -234,11 +393,21 @@ struct PluginTransformTracker {
     // Reference to the pointers succesfully allocated which'll be freed by Drop.
     allocated_ptr_vec: Vec<(i32, i32)>,
     transform_result: Arc<Mutex<Vec<u8>>>,
+    limits: PluginLimits,
 }
 
 impl PluginTransformTracker {
-    fn new(path: &Path, cache: &mut Option<PluginCache>) -> Result<PluginTransformTracker, Error> {
-        let (instance, transform_result) = load_plugin(path, cache)?;
+    fn new(
+        path: &Path,
+        cache: &mut Option<PluginCache>,
+        comments: Option<SwcComments>,
+        source_map: Arc<SourceMap>,
+        plugin_context: PluginContext,
+        limits: PluginLimits,
+    ) -> Result<PluginTransformTracker, Error> {
+        let (instance, transform_result) =
+            load_plugin(path, cache, comments, source_map, plugin_context, &limits)?;
+        limits.init_instance(&instance)?;
 
         let tracker = PluginTransformTracker {
             exported_plugin_transform: instance
@@ -253,6 +422,7 @@ impl PluginTransformTracker {
             instance,
             allocated_ptr_vec: Vec::with_capacity(3),
             transform_result,
+            limits,
         };
 
         Ok(tracker)
@@ -265,28 +435,7 @@ impl PluginTransformTracker {
         serialized_bytes: &Serialized,
     ) -> Result<(i32, i32), Error> {
         let memory = self.instance.exports.get_memory("memory")?;
-
-        let serialized = serialized_bytes.as_ref();
-        let serialized_len = serialized.len();
-
-        let allocated_ptr = self
-            .exported_plugin_alloc
-            .call(serialized_len.try_into()?)?;
-
-        // Note: it's important to get a view from memory _after_ alloc completes
-        let view = memory.view::<u8>();
-
-        // loop over the Wasm memory view's bytes, assign bytes value of alignedvec from
-        // serialized
-        let ptr_start: usize = allocated_ptr.try_into()?;
-        for (cell, byte) in view[ptr_start..ptr_start + serialized_len + 1]
-            .iter()
-            .zip(serialized.iter())
-        {
-            cell.set(*byte)
-        }
-
-        let ptr = (allocated_ptr, serialized_len.try_into()?);
+        let ptr = write_bytes_into_guest(memory, &self.exported_plugin_alloc, serialized_bytes)?;
 
         self.allocated_ptr_vec.push(ptr);
         Ok(ptr)
@@ -326,12 +475,15 @@ impl PluginTransformTracker {
         let guest_program_ptr = self.write_bytes_into_guest(program)?;
         let config_str_ptr = self.write_bytes_into_guest(config)?;
 
-        let result = self.exported_plugin_transform.call(
-            guest_program_ptr.0,
-            guest_program_ptr.1,
-            config_str_ptr.0,
-            config_str_ptr.1,
-        )?;
+        let result = self
+            .exported_plugin_transform
+            .call(
+                guest_program_ptr.0,
+                guest_program_ptr.1,
+                config_str_ptr.0,
+                config_str_ptr.1,
+            )
+            .map_err(|err| self.limits.describe_error(&self.instance, err))?;
 
         self.read_bytes_from_guest(result)
     }
@@ -339,23 +491,30 @@ impl PluginTransformTracker {
 
 impl Drop for PluginTransformTracker {
     fn drop(&mut self) {
+        // Freeing can fail if the plugin trapped (e.g. it ran out of fuel). As the
+        // instance is dropped along with its memory, errors are ignored to not
+        // abort the host.
         for ptr in self.allocated_ptr_vec.iter() {
-            self.exported_plugin_free
-                .call(ptr.0, ptr.1)
-                .expect("Failed to free memory allocated in the plugin");
+            let _ = self.exported_plugin_free.call(ptr.0, ptr.1);
         }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn apply_js_plugin(
     plugin_name: &str,
     path: &Path,
     cache: &mut Option<PluginCache>,
     config_json: Serialized,
     program: Serialized,
+    comments: Option<SwcComments>,
+    source_map: Arc<SourceMap>,
+    plugin_context: PluginContext,
+    limits: PluginLimits,
 ) -> Result<Serialized, Error> {
     (|| -> Result<_, Error> {
-        let mut transform_tracker = PluginTransformTracker::new(path, cache)?;
+        let mut transform_tracker =
+            PluginTransformTracker::new(path, cache, comments, source_map, plugin_context, limits)?;
 
         transform_tracker.transform(&program, &config_json)
     })()
@@ -0,0 +1,204 @@
+//! Resource limits applied to plugins.
+//!
+//! Memory is limited by the [Tunables] of the store, and the number of
+//! executed instructions (fuel) is limited by the [Metering] middleware, which
+//! makes the plugin trap once it runs out of fuel.
+use std::{ptr::NonNull, sync::Arc};
+
+use anyhow::Error;
+use loupe::{MemoryUsage, MemoryUsageTracker};
+use wasmer::{
+    vm::{self, MemoryError, MemoryStyle, TableStyle, VMMemoryDefinition, VMTableDefinition},
+    wasmparser::Operator,
+    BaseTunables, CompilerConfig, Cranelift, Instance, MemoryType, Pages, RuntimeError, Store,
+    TableType, Target, Tunables, Universal, WASM_PAGE_SIZE,
+};
+use wasmer_middlewares::{
+    metering::{get_remaining_points, set_remaining_points, MeteringPoints},
+    Metering,
+};
+
+/// Limits applied to each invocation of a plugin.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PluginLimits {
+    /// Maximum size of the linear memory of the plugin, in bytes.
+    pub memory: Option<u64>,
+    /// Maximum number of wasm instructions the plugin can execute for a
+    /// file.
+    pub fuel: Option<u64>,
+}
+
+impl PluginLimits {
+    /// Creates a store which enforces the limits to the modules compiled with
+    /// it.
+    pub(crate) fn create_store(&self) -> Store {
+        let mut compiler = Cranelift::default();
+        if let Some(fuel) = self.fuel {
+            compiler.push_middleware(Arc::new(Metering::new(fuel, cost)));
+        }
+        let engine = Universal::new(compiler).engine();
+
+        match self.memory_pages() {
+            Some(limit) => {
+                let tunables = LimitingTunables {
+                    limit,
+                    base: BaseTunables::for_target(&Target::default()),
+                };
+
+                Store::new_with_tunables(&engine, tunables)
+            }
+            None => Store::new(&engine),
+        }
+    }
+
+    /// Memory limit in the number of wasm pages, rounded down.
+    fn memory_pages(&self) -> Option<Pages> {
+        self.memory.map(|limit| {
+            let pages = (limit / WASM_PAGE_SIZE as u64).max(1);
+            Pages(pages.try_into().unwrap_or(u32::MAX))
+        })
+    }
+
+    /// Returns a key to distinguish compiled modules, as metering and the
+    /// memory style depending on the memory limit are a part of the compiled
+    /// code.
+    pub(crate) fn cache_key(&self) -> Option<Vec<u8>> {
+        if self.fuel.is_none() && self.memory.is_none() {
+            return None;
+        }
+
+        Some(
+            format!(
+                "swc-limits;metering={};memory-pages={:?}",
+                self.fuel.is_some(),
+                self.memory_pages().map(|pages| pages.0)
+            )
+            .into_bytes(),
+        )
+    }
+
+    /// Sets the fuel of a freshly created instance.
+    ///
+    /// The initial fuel is a part of the compiled code, so it's reset in case
+    /// the module is loaded from the cache.
+    pub(crate) fn init_instance(&self, instance: &Instance) -> Result<(), Error> {
+        if let Some(fuel) = self.fuel {
+            set_remaining_points(instance, fuel);
+        }
+
+        Ok(())
+    }
+
+    /// Adds the limit the instance exceeded, if any, to the error occurred
+    /// while running it.
+    pub(crate) fn describe_error(&self, instance: &Instance, err: RuntimeError) -> Error {
+        if let Some(fuel) = self.fuel {
+            if let MeteringPoints::Exhausted = get_remaining_points(instance) {
+                return Error::new(err)
+                    .context(format!("Plugin exceeded the fuel limit ({})", fuel));
+            }
+        }
+
+        if let (Some(limit), Ok(memory)) =
+            (self.memory_pages(), instance.exports.get_memory("memory"))
+        {
+            if memory.size() >= limit {
+                return Error::new(err).context(format!(
+                    "Plugin may have exceeded the memory limit ({} bytes)",
+                    self.memory.unwrap_or_default()
+                ));
+            }
+        }
+
+        err.into()
+    }
+}
+
+/// Charges a unit of fuel for each operator.
+fn cost(_: &Operator) -> u64 {
+    1
+}
+
+/// [Tunables] which limits the maximum size of memories.
+struct LimitingTunables {
+    limit: Pages,
+    base: BaseTunables,
+}
+
+impl LimitingTunables {
+    /// Clamps the maximum size of the memory to the limit.
+    fn adjust_memory(&self, requested: &MemoryType) -> MemoryType {
+        let mut adjusted = *requested;
+        adjusted.maximum = Some(match requested.maximum {
+            Some(maximum) if maximum < self.limit => maximum,
+            _ => self.limit,
+        });
+        adjusted
+    }
+
+    fn validate_memory(&self, ty: &MemoryType) -> Result<(), MemoryError> {
+        if ty.minimum > self.limit {
+            return Err(MemoryError::MinimumMemoryTooLarge {
+                min_requested: ty.minimum,
+                max_allowed: self.limit,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl MemoryUsage for LimitingTunables {
+    fn size_of_val(&self, tracker: &mut dyn MemoryUsageTracker) -> usize {
+        std::mem::size_of_val(&self.limit) + self.base.size_of_val(tracker)
+    }
+}
+
+impl Tunables for LimitingTunables {
+    fn memory_style(&self, memory: &MemoryType) -> MemoryStyle {
+        self.base.memory_style(&self.adjust_memory(memory))
+    }
+
+    fn table_style(&self, table: &TableType) -> TableStyle {
+        self.base.table_style(table)
+    }
+
+    fn create_host_memory(
+        &self,
+        ty: &MemoryType,
+        style: &MemoryStyle,
+    ) -> Result<Arc<dyn vm::Memory>, MemoryError> {
+        let adjusted = self.adjust_memory(ty);
+        self.validate_memory(&adjusted)?;
+        self.base.create_host_memory(&adjusted, style)
+    }
+
+    unsafe fn create_vm_memory(
+        &self,
+        ty: &MemoryType,
+        style: &MemoryStyle,
+        vm_definition_location: NonNull<VMMemoryDefinition>,
+    ) -> Result<Arc<dyn vm::Memory>, MemoryError> {
+        let adjusted = self.adjust_memory(ty);
+        self.validate_memory(&adjusted)?;
+        self.base
+            .create_vm_memory(&adjusted, style, vm_definition_location)
+    }
+
+    fn create_host_table(
+        &self,
+        ty: &TableType,
+        style: &TableStyle,
+    ) -> Result<Arc<dyn vm::Table>, String> {
+        self.base.create_host_table(ty, style)
+    }
+
+    unsafe fn create_vm_table(
+        &self,
+        ty: &TableType,
+        style: &TableStyle,
+        vm_definition_location: NonNull<VMTableDefinition>,
+    ) -> Result<Arc<dyn vm::Table>, String> {
+        self.base.create_vm_table(ty, style, vm_definition_location)
+    }
+}
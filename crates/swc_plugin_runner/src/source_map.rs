@@ -0,0 +1,90 @@
+//! Host functions backing `swc_plugin::source_map::PluginSourceMapProxy`.
+//!
+//! Positions are passed as raw `u32` of [BytePos]. Functions return `0` if
+//! the value cannot be resolved. See [write_into_allocated_ret_ptr] for the
+//! details.
+use crate::{trap, write_into_allocated_ret_ptr, HostEnvironment};
+use swc_common::{
+    plugin::{PluginLoc, Serialized},
+    sync::Lrc,
+    BytePos, SourceFile, SourceMap, Span, SyntaxContext,
+};
+use wasmer::{Exports, Function, RuntimeError, Store};
+
+/// Returns the file containing `pos`, or [None] if `pos` passed by guest is not
+/// a part of any file.
+fn source_file_of(env: &HostEnvironment, pos: BytePos) -> Option<Lrc<SourceFile>> {
+    let fm = SourceMap::lookup_source_file_in(&env.source_map.files(), pos)?;
+
+    if pos < fm.start_pos || pos > fm.end_pos {
+        return None;
+    }
+
+    Some(fm)
+}
+
+fn lookup_char_pos_proxy(
+    env: &HostEnvironment,
+    byte_pos: u32,
+    allocated_ret_ptr: i32,
+) -> Result<i32, RuntimeError> {
+    // Dummy positions are not a part of any file.
+    if byte_pos == 0 {
+        return Ok(0);
+    }
+
+    let pos = BytePos(byte_pos);
+    let fm = match source_file_of(env, pos) {
+        Some(fm) => fm,
+        None => return Ok(0),
+    };
+
+    let loc = env.source_map.lookup_char_pos_with(fm, pos);
+    let loc = PluginLoc {
+        filename: loc.file.name.to_string(),
+        line: loc.line,
+        col: loc.col.0,
+        col_display: loc.col_display,
+    };
+    let serialized = Serialized::serialize(&loc)
+        .map_err(|err| trap(format!("Failed to serialize PluginLoc: {:?}", err)))?;
+
+    write_into_allocated_ret_ptr(env, Some(serialized), allocated_ret_ptr)
+}
+
+fn span_to_snippet_proxy(
+    env: &HostEnvironment,
+    span_lo: u32,
+    span_hi: u32,
+    allocated_ret_ptr: i32,
+) -> Result<i32, RuntimeError> {
+    let span = Span::new(BytePos(span_lo), BytePos(span_hi), SyntaxContext::empty());
+    if span.is_dummy()
+        || source_file_of(env, span.lo).is_none()
+        || source_file_of(env, span.hi).is_none()
+    {
+        return Ok(0);
+    }
+
+    let serialized = env
+        .source_map
+        .span_to_snippet(span)
+        .ok()
+        .map(|snippet| Serialized::serialize(&snippet))
+        .transpose()
+        .map_err(|err| trap(format!("Failed to serialize snippet: {:?}", err)))?;
+
+    write_into_allocated_ret_ptr(env, serialized, allocated_ret_ptr)
+}
+
+/// Registers host functions for the source map into `exports`.
+pub(crate) fn register(store: &Store, env: &HostEnvironment, exports: &mut Exports) {
+    exports.insert(
+        "__lookup_char_pos_proxy",
+        Function::new_native_with_env(store, env.clone(), lookup_char_pos_proxy),
+    );
+    exports.insert(
+        "__span_to_snippet_proxy",
+        Function::new_native_with_env(store, env.clone(), span_to_snippet_proxy),
+    );
+}
@@ -4,10 +4,16 @@ use std::{
     path::{Path, PathBuf},
     process::{Command, Stdio},
 };
-use swc_common::{errors::HANDLER, plugin::Serialized, FileName};
+use swc_common::{
+    comments::Comments,
+    errors::HANDLER,
+    plugin::{PluginContext, Serialized},
+    FileName, Mark, Span,
+};
 use swc_ecma_ast::{CallExpr, Callee, EsVersion, Expr, Lit, MemberExpr, Program, Str};
 use swc_ecma_parser::{lexer::Lexer, EsConfig, Parser, StringInput, Syntax};
 use swc_ecma_visit::{Visit, VisitWith};
+use swc_node_comments::SwcComments;
 
 /// Returns the path to the built plugin
 fn build_plugin(dir: &Path) -> Result<PathBuf, Error> {
@@ -33,6 +39,7 @@ fn build_plugin(dir: &Path) -> Result<PathBuf, Error> {
 
 struct TestVisitor {
     pub plugin_transform_found: bool,
+    pub replaced_span: Option<Span>,
 }
 
 impl Visit for TestVisitor {
@@ -42,8 +49,9 @@ impl Visit for TestVisitor {
                 if let Expr::Ident(ident) = &**obj {
                     if ident.sym == *"console" {
                         let args = &*(call.args[0].expr);
-                        if let Expr::Lit(Lit::Str(Str { value, .. })) = args {
+                        if let Expr::Lit(Lit::Str(Str { value, span, .. })) = args {
                             self.plugin_transform_found = value == "changed_via_plugin";
+                            self.replaced_span = Some(*span);
                         }
                     }
                 }
@@ -81,18 +89,46 @@ fn internal() -> Result<(), Error> {
 
         let program = Serialized::serialize(&program).expect("Should serializable");
         let config = Serialized::serialize(&"{}".to_string()).expect("Should serializable");
+        let comments = SwcComments::default();
+        let top_level_mark = Mark::fresh(Mark::root());
 
-        let program_bytes =
-            swc_plugin_runner::apply_js_plugin("internal-test", &path, &mut None, config, program)
-                .expect("Plugin should apply transform");
+        let program_bytes = swc_plugin_runner::apply_js_plugin(
+            "internal-test",
+            &path,
+            &mut None,
+            config,
+            program,
+            Some(comments.clone()),
+            cm,
+            PluginContext {
+                top_level_mark,
+                ..Default::default()
+            },
+            Default::default(),
+        )
+        .expect("Plugin should apply transform");
+
+        // Plugin marks `console.log(foo)` as pure via host's comments.
+        assert!(comments.has_leading(fm.start_pos));
+        // Plugin annotates the location and the snippet of `console.log(foo)`
+        // resolved via host's source map.
+        let trailing = comments
+            .get_trailing(fm.end_pos)
+            .expect("Plugin should add a trailing comment");
+        assert_eq!(trailing[0].text, "<anon>:1:0 console.log(foo)");
 
         let program: Program =
             Serialized::deserialize(&program_bytes).expect("Should able to deserialize");
         let mut visitor = TestVisitor {
             plugin_transform_found: false,
+            replaced_span: None,
         };
         program.visit_with(&mut visitor);
 
+        // Mark created by plugin should be registered into host's hygiene data.
+        let mut ctxt = visitor.replaced_span.expect("Should have a span").ctxt;
+        assert_eq!(ctxt.remove_mark().parent(), top_level_mark);
+
         visitor
             .plugin_transform_found
             .then(|| visitor.plugin_transform_found)
@@ -120,8 +156,18 @@ fn internal() -> Result<(), Error> {
         let config = Serialized::serialize(&"{}".to_string()).expect("Should serializable");
 
         let _res = HANDLER.set(&handler, || {
-            swc_plugin_runner::apply_js_plugin("internal-test", &path, &mut None, config, program)
-                .expect("Plugin should apply transform")
+            swc_plugin_runner::apply_js_plugin(
+                "internal-test",
+                &path,
+                &mut None,
+                config,
+                program,
+                None,
+                cm.clone(),
+                PluginContext::default(),
+                Default::default(),
+            )
+            .expect("Plugin should apply transform")
         });
 
         Ok(())
@@ -152,6 +198,10 @@ fn internal() -> Result<(), Error> {
             &mut None,
             Serialized::serialize(&"{}".to_string()).expect("Should serializable"),
             serialized_program,
+            None,
+            cm.clone(),
+            PluginContext::default(),
+            Default::default(),
         )
         .expect("Plugin should apply transform");
 
@@ -162,6 +212,10 @@ fn internal() -> Result<(), Error> {
             &mut None,
             Serialized::serialize(&"{}".to_string()).expect("Should serializable"),
             serialized_program,
+            None,
+            cm,
+            PluginContext::default(),
+            Default::default(),
         )
         .expect("Plugin should apply transform");
 
@@ -169,6 +223,7 @@ fn internal() -> Result<(), Error> {
             Serialized::deserialize(&serialized_program).expect("Should able to deserialize");
         let mut visitor = TestVisitor {
             plugin_transform_found: false,
+            replaced_span: None,
         };
         program.visit_with(&mut visitor);
 
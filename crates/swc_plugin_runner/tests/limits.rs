@@ -0,0 +1,97 @@
+use std::{env, fs};
+
+use swc_common::{plugin::Serialized, FileName};
+use swc_ecma_ast::EsVersion;
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax};
+use swc_plugin_runner::PluginLimits;
+
+/// A plugin which never returns.
+const INFINITE_LOOP_PLUGIN: &str = r#"
+(module
+  (memory (export "memory") 1)
+  (func (export "__alloc") (param i32) (result i32) i32.const 0)
+  (func (export "__free") (param i32 i32) (result i32) i32.const 0)
+  (func (export "__plugin_process_impl") (param i32 i32 i32 i32) (result i32)
+    (loop $l (br $l))
+    i32.const 0))
+"#;
+
+/// A plugin which requires a large memory.
+const LARGE_MEMORY_PLUGIN: &str = r#"
+(module
+  (memory (export "memory") 64)
+  (func (export "__alloc") (param i32) (result i32) i32.const 0)
+  (func (export "__free") (param i32 i32) (result i32) i32.const 0)
+  (func (export "__plugin_process_impl") (param i32 i32 i32 i32) (result i32)
+    i32.const 0))
+"#;
+
+fn run(name: &str, plugin: &str, limits: PluginLimits) -> String {
+    let path = env::temp_dir().join(format!("swc_plugin_runner_{}.wat", name));
+    fs::write(&path, plugin).unwrap();
+
+    testing::run_test(false, |cm, _handler| {
+        let fm = cm.new_source_file(FileName::Anon, "console.log(foo)".into());
+        let lexer = Lexer::new(
+            Syntax::Es(Default::default()),
+            EsVersion::latest(),
+            StringInput::from(&*fm),
+            None,
+        );
+        let program = Parser::new_from(lexer).parse_program().unwrap();
+
+        let res = swc_plugin_runner::apply_js_plugin(
+            name,
+            &path,
+            &mut None,
+            Serialized::serialize(&"{}".to_string()).unwrap(),
+            Serialized::serialize(&program).unwrap(),
+            None,
+            cm,
+            Default::default(),
+            limits,
+        );
+
+        match res {
+            Ok(..) => panic!("Plugin should fail"),
+            Err(err) => Ok(format!("{:?}", err)),
+        }
+    })
+    .unwrap()
+}
+
+#[test]
+fn fuel_limit() {
+    let err = run(
+        "fuel_limit",
+        INFINITE_LOOP_PLUGIN,
+        PluginLimits {
+            fuel: Some(10_000),
+            ..Default::default()
+        },
+    );
+
+    assert!(
+        err.contains("Plugin exceeded the fuel limit (10000)"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn memory_limit() {
+    let err = run(
+        "memory_limit",
+        LARGE_MEMORY_PLUGIN,
+        PluginLimits {
+            memory: Some(1024 * 1024),
+            ..Default::default()
+        },
+    );
+
+    assert!(
+        err.contains("greater than the maximum allowed memory"),
+        "{}",
+        err
+    );
+}
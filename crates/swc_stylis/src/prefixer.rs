@@ -1,19 +1,134 @@
+use anyhow::{Context, Error};
+use once_cell::sync::Lazy;
+pub use preset_env_base::{
+    query::{Query, Targets},
+    version::Version,
+    BrowserData, Versions,
+};
+use serde::Deserialize;
 use std::mem::take;
-use swc_common::DUMMY_SP;
+use swc_common::{collections::AHashMap, DUMMY_SP};
 use swc_css_ast::*;
 use swc_css_utils::replace_ident;
-use swc_css_visit::{VisitMut, VisitMutWith};
+use swc_css_visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+
+mod targets;
+
+/// The last browser versions which require each prefixed property or value,
+/// taken from the `caniuse` data used by autoprefixer.
+///
+/// Prefixes not listed here are always added.
+static PREFIXES: Lazy<AHashMap<String, Versions>> = Lazy::new(|| {
+    serde_json::from_str(include_str!("prefixer/data.json"))
+        .expect("failed to parse compat data of prefixes")
+});
+
+/// Prefixes which are not supported by any browser. They are added like
+/// `stylis` does if targets are not specified.
+const UNSUPPORTED: &[&str] = &["-ms-appearance"];
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Options {
+    /// If specified, prefixes not required by the targets are not added, and
+    /// such prefixed declarations are removed.
+    ///
+    /// All prefixes are added if this is [None].
+    #[serde(default)]
+    pub targets: Option<Targets>,
+}
 
 pub fn prefixer() -> impl VisitMut {
     Prefixer::default()
 }
 
+/// Returns an error if `targets` of `options` is invalid.
+pub fn prefixer_with_options(options: Options) -> Result<impl VisitMut, Error> {
+    let env = match options.targets {
+        Some(targets) => Some(
+            preset_env_base::query::targets_to_versions(Some(targets))
+                .context("failed to parse targets of the prefixer")?,
+        ),
+        None => None,
+    };
+
+    Ok(Prefixer {
+        env,
+        ..Default::default()
+    })
+}
+
 #[derive(Default)]
 struct Prefixer {
+    env: Option<Versions>,
     in_block: bool,
     added: Vec<Declaration>,
 }
 
+impl Prefixer {
+    /// Returns true if `prefixed`, which is a prefixed property or value, is
+    /// required by the targets.
+    fn should_prefix(&self, prefixed: &str) -> bool {
+        let env = match &self.env {
+            Some(v) => v,
+            None => return true,
+        };
+
+        if UNSUPPORTED.contains(&prefixed) {
+            return false;
+        }
+
+        match PREFIXES.get(prefixed) {
+            Some(data) => targets::needs_prefix(env, data),
+            None => true,
+        }
+    }
+
+    /// Returns true if the name or the value of `n` is prefixed, and the prefix
+    /// is not required by the targets.
+    fn is_unnecessary(&self, n: &DeclarationBlockItem, has_line_clamp: bool) -> bool {
+        if self.env.is_none() {
+            return false;
+        }
+
+        let n = match n {
+            DeclarationBlockItem::Declaration(n) => n,
+            _ => return false,
+        };
+
+        let mut v = UnnecessaryPrefixFinder {
+            prefixer: self,
+            has_line_clamp,
+            found: false,
+        };
+        n.visit_with(&mut v);
+        v.found
+    }
+}
+
+/// Finds prefixed identifiers and functions which are not required by the
+/// targets.
+struct UnnecessaryPrefixFinder<'a> {
+    prefixer: &'a Prefixer,
+    /// `display: -webkit-box` is required by `-webkit-line-clamp`.
+    has_line_clamp: bool,
+    found: bool,
+}
+
+impl Visit for UnnecessaryPrefixFinder<'_> {
+    fn visit_ident(&mut self, n: &Ident) {
+        if self.has_line_clamp && &*n.value == "-webkit-box" {
+            return;
+        }
+
+        if (PREFIXES.contains_key(&*n.value) || UNSUPPORTED.contains(&&*n.value))
+            && !self.prefixer.should_prefix(&n.value)
+        {
+            self.found = true;
+        }
+    }
+}
+
 impl VisitMut for Prefixer {
     fn visit_mut_declaration(&mut self, n: &mut Declaration) {
         n.visit_mut_children_with(self);
@@ -24,54 +139,60 @@ impl VisitMut for Prefixer {
 
         macro_rules! simple {
             ($name:expr,$val:expr) => {{
-                let val = Value::Ident(Ident {
-                    span: DUMMY_SP,
-                    value: $val.into(),
-                    raw: $val.into(),
-                });
-                let name = DeclarationName::Ident(Ident {
-                    span: DUMMY_SP,
-                    value: $name.into(),
-                    raw: $name.into(),
-                });
-                self.added.push(Declaration {
-                    span: n.span,
-                    name,
-                    value: vec![val],
-                    important: n.important.clone(),
-                });
+                if self.should_prefix($name) {
+                    let val = Value::Ident(Ident {
+                        span: DUMMY_SP,
+                        value: $val.into(),
+                        raw: $val.into(),
+                    });
+                    let name = DeclarationName::Ident(Ident {
+                        span: DUMMY_SP,
+                        value: $name.into(),
+                        raw: $name.into(),
+                    });
+                    self.added.push(Declaration {
+                        span: n.span,
+                        name,
+                        value: vec![val],
+                        important: n.important.clone(),
+                    });
+                }
             }};
         }
 
         macro_rules! same_content {
             ($name:expr) => {{
-                let name = DeclarationName::Ident(Ident {
-                    span: DUMMY_SP,
-                    value: $name.into(),
-                    raw: $name.into(),
-                });
-                self.added.push(Declaration {
-                    span: n.span,
-                    name,
-                    value: n.value.clone(),
-                    important: n.important.clone(),
-                });
+                if self.should_prefix($name) {
+                    let name = DeclarationName::Ident(Ident {
+                        span: DUMMY_SP,
+                        value: $name.into(),
+                        raw: $name.into(),
+                    });
+                    self.added.push(Declaration {
+                        span: n.span,
+                        name,
+                        value: n.value.clone(),
+                        important: n.important.clone(),
+                    });
+                }
             }};
         }
 
         macro_rules! same_name {
             ($name:expr) => {{
-                let val = Ident {
-                    span: DUMMY_SP,
-                    value: $name.into(),
-                    raw: $name.into(),
-                };
-                self.added.push(Declaration {
-                    span: n.span,
-                    name: n.name.clone(),
-                    value: vec![Value::Ident(val)],
-                    important: n.important.clone(),
-                });
+                if self.should_prefix($name) {
+                    let val = Ident {
+                        span: DUMMY_SP,
+                        value: $name.into(),
+                        raw: $name.into(),
+                    };
+                    self.added.push(Declaration {
+                        span: n.span,
+                        name: n.name.clone(),
+                        value: vec![Value::Ident(val)],
+                        important: n.important.clone(),
+                    });
+                }
             }};
         }
 
@@ -173,7 +294,8 @@ impl VisitMut for Prefixer {
             "background" => {
                 if !n.value.is_empty() {
                     if let Value::Function(f) = &n.value[0] {
-                        if &*f.name.value == "image-set" {
+                        if &*f.name.value == "image-set" && self.should_prefix("-webkit-image-set")
+                        {
                             let val = Value::Function(Function {
                                 span: DUMMY_SP,
                                 name: Ident {
@@ -197,7 +319,8 @@ impl VisitMut for Prefixer {
             "background-image" => {
                 if !n.value.is_empty() {
                     if let Value::Function(f) = &n.value[0] {
-                        if let "image-set" = &*f.name.value {
+                        if &*f.name.value == "image-set" && self.should_prefix("-webkit-image-set")
+                        {
                             let val = Value::Function(Function {
                                 span: DUMMY_SP,
                                 name: Ident {
@@ -225,7 +348,7 @@ impl VisitMut for Prefixer {
                         .iter()
                         .map(|node| match node {
                             Value::Ident(Ident { value, .. }) => {
-                                if &**value == "grab" {
+                                if &**value == "grab" && self.should_prefix("-webkit-grab") {
                                     Value::Ident(Ident {
                                         span: DUMMY_SP,
                                         value: "-webkit-grab".into(),
@@ -236,7 +359,9 @@ impl VisitMut for Prefixer {
                                 }
                             }
                             Value::Function(Function { name, value, .. }) => {
-                                if &*name.value == "image-set" {
+                                if &*name.value == "image-set"
+                                    && self.should_prefix("-webkit-image-set")
+                                {
                                     Value::Function(Function {
                                         span: DUMMY_SP,
                                         name: Ident {
@@ -466,9 +591,15 @@ impl VisitMut for Prefixer {
             }
 
             "transition" => {
+                if !self.should_prefix("-webkit-transition") {
+                    return;
+                }
+
                 let mut value = n.value.clone();
 
-                replace_ident(&mut value, "transform", "-webkit-transform");
+                if self.should_prefix("-webkit-transform") {
+                    replace_ident(&mut value, "transform", "-webkit-transform");
+                }
 
                 let name = DeclarationName::Ident(Ident {
                     span: DUMMY_SP,
@@ -555,8 +686,22 @@ impl VisitMut for Prefixer {
     }
 
     fn visit_mut_declaration_block_items(&mut self, props: &mut Vec<DeclarationBlockItem>) {
+        let has_line_clamp = props.iter().any(|n| {
+            matches!(
+                n,
+                DeclarationBlockItem::Declaration(Declaration {
+                    name: DeclarationName::Ident(name),
+                    ..
+                }) if &*name.value == "-webkit-line-clamp"
+            )
+        });
+
         let mut new = vec![];
         for mut n in take(props) {
+            if self.is_unnecessary(&n, has_line_clamp) {
+                continue;
+            }
+
             n.visit_mut_with(self);
             new.extend(self.added.drain(..).map(DeclarationBlockItem::Declaration));
             new.push(n);
@@ -0,0 +1,13 @@
+use preset_env_base::Versions;
+
+/// Returns true if any of `targets` is listed in `data` with a version equal
+/// to or lower than the last version which requires a prefix.
+pub(super) fn needs_prefix(targets: &Versions, data: &Versions) -> bool {
+    targets
+        .iter()
+        .zip(data.iter())
+        .any(|((_, target), (_, last))| match (target, last) {
+            (Some(target), Some(last)) => target <= last,
+            _ => false,
+        })
+}
@@ -13,7 +13,7 @@ use swc_css_codegen::{
 };
 use swc_css_parser::{parse_file, parser::ParserConfig};
 use swc_css_visit::VisitMutWith;
-use swc_stylis::prefixer::prefixer;
+use swc_stylis::prefixer::{prefixer, prefixer_with_options, Options, Query, Targets};
 use testing::NormalizedOutput;
 
 #[test]
@@ -460,6 +460,105 @@ fn appearance() {
     );
 }
 
+#[test]
+fn targets() {
+    t_with_targets(
+        "display:flex;",
+        "ie 10",
+        "display:-ms-flexbox;display:flex;",
+    );
+    t_with_targets(
+        "display:flex;",
+        "safari 7",
+        "display:-webkit-flex;display:flex;",
+    );
+    t_with_targets("display:flex;", "chrome 90", "display:flex;");
+    t_with_targets(
+        "transform:rotate(30deg);",
+        "chrome 90, firefox 90",
+        "transform:rotate(30deg);",
+    );
+    t_with_targets(
+        "appearance:none;",
+        "chrome 80, firefox 90",
+        "-webkit-appearance:none;appearance:none;",
+    );
+    t_with_targets("appearance:none;", "ie 11", "appearance:none;");
+    t_with_targets(
+        "transition:transform 1s;",
+        "chrome 24",
+        "-webkit-transition:-webkit-transform 1s;transition:transform 1s;",
+    );
+    t_with_targets(
+        "transition:transform 1s;",
+        "chrome 30",
+        "transition:transform 1s;",
+    );
+}
+
+#[test]
+fn targets_unknown_prefix() {
+    // Prefixes without compat data are always added.
+    t_with_targets("mask:none;", "chrome 90", "-webkit-mask:none;mask:none;");
+}
+
+#[test]
+fn targets_remove_prefixed() {
+    t_with_targets(
+        "-webkit-transform:rotate(30deg);-ms-transform:rotate(30deg);transform:rotate(30deg);",
+        "chrome 90",
+        "transform:rotate(30deg);",
+    );
+    t_with_targets(
+        "display:-webkit-box;display:-webkit-flex;display:flex;",
+        "chrome 90",
+        "display:flex;",
+    );
+    t_with_targets(
+        "display:-webkit-box;-webkit-box-orient:vertical;-webkit-line-clamp:2;",
+        "chrome 90",
+        "display:-webkit-box;-webkit-box-orient:vertical;-webkit-line-clamp:2;",
+    );
+    t_with_targets(
+        "background:-webkit-linear-gradient(red,blue);background:linear-gradient(red,blue);",
+        "chrome 90",
+        "background:linear-gradient(red,blue);",
+    );
+    t_with_targets(
+        "cursor:-webkit-grab;cursor:grab;",
+        "chrome 90",
+        "cursor:grab;",
+    );
+    t_with_targets("-ms-flex-positive:1;", "ie 10", "-ms-flex-positive:1;");
+    t_with_targets("-ms-flex-positive:1;", "ie 11", "");
+}
+
+#[test]
+fn targets_versions() {
+    t_with_options(
+        "display:flex;",
+        "display:-ms-flexbox;display:flex;",
+        Options {
+            targets: Some(serde_json::from_str(r#"{ "ie": "10" }"#).unwrap()),
+        },
+    );
+    t_with_options(
+        "appearance:none;",
+        "-webkit-appearance:none;appearance:none;",
+        Options {
+            targets: Some(serde_json::from_str(r#"{ "chrome": "79" }"#).unwrap()),
+        },
+    );
+}
+
+#[test]
+fn targets_invalid() {
+    assert!(prefixer_with_options(Options {
+        targets: Some(serde_json::from_str(r#"{ "browsers": "chrome 80", "foo": "1" }"#).unwrap()),
+    })
+    .is_err());
+}
+
 #[test]
 fn error_recovery_1() {
     // This behavior is wrong, but it's what `stylis@3` does.
@@ -482,6 +581,21 @@ fn error_recovery_1() {
 
 /// Test
 fn t(src: &str, expected: &str) {
+    t_with_options(src, expected, Default::default())
+}
+
+/// Test with browser targets.
+fn t_with_targets(src: &str, targets: &str, expected: &str) {
+    t_with_options(
+        src,
+        expected,
+        Options {
+            targets: Some(Targets::Query(Query::Single(targets.into()))),
+        },
+    )
+}
+
+fn t_with_options(src: &str, expected: &str, options: Options) {
     testing::run_test2(false, |cm, handler| {
         //
         let fm = cm.new_source_file(FileName::Anon, src.to_string());
@@ -510,7 +624,7 @@ fn t(src: &str, expected: &str) {
                 value: props,
             },
         };
-        node.visit_mut_with(&mut prefixer());
+        node.visit_mut_with(&mut prefixer_with_options(options).unwrap());
 
         let mut wr = String::new();
 
@@ -518,7 +632,8 @@ fn t(src: &str, expected: &str) {
             for p in &node.block.value {
                 let mut s = String::new();
                 {
-                    let mut wr = BasicCssWriter::new(&mut s, BasicCssWriterConfig { indent: "  " });
+                    let mut wr =
+                        BasicCssWriter::new(&mut s, None, BasicCssWriterConfig { indent: "  " });
                     let mut gen = swc_css_codegen::CodeGenerator::new(
                         &mut wr,
                         CodegenConfig { minify: true },
@@ -564,7 +679,7 @@ fn fixture(input: PathBuf) {
 
         let mut s = String::new();
         {
-            let mut wr = BasicCssWriter::new(&mut s, BasicCssWriterConfig { indent: "  " });
+            let mut wr = BasicCssWriter::new(&mut s, None, BasicCssWriterConfig { indent: "  " });
             let mut gen =
                 swc_css_codegen::CodeGenerator::new(&mut wr, CodegenConfig { minify: true });
 
@@ -80,6 +80,27 @@ export interface SpackConfig {
      * Modules to exclude from bundle.
      */
     externalModules?: string[]
+
+    resolve?: ResolveConfig
+
+    /**
+     * Emit targets of dynamic imports and modules shared by bundles as
+     * separate bundles.
+     *
+     * @default false
+     */
+    codeSplitting?: boolean
+}
+
+export interface ResolveConfig {
+    /**
+     * Condition names used for `exports` and `imports` of package.json.
+     * `default` always matches, and `import` or `require` matches depending
+     * on how the module is imported.
+     *
+     * @default [target]
+     */
+    conditionNames?: string[]
 }
 
 export interface OutputConfig {
@@ -36,6 +36,18 @@ export interface JsMinifyOptions {
   outputPath?: string
 
   inlineSourcesContent?: boolean
+
+  /**
+   * Wraps the code with a CommonJS-like wrapper, and exposes `exports` of it as a
+   * global variable with this name.
+   */
+  wrap?: string
+
+  /**
+   * Wraps the code with an IIFE. Pass `"params:args"` like `"window,undefined:window"`
+   * to configure parameters and arguments of it.
+   */
+  enclose?: boolean | string
 }
 
 export interface TerserCompressOptions {
@@ -433,7 +445,7 @@ export interface Config {
  * Configuration ported from babel-preset-env
  */
 export interface EnvConfig {
-  mode?: "usage" | "entry";
+  mode?: "usage" | "entry" | "usage-pure";
   debug?: boolean;
   dynamicImport?: boolean;
 
@@ -492,7 +504,19 @@ export interface JscConfig {
 
   experimental?: {
     optimizeHygiene?: boolean,
-    keepImportAssertions?: boolean
+    keepImportAssertions?: boolean,
+    /**
+     * What to do when a plugin fails. Defaults to `failFile`.
+     */
+    pluginFailure?: "failFile" | "failBuild",
+    /**
+     * Maximum size of the memory each plugin can use, in bytes.
+     */
+    pluginMemoryLimit?: number,
+    /**
+     * Maximum number of wasm instructions each plugin can execute for a file.
+     */
+    pluginFuelLimit?: number
   },
 
   baseUrl?: string
@@ -516,7 +540,7 @@ export type JscTarget =
   | "es2021"
   | "es2022";
 
-export type ParserConfig = TsParserConfig | EsParserConfig;
+export type ParserConfig = TsParserConfig | EsParserConfig | FlowParserConfig;
 export interface TsParserConfig {
   syntax: "typescript";
   /**
@@ -533,6 +557,18 @@ export interface TsParserConfig {
   dynamicImport?: boolean;
 }
 
+export interface FlowParserConfig {
+  syntax: "flow";
+  /**
+   * Defaults to `false`.
+   */
+  jsx?: boolean;
+  /**
+   * Defaults to `false`.
+   */
+  decorators?: boolean;
+}
+
 export interface EsParserConfig {
   syntax: "ecmascript";
   /**
@@ -599,6 +635,10 @@ export interface EsParserConfig {
    * Defaults to `false`
    */
   importAssertions?: boolean;
+  /**
+   * Defaults to `false`
+   */
+  autoAccessors?: boolean;
 }
 
 /**
@@ -626,6 +666,21 @@ export interface TransformConfig {
    * https://swc.rs/docs/configuring-swc.html#jsctransformdecoratormetadata
    */
   decoratorMetadata?: boolean;
+
+  /**
+   * The version of the decorators proposal. Ignored if `legacyDecorator` is
+   * true.
+   *
+   * Defaults to `2018-09`.
+   */
+  decoratorVersion?: "2018-09" | "2022-03";
+
+  /**
+   * Inline members of `const enum`s declared in typescript files.
+   *
+   * Defaults to false.
+   */
+  inlineConstEnums?: boolean;
 }
 
 export interface ReactConfig {
@@ -719,7 +774,12 @@ export interface GlobalPassOption {
   envs?: string[];
 }
 
-export type ModuleConfig = Es6Config | CommonJsConfig | UmdConfig | AmdConfig;
+export type ModuleConfig =
+  | Es6Config
+  | CommonJsConfig
+  | UmdConfig
+  | AmdConfig
+  | SystemjsConfig;
 
 export interface BaseModuleConfig {
   /**
@@ -807,6 +867,22 @@ export interface AmdConfig extends BaseModuleConfig {
   moduleId?: string;
 }
 
+export interface SystemjsConfig {
+  type: "systemjs";
+  /**
+   * The global object used to register the module.
+   *
+   * Defaults to `System`.
+   */
+  systemGlobal?: string;
+  /**
+   * If `false`, `this` at the top level is replaced with `undefined`.
+   *
+   * Defaults to `false`.
+   */
+  allowTopLevelThis?: boolean;
+}
+
 export interface Output {
   /**
    * Transformed code
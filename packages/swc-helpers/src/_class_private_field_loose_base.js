@@ -1,4 +1,4 @@
-export default function _classPrivateFieldBase(receiver, privateKey) {
+export default function _classPrivateFieldLooseBase(receiver, privateKey) {
   if (!Object.prototype.hasOwnProperty.call(receiver, privateKey)) {
     throw new TypeError("attempted to use private field on non-instance");
   }
@@ -0,0 +1,5 @@
+var id = 0;
+
+export default function _classPrivateFieldLooseKey(name) {
+  return "__private_" + id++ + "_" + name;
+}
@@ -0,0 +1,9 @@
+import _arrayLikeToArray from './_array_like_to_array';
+
+export default function _maybeArrayLike(next, arr, i) {
+  if (arr && !Array.isArray(arr) && typeof arr.length === "number") {
+    var len = arr.length;
+    return _arrayLikeToArray(arr, i !== void 0 && i < len ? i : len);
+  }
+  return next(arr, i);
+}
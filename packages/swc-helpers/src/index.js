@@ -1,4 +1,5 @@
 export { default as applyDecoratedDescriptor } from './_apply_decorated_descriptor';
+export { default as applyDecs2203 } from './_apply_decs_2203';
 export { default as arrayLikeToArray } from './_array_like_to_array';
 export { default as arrayWithHoles } from './_array_with_holes';
 export { default as arrayWithoutHoles } from './_array_without_holes';
@@ -13,6 +14,7 @@ export { default as classCallCheck } from './_class_call_check';
 export { default as classNameTDZError } from './_class_name_tdz_error';
 export { default as classPrivateFieldGet } from './_class_private_field_get';
 export { default as classPrivateFieldLooseBase } from './_class_private_field_loose_base';
+export { default as classPrivateFieldLooseKey } from './_class_private_field_loose_key';
 export { default as classPrivateFieldSet } from './_class_private_field_set';
 export { default as classPrivateMethodGet } from './_class_private_method_get';
 export { default as classPrivateMethodSet } from './_class_private_method_set';
@@ -41,6 +43,7 @@ export { default as iterableToArray } from './_iterable_to_array';
 export { default as iterableToArrayLimit } from './_iterable_to_array_limit';
 export { default as iterableToArrayLimitLoose } from './_iterable_to_array_limit_loose';
 export { default as jsx } from './_jsx';
+export { default as maybeArrayLike } from './_maybe_array_like';
 export { default as newArrowCheck } from './_new_arrow_check';
 export { default as nonIterableRest } from './_non_iterable_rest';
 export { default as nonIterableSpread } from './_non_iterable_spread';
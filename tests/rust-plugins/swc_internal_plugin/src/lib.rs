@@ -1,17 +1,51 @@
-use swc_plugin::{ast::*, plugin_module, DUMMY_SP, errors::{Diagnostic, Level}, environment::{HostContext}};
+use swc_plugin::{
+    ast::*,
+    comments::{Comment, CommentKind, Comments, PluginCommentsProxy},
+    environment::HostContext,
+    errors::{Diagnostic, Level},
+    plugin_module,
+    source_map::PluginSourceMapProxy,
+    Mark, DUMMY_SP,
+};
 
-struct ConsoleOutputReplacer;
+struct ConsoleOutputReplacer {
+    comments: PluginCommentsProxy,
+    source_map: PluginSourceMapProxy,
+    /// Mark created via host, applied to the replaced argument.
+    mark: Mark,
+}
 
 /// An example plugin replaces any `console.log(${text})` into
-/// `console.log('changed_via_plugin')`.
+/// `console.log('changed_via_plugin')`, marks it as pure and annotates its
+/// original location. Replaced argument has a fresh mark created by host.
 impl VisitMut for ConsoleOutputReplacer {
     fn visit_mut_call_expr(&mut self, call: &mut CallExpr) {
         if let Callee::Expr(expr) = &call.callee {
             if let Expr::Member(MemberExpr { obj, .. }) = &**expr {
                 if let Expr::Ident(ident) = &**obj {
                     if ident.sym == *"console" {
+                        self.comments.add_pure_comment(call.span.lo);
+                        if let (Some(loc), Some(snippet)) = (
+                            self.source_map.lookup_char_pos(call.span.lo),
+                            self.source_map.span_to_snippet(call.span),
+                        ) {
+                            self.comments.add_trailing(
+                                call.span.hi,
+                                Comment {
+                                    kind: CommentKind::Block,
+                                    span: DUMMY_SP,
+                                    text: format!(
+                                        "{}:{}:{} {}",
+                                        loc.filename, loc.line, loc.col, snippet
+                                    ),
+                                },
+                            );
+                        }
+                        let span = DUMMY_SP.apply_mark(self.mark);
+                        // Hygiene data is looked up via host.
+                        assert_eq!(span.ctxt.outer(), self.mark);
                         call.args[0].expr = Box::new(Expr::Lit(Lit::Str(Str {
-                            span: DUMMY_SP,
+                            span,
                             has_escape: false,
                             kind: StrKind::default(),
                             value: JsWord::from("changed_via_plugin"),
@@ -35,12 +69,22 @@ impl VisitMut for ConsoleOutputReplacer {
 ///     config_str_ptr_len: i32) ->
 ///     i32 /* 0 means success */
 ///
-/// if plugin need to handle low-level ptr directly. However, there are important steps
-/// manually need to be performed like sending transformed results back to host. Refer swc_plugin_macro
-/// how does it work internally.
+/// if plugin need to handle low-level ptr directly. However, there are
+/// important steps manually need to be performed like sending transformed
+/// results back to host. Refer swc_plugin_macro how does it work internally.
 #[plugin_module]
 pub fn process(program: Program, _plugin_config: String, ctx: &HostContext) -> Program {
     let test_diag = Diagnostic::new(Level::Error, "test_diag_error_from_plugin");
     ctx.diagnostics.emit(test_diag);
-    program.fold_with(&mut as_folder(ConsoleOutputReplacer))
+
+    let top_level_mark = ctx.transform_context().top_level_mark;
+    let mark = Mark::fresh(top_level_mark);
+    assert!(mark.is_descendant_of(top_level_mark));
+    assert_eq!(Mark::least_ancestor(mark, top_level_mark), top_level_mark);
+
+    program.fold_with(&mut as_folder(ConsoleOutputReplacer {
+        comments: ctx.comments,
+        source_map: ctx.source_map,
+        mark,
+    }))
 }